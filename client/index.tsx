@@ -78,8 +78,54 @@ function App() {
       </div>
     ) : null;
   };
+  // 展示 tree shaking 的效果
+  const renderTreeShaking = (info) => {
+    if (!info) return null;
+    return (
+      <div>
+        Tree shaking:{' '}
+        <strong>
+          {info.retainedStatements}/{info.totalStatements}
+        </strong>{' '}
+        statements retained, <strong>{info.removedIdents}</strong> idents
+        removed
+      </div>
+    );
+  };
+  // 展示每个入口的关键请求链
+  const renderCriticalRequestChains = (entrypoints) => {
+    if (!entrypoints) return null;
+    return (
+      <div
+        style={{
+          position: 'fixed',
+          right: 10,
+          bottom: 10,
+          padding: '5px 10px',
+          background: '#fff',
+          border: '1px solid #aaa',
+          opacity: 0.9,
+        }}
+      >
+        {Object.values(entrypoints).map((entry: any) => {
+          const chain = entry.criticalRequestChain;
+          return (
+            <div key={entry.name}>
+              <strong>{entry.name}</strong>: {chain.depth} requests,{' '}
+              <strong>{filesize(chain.size)}</strong>
+              <div>
+                {chain.steps
+                  .map((step) => step.chunks.join(' + '))
+                  .join(' → ')}
+              </div>
+            </div>
+          );
+        })}
+      </div>
+    );
+  };
   // 格式化 module 数据到 toolTip 中
-  const getTooltipContent = (module) => {
+  const getTooltipContent = (module, treeShaking) => {
     if (!module) return null;
 
     return (
@@ -90,6 +136,7 @@ function App() {
         <br />
 
         {renderModuleSize(module, 'stat')}
+        {renderTreeShaking(treeShaking?.[module.id])}
         {module.path && (
           <div>
             Path: <strong>{module.path}</strong>
@@ -146,7 +193,7 @@ function App() {
         // 显示 tooltip
         if (group) {
           setVisible(true);
-          setToolTipContent(getTooltipContent(group));
+          setToolTipContent(getTooltipContent(group, chartData?.treeShaking));
         } else {
           setVisible(false);
         }
@@ -178,6 +225,7 @@ function App() {
     <>
       <div style={{ width: '100vw', height: '100vh' }} ref={chartRef}></div>
       <Tooltip visible={visible} content={tooltipContent} />
+      {chartData && renderCriticalRequestChains(chartData.entrypoints)}
     </>
   );
 }
@@ -16,6 +16,10 @@ impl Plugin for JsPlugin {
         "js_plugin"
     }
 
+    fn cache_key(&self) -> Option<String> {
+        Some(self.hooks.name.clone())
+    }
+
     fn build_start(&self, _context: &Arc<Context>) -> Result<()> {
         if let Some(hook) = &self.hooks.build_start {
             let (tx, rx) = mpsc::channel::<napi::Result<()>>();
@@ -49,17 +49,27 @@ pub struct BuildParams {
         preserveModules?: boolean;
         preserveModulesRoot?: string;
         skipWrite?: boolean;
+        format?: "iife" | "system";
+        compression?: false | {
+            algorithms?: Array<"gzip" | "brotli">;
+            threshold?: number;
+            extensions?: string[];
+        };
     };
     resolve?: {
        alias?: Array<[string, string]>;
        extensions?: string[];
+       dedupe?: string[];
+       conditionNames?: string[];
     };
     manifest?: false | {
         fileName: string;
         basePath: string;
+        integrity?: false | "sha256" | "sha384" | "sha512";
     };
     mode?: "development" | "production";
     define?: Record<string, string>;
+    envPrefix?: string[];
     devtool?: false | "source-map" | "inline-source-map";
     externals?: Record<
         string,
@@ -76,7 +86,7 @@ pub struct BuildParams {
             };
         }
     >;
-    copy?: string[];
+    copy?: (string | { from: string; to?: string; context?: string })[];
     codeSplitting?:
       | false
       | {
@@ -101,16 +111,37 @@ pub struct BuildParams {
               minSize?: number;
               maxSize?: number;
               priority?: number;
+              enforce?: boolean;
             }[];
           }
         };
+    chunkGroups?: {
+        name: string;
+        test: string;
+        priority?: number;
+    }[];
     providers?: Record<string, string[]>;
+    preloadChunks?: string[];
+    html?: Record<string, {
+        template: string;
+        filename?: string;
+        inject?: string;
+        variables?: Record<string, string>;
+    }>;
+    publicDir?: false | string;
     publicPath?: string;
+    rootFiles?: string[];
     inlineLimit?: number;
+    wasm?: { mode?: "async" | "sync"; syncLimit?: number };
+    json?: { lenient?: boolean };
+    assetRules?: {
+      test: string;
+      emit: "file" | "inline" | "reference";
+    }[];
     targets?: Record<string, number>;
     platform?: "node" | "browser";
-    hmr?: false | {};
-    devServer?: false | { host?: string; port?: number };
+    hmr?: false | { hotUpdateDir?: string; pingInterval?: number };
+    devServer?: false | { host?: string; port?: number; maxPortAttempts?: number; mimeTypes?: Record<string, string>; https?: { cert: string; key: string }; historyApiFallback?: boolean | { rewrites: { from: string; to: string }[] }; proxy?: Record<string, string | { target: string; changeOrigin?: boolean }>; cors?: boolean | string | { origin?: string; methods?: string[]; headers?: string[] } };
     px2rem?: false | {
         root?: number;
         propBlackList?: string[];
@@ -120,20 +151,39 @@ pub struct BuildParams {
         selectorDoubleList?: string[];
         mediaQuery?: boolean;
     };
-    stats?: boolean;
+    stats?: boolean | { modules: boolean; importRewrites?: boolean };
     hash?: boolean;
     autoCSSModules?: boolean;
     ignoreCSSParserErrors?: boolean;
     dynamicImportToRequire?: boolean;
+    dynamicRequire?: "ignore" | "warn" | "error";
     umd?: false | string;
     cjs?: boolean;
     writeToDisk?: boolean;
     transformImport?: { libraryName: string; libraryDirectory?: string; style?: boolean | string }[];
+    treeShakingLevel?: "safe" | "recommended" | "aggressive";
+    keepExports?: string[];
+    sideEffectsOverrides?: Record<string, boolean>;
+    treeShakingAnnotations?: boolean;
     clean?: boolean;
+    /** @deprecated use cache instead */
+    persistentCache?: boolean;
+    cache?: false | { dir?: string };
     nodePolyfill?: boolean;
     ignores?: string[];
     moduleIdStrategy?: "hashed" | "named";
+    chunkIds?: "hashed" | "named";
+    runtimeChunk?: false | "single" | "multiple";
     minify?: boolean;
+    jsMinify?: {
+        passes?: number;
+        ecma?: string;
+        toplevel?: boolean;
+        mangleProps?: {
+            regex: string;
+            reserved?: string[];
+        };
+    };
     _minifish?: false | {
         mapping: Record<string, string>;
         metaPath?: string;
@@ -147,7 +197,11 @@ pub struct BuildParams {
     };
     optimization?: false | {
         skipModules?: boolean;
+        concatenateModules?: boolean;
+        inlineConstants?: boolean;
+        classMethods?: boolean;
     };
+    optimizePackageImports?: boolean;
     react?: {
         runtime?: "automatic" | "classic";
         pragma?: string;
@@ -157,15 +211,36 @@ pub struct BuildParams {
     emitAssets?: boolean;
     cssModulesExportOnlyLocales?: boolean;
     inlineCSS?: false | {};
+    splitCSSMedia?: string[];
     rscServer?: false | {
         "emitCSS": boolean;
         "clientComponentTpl": string;
     };
     rscClient?: false | {
         "logServerComponent": "error" | "ignore";
+        "preserveDirectives"?: boolean;
+        "clientReferenceManifest"?: boolean;
+    };
+    differential?: false | {
+        legacyTargets: Record<string, number>;
+        modulePreloadPolyfill?: boolean;
+    };
+    sri?: false | {
+        algorithm?: "sha256" | "sha384" | "sha512";
+    };
+    crossOrigin?: false | "anonymous" | "use-credentials";
+    noEmitOnError?: boolean;
+    transform?: {
+        decorators?: {
+            legacy?: boolean;
+            emitMetadata?: boolean;
+        };
+        legalComments?: "none" | "inline" | "eof" | "external" | "linked";
+        legalCommentsFile?: "chunk" | "combined";
     };
     experimental?: {
         webpackSyntaxValidate?: string[];
+        circularRuntimeWarning?: boolean;
     };
     watch?: {
         ignoredPaths?: string[];
@@ -175,6 +250,7 @@ pub struct BuildParams {
     pub config: serde_json::Value,
     pub plugins: Vec<JsHooks>,
     pub watch: bool,
+    pub watch_build: Option<bool>,
 }
 
 #[napi(ts_return_type = r#"Promise<void>"#)]
@@ -192,11 +268,17 @@ pub fn build(env: Env, build_params: BuildParams) -> napi::Result<JsObject> {
 
     let root = std::path::PathBuf::from(&build_params.root);
     let default_config = serde_json::to_string(&build_params.config).unwrap();
-    let config = Config::new(&root, Some(&default_config), None).map_err(|e| {
+    let mut config = Config::new(&root, Some(&default_config), None).map_err(|e| {
         napi::Error::new(Status::GenericFailure, format!("Load config failed: {}", e))
     })?;
 
-    if build_params.watch {
+    let watch_build = build_params.watch_build.unwrap_or(false);
+    if watch_build {
+        config.hmr = None;
+        config.dev_server = None;
+    }
+
+    if build_params.watch || watch_build {
         let (deferred, promise) = env.create_deferred()?;
         env.execute_tokio_future(
             async move {
@@ -218,7 +300,13 @@ pub fn build(env: Env, build_params: BuildParams) -> napi::Result<JsObject> {
                 }
                 let d = DevServer::new(root.clone(), Arc::new(compiler));
                 deferred.resolve(move |env| env.get_undefined());
-                d.serve().await;
+                if watch_build {
+                    if let Err(e) = d.watch_build() {
+                        eprintln!("Error watching files: {:?}", e);
+                    }
+                } else {
+                    d.serve().await;
+                }
                 Ok(())
             },
             move |&mut _, _res| Ok(()),
@@ -227,9 +315,13 @@ pub fn build(env: Env, build_params: BuildParams) -> napi::Result<JsObject> {
     } else {
         let (deferred, promise) = env.create_deferred()?;
         rayon::spawn(move || {
-            let compiler =
-                Compiler::new(config, root.clone(), Args { watch: false }, Some(plugins))
-                    .map_err(|e| napi::Error::new(Status::GenericFailure, format!("{}", e)));
+            let compiler = Compiler::new(
+                config,
+                root.clone(),
+                Args { watch: false },
+                Some(plugins.clone()),
+            )
+            .map_err(|e| napi::Error::new(Status::GenericFailure, format!("{}", e)));
             let compiler = match compiler {
                 Ok(c) => c,
                 Err(e) => {
@@ -239,6 +331,9 @@ pub fn build(env: Env, build_params: BuildParams) -> napi::Result<JsObject> {
             };
             let ret = compiler
                 .compile()
+                .and_then(|_| {
+                    compiler.compile_legacy(Some(default_config.as_str()), None, Some(plugins))
+                })
                 .map_err(|e| napi::Error::new(Status::GenericFailure, format!("{}", e)));
             if let Err(e) = ret {
                 deferred.reject(e);
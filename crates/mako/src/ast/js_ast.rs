@@ -64,6 +64,7 @@ impl JsAst {
                 jsx,
                 decorators: true,
                 decorators_before_export: true,
+                import_attributes: true,
                 ..Default::default()
             })
         };
@@ -0,0 +1,435 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::hash::Hasher;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use swc_core::common::GLOBALS;
+use swc_core::ecma::codegen::text_writer::JsWriter;
+use swc_core::ecma::codegen::{Config as JsCodegenConfig, Emitter};
+use swc_core::ecma::transforms::base::fixer::fixer;
+use swc_core::ecma::transforms::base::hygiene::{self, hygiene_with_config};
+use swc_core::ecma::transforms::base::resolver;
+use swc_core::ecma::visit::VisitMutWith;
+use tracing::debug;
+use twox_hash::XxHash64;
+
+use crate::ast::error;
+use crate::ast::file::{Content, File, JsContent};
+use crate::ast::js_ast::JsAst;
+use crate::build::load::JS_EXTENSIONS;
+use crate::compiler::Context;
+use crate::config::Config;
+use crate::module::ModuleAst;
+
+const CACHE_FILE: &str = "modules.json";
+/// Bumped when the schema of the cache file is changed
+const CACHE_VERSION: u32 = 1;
+
+pub fn default_cache_dir() -> String {
+    "node_modules/.cache_mako".to_string()
+}
+
+/// The schema of the cache file, e.g.
+/// `{"version":1,"fingerprint":"0.1.0-..","modules":{"/path/to/a.js":{"rawHash":1,"code":".."}}}`
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct CacheData {
+    version: u32,
+    fingerprint: String,
+    modules: HashMap<String, CachedModule>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedModule {
+    pub raw_hash: u64,
+    /// The transformed code of the module
+    pub code: String,
+}
+
+/// Transform outputs of the script modules persisted between runs, a module
+/// whose loaded content is not changed skips parse and transform and is
+/// restored from the transformed code. Deps are still analyzed and resolved
+/// since they depend on the file system.
+///
+/// A hit skips every side effect of parse and transform too, so the modules
+/// whose transform has one, e.g. an emitted asset or a stats entry, are kept
+/// out of the cache with [PersistentCache::skip].
+pub struct PersistentCache {
+    path: PathBuf,
+    fingerprint: String,
+    cached: HashMap<String, CachedModule>,
+    current: Mutex<HashMap<String, CachedModule>>,
+    skipped: Mutex<HashSet<String>>,
+    hits: AtomicUsize,
+}
+
+impl PersistentCache {
+    pub fn load(root: &Path, config: &Config, plugins: &[String], watch: bool) -> Self {
+        let path = match &config.cache {
+            Some(cache) => root.join(&cache.dir),
+            None => root.join(default_cache_dir()),
+        }
+        .join(CACHE_FILE);
+        let fingerprint = Self::fingerprint(config, plugins, watch);
+        let cached = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| {
+                serde_json::from_str::<CacheData>(&content)
+                    .inspect_err(|e| debug!("persistent cache is invalid: {}", e))
+                    .ok()
+            })
+            .filter(|data| {
+                let matched = data.version == CACHE_VERSION && data.fingerprint == fingerprint;
+                if !matched {
+                    debug!("persistent cache is outdated, start a clean build");
+                }
+                matched
+            })
+            .map(|data| data.modules)
+            .unwrap_or_default();
+        Self {
+            path,
+            fingerprint,
+            cached,
+            current: Mutex::new(HashMap::new()),
+            skipped: Mutex::new(HashSet::new()),
+            hits: AtomicUsize::new(0),
+        }
+    }
+
+    // the cache is invalid once mako, the config or the plugins are changed
+    fn fingerprint(config: &Config, plugins: &[String], watch: bool) -> String {
+        let mut hasher: XxHash64 = Default::default();
+        // serialize to Value first so that the keys of the maps are sorted
+        let config = serde_json::to_value(config)
+            .map(|value| value.to_string())
+            .unwrap_or_default();
+        hasher.write(config.as_bytes());
+        // the cached modules skip the transform_js hooks of the plugins
+        for plugin in plugins {
+            hasher.write(plugin.as_bytes());
+            hasher.write_u8(0);
+        }
+        // react refresh is only enabled in watch mode
+        hasher.write_u8(watch as u8);
+        format!("{}-{:x}", env!("CARGO_PKG_VERSION"), hasher.finish())
+    }
+
+    pub fn is_cacheable(file: &File) -> bool {
+        !file.is_virtual
+            && file.search.is_empty()
+            && JS_EXTENSIONS.contains(&file.extname.as_str())
+            && matches!(file.content, Some(Content::Js(_)))
+    }
+
+    pub fn get(&self, path: &str, raw_hash: u64) -> Option<CachedModule> {
+        let cached = self
+            .cached
+            .get(path)
+            .filter(|cached| cached.raw_hash == raw_hash)?;
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        self.current
+            .lock()
+            .unwrap()
+            .insert(path.to_string(), cached.clone());
+        Some(cached.clone())
+    }
+
+    pub fn insert(&self, path: &str, ast: &JsAst, raw_hash: u64, context: &Arc<Context>) {
+        if self.skipped.lock().unwrap().contains(path) {
+            return;
+        }
+        match Self::generate(ast, context) {
+            Ok(code) => {
+                self.current
+                    .lock()
+                    .unwrap()
+                    .insert(path.to_string(), CachedModule { raw_hash, code });
+            }
+            Err(e) => debug!("skip persistent cache of {}: {}", path, e),
+        }
+    }
+
+    /// Keeps the module out of the cache, it's called while the module is
+    /// transformed so the next run transforms it again
+    pub fn skip(&self, path: &str) {
+        self.skipped.lock().unwrap().insert(path.to_string());
+    }
+
+    /// Count of the modules restored from the cache in this run
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    // only the modules of this run are written, so removed files are dropped
+    pub fn write(&self) -> Result<()> {
+        let data = CacheData {
+            version: CACHE_VERSION,
+            fingerprint: self.fingerprint.clone(),
+            modules: self.current.lock().unwrap().clone(),
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, serde_json::to_string(&data)?)?;
+        Ok(())
+    }
+
+    pub fn restore(cached: &CachedModule, file: &File, context: Arc<Context>) -> Result<ModuleAst> {
+        // the transformed code is plain js
+        let mut cached_file = file.clone();
+        cached_file.extname = "js".to_string();
+        cached_file.set_content(Content::Js(JsContent {
+            content: cached.code.clone(),
+            is_jsx: false,
+        }));
+        let mut ast = JsAst::new(&cached_file, context.clone())?;
+        GLOBALS.set(&context.meta.script.globals, || {
+            ast.ast.visit_mut_with(&mut resolver(
+                ast.unresolved_mark,
+                ast.top_level_mark,
+                false,
+            ));
+        });
+        Ok(ModuleAst::Script(ast))
+    }
+
+    fn generate(ast: &JsAst, context: &Arc<Context>) -> Result<String> {
+        let mut ast = ast.clone();
+        let cm = context.meta.script.cm.clone();
+        let comments = context.meta.script.origin_comments.read().unwrap();
+        let swc_comments = comments.get_swc_comments();
+        GLOBALS.set(&context.meta.script.globals, || {
+            // idents with the same name but different marks must be renamed
+            // before they are printed
+            ast.ast
+                .visit_mut_with(&mut hygiene_with_config(hygiene::Config {
+                    top_level_mark: ast.top_level_mark,
+                    ..Default::default()
+                }));
+            ast.ast.visit_mut_with(&mut fixer(Some(swc_comments)));
+        });
+
+        let mut buf = vec![];
+        {
+            let mut emitter = Emitter {
+                cfg: JsCodegenConfig::default().with_target(context.config.output.es_version),
+                cm: cm.clone(),
+                comments: Some(swc_comments),
+                wr: Box::new(JsWriter::new(cm, "\n", &mut buf, None)),
+            };
+            emitter.emit_module(&ast.ast).map_err(|err| {
+                anyhow!(error::GenerateError::JsGenerateError {
+                    message: err.to_string()
+                })
+            })?;
+        }
+        Ok(String::from_utf8(buf)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::sync::Arc;
+
+    use serde_json::Value;
+
+    use super::{PersistentCache, CACHE_FILE, CACHE_VERSION};
+    use crate::compiler::Compiler;
+    use crate::config::Config;
+    use crate::plugin::Plugin;
+    use crate::utils::test_helper::{setup_compiler, PluginTestBuilder};
+
+    struct VersionedPlugin(&'static str);
+
+    impl Plugin for VersionedPlugin {
+        fn name(&self) -> &str {
+            "versioned"
+        }
+
+        fn cache_key(&self) -> Option<String> {
+            Some(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn test_persistent_cache() {
+        let root = std::env::current_dir()
+            .unwrap()
+            .join("test/build/persistent-cache");
+        let _ = fs::remove_dir_all(root.join("node_modules"));
+
+        let compiler = setup_compiler("test/build/persistent-cache", false);
+        compiler.compile().unwrap();
+        let cache = compiler.context.persistent_cache.as_ref().unwrap();
+        assert_eq!(cache.hits(), 0);
+
+        // the second build restores both modules from the cache
+        let compiler = setup_compiler("test/build/persistent-cache", false);
+        compiler.compile().unwrap();
+        let cache = compiler.context.persistent_cache.as_ref().unwrap();
+        assert_eq!(cache.hits(), 2);
+        let output = fs::read_to_string(root.join("dist/index.js")).unwrap();
+        assert!(output.contains("return a + b;"));
+        assert!(output.contains("result: "));
+
+        // a different fingerprint falls back to a clean build
+        let plugins = compiler.context.plugin_driver.cache_keys();
+        let cache = PersistentCache::load(&root, &compiler.context.config, &plugins, true);
+        assert!(cache.cached.is_empty());
+
+        fs::remove_dir_all(root.join("node_modules")).unwrap();
+    }
+
+    #[test]
+    fn test_persistent_cache_dir() {
+        let config = r#"{
+            "cache": { "dir": ".cache/mako" },
+            "minify": false,
+            "mode": "production",
+            "hmr": false,
+            "optimization": false
+        }"#;
+        let compiler = PluginTestBuilder::new("persistent-cache-dir")
+            .file(
+                "src/index.js",
+                "import { foo } from './foo';\nconsole.log(foo);",
+            )
+            .file("src/foo.js", "export const foo = 1;")
+            .config(config)
+            .build();
+        compiler.compile().unwrap();
+        let root = compiler.context.root.clone();
+        let cache_file = root.join(".cache/mako").join(CACHE_FILE);
+        let data: Value = serde_json::from_str(&fs::read_to_string(&cache_file).unwrap()).unwrap();
+        assert_eq!(data["version"], CACHE_VERSION);
+        assert!(
+            data["modules"][root.join("src/foo.js").to_string_lossy().as_ref()]["rawHash"].is_u64()
+        );
+
+        // the unchanged module skips parse and transform
+        fs::write(root.join("src/foo.js"), "export const foo = 2;").unwrap();
+        let rebuild = || {
+            let config = Config::new(
+                &root,
+                Some(r#"{ "entry": { "index": "src/index.js" } }"#),
+                None,
+            )
+            .unwrap();
+            Compiler::new(config, root.clone(), Default::default(), None).unwrap()
+        };
+        let compiler = rebuild();
+        compiler.compile().unwrap();
+        assert_eq!(
+            compiler.context.persistent_cache.as_ref().unwrap().hits(),
+            1
+        );
+
+        // the cache of another version is discarded
+        let mut data: Value =
+            serde_json::from_str(&fs::read_to_string(&cache_file).unwrap()).unwrap();
+        data["version"] = (CACHE_VERSION + 1).into();
+        fs::write(&cache_file, data.to_string()).unwrap();
+        let compiler = rebuild();
+        assert!(compiler
+            .context
+            .persistent_cache
+            .as_ref()
+            .unwrap()
+            .cached
+            .is_empty());
+
+        // so is an invalid one
+        fs::write(&cache_file, "{").unwrap();
+        let compiler = rebuild();
+        assert!(compiler
+            .context
+            .persistent_cache
+            .as_ref()
+            .unwrap()
+            .cached
+            .is_empty());
+        compiler.compile().unwrap();
+    }
+
+    #[test]
+    fn test_persistent_cache_plugins() {
+        let config = r#"{
+            "cache": { "dir": ".cache/mako" },
+            "minify": false,
+            "mode": "production",
+            "hmr": false,
+            "optimization": false
+        }"#;
+        let compiler = PluginTestBuilder::new("persistent-cache-plugins")
+            .file("src/index.js", "console.log(1);")
+            .config(config)
+            .plugin(Arc::new(VersionedPlugin("1")))
+            .build();
+        compiler.compile().unwrap();
+        let root = compiler.context.root.clone();
+        let rebuild = |plugins: Vec<Arc<dyn Plugin>>| {
+            let config = Config::new(
+                &root,
+                Some(r#"{ "entry": { "index": "src/index.js" } }"#),
+                None,
+            )
+            .unwrap();
+            Compiler::new(config, root.clone(), Default::default(), Some(plugins)).unwrap()
+        };
+        let compiler = rebuild(vec![Arc::new(VersionedPlugin("1"))]);
+        compiler.compile().unwrap();
+        assert_eq!(
+            compiler.context.persistent_cache.as_ref().unwrap().hits(),
+            1
+        );
+
+        // the modules are transformed again after a plugin is upgraded or
+        // removed
+        for plugins in [
+            vec![Arc::new(VersionedPlugin("2")) as Arc<dyn Plugin>],
+            vec![],
+        ] {
+            let compiler = rebuild(plugins);
+            assert!(compiler
+                .context
+                .persistent_cache
+                .as_ref()
+                .unwrap()
+                .cached
+                .is_empty());
+        }
+    }
+
+    #[test]
+    fn test_persistent_cache_side_effects() {
+        let root = std::env::current_dir()
+            .unwrap()
+            .join("test/build/persistent-cache-side-effects");
+        let _ = fs::remove_dir_all(root.join(".cache"));
+
+        let compiler = setup_compiler("test/build/persistent-cache-side-effects", false);
+        compiler.compile().unwrap();
+
+        let compiler = setup_compiler("test/build/persistent-cache-side-effects", false);
+        compiler.compile().unwrap();
+        // only the modules without side effects are restored
+        let cache = compiler.context.persistent_cache.as_ref().unwrap();
+        assert_eq!(cache.hits(), 2);
+        let stats = &compiler.context.stats_info;
+        let clients = stats.get_rsc_client_components();
+        assert_eq!(clients.len(), 1);
+        assert_eq!(clients[0].path, "src/button.js");
+        let rewrites = stats.get_import_rewrites();
+        assert_eq!(rewrites.len(), 1);
+        assert_eq!(rewrites[0].rewritten, "foo/lib/date-picker");
+
+        fs::remove_dir_all(root.join(".cache")).unwrap();
+    }
+}
@@ -3,7 +3,9 @@ use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::{anyhow, Result};
+use glob_match::glob_match;
 use mdxjs::{compile, Options as MdxOptions};
+use serde::de::IgnoredAny;
 use serde_xml_rs::from_str as from_xml_str;
 use serde_yaml::{from_str as from_yaml_str, Value as YamlValue};
 use thiserror::Error;
@@ -12,7 +14,7 @@ use tracing::debug;
 
 use crate::ast::file::{Content, File, JsContent};
 use crate::compiler::Context;
-use crate::config::Mode;
+use crate::config::{AssetEmit, Mode};
 use crate::plugin::PluginLoadParam;
 
 #[derive(Debug, Error)]
@@ -27,11 +29,13 @@ enum LoadError {
     ToSvgrError { path: String, reason: String },
     #[error("Compile md error: {path:?}, reason: {reason:?}")]
     CompileMdError { path: String, reason: String },
+    #[error("Parse json error: {path:?}, reason: {reason:?}")]
+    ParseJsonError { path: String, reason: String },
 }
 
 pub const JS_EXTENSIONS: [&str; 6] = ["js", "jsx", "ts", "tsx", "cjs", "mjs"];
 const CSS_EXTENSIONS: [&str; 1] = ["css"];
-const JSON_EXTENSIONS: [&str; 2] = ["json", "json5"];
+const JSON_EXTENSIONS: [&str; 3] = ["json", "json5", "jsonc"];
 const YAML_EXTENSIONS: [&str; 2] = ["yaml", "yml"];
 const XML_EXTENSIONS: [&str; 1] = ["xml"];
 const WASM_EXTENSIONS: [&str; 1] = ["wasm"];
@@ -74,6 +78,34 @@ export function moduleToDom(css) {
             }));
         }
 
+        // virtual:css_sheet:runtime
+        if file.path.to_str().unwrap() == "virtual:css_sheet:runtime" {
+            return Ok(Content::Js(JsContent {
+                content: r#"
+export function createStyleSheet(css) {
+    if (typeof CSSStyleSheet !== 'undefined' && 'replaceSync' in CSSStyleSheet.prototype) {
+        var sheet = new CSSStyleSheet();
+        sheet.replaceSync(css);
+        return sheet;
+    }
+    // shim for environments without constructable stylesheets
+    return {
+        cssText: css,
+        replaceSync: function (text) {
+            this.cssText = text;
+        },
+        replace: function (text) {
+            this.cssText = text;
+            return Promise.resolve(this);
+        },
+    };
+}
+                                "#
+                .to_string(),
+                ..Default::default()
+            }));
+        }
+
         // file exists check must after virtual modules handling
         if !file.pathname.exists() || !file.pathname.is_file() {
             return Err(anyhow!(LoadError::FileNotFound {
@@ -111,6 +143,15 @@ export function moduleToDom(css) {
                 );
                 return Ok(Content::Js(JsContent { content, is_jsx }));
             }
+            // entry with ?reload, when hmr is disabled in dev server
+            if file.is_entry && file.has_param("reload") {
+                let content = format!(
+                    "{}\nmodule.exports = require(\"{}\");\n",
+                    include_str!("../runtime/runtime_reload_entry.js"),
+                    file.pathname.to_string_lossy(),
+                );
+                return Ok(Content::Js(JsContent { content, is_jsx }));
+            }
             let content = FileSystem::read_file(&file.pathname)?;
             return Ok(Content::Js(JsContent { content, is_jsx }));
         }
@@ -179,6 +220,15 @@ export function moduleToDom(css) {
         }
 
         // wasm
+        if Self::is_sync_wasm(file, &context) {
+            return Ok(Content::Js(JsContent {
+                content: format!(
+                    "module.exports = require._interopreRequireWasmSync(exports, \"{}\")",
+                    file.get_base64()?
+                ),
+                ..Default::default()
+            }));
+        }
         if WASM_EXTENSIONS.contains(&file.extname.as_str()) {
             let final_file_name = format!(
                 "{}.{}.{}",
@@ -224,6 +274,19 @@ export function moduleToDom(css) {
         // json
         if JSON_EXTENSIONS.contains(&file.extname.as_str()) {
             let content = FileSystem::read_file(&file.pathname)?;
+            // the relaxed formats are valid js already, they are only checked
+            // so that a json file can't run code, JSON5 is a superset of the
+            // json with comments
+            let data = content.trim_start_matches('\u{feff}');
+            let checked = if file.extname == "json" && !context.config.json.lenient {
+                serde_json::from_str::<IgnoredAny>(data).map_err(|err| err.to_string())
+            } else {
+                json5::from_str::<IgnoredAny>(data).map_err(|err| err.to_string())
+            };
+            checked.map_err(|reason| LoadError::ParseJsonError {
+                path: file.path.to_string_lossy().to_string(),
+                reason,
+            })?;
             return Ok(Content::Js(JsContent {
                 content: format!("module.exports = {}", content),
                 ..Default::default()
@@ -238,6 +301,15 @@ export function moduleToDom(css) {
         }))
     }
 
+    // the small wasm modules are inlined and instantiated synchronously with
+    // `wasm.mode: "sync"`, so they are not async modules
+    pub fn is_sync_wasm(file: &File, context: &Arc<Context>) -> bool {
+        WASM_EXTENSIONS.contains(&file.extname.as_str())
+            && file
+                .get_file_size()
+                .is_ok_and(|size| context.config.wasm.is_sync(size))
+    }
+
     pub fn handle_asset(
         file: &File,
         inject_public_path: bool,
@@ -249,26 +321,36 @@ export function moduleToDom(css) {
             .map_err(|_| LoadError::ReadFileSizeError {
                 path: file.path.to_string_lossy().to_string(),
             })?;
-        let emit_assets = || -> Result<String> {
-            let final_file_name = Self::emit_asset(file, context.clone());
+        let url = |final_file_name: String| {
             if inject_public_path {
-                Ok(format!("`${{require.publicPath}}{}`", final_file_name))
+                format!("`${{require.publicPath}}{}`", final_file_name)
             } else {
-                Ok(final_file_name)
+                final_file_name
             }
         };
+        let emit_assets = || -> Result<String> { Ok(url(Self::emit_asset(file, context.clone()))) };
+        let inline = |base64: String| {
+            if inject_public_path {
+                format!("\"{}\"", base64)
+            } else {
+                base64
+            }
+        };
+
+        // the rules of assetRules win over inlineLimit
+        match Self::asset_emit(file, &context) {
+            Some(AssetEmit::File) => return emit_assets(),
+            Some(AssetEmit::Inline) => return Ok(inline(file.get_base64()?)),
+            Some(AssetEmit::Reference) => return Ok(url(Self::asset_file_name(file))),
+            None => {}
+        }
+
         if !limit || file_size > context.config.inline_limit.try_into().unwrap() {
             emit_assets()
         } else {
             let base64_result = file.get_base64();
             match base64_result {
-                Ok(base64) => {
-                    if inject_public_path {
-                        Ok(format!("\"{}\"", base64))
-                    } else {
-                        Ok(base64)
-                    }
-                }
+                Ok(base64) => Ok(inline(base64)),
                 Err(_) => emit_assets(),
             }
         }
@@ -276,14 +358,29 @@ export function moduleToDom(css) {
 
     pub fn emit_asset(file: &File, context: Arc<Context>) -> String {
         let path = file.pathname.to_string_lossy().to_string();
-        let final_file_name = format!(
+        let final_file_name = Self::asset_file_name(file);
+        context.emit_assets(path, final_file_name.clone());
+        final_file_name
+    }
+
+    fn asset_file_name(file: &File) -> String {
+        format!(
             "{}.{}.{}",
             file.get_file_stem(),
             file.get_content_hash().unwrap(),
             file.extname
-        );
-        context.emit_assets(path, final_file_name.clone());
-        final_file_name
+        )
+    }
+
+    // the first matched rule of assetRules
+    fn asset_emit(file: &File, context: &Arc<Context>) -> Option<AssetEmit> {
+        let path = file.relative_path.to_string_lossy();
+        context
+            .config
+            .asset_rules
+            .iter()
+            .find(|rule| glob_match(rule.test.trim_start_matches("./"), &path))
+            .map(|rule| rule.emit)
     }
 }
 
@@ -297,3 +394,59 @@ impl FileSystem {
         Ok(String::from_utf8_lossy(&buf).to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::utils::test_helper::setup_compiler;
+
+    #[test]
+    fn test_asset_rules() {
+        let compiler = setup_compiler("test/build/asset-rules", false);
+        compiler.compile().unwrap();
+        let output_path = &compiler.context.config.output.path;
+        let files = fs::read_dir(output_path)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        let output = fs::read_to_string(output_path.join("index.js")).unwrap();
+
+        // emitted although it's smaller than inlineLimit
+        assert!(files.iter().any(|file| file.starts_with("icon.")));
+        // referenced by url but not emitted
+        assert!(!files.iter().any(|file| file.starts_with("photo.")));
+        assert!(output.contains("photo."));
+        // no rule matched, inlined by inlineLimit
+        assert!(!files.iter().any(|file| file.starts_with("dot.")));
+        assert!(output.contains("data:image/png;base64,"));
+    }
+
+    #[test]
+    fn test_relaxed_json() {
+        let build = |name: &str| {
+            let compiler = setup_compiler(&format!("test/build/{}", name), false);
+            compiler.compile().map(|_| {
+                fs::read_to_string(compiler.context.config.output.path.join("index.js")).unwrap()
+            })
+        };
+
+        // strict json is opt-in
+        let err = build("relaxed-json-strict").unwrap_err();
+        assert!(err.to_string().contains("c.json"));
+
+        let output = build("relaxed-json").unwrap();
+        assert!(output.contains(r#""port": 3000"#));
+        assert!(output.contains(r#""hosts": ["#));
+        assert!(output.contains("name: 'app'"));
+        assert!(output.contains("max: Infinity"));
+        assert!(output.contains(r#""version": "1.0.0""#));
+    }
+
+    #[test]
+    fn test_json_code_is_rejected() {
+        let compiler = setup_compiler("test/build/json-code", false);
+        let err = compiler.compile().unwrap_err();
+        assert!(err.to_string().contains("a.jsonc"));
+    }
+}
@@ -1,4 +1,5 @@
 pub(crate) mod analyze_deps;
+pub(crate) mod cache;
 pub(crate) mod load;
 pub(crate) mod parse;
 pub(crate) mod targets;
@@ -13,7 +14,9 @@ use colored::Colorize;
 use thiserror::Error;
 
 use crate::ast::file::{Content, File, JsContent};
+use crate::build::cache::PersistentCache;
 use crate::compiler::{Compiler, Context};
+use crate::config::OutputFormat;
 use crate::generate::chunk_pot::util::hash_hashmap;
 use crate::module::{Module, ModuleAst, ModuleId, ModuleInfo};
 use crate::plugin::NextBuildParam;
@@ -156,12 +159,20 @@ impl Compiler {
             .get_external()
             // safe
             .unwrap();
-        let external_script = resolved_resource.get_script();
+        let is_system = context.config.output.format == OutputFormat::System;
+        let external_script = resolved_resource.get_script().filter(|_| !is_system);
         let is_async = external_script.is_some();
         let origin_path = resolved_resource.get_resolved_path();
         let path = format!("virtual:external_{}", origin_path);
         let mut file = File::new(path.clone(), context.clone());
-        let code = if let Some(url) = external_script {
+        let code = if is_system {
+            // externals are the deps of System.register
+            format!(
+                "module.exports = __mako_require__.systemDeps[{}];",
+                // safe
+                serde_json::to_string(&origin_path).unwrap()
+            )
+        } else if let Some(url) = external_script {
             format!(
                 r#"
 module.exports = new Promise((resolve, reject) => {{
@@ -264,6 +275,29 @@ __mako_require__.loadScript('{}', (e) => e.type === 'load' ? resolve() : reject(
         }
     }
 
+    fn parse_and_transform(file: &File, context: Arc<Context>) -> Result<ModuleAst> {
+        let cache = context
+            .persistent_cache
+            .as_ref()
+            .filter(|_| PersistentCache::is_cacheable(file))
+            .map(|cache| (cache, file.get_raw_hash()));
+        let path = file.path.to_string_lossy().to_string();
+        if let Some((cache, raw_hash)) = cache
+            && let Some(cached) = cache.get(&path, raw_hash)
+        {
+            return PersistentCache::restore(&cached, file, context);
+        }
+
+        let mut ast = parse::Parse::parse(file, context.clone())?;
+        transform::Transform::transform(&mut ast, file, context.clone())?;
+        if let Some((cache, raw_hash)) = cache
+            && let ModuleAst::Script(js_ast) = &ast
+        {
+            cache.insert(&path, js_ast, raw_hash, &context);
+        }
+        Ok(ast)
+    }
+
     pub fn build_module(
         file: &File,
         parent_resource: Option<ResolverResource>,
@@ -274,11 +308,8 @@ __mako_require__.loadScript('{}', (e) => e.type === 'load' ? resolve() : reject(
         let content = load::Load::load(&file, context.clone())?;
         file.set_content(content);
 
-        // 2. parse
-        let mut ast = parse::Parse::parse(&file, context.clone())?;
-
-        // 3. transform
-        transform::Transform::transform(&mut ast, &file, context.clone())?;
+        // 2. parse + 3. transform, or restore from the persistent cache
+        let ast = Self::parse_and_transform(&file, context.clone())?;
 
         // 4. analyze deps + resolve
         let deps = analyze_deps::AnalyzeDeps::analyze_deps(&ast, &file, context.clone())?;
@@ -293,7 +324,7 @@ __mako_require__.loadScript('{}', (e) => e.type === 'load' ? resolve() : reject(
             ModuleAst::Script(ast) => ast.contains_top_level_await,
             _ => false,
         };
-        let is_async_module = file.extname == "wasm";
+        let is_async_module = file.extname == "wasm" && !load::Load::is_sync_wasm(&file, &context);
         let is_async = is_async_module || top_level_await;
 
         // raw_hash is only used in watch mode
@@ -89,34 +89,31 @@ impl Parse {
                     file.set_content(Content::Css("".to_string()));
                     CssAst::new(&file, context.clone(), css_modules)?
                 };
+                // import sheet from './a.css' with { type: 'css' }
+                if file.has_param("sheet") {
+                    let (deps, code) =
+                        Parse::compile_css_for_js(ast, &file, context.clone(), false)?;
+                    file.set_content(Content::Js(JsContent {
+                        content: format!(
+                            r#"
+import {{ createStyleSheet }} from 'virtual:css_sheet:runtime';
+{}
+export default createStyleSheet({});
+                        "#,
+                            deps,
+                            serde_json::to_string(&code)?
+                        ),
+                        ..Default::default()
+                    }));
+                    let ast = JsAst::new(&file, context.clone())?;
+                    return Ok(ModuleAst::Script(ast));
+                }
                 // when inline_css is enabled
                 // we need to go through the css-related process first
                 // and then hand it over to js for processing
                 if context.config.inline_css.is_some() {
-                    let mut ast = ModuleAst::Css(ast);
-                    // transform
-                    Transform::transform(&mut ast, &file, context.clone())?;
-                    // analyze_deps
-                    let deps = AnalyzeDeps::analyze_deps(&ast, &file, context.clone())?;
-                    if !deps.missing_deps.is_empty() {
-                        return Err(anyhow!(ParseError::InlineCSSMissingDeps {
-                            path: file.path.to_string_lossy().to_string(),
-                        }));
-                    }
-                    let deps = deps
-                        .resolved_deps
-                        .iter()
-                        .map(|dep| {
-                            format!("import '{}';", dep.resolver_resource.get_resolved_path())
-                        })
-                        .collect::<Vec<String>>()
-                        .join("\n");
-                    let ast = ast.as_css_mut();
-                    // transform (remove @imports)
-                    let mut css_handler = CSSImports {};
-                    ast.ast.visit_mut_with(&mut css_handler);
-                    // ast to code
-                    let code = ast.generate(context.clone())?.code;
+                    let (deps, code) =
+                        Parse::compile_css_for_js(ast, &file, context.clone(), true)?;
                     let mut file = file.clone();
                     file.set_content(Content::Js(JsContent {
                         content: format!(
@@ -146,4 +143,40 @@ moduleToDom(`
             path: file.path.to_string_lossy().to_string(),
         }))
     }
+
+    // go through the css-related process, and return the imports of its deps
+    // and the generated css, for css which is handed over to js
+    fn compile_css_for_js(
+        ast: CssAst,
+        file: &File,
+        context: Arc<Context>,
+        with_css_deps: bool,
+    ) -> Result<(String, String)> {
+        let mut ast = ModuleAst::Css(ast);
+        // transform
+        Transform::transform(&mut ast, file, context.clone())?;
+        // analyze_deps
+        let deps = AnalyzeDeps::analyze_deps(&ast, file, context.clone())?;
+        if !deps.missing_deps.is_empty() {
+            return Err(anyhow!(ParseError::InlineCSSMissingDeps {
+                path: file.path.to_string_lossy().to_string(),
+            }));
+        }
+        let deps = deps
+            .resolved_deps
+            .iter()
+            .map(|dep| dep.resolver_resource.get_resolved_path())
+            // @import is not allowed in constructable stylesheets
+            .filter(|path| with_css_deps || !path.ends_with(".css"))
+            .map(|path| format!("import '{}';", path))
+            .collect::<Vec<String>>()
+            .join("\n");
+        let ast = ast.as_css_mut();
+        // transform (remove @imports)
+        let mut css_handler = CSSImports {};
+        ast.ast.visit_mut_with(&mut css_handler);
+        // ast to code
+        let code = ast.generate(context.clone())?.code;
+        Ok((deps, code))
+    }
 }
@@ -12,6 +12,7 @@ use swc_core::ecma::transforms::base::{resolver, Assumptions};
 use swc_core::ecma::transforms::compat::reserved_words;
 use swc_core::ecma::transforms::optimization::simplifier;
 use swc_core::ecma::transforms::optimization::simplify::{dce, Config as SimpilifyConfig};
+use swc_core::ecma::transforms::proposal::decorator_2022_03::decorator_2022_03;
 use swc_core::ecma::transforms::proposal::decorators;
 use swc_core::ecma::visit::{Fold, VisitMut};
 
@@ -22,6 +23,7 @@ use crate::build::targets::swc_preset_env_targets_from_map;
 use crate::compiler::Context;
 use crate::config::Mode;
 use crate::features;
+use crate::features::rsc::Rsc;
 use crate::module::ModuleAst;
 use crate::plugins::context_module::ContextModuleVisitor;
 use crate::visitors::css_assets::CSSAssets;
@@ -29,7 +31,7 @@ use crate::visitors::css_flexbugs::CSSFlexbugs;
 use crate::visitors::css_px2rem::Px2Rem;
 use crate::visitors::default_export_namer::DefaultExportNamer;
 use crate::visitors::dynamic_import_to_require::DynamicImportToRequire;
-use crate::visitors::env_replacer::{build_env_map, EnvReplacer};
+use crate::visitors::env_replacer::{get_define_exprs, EnvReplacer};
 use crate::visitors::fix_helper_inject_position::FixHelperInjectPosition;
 use crate::visitors::fix_symbol_conflict::FixSymbolConflict;
 use crate::visitors::import_template_to_string_literal::ImportTemplateToStringLiteral;
@@ -114,13 +116,8 @@ impl Transform {
                         ));
                     }
                     {
-                        let mut define = context.config.define.clone();
-                        let mode = context.config.mode.to_string();
-                        define
-                            .entry("process.env.NODE_ENV".to_string())
-                            .or_insert_with(|| format!("\"{}\"", mode).into());
-                        let env_map = build_env_map(define, &context)?;
-                        visitors.push(Box::new(EnvReplacer::new(env_map, unresolved_mark)));
+                        let define = get_define_exprs(&context)?;
+                        visitors.push(Box::new(EnvReplacer::new(define, unresolved_mark)));
                     }
                     visitors.push(Box::new(TryResolve {
                         path: file.path.to_string_lossy().to_string(),
@@ -158,11 +155,16 @@ impl Transform {
                     // decorators should go before preset_env, when compile down to es5,
                     // classes become functions, then the decorators on the functions
                     // will be removed silently.
-                    folders.push(Box::new(decorators(decorators::Config {
-                        legacy: true,
-                        emit_metadata: context.config.emit_decorator_metadata,
-                        ..Default::default()
-                    })));
+                    let decorators_config = &context.config.transform.decorators;
+                    if decorators_config.legacy {
+                        folders.push(Box::new(decorators(decorators::Config {
+                            legacy: true,
+                            emit_metadata: decorators_config.emit_metadata,
+                            ..Default::default()
+                        })));
+                    } else {
+                        folders.push(Box::new(decorator_2022_03()));
+                    }
                     let comments = origin_comments.get_swc_comments().clone();
                     let assumptions = context.assumptions_for(file);
 
@@ -194,16 +196,14 @@ impl Transform {
                             ..Default::default()
                         },
                     )));
-                    // NOTICE: remove optimize_package_imports temporarily
-                    // folders.push(Box::new(Optional {
-                    //     enabled: should_optimize(file.path.to_str().unwrap(), context.clone()),
-                    //     visitor: optimize_package_imports(
-                    //         file.path.to_string_lossy().to_string(),
-                    //         context.clone(),
-                    //     ),
-                    // }));
 
+                    let directives = if Rsc::should_preserve_directives(&context) {
+                        Rsc::collect_directives(&ast.ast)
+                    } else {
+                        vec![]
+                    };
                     ast.transform(&mut visitors, &mut folders, file, true, context.clone())?;
+                    Rsc::restore_directives(&mut ast.ast, &directives);
 
                     Ok(())
                 })
@@ -1,15 +1,18 @@
 use std::path::PathBuf;
 
-use clap;
 use clap::builder::TypedValueParser;
-use clap::Parser;
+use clap::{self, Parser};
 
 use crate::config::Mode;
+use crate::utils::logger::LogFormat;
 
 #[derive(Parser)]
 pub struct Cli {
     #[arg(short, long)]
     pub watch: bool,
+    // rebuild on changes and write to output.path, without dev server and hmr
+    #[arg(long, conflicts_with = "watch")]
+    pub watch_build: bool,
     pub root: PathBuf,
     #[arg(long, default_value_t = Mode::Development,
         value_parser = clap::builder::PossibleValuesParser::new(["production", "prod", "p", "development","dev"])
@@ -22,4 +25,13 @@ pub struct Cli {
                 })
     )]
     pub mode: Mode,
+    // print the resolved config as json and exit
+    #[arg(long)]
+    pub print_config: bool,
+    // json writes the build events as json lines to --log-file, for the ci
+    #[arg(long, value_enum, default_value_t = LogFormat::Text, requires_if("json", "log_file"))]
+    pub log_format: LogFormat,
+    // the file of the json build events, the human logs stay on the terminal
+    #[arg(long)]
+    pub log_file: Option<PathBuf>,
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex, RwLock};
 use std::time::Instant;
 
@@ -9,13 +9,17 @@ use colored::Colorize;
 use regex::Regex;
 use swc_core::common::sync::Lrc;
 use swc_core::common::{Globals, SourceMap, DUMMY_SP};
-use swc_core::ecma::ast::Ident;
+use swc_core::ecma::ast::{Expr, Ident};
 use tracing::debug;
 
 use crate::ast::comments::Comments;
-use crate::config::{Config, OutputMode};
+use crate::build::cache::PersistentCache;
+use crate::config::{Config, DynamicRequireLevel, Mode, OutputMode};
+use crate::features::regenerate::RetainedBuild;
+use crate::generate::chunk::{ChunkId, ChunkType};
 use crate::generate::chunk_graph::ChunkGraph;
 use crate::generate::optimize_chunk::OptimizeChunksInfo;
+use crate::module::ModuleId;
 use crate::module_graph::ModuleGraph;
 use crate::plugin::{Plugin, PluginDriver, PluginGenerateEndParams};
 use crate::plugins;
@@ -31,12 +35,26 @@ pub struct Context {
     pub config: Config,
     pub args: Args,
     pub root: PathBuf,
-    pub meta: Meta,
+    // shared with the compilers created by `regenerate`, the asts of the
+    // module graph refer to its source map and marks
+    pub meta: Arc<Meta>,
     pub plugin_driver: PluginDriver,
     pub stats_info: StatsInfo,
     pub resolvers: Resolvers,
     pub static_cache: RwLock<MemoryChunkFileCache>,
     pub optimize_infos: Mutex<Option<Vec<OptimizeChunksInfo>>>,
+    pub persistent_cache: Option<PersistentCache>,
+    /// The parsed values of `define`, shared by all the modules
+    pub define_exprs: Mutex<Option<Arc<HashMap<String, Expr>>>>,
+    /// The shaking decisions of the exports by module, for the
+    /// `treeShakingAnnotations` comments
+    pub export_annotations: Mutex<HashMap<ModuleId, String>>,
+    /// The files written to the output by the build_success hooks, e.g. the
+    /// html files and the copied ones, which are compressed with the assets
+    pub written_files: Mutex<Vec<PathBuf>>,
+    // where the files are emitted during the generate stage when the output
+    // is staged, see Compiler::stage_output
+    staging_path: Mutex<Option<PathBuf>>,
 }
 
 #[derive(Default)]
@@ -88,6 +106,18 @@ pub struct Args {
     pub watch: bool,
 }
 
+/// Emitted files of an entry, see [Compiler::entrypoints].
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntrypointInfo {
+    pub name: String,
+    /// Js files loaded initially, the shared chunks first and the entry chunk last
+    pub js: Vec<String>,
+    /// Css files loaded initially, in the same order as `js`
+    pub css: Vec<String>,
+    /// Files of the chunks loaded on demand by the entry
+    pub async_chunks: Vec<String>,
+}
+
 impl Context {
     pub fn write_static_content<T: AsRef<str>>(
         &self,
@@ -103,6 +133,22 @@ impl Context {
         let map = self.static_cache.read().unwrap();
         map.read(path)
     }
+
+    pub fn take_written_files(&self) -> Vec<PathBuf> {
+        std::mem::take(&mut *self.written_files.lock().unwrap())
+    }
+
+    /// The directory the files are emitted to, it's `output.path` except
+    /// during the generate stage of a build with `noEmitOnError`, in which
+    /// the output is staged in a sibling directory. The paths reported in
+    /// the stats are under `output.path` anyway.
+    pub fn output_path(&self) -> PathBuf {
+        self.staging_path
+            .lock()
+            .unwrap()
+            .clone()
+            .unwrap_or_else(|| self.config.output.path.clone())
+    }
 }
 
 impl Default for Context {
@@ -117,12 +163,17 @@ impl Default for Context {
             chunk_graph: RwLock::new(ChunkGraph::new()),
             assets_info: Mutex::new(HashMap::new()),
             modules_with_missing_deps: RwLock::new(Vec::new()),
-            meta: Meta::new(),
+            meta: Arc::new(Meta::new()),
             plugin_driver: Default::default(),
             stats_info: StatsInfo::new(),
             resolvers,
             optimize_infos: Mutex::new(None),
             static_cache: Default::default(),
+            persistent_cache: None,
+            define_exprs: Mutex::new(None),
+            export_annotations: Mutex::new(HashMap::new()),
+            written_files: Mutex::new(vec![]),
+            staging_path: Mutex::new(None),
         }
     }
 }
@@ -199,10 +250,22 @@ impl Context {
         let mut assets_info = self.assets_info.lock().unwrap();
         assets_info.insert(origin_path, output_path);
     }
+
+    // for the transforms with side effects, see [PersistentCache::skip]
+    pub fn skip_persistent_cache(&self, path: &str) {
+        if let Some(cache) = &self.persistent_cache {
+            cache.skip(path);
+        }
+    }
 }
 
 pub struct Compiler {
     pub context: Arc<Context>,
+    // kept to create the compiler again with a reloaded config
+    pub(crate) extra_plugins: Vec<Arc<dyn Plugin>>,
+    // keep the built module graph for `regenerate`, see [Compiler::retain_build]
+    pub(crate) retain_build: bool,
+    pub(crate) retained_build: Mutex<Option<Arc<RetainedBuild>>>,
 }
 
 impl Compiler {
@@ -211,6 +274,16 @@ impl Compiler {
         root: PathBuf,
         args: Args,
         extra_plugins: Option<Vec<Arc<dyn Plugin>>>,
+    ) -> Result<Self> {
+        Self::new_with_meta(config, root, args, extra_plugins, Arc::new(Meta::new()))
+    }
+
+    pub(crate) fn new_with_meta(
+        config: Config,
+        root: PathBuf,
+        args: Args,
+        extra_plugins: Option<Vec<Arc<dyn Plugin>>>,
+        meta: Arc<Meta>,
     ) -> Result<Self> {
         if !root.is_absolute() {
             return Err(anyhow!("root path must be absolute"));
@@ -219,10 +292,8 @@ impl Compiler {
         // why add plugins before builtin plugins?
         // because plugins like less-loader need to be added before assets plugin
         // TODO: support plugin orders
-        let mut plugins: Vec<Arc<dyn Plugin>> = vec![];
-        if let Some(extra_plugins) = extra_plugins {
-            plugins.extend(extra_plugins);
-        }
+        let extra_plugins = extra_plugins.unwrap_or_default();
+        let mut plugins: Vec<Arc<dyn Plugin>> = extra_plugins.clone();
         let builtin_plugins: Vec<Arc<dyn Plugin>> = vec![
             // features
             Arc::new(plugins::manifest::ManifestPlugin {}),
@@ -236,8 +307,9 @@ impl Compiler {
             Arc::new(plugins::wasm_runtime::WasmRuntimePlugin {}),
             Arc::new(plugins::async_runtime::AsyncRuntimePlugin {}),
             Arc::new(plugins::emotion::EmotionPlugin {}),
-            Arc::new(plugins::tree_shaking::FarmTreeShake {}),
+            Arc::new(plugins::tree_shaking::FarmTreeShake::default()),
             Arc::new(plugins::detect_circular_dependence::LoopDetector {}),
+            Arc::new(plugins::unused_assets::UnusedAssetsPlugin {}),
         ];
         plugins.extend(builtin_plugins);
 
@@ -255,10 +327,32 @@ impl Compiler {
             )));
         }
 
+        if config.public_dir.is_some() {
+            plugins.push(Arc::new(plugins::public_dir::PublicDirPlugin {}));
+        }
+
+        // after copy and public_dir, so the root files win
+        if !config.root_files.is_empty() {
+            plugins.push(Arc::new(plugins::root_files::RootFilesPlugin {}));
+        }
+
+        if !config.html.is_empty() {
+            plugins.push(Arc::new(plugins::html::HtmlPlugin::default()));
+        }
+
+        // should be after copy and public_dir, which emit the html files
+        if !config.preload_chunks.is_empty() {
+            plugins.push(Arc::new(plugins::preload::PreloadPlugin {}));
+        }
+
         if config.experimental.require_context {
             plugins.push(Arc::new(plugins::require_context::RequireContextPlugin {}))
         }
 
+        if config.dynamic_require != DynamicRequireLevel::Ignore {
+            plugins.push(Arc::new(plugins::dynamic_require::DynamicRequirePlugin {}));
+        }
+
         if config.output.mode == OutputMode::Bundless {
             plugins.insert(
                 0,
@@ -321,6 +415,16 @@ impl Compiler {
         plugin_driver.modify_config(&mut config, &root, &args)?;
 
         let resolvers = get_resolvers(&config);
+        let persistent_cache = if config.cache.is_some() {
+            Some(PersistentCache::load(
+                &root,
+                &config,
+                &plugin_driver.cache_keys(),
+                args.watch,
+            ))
+        } else {
+            None
+        };
         Ok(Self {
             context: Arc::new(Context {
                 static_cache: if config.write_to_disk {
@@ -335,15 +439,36 @@ impl Compiler {
                 chunk_graph: RwLock::new(ChunkGraph::new()),
                 assets_info: Mutex::new(HashMap::new()),
                 modules_with_missing_deps: RwLock::new(Vec::new()),
-                meta: Meta::new(),
+                meta,
                 plugin_driver,
                 stats_info: StatsInfo::new(),
                 resolvers,
                 optimize_infos: Mutex::new(None),
+                persistent_cache,
+                define_exprs: Mutex::new(None),
+                export_annotations: Mutex::new(HashMap::new()),
+                written_files: Mutex::new(vec![]),
+                staging_path: Mutex::new(None),
             }),
+            extra_plugins,
+            retain_build: false,
+            retained_build: Mutex::new(None),
         })
     }
 
+    /// A new compiler of the same root, args and plugins, nothing of this
+    /// compiler is reused since the config may affect every module
+    pub fn with_config(&self, config: Config) -> Result<Self> {
+        Compiler::new(
+            config,
+            self.context.root.clone(),
+            Args {
+                watch: self.context.args.watch,
+            },
+            Some(self.extra_plugins.clone()),
+        )
+    }
+
     pub fn compile(&self) -> Result<()> {
         // 先清空 dist 目录
         if self.context.config.clean {
@@ -374,8 +499,11 @@ impl Compiler {
                     );
                     let watch = self.context.args.watch;
                     let hmr = self.context.config.hmr.is_some();
+                    let dev_server = self.context.config.dev_server.is_some();
                     if is_browser && watch && hmr {
                         entry = format!("{}?hmr", entry);
+                    } else if is_browser && watch && dev_server {
+                        entry = format!("{}?reload", entry);
                     }
                     crate::ast::file::File::new_entry(entry, self.context.clone())
                 })
@@ -384,6 +512,10 @@ impl Compiler {
 
             self.build(files)?;
 
+            if let Some(cache) = &self.context.persistent_cache {
+                cache.write()?;
+            }
+
             debug!("start after build");
 
             self.context
@@ -391,14 +523,30 @@ impl Compiler {
                 .after_build(&self.context, self)?;
         }
 
+        self.retain_module_graph();
+
+        self.generate_stage(t_compiler, start_time)
+    }
+
+    // shared by `compile` and `regenerate`
+    pub(crate) fn generate_stage(&self, t_compiler: Instant, start_time: i64) -> Result<()> {
         self.context.plugin_driver.before_generate(&self.context)?;
 
+        self.stage_output()?;
         let result = {
             crate::mako_profile_scope!("Generate Stage");
             // need to put all rayon parallel iterators run in the existed scope, or else rayon
             // will create a new thread pool for those parallel iterators
             thread_pool::scope(|_| self.generate())
         };
+        // the generate_end hooks see the output in place
+        let result = match result {
+            Ok(stats) => self.commit_output().map(|_| stats),
+            Err(e) => {
+                self.drop_staged_output()?;
+                Err(e)
+            }
+        };
         let t_compiler_duration = t_compiler.elapsed();
         match result {
             Ok(mut stats) => {
@@ -436,7 +584,91 @@ impl Compiler {
         cg.full_hash(&mg)
     }
 
-    fn clean_dist(&self) -> Result<()> {
+    /// The effective config after the defaults, the user config and the cli
+    /// args are merged, with the source of every value, see [Config::resolved]
+    pub fn resolved_config(&self) -> serde_json::Value {
+        self.context.config.resolved()
+    }
+
+    /// Emitted file names of the chunks which contain the module, a module
+    /// may be bundled into more than one chunk. Available after a build.
+    pub fn chunk_for_module(&self, module_id: &ModuleId) -> Vec<String> {
+        let chunk_graph = self.context.chunk_graph.read().unwrap();
+        let assets = self.context.stats_info.get_assets();
+        let mut files: Vec<String> = vec![];
+        for chunk in chunk_graph.get_chunks_for_module(module_id) {
+            assets
+                .iter()
+                .filter(|asset| asset.chunk_id == chunk.id.id && !asset.hashname.ends_with(".map"))
+                .for_each(|asset| {
+                    if !files.contains(&asset.hashname) {
+                        files.push(asset.hashname.clone());
+                    }
+                });
+        }
+        files
+    }
+
+    /// Entries and their emitted files, sorted by the entry name. Available
+    /// after a build.
+    pub fn entrypoints(&self) -> Vec<EntrypointInfo> {
+        let chunk_graph = self.context.chunk_graph.read().unwrap();
+        let assets = self.context.stats_info.get_assets();
+        let files_of = |chunk_ids: &[ChunkId], ext: &str| {
+            let mut files: Vec<String> = vec![];
+            for chunk_id in chunk_ids {
+                assets
+                    .iter()
+                    .filter(|asset| asset.chunk_id == chunk_id.id && asset.hashname.ends_with(ext))
+                    .for_each(|asset| {
+                        if !files.contains(&asset.hashname) {
+                            files.push(asset.hashname.clone());
+                        }
+                    });
+            }
+            files
+        };
+
+        let mut entrypoints = chunk_graph
+            .get_chunks()
+            .into_iter()
+            .filter_map(|chunk| match &chunk.chunk_type {
+                // shared entry chunks are not entries by themselves
+                ChunkType::Entry(_, name, false) => {
+                    let mut initial = chunk
+                        .chunk_type
+                        .runtime_chunk_id(self.context.config.runtime_chunk)
+                        .into_iter()
+                        .collect::<Vec<_>>();
+                    initial.extend(chunk_graph.entry_dependencies_chunk(&chunk.id));
+                    initial.extend(chunk_graph.sync_dependencies_chunk(&chunk.id));
+                    initial.push(chunk.id.clone());
+                    let async_chunks = chunk_graph
+                        .installable_descendants_chunk(&chunk.id)
+                        .into_iter()
+                        .filter(|chunk_id| !initial.contains(chunk_id))
+                        .collect::<Vec<_>>();
+                    let mut async_files = files_of(&async_chunks, ".js");
+                    async_files.extend(files_of(&async_chunks, ".css"));
+                    Some(EntrypointInfo {
+                        name: name.clone(),
+                        js: files_of(&initial, ".js"),
+                        css: files_of(&initial, ".css"),
+                        async_chunks: async_files,
+                    })
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        entrypoints.sort_by(|a, b| a.name.cmp(&b.name));
+        entrypoints
+    }
+
+    pub(crate) fn clean_dist(&self) -> Result<()> {
+        // the staged output replaces it after the build
+        if self.stages_output() {
+            return Ok(());
+        }
         // compiler 前清除 dist，如果后续 dev 环境不在 output_path 里，需要再补上 dev 的逻辑
         let output_path = &self.context.config.output.path;
         if fs::metadata(output_path).is_ok() {
@@ -444,4 +676,309 @@ impl Compiler {
         }
         Ok(())
     }
+
+    // with `noEmitOnError`, the files are emitted into a sibling directory
+    // and moved over the output once the generate stage succeeds, so that a
+    // failed build doesn't touch the previous output
+    fn stages_output(&self) -> bool {
+        let config = &self.context.config;
+        let no_emit_on_error = config
+            .no_emit_on_error
+            .unwrap_or(config.mode == Mode::Production);
+        no_emit_on_error && !self.context.args.watch
+    }
+
+    fn stage_output(&self) -> Result<()> {
+        if !self.stages_output() {
+            return Ok(());
+        }
+        let staging_path = dist_staging_path(&self.context.config.output.path);
+        // left by a build which is killed
+        if staging_path.exists() {
+            fs::remove_dir_all(&staging_path)?;
+        }
+        *self.context.staging_path.lock().unwrap() = Some(staging_path);
+        Ok(())
+    }
+
+    // with `clean` the staged output replaces the previous one, otherwise
+    // the staged files are moved into it
+    fn commit_output(&self) -> Result<()> {
+        let Some(staging_path) = self.context.staging_path.lock().unwrap().take() else {
+            return Ok(());
+        };
+        if !staging_path.exists() {
+            return Ok(());
+        }
+        let output_path = &self.context.config.output.path;
+        if self.context.config.clean && output_path.exists() {
+            fs::remove_dir_all(output_path)?;
+        }
+        move_into(&staging_path, output_path)
+    }
+
+    fn drop_staged_output(&self) -> Result<()> {
+        if let Some(staging_path) = self.context.staging_path.lock().unwrap().take() {
+            if staging_path.exists() {
+                fs::remove_dir_all(staging_path)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// next to the output, so that it can be renamed over it
+fn dist_staging_path(output_path: &Path) -> PathBuf {
+    let name = output_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    output_path.with_file_name(format!(".{}.mako-staging", name))
+}
+
+// renames `from` to `to`, or its entries into `to` when it exists already
+fn move_into(from: &Path, to: &Path) -> Result<()> {
+    if !to.exists() {
+        fs::rename(from, to)?;
+        return Ok(());
+    }
+    if !to.is_dir() {
+        fs::remove_file(to)?;
+        fs::rename(from, to)?;
+        return Ok(());
+    }
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            move_into(&entry.path(), &target)?;
+        } else {
+            if target.is_dir() {
+                fs::remove_dir_all(&target)?;
+            }
+            fs::rename(entry.path(), &target)?;
+        }
+    }
+    fs::remove_dir(from)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::sync::Arc;
+
+    use anyhow::{anyhow, Result};
+
+    use super::{Compiler, Context, EntrypointInfo};
+    use crate::module::ModuleId;
+    use crate::plugin::Plugin;
+    use crate::stats::StatsJsonMap;
+    use crate::utils::test_helper::{setup_compiler, PluginTestBuilder};
+
+    struct LateError {}
+
+    impl Plugin for LateError {
+        fn name(&self) -> &str {
+            "late_error"
+        }
+
+        fn build_success(&self, _stats: &StatsJsonMap, _context: &Arc<Context>) -> Result<()> {
+            Err(anyhow!("late error"))
+        }
+    }
+
+    fn output_files(dir: &std::path::Path) -> Vec<String> {
+        let mut files = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn test_chunk_for_module() {
+        let compiler = setup_compiler("test/build/chunk-for-module", false);
+        compiler.compile().unwrap();
+        let module_id = |path: &str| ModuleId::from(compiler.context.root.join(path));
+
+        assert_eq!(
+            compiler.chunk_for_module(&module_id("src/a.ts")),
+            vec!["a.js"]
+        );
+
+        let mut shared = compiler.chunk_for_module(&module_id("src/shared.ts"));
+        shared.sort();
+        assert_eq!(shared, vec!["a.js", "b.js"]);
+
+        let lazy = compiler.chunk_for_module(&module_id("src/lazy.ts"));
+        assert_eq!(lazy.len(), 1);
+        assert!(lazy[0].ends_with("-async.js"));
+
+        assert!(compiler
+            .chunk_for_module(&module_id("src/not-exists.ts"))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_entrypoints() {
+        let compiler = setup_compiler("test/build/entrypoints", false);
+        compiler.compile().unwrap();
+        let entrypoints = compiler.entrypoints();
+
+        assert_eq!(
+            entrypoints[0],
+            EntrypointInfo {
+                name: "a".to_string(),
+                js: vec!["common.js".to_string(), "a.js".to_string()],
+                css: vec![],
+                async_chunks: vec!["src_lazy_ts-async.js".to_string()],
+            }
+        );
+        assert_eq!(
+            entrypoints[1],
+            EntrypointInfo {
+                name: "b".to_string(),
+                js: vec!["common.js".to_string(), "b.js".to_string()],
+                css: vec!["b.css".to_string()],
+                async_chunks: vec![],
+            }
+        );
+        assert_eq!(entrypoints.len(), 2);
+    }
+
+    fn compile_with_runtime_chunk(runtime_chunk: &str) -> Compiler {
+        let compiler = PluginTestBuilder::new(&format!("runtime-chunk-{}", runtime_chunk))
+            .file("src/a.js", "import('./lazy');\nconsole.log('a');")
+            .file("src/b.js", "console.log('b');")
+            .file("src/lazy.js", "console.log('lazy');")
+            .config(&format!(
+                r#"{{ "entry": {{ "a": "src/a.js", "b": "src/b.js" }}, "runtimeChunk": {} }}"#,
+                runtime_chunk
+            ))
+            .build();
+        compiler.compile().unwrap();
+        compiler
+    }
+
+    fn entry_js(compiler: &Compiler) -> Vec<Vec<String>> {
+        compiler
+            .entrypoints()
+            .into_iter()
+            .map(|entrypoint| entrypoint.js)
+            .collect()
+    }
+
+    #[test]
+    fn test_runtime_chunk_single() {
+        let compiler = compile_with_runtime_chunk(r#""single""#);
+        let output_path = &compiler.context.config.output.path;
+        let read = |file: &str| fs::read_to_string(output_path.join(file)).unwrap();
+
+        assert_eq!(
+            entry_js(&compiler),
+            vec![vec!["runtime.js", "a.js"], vec!["runtime.js", "b.js"]]
+        );
+        let runtime = read("runtime.js");
+        assert!(runtime.contains("function createRuntime"));
+        assert!(runtime.contains("src_lazy_js-async.js"));
+        for entry in ["a.js", "b.js"] {
+            let content = read(entry);
+            assert!(!content.contains("function createRuntime"));
+            assert!(content.contains(".push(["));
+        }
+        assert!(!output_files(output_path).contains(&"runtime~a.js".to_string()));
+    }
+
+    #[test]
+    fn test_runtime_chunk_multiple() {
+        let compiler = compile_with_runtime_chunk(r#""multiple""#);
+        let output_path = &compiler.context.config.output.path;
+        let read = |file: &str| fs::read_to_string(output_path.join(file)).unwrap();
+
+        assert_eq!(
+            entry_js(&compiler),
+            vec![vec!["runtime~a.js", "a.js"], vec!["runtime~b.js", "b.js"]]
+        );
+        assert!(read("runtime~a.js").contains("src_lazy_js-async.js"));
+        assert!(!read("runtime~b.js").contains("src_lazy_js-async.js"));
+        assert!(!read("a.js").contains("function createRuntime"));
+        assert!(!output_files(output_path).contains(&"runtime.js".to_string()));
+    }
+
+    #[test]
+    fn test_runtime_chunk_inline() {
+        let compiler = compile_with_runtime_chunk("false");
+        let output_path = &compiler.context.config.output.path;
+
+        assert_eq!(entry_js(&compiler), vec![vec!["a.js"], vec!["b.js"]]);
+        for entry in ["a.js", "b.js"] {
+            let content = fs::read_to_string(output_path.join(entry)).unwrap();
+            assert!(content.contains("function createRuntime"));
+        }
+        assert!(output_files(output_path)
+            .iter()
+            .all(|file| !file.starts_with("runtime")));
+    }
+
+    #[test]
+    fn test_no_emit_on_error() {
+        let compiler = PluginTestBuilder::new("no-emit-on-error")
+            .file("src/index.js", "console.log(1);")
+            .file("dist/index.js", "good output")
+            .plugin(Arc::new(LateError {}))
+            .build();
+        let err = compiler.compile().unwrap_err();
+
+        let output_path = &compiler.context.config.output.path;
+        assert_eq!(err.to_string(), "late error");
+        assert_eq!(
+            fs::read_to_string(output_path.join("index.js")).unwrap(),
+            "good output"
+        );
+        assert_eq!(output_files(output_path), vec!["index.js"]);
+        assert_eq!(
+            output_files(output_path.parent().unwrap()),
+            vec!["dist", "src"]
+        );
+    }
+
+    #[test]
+    fn test_no_emit_on_error_without_clean() {
+        let compiler = setup_compiler("test/build/no-emit-on-error-without-clean", false);
+        let output_path = &compiler.context.config.output.path;
+        fs::create_dir_all(output_path).unwrap();
+        fs::write(output_path.join("index.js"), "previous output").unwrap();
+        fs::write(output_path.join("old.js"), "previous output").unwrap();
+        compiler.compile().unwrap();
+
+        // the staged files are moved into the previous output
+        assert_eq!(output_files(output_path), vec!["index.js", "old.js"]);
+        assert_ne!(
+            fs::read_to_string(output_path.join("index.js")).unwrap(),
+            "previous output"
+        );
+        assert_eq!(
+            output_files(output_path.parent().unwrap()),
+            vec!["dist", "mako.config.json", "src"]
+        );
+    }
+
+    #[test]
+    fn test_no_emit_on_error_disabled() {
+        let compiler = PluginTestBuilder::new("no-emit-on-error-disabled")
+            .file("src/index.js", "console.log(1);")
+            .file("dist/index.js", "good output")
+            .config(r#"{ "noEmitOnError": false }"#)
+            .plugin(Arc::new(LateError {}))
+            .build();
+        assert!(compiler.compile().is_err());
+
+        let output_path = &compiler.context.config.output.path;
+        assert_ne!(
+            fs::read_to_string(output_path.join("index.js")).unwrap(),
+            "good output"
+        );
+    }
 }
@@ -1,5 +1,6 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs};
 use std::path::{Path, PathBuf};
 
 use anyhow::{anyhow, Result};
@@ -11,10 +12,14 @@ use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 use swc_core::ecma::ast::EsVersion;
 use thiserror::Error;
+use tracing::warn;
 use {clap, config, thiserror};
 
+use super::resolved::{collect_sources, load_source, ConfigSource};
+use super::validate_config;
+use crate::features::env::Env;
 use crate::features::node::Node;
-use crate::{plugins, visitors};
+use crate::{build, generate, plugins, visitors};
 
 #[derive(Debug, Diagnostic)]
 #[diagnostic(code("mako.config.json parsed failed"))]
@@ -39,17 +44,28 @@ fn validate_mako_config(abs_config_file: String) -> miette::Result<()> {
         let content = std::fs::read_to_string(abs_config_file.clone())
             .map_err(|e| miette!("Failed to read file '{}': {}", &abs_config_file, e))?;
         let result: Result<Value, serde_json::Error> = serde_json::from_str(&content);
-        if let Err(e) = result {
-            let line = e.line();
-            let column = e.column();
-            let start = SourceOffset::from_location(&content, line, column);
-            let span = SourceSpan::new(start, (1 as ByteOffset).into());
-            return Err(ConfigParseError {
-                src: NamedSource::new("mako.config.json", content),
-                span,
-                message: e.to_string(),
+        match result {
+            Err(e) => {
+                let line = e.line();
+                let column = e.column();
+                let start = SourceOffset::from_location(&content, line, column);
+                let span = SourceSpan::new(start, (1 as ByteOffset).into());
+                return Err(ConfigParseError {
+                    src: NamedSource::new("mako.config.json", content),
+                    span,
+                    message: e.to_string(),
+                }
+                .into());
+            }
+            Ok(value) => {
+                let issues = validate_config(&value);
+                for warning in issues.warnings {
+                    warn!("{}", warning);
+                }
+                if !issues.errors.is_empty() {
+                    return Err(miette!("{}", issues.errors.join("\n")));
+                }
             }
-            .into());
         }
     }
     Ok(())
@@ -90,10 +106,15 @@ macro_rules! create_deserialize_fn {
 create_deserialize_fn!(deserialize_hmr, HmrConfig);
 create_deserialize_fn!(deserialize_dev_server, DevServerConfig);
 create_deserialize_fn!(deserialize_manifest, ManifestConfig);
+create_deserialize_fn!(deserialize_compression, CompressionConfig);
 create_deserialize_fn!(deserialize_code_splitting, CodeSplitting);
 create_deserialize_fn!(deserialize_px2rem, Px2RemConfig);
 create_deserialize_fn!(deserialize_progress, ProgressConfig);
 create_deserialize_fn!(deserialize_umd, String);
+create_deserialize_fn!(deserialize_public_dir, String);
+create_deserialize_fn!(deserialize_differential, DifferentialConfig);
+create_deserialize_fn!(deserialize_sri, SriConfig);
+create_deserialize_fn!(deserialize_cross_origin, CrossOrigin);
 create_deserialize_fn!(deserialize_devtool, DevtoolConfig);
 create_deserialize_fn!(deserialize_tree_shaking, TreeShakingStrategy);
 create_deserialize_fn!(deserialize_optimization, OptimizationConfig);
@@ -103,6 +124,9 @@ create_deserialize_fn!(deserialize_rsc_client, RscClientConfig);
 create_deserialize_fn!(deserialize_rsc_server, RscServerConfig);
 create_deserialize_fn!(deserialize_stats, StatsConfig);
 create_deserialize_fn!(deserialize_detect_loop, DetectCircularDependence);
+create_deserialize_fn!(deserialize_runtime_chunk, RuntimeChunk);
+create_deserialize_fn!(deserialize_cache, CacheConfig);
+create_deserialize_fn!(deserialize_manifest_integrity, SriAlgorithm);
 
 #[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -115,6 +139,47 @@ pub struct OutputConfig {
     pub preserve_modules: bool,
     pub preserve_modules_root: PathBuf,
     pub skip_write: bool,
+    pub format: OutputFormat,
+    #[serde(deserialize_with = "deserialize_compression", default)]
+    pub compression: Option<CompressionConfig>,
+}
+
+/// Precompressed sidecar files, e.g. `index.js.gz`, emitted next to the
+/// assets so that the web server can serve them directly
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CompressionConfig {
+    #[serde(default = "generate::compression::default_compression_algorithms")]
+    pub algorithms: Vec<CompressionAlgorithm>,
+    /// Assets smaller than the threshold in bytes are not compressed
+    #[serde(default = "generate::compression::default_compression_threshold")]
+    pub threshold: u64,
+    #[serde(default = "generate::compression::default_compression_extensions")]
+    pub extensions: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompressionAlgorithm {
+    #[serde(rename = "gzip")]
+    Gzip,
+    #[serde(rename = "brotli")]
+    Brotli,
+}
+
+/// The persistent cache, see [crate::build::cache::PersistentCache]
+#[derive(Deserialize, Serialize, Debug)]
+pub struct CacheConfig {
+    /// The directory of the cache files, relative to the root
+    #[serde(default = "build::cache::default_cache_dir")]
+    pub dir: String,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            dir: build::cache::default_cache_dir(),
+        }
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -126,12 +191,21 @@ pub struct ManifestConfig {
     pub file_name: String,
     #[serde(rename(deserialize = "basePath"), default)]
     pub base_path: String,
+    /// Add the subresource integrity of the files to the entries
+    #[serde(deserialize_with = "deserialize_manifest_integrity", default)]
+    pub integrity: Option<SriAlgorithm>,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct ResolveConfig {
     pub alias: Vec<(String, String)>,
     pub extensions: Vec<String>,
+    /// Packages which are always resolved from the root
+    pub dedupe: Vec<String>,
+    /// Conditions of the exports and imports fields, replace the defaults
+    /// unless `...` is used to extend them
+    pub condition_names: Vec<String>,
 }
 
 // format: HashMap<identifier, (import_source, specifier)>
@@ -156,6 +230,15 @@ pub enum OutputMode {
     Bundless,
 }
 
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub enum OutputFormat {
+    #[serde(rename = "iife")]
+    Iife,
+    /// Wrap the entry chunks with `System.register` for SystemJS
+    #[serde(rename = "system")]
+    System,
+}
+
 #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
 pub enum Platform {
     #[serde(rename = "browser")]
@@ -188,6 +271,28 @@ pub enum ModuleIdStrategy {
     Named,
 }
 
+/// How the ids of the chunks split at the dynamic imports and workers are
+/// generated, the module ids are used when not set
+#[derive(Deserialize, Serialize, Clone, Copy, Debug)]
+pub enum ChunkIdStrategy {
+    #[serde(rename = "hashed")]
+    Hashed,
+    #[serde(rename = "named")]
+    Named,
+}
+
+/// Where the runtime of the entries goes, it's inlined into every entry when
+/// not set
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RuntimeChunk {
+    // one runtime chunk shared by all the entries
+    #[serde(rename = "single")]
+    Single,
+    // a runtime chunk for each entry
+    #[serde(rename = "multiple")]
+    Multiple,
+}
+
 #[derive(Deserialize, Serialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct CodeSplittingGranularOptions {
@@ -199,6 +304,9 @@ pub struct CodeSplittingGranularOptions {
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct StatsConfig {
     pub modules: bool,
+    /// Record the named imports rewritten by `transformImport`, for debugging
+    #[serde(rename = "importRewrites", default)]
+    pub import_rewrites: bool,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
@@ -235,6 +343,53 @@ pub enum TreeShakingStrategy {
     Advanced,
 }
 
+/// How much the tree shaking trusts the code to be side effect free
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TreeShakingLevel {
+    /// Ignore the `sideEffects` field of package.json
+    #[serde(rename = "safe")]
+    Safe,
+    #[serde(rename = "recommended")]
+    Recommended,
+    /// Assume the function calls at the top level are pure
+    #[serde(rename = "aggressive")]
+    Aggressive,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AssetRule {
+    /// Glob of the asset path relative to the root, e.g. `src/icons/**`
+    pub test: String,
+    pub emit: AssetEmit,
+}
+
+/// How an asset matched by [AssetRule] is handled, instead of comparing the
+/// size with `inlineLimit`
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetEmit {
+    /// Always emitted as a file
+    #[serde(rename = "file")]
+    File,
+    /// Always inlined as a data url
+    #[serde(rename = "inline")]
+    Inline,
+    /// Not emitted, the url of the file is still returned, e.g. when the
+    /// file is deployed separately
+    #[serde(rename = "reference")]
+    Reference,
+}
+
+/// How the require and import calls with non-static arguments are reported
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DynamicRequireLevel {
+    #[serde(rename = "ignore")]
+    Ignore,
+    #[serde(rename = "warn")]
+    Warn,
+    #[serde(rename = "error")]
+    Error,
+}
+
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct Px2RemConfig {
     #[serde(default = "visitors::css_px2rem::default_root")]
@@ -367,6 +522,25 @@ pub enum ExternalConfig {
     Advanced(ExternalAdvanced),
 }
 
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(untagged)]
+pub enum CopyConfig {
+    Basic(String),
+    Advanced(CopyAdvancedConfig),
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct CopyAdvancedConfig {
+    /// A path or a glob relative to the root, e.g. `assets/**/*.png`
+    pub from: String,
+    /// The directory in the output path to copy to
+    #[serde(default)]
+    pub to: String,
+    /// The base of the paths of the matched files in `to`, defaults to the
+    /// static part of `from`
+    pub context: Option<String>,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct InjectItem {
@@ -409,6 +583,12 @@ pub struct MinifishConfig {
 pub struct OptimizationConfig {
     pub skip_modules: Option<bool>,
     pub concatenate_modules: Option<bool>,
+    /// Inline the imported primitive constants
+    #[serde(default)]
+    pub inline_constants: Option<bool>,
+    /// Remove the instance methods whose names are never read
+    #[serde(default)]
+    pub class_methods: Option<bool>,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -434,6 +614,15 @@ pub enum LogServerComponent {
 #[serde(rename_all = "camelCase")]
 pub struct RscClientConfig {
     pub log_server_component: LogServerComponent,
+    /// Keep `"use client"` and `"use server"` directives at the top of
+    /// the module through transform and minify.
+    #[serde(default)]
+    pub preserve_directives: bool,
+    /// Split every `"use client"` module into its own async chunk, and emit
+    /// `client-reference-manifest.json`, which maps the module to its
+    /// module id and chunks.
+    #[serde(default)]
+    pub client_reference_manifest: bool,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -450,6 +639,9 @@ pub struct ExperimentalConfig {
     pub require_context: bool,
     #[serde(deserialize_with = "deserialize_detect_loop")]
     pub detect_circular_dependence: Option<DetectCircularDependence>,
+    /// Warn at runtime when exports of a module in a cycle are accessed
+    /// before it's initialized, only works in development mode.
+    pub circular_runtime_warning: bool,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -460,15 +652,395 @@ pub struct WatchConfig {
     pub node_modules_regexes: Option<Vec<String>>,
 }
 
+/// How the `.wasm` modules are instantiated
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WasmMode {
+    /// Fetch and instantiate the emitted file, it's an async module
+    #[serde(rename = "async")]
+    #[default]
+    Async,
+    /// Inline the bytes and instantiate synchronously
+    #[serde(rename = "sync")]
+    Sync,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
-pub struct HmrConfig {}
+pub struct WasmConfig {
+    #[serde(default)]
+    pub mode: WasmMode,
+    /// Max size in bytes of the modules instantiated synchronously with the
+    /// sync mode, the larger ones are still async, since the browsers only
+    /// compile small modules synchronously on the main thread.
+    #[serde(default = "GenericUsizeDefault::<4096>::value")]
+    pub sync_limit: usize,
+}
+
+impl Default for WasmConfig {
+    fn default() -> Self {
+        Self {
+            mode: WasmMode::Async,
+            sync_limit: 4096,
+        }
+    }
+}
+
+impl WasmConfig {
+    pub fn is_sync(&self, file_size: u64) -> bool {
+        self.mode == WasmMode::Sync && file_size <= self.sync_limit as u64
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct JsonConfig {
+    /// Check the `.json` files as JSON5 like `.json5` and `.jsonc`, for the
+    /// comments and trailing commas, false to check them as strict json
+    #[serde(default = "default_json_lenient")]
+    pub lenient: bool,
+}
+
+impl Default for JsonConfig {
+    fn default() -> Self {
+        Self { lenient: true }
+    }
+}
+
+fn default_json_lenient() -> bool {
+    true
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HmrConfig {
+    /// Directory to write hot update files to, defaults to the output path.
+    pub hot_update_dir: Option<PathBuf>,
+    /// Interval in milliseconds of the pings to the websocket clients, the
+    /// clients not answering before the next ping are disconnected, 0 to
+    /// disable.
+    #[serde(default = "GenericUsizeDefault::<30000>::value")]
+    pub ping_interval: usize,
+}
 
 #[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct DevServerConfig {
     pub host: String,
     pub port: u16,
+    /// Ports to try from `port` on when they are in use
+    #[serde(default = "GenericUsizeDefault::<10>::value")]
+    pub max_port_attempts: usize,
+    /// Extension to MIME type map, merged over the defaults.
+    #[serde(default)]
+    pub mime_types: HashMap<String, String>,
+    /// Serve over https with the PEM files, relative to the root
+    #[serde(default)]
+    pub https: Option<HttpsConfig>,
+    #[serde(default)]
+    pub history_api_fallback: Option<HistoryApiFallback>,
+    /// Path prefix to the backend to forward the requests to
+    #[serde(default)]
+    pub proxy: HashMap<String, ProxyConfig>,
+    #[serde(default)]
+    pub cors: Option<CorsConfig>,
+}
+
+/// The CORS headers of the responses, `true` allows any origin
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(untagged)]
+pub enum CorsConfig {
+    Enabled(bool),
+    Origin(String),
+    Options(CorsOptions),
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct CorsOptions {
+    #[serde(default = "default_cors_origin")]
+    pub origin: String,
+    /// The methods allowed by the preflight requests
+    pub methods: Option<Vec<String>>,
+    /// The headers allowed by the preflight requests, the requested ones
+    /// are allowed when not set
+    pub headers: Option<Vec<String>>,
+}
+
+fn default_cors_origin() -> String {
+    "*".to_string()
+}
+
+impl CorsConfig {
+    /// The allowed origin, `None` when CORS is disabled
+    pub fn origin(&self) -> Option<&str> {
+        match self {
+            CorsConfig::Enabled(enabled) => enabled.then_some("*"),
+            CorsConfig::Origin(origin) => Some(origin),
+            CorsConfig::Options(options) => Some(&options.origin),
+        }
+    }
+
+    pub fn methods(&self) -> String {
+        match self {
+            CorsConfig::Options(CorsOptions {
+                methods: Some(methods),
+                ..
+            }) => methods.join(", "),
+            _ => "GET, HEAD, OPTIONS".to_string(),
+        }
+    }
+
+    pub fn headers(&self) -> Option<String> {
+        match self {
+            CorsConfig::Options(CorsOptions {
+                headers: Some(headers),
+                ..
+            }) => Some(headers.join(", ")),
+            _ => None,
+        }
+    }
+}
+
+/// The backend url, or the options with it
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(untagged)]
+pub enum ProxyConfig {
+    Target(String),
+    Options(ProxyOptions),
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyOptions {
+    pub target: String,
+    /// Rewrite the Host header to the one of the target
+    #[serde(default)]
+    pub change_origin: bool,
+}
+
+impl ProxyConfig {
+    pub fn target(&self) -> &str {
+        match self {
+            ProxyConfig::Target(target) => target,
+            ProxyConfig::Options(options) => &options.target,
+        }
+    }
+
+    pub fn change_origin(&self) -> bool {
+        matches!(self, ProxyConfig::Options(options) if options.change_origin)
+    }
+}
+
+/// Serve an html file for the client side routes, `true` for `index.html`
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(untagged)]
+pub enum HistoryApiFallback {
+    Enabled(bool),
+    Rewrites {
+        rewrites: Vec<HistoryApiFallbackRewrite>,
+    },
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct HistoryApiFallbackRewrite {
+    /// Regex of the request path
+    pub from: String,
+    /// Path of the html file in the output
+    pub to: String,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct HttpsConfig {
+    pub cert: Option<String>,
+    pub key: Option<String>,
+}
+
+impl DevServerConfig {
+    /// The address to bind, `localhost` is taken as `127.0.0.1` and the
+    /// other host names are resolved, so it's called when serving only
+    pub fn ip(&self) -> Result<IpAddr> {
+        self.ip_with(|host, port| {
+            (host, port)
+                .to_socket_addrs()
+                .ok()
+                .and_then(|mut addrs| addrs.next())
+                .map(|addr| addr.ip())
+        })
+    }
+
+    // with the resolver of the host names, so that the tests don't look up
+    // the dns
+    fn ip_with(&self, resolve: impl Fn(&str, u16) -> Option<IpAddr>) -> Result<IpAddr> {
+        if self.host == "localhost" {
+            return Ok(IpAddr::V4(Ipv4Addr::LOCALHOST));
+        }
+        if let Ok(ip) = self.host.parse() {
+            return Ok(ip);
+        }
+        resolve(&self.host, self.port).ok_or_else(|| {
+            anyhow!(
+                "devServer.host must be an IP address or a host name which resolves, but we found {}",
+                self.host
+            )
+        })
+    }
+
+    /// The html file in the output to serve for the path of a client side
+    /// route, the first matched rewrite wins, `index.html` by default
+    pub fn history_api_fallback(&self, path: &str) -> Option<String> {
+        let to = match self.history_api_fallback.as_ref()? {
+            HistoryApiFallback::Enabled(enabled) => enabled.then_some("index.html")?,
+            HistoryApiFallback::Rewrites { rewrites } => rewrites
+                .iter()
+                .find(|rewrite| Regex::new(&rewrite.from).is_ok_and(|from| from.is_match(path)))
+                .map_or("index.html", |rewrite| rewrite.to.as_str()),
+        };
+        Some(to.trim_start_matches('/').to_string())
+    }
+
+    /// The proxy of the longest path prefix the path starts with
+    pub fn proxy(&self, path: &str) -> Option<&ProxyConfig> {
+        self.proxy
+            .iter()
+            .filter(|(prefix, _)| path.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, proxy)| proxy)
+    }
+
+    /// The certificate and private key files to serve over https
+    pub fn tls_files(&self) -> Result<Option<(&str, &str)>> {
+        let Some(https) = &self.https else {
+            return Ok(None);
+        };
+        match (&https.cert, &https.key) {
+            (Some(cert), Some(key)) => Ok(Some((cert, key))),
+            (cert, _) => Err(anyhow!(
+                "devServer.https requires both cert and key, but {} is missing",
+                if cert.is_none() { "cert" } else { "key" }
+            )),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HtmlConfig {
+    pub template: String,
+    pub filename: Option<String>,
+    /// Marker in the template to inject the tags at, defaults to before `</body>`.
+    pub inject: Option<String>,
+    #[serde(default)]
+    pub variables: HashMap<String, String>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DifferentialConfig {
+    pub legacy_targets: HashMap<String, f32>,
+    /// Inline the modulepreload polyfill before the scripts of the html files.
+    #[serde(default)]
+    pub module_preload_polyfill: bool,
+    /// Set internally for the legacy pass of a differential build.
+    #[serde(rename = "_isLegacy", default)]
+    pub is_legacy: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SriConfig {
+    #[serde(default)]
+    pub algorithm: SriAlgorithm,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SriAlgorithm {
+    #[serde(rename = "sha256")]
+    Sha256,
+    #[default]
+    #[serde(rename = "sha384")]
+    Sha384,
+    #[serde(rename = "sha512")]
+    Sha512,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossOrigin {
+    #[serde(rename = "anonymous")]
+    Anonymous,
+    #[serde(rename = "use-credentials")]
+    UseCredentials,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DecoratorsConfig {
+    /// Use the legacy (TypeScript experimentalDecorators) semantics,
+    /// otherwise the TC39 2022-03 proposal is used
+    pub legacy: bool,
+    pub emit_metadata: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TransformConfig {
+    pub decorators: DecoratorsConfig,
+    /// How the `/*! */`, `@license` and `@preserve` comments are kept, if not
+    /// set they are kept inline unless minified
+    #[serde(default)]
+    pub legal_comments: Option<LegalCommentsMode>,
+    /// Where the legal comments are written to with `legalComments: "external"`
+    pub legal_comments_file: LegalCommentsFile,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegalCommentsMode {
+    /// Dropped
+    #[serde(rename = "none")]
+    None,
+    /// Kept where they are, even when minified
+    #[serde(rename = "inline")]
+    Inline,
+    /// Moved to the end of the chunk
+    #[serde(rename = "eof")]
+    Eof,
+    /// Moved to a separate `.LEGAL.txt` file
+    #[serde(rename = "external")]
+    External,
+    /// Moved to a `.LICENSE.txt` file next to each chunk, which is referenced
+    /// by a comment at the top of the chunk
+    #[serde(rename = "linked")]
+    Linked,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegalCommentsFile {
+    /// `<chunk>.LEGAL.txt` for each chunk
+    #[serde(rename = "chunk")]
+    Chunk,
+    /// One `LEGAL.txt` for all the chunks
+    #[serde(rename = "combined")]
+    Combined,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JsMinifyConfig {
+    /// Max passes of the compressor, 0 means compressing until nothing changes
+    pub passes: usize,
+    /// The syntax the compressor may output, ES5 if not set
+    #[serde(default)]
+    pub ecma: Option<EsVersion>,
+    /// Drop and mangle the unused top level declarations
+    pub toplevel: bool,
+    #[serde(default)]
+    pub mangle_props: Option<ManglePropsConfig>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ManglePropsConfig {
+    /// Only the properties matching the regex are mangled
+    pub regex: String,
+    /// Properties which are never mangled, e.g. the ones of external APIs
+    #[serde(default)]
+    pub reserved: Vec<String>,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -481,17 +1053,33 @@ pub struct Config {
     pub manifest: Option<ManifestConfig>,
     pub mode: Mode,
     pub minify: bool,
+    pub js_minify: JsMinifyConfig,
     #[serde(deserialize_with = "deserialize_devtool")]
     pub devtool: Option<DevtoolConfig>,
     pub externals: HashMap<String, ExternalConfig>,
     pub providers: Providers,
-    pub copy: Vec<String>,
+    pub copy: Vec<CopyConfig>,
+    #[serde(deserialize_with = "deserialize_public_dir", default)]
+    pub public_dir: Option<String>,
     pub public_path: String,
+    pub root_files: Vec<String>,
+    pub preload_chunks: Vec<String>,
+    pub html: HashMap<String, HtmlConfig>,
     pub inline_limit: usize,
+    #[serde(default)]
+    pub wasm: WasmConfig,
+    #[serde(default)]
+    pub json: JsonConfig,
+    pub asset_rules: Vec<AssetRule>,
     pub targets: HashMap<String, f32>,
     pub platform: Platform,
     pub module_id_strategy: ModuleIdStrategy,
+    #[serde(default)]
+    pub chunk_ids: Option<ChunkIdStrategy>,
+    #[serde(deserialize_with = "deserialize_runtime_chunk", default)]
+    pub runtime_chunk: Option<RuntimeChunk>,
     pub define: HashMap<String, Value>,
+    pub env_prefix: Vec<String>,
     pub analyze: Option<AnalyzeConfig>,
     pub stats: Option<StatsConfig>,
     pub mdx: bool,
@@ -501,6 +1089,7 @@ pub struct Config {
     pub dev_server: Option<DevServerConfig>,
     #[serde(deserialize_with = "deserialize_code_splitting", default)]
     pub code_splitting: Option<CodeSplitting>,
+    pub chunk_groups: Vec<ChunkGroupConfig>,
     #[serde(deserialize_with = "deserialize_px2rem", default)]
     pub px2rem: Option<Px2RemConfig>,
     #[serde(deserialize_with = "deserialize_progress", default)]
@@ -508,11 +1097,25 @@ pub struct Config {
     pub hash: bool,
     #[serde(rename = "_treeShaking", deserialize_with = "deserialize_tree_shaking")]
     pub _tree_shaking: Option<TreeShakingStrategy>,
+    pub tree_shaking_level: TreeShakingLevel,
+    /// The globs of the modules whose exports are all kept by tree shaking,
+    /// matched against the paths relative to the root
+    #[serde(default)]
+    pub keep_exports: Vec<String>,
+    /// The package name globs to the `sideEffects` used instead of the one
+    /// in their package.json, for the packages which declare it wrongly
+    #[serde(default)]
+    pub side_effects_overrides: HashMap<String, bool>,
+    /// Annotate the exports of each module with the tree shaking decisions
+    /// in the output, for debugging, ignored when minify is on
+    #[serde(default)]
+    pub tree_shaking_annotations: bool,
     #[serde(rename = "autoCSSModules")]
     pub auto_css_modules: bool,
     #[serde(rename = "ignoreCSSParserErrors")]
     pub ignore_css_parser_errors: bool,
     pub dynamic_import_to_require: bool,
+    pub dynamic_require: DynamicRequireLevel,
     #[serde(deserialize_with = "deserialize_umd", default)]
     pub umd: Option<String>,
     pub cjs: bool,
@@ -520,6 +1123,10 @@ pub struct Config {
     pub transform_import: Vec<TransformImportConfig>,
     pub chunk_parallel: bool,
     pub clean: bool,
+    /// Deprecated, it's folded into `cache` by [Config::new]
+    pub persistent_cache: bool,
+    #[serde(deserialize_with = "deserialize_cache", default)]
+    pub cache: Option<CacheConfig>,
     pub node_polyfill: bool,
     pub ignores: Vec<String>,
     #[serde(
@@ -544,6 +1151,10 @@ pub struct Config {
         default
     )]
     pub inline_css: Option<InlineCssConfig>,
+    /// Media of the top level `@media` rules moved out of the entry css into
+    /// separate files, which are linked with the `media` attribute in html
+    #[serde(rename = "splitCSSMedia")]
+    pub split_css_media: Vec<String>,
     #[serde(
         rename = "rscServer",
         deserialize_with = "deserialize_rsc_server",
@@ -560,8 +1171,58 @@ pub struct Config {
     pub watch: WatchConfig,
     pub use_define_for_class_fields: bool,
     pub emit_decorator_metadata: bool,
+    pub transform: TransformConfig,
+    #[serde(deserialize_with = "deserialize_differential", default)]
+    pub differential: Option<DifferentialConfig>,
+    /// Add `integrity` to the script and link tags injected into the html files
+    #[serde(deserialize_with = "deserialize_sri", default)]
+    pub sri: Option<SriConfig>,
+    /// `crossorigin` of the script and link tags injected into the html files
+    #[serde(deserialize_with = "deserialize_cross_origin", default)]
+    pub cross_origin: Option<CrossOrigin>,
+    /// Keep the previous output when the build fails, on by default in
+    /// production, not used in watch mode
+    #[serde(default)]
+    pub no_emit_on_error: Option<bool>,
+    /// Paths of the values set by the user, the cli or the env, see [Config::resolved]
+    #[serde(skip)]
+    pub sources: BTreeMap<String, ConfigSource>,
+    /// The default and cli config, kept to resolve the config again
+    #[serde(skip)]
+    pub inputs: ConfigInputs,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ConfigInputs {
+    pub default_config: Option<String>,
+    pub cli_config: Option<String>,
 }
 
+// the server, the clients and the watcher are set up with these values, so
+// changing them can't be applied by a rebuild
+const RESTART_REQUIRED_CONFIG: [&str; 3] = ["/devServer", "/hmr", "/output/path"];
+
+// only used by the generate stage, so the built module graph can be generated
+// again with other values, see `Compiler::regenerate`
+const GENERATE_ONLY_CONFIG: [&str; 16] = [
+    "/analyze",
+    "/chunkGroups",
+    "/clean",
+    "/codeSplitting",
+    "/devtool",
+    "/hash",
+    "/jsMinify",
+    "/manifest",
+    "/minify",
+    "/output/chunkLoadingGlobal",
+    "/output/compression",
+    "/output/path",
+    "/output/skipWrite",
+    "/stats",
+    "/transform/legalComments",
+    "/transform/legalCommentsFile",
+];
+
 #[derive(Deserialize, Serialize, Clone, Debug, Default)]
 pub enum OptimizeAllowChunks {
     #[serde(rename = "all")]
@@ -618,6 +1279,9 @@ pub struct OptimizeChunkGroup {
     pub priority: i8,
     #[serde(default, with = "optimize_test_format")]
     pub test: Option<Regex>,
+    /// Ignore min_chunks, min_size and max_size, always split the matched modules.
+    #[serde(default)]
+    pub enforce: bool,
 }
 
 impl Default for OptimizeChunkGroup {
@@ -632,10 +1296,20 @@ impl Default for OptimizeChunkGroup {
             min_module_size: None,
             test: None,
             priority: i8::default(),
+            enforce: false,
         }
     }
 }
 
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct ChunkGroupConfig {
+    pub name: String,
+    #[serde(with = "optimize_test_format")]
+    pub test: Option<Regex>,
+    #[serde(default)]
+    pub priority: i8,
+}
+
 /**
  * custom formatter for convert string to regex
  * @see https://serde.rs/custom-date-format.html
@@ -681,33 +1355,45 @@ const DEFAULT_CONFIG: &str = r#"
       "chunkLoadingGlobal": "",
       "preserveModules": false,
       "preserveModulesRoot": "",
-      "skipWrite": false
+      "skipWrite": false,
+      "format": "iife"
     },
-    "resolve": { "alias": [], "extensions": ["js", "jsx", "ts", "tsx"] },
+    "resolve": { "alias": [], "extensions": ["js", "jsx", "ts", "tsx"], "dedupe": [], "conditionNames": [] },
     "mode": "development",
     "minify": true,
+    "jsMinify": { "passes": 0, "toplevel": false },
     "devtool": "source-map",
     "externals": {},
     "copy": ["public"],
+    "publicDir": false,
     "providers": {},
     "publicPath": "/",
+    "rootFiles": [],
+    "preloadChunks": [],
+    "chunkGroups": [],
+    "html": {},
     "inlineLimit": 10000,
+    "assetRules": [],
     "targets": { "chrome": 80 },
     "less": { "theme": {}, "lesscPath": "", javascriptEnabled: true },
     "define": {},
+    "envPrefix": ["MAKO_APP_"],
     "mdx": false,
     "platform": "browser",
     "hmr": {},
     "moduleIdStrategy": "named",
     "hash": false,
     "_treeShaking": "basic",
+    "treeShakingLevel": "recommended",
     "autoCSSModules": false,
     "ignoreCSSParserErrors": false,
     "dynamicImportToRequire": false,
+    "dynamicRequire": "ignore",
     "writeToDisk": true,
     "transformImport": [],
     "chunkParallel": true,
     "clean": true,
+    "persistentCache": false,
     "nodePolyfill": true,
     "ignores": [],
     "optimizePackageImports": false,
@@ -727,15 +1413,21 @@ const DEFAULT_CONFIG: &str = r#"
     "emitAssets": true,
     "cssModulesExportOnlyLocales": false,
     "inlineCSS": false,
+    "splitCSSMedia": [],
     "rscServer": false,
     "rscClient": false,
     "experimental": {
       "webpackSyntaxValidate": [],
       "requireContext": true,
-      "detectCircularDependence": { "ignores": ["node_modules"], "graphviz": false }
+      "detectCircularDependence": { "ignores": ["node_modules"], "graphviz": false },
+      "circularRuntimeWarning": false
     },
     "useDefineForClassFields": true,
     "emitDecoratorMetadata": false,
+    "transform": { "decorators": { "legacy": true, "emitMetadata": false }, "legalCommentsFile": "chunk" },
+    "differential": false,
+    "sri": false,
+    "crossOrigin": false,
     "watch": { "ignorePaths": [], "_nodeModulesRegexes": [] },
     "devServer": { "host": "127.0.0.1", "port": 3000 }
 }
@@ -769,8 +1461,19 @@ impl Config {
             .map_err(|e| anyhow!("{}", format!("{:?}", e)))?;
         // user config
         let c = c.add_source(config::File::with_name(abs_config_file).required(false));
+        let mut layers = vec![(
+            ConfigSource::User,
+            load_source(config::File::with_name(abs_config_file).required(false))?,
+        )];
         // cli config
         let c = if let Some(cli_config) = cli_config {
+            layers.push((
+                ConfigSource::Cli,
+                load_source(config::File::from_str(
+                    cli_config,
+                    config::FileFormat::Json5,
+                ))?,
+            ));
             c.add_source(config::File::from_str(
                 cli_config,
                 config::FileFormat::Json5,
@@ -783,11 +1486,25 @@ impl Config {
         let mut ret = c.try_deserialize::<Config>();
         // normalize & check
         if let Ok(config) = &mut ret {
+            config.sources = collect_sources(&layers);
+            config.inputs = ConfigInputs {
+                default_config: default_config.map(|c| c.to_string()),
+                cli_config: cli_config.map(|c| c.to_string()),
+            };
+
             // normalize output
             if config.output.path.is_relative() {
                 config.output.path = root.join(config.output.path.to_string_lossy().to_string());
             }
 
+            // normalize hot update dir
+            if let Some(hmr) = &mut config.hmr
+                && let Some(hot_update_dir) = &hmr.hot_update_dir
+                && hot_update_dir.is_relative()
+            {
+                hmr.hot_update_dir = Some(root.join(hot_update_dir));
+            }
+
             if config.output.chunk_loading_global.is_empty() {
                 config.output.chunk_loading_global =
                     get_default_chunk_loading_global(config.umd.clone(), root);
@@ -796,29 +1513,125 @@ impl Config {
             let node_env_config_opt = config.define.get("NODE_ENV");
             if let Some(node_env_config) = node_env_config_opt {
                 if node_env_config.as_str() != Some(config.mode.to_string().as_str()) {
-                    let warn_message = format!(
-                        "{}: The configuration of {} conflicts with current {} and will be overwritten as {} ",
-                        "warning".to_string().yellow(),
+                    warn!(
+                        "The configuration of {} conflicts with current {} and will be overwritten as {}",
                         "NODE_ENV".to_string().yellow(),
                         "mode".to_string().yellow(),
                         config.mode.to_string().red()
                     );
-                    println!("{}", warn_message);
                 }
             }
 
+            // `persistentCache: true` is the same as `cache: {}`
+            if config.persistent_cache && config.cache.is_none() {
+                config.cache = Some(CacheConfig::default());
+            }
+
+            // the files of publicDir are copied by itself, so the dir is
+            // dropped from the default `copy`, the set one is kept as is
+            if let Some(public_dir) = &config.public_dir
+                && !config.sources.contains_key("copy")
+            {
+                let public_dir = Path::new(public_dir.trim_start_matches("./"));
+                config.copy.retain(
+                    |copy| !matches!(copy, CopyConfig::Basic(src) if Path::new(src) == public_dir),
+                );
+            }
+
             if config.cjs && config.umd.is_some() {
                 return Err(anyhow!("cjs and umd cannot be used at the same time",));
             }
 
+            if config.output.format == OutputFormat::System && (config.cjs || config.umd.is_some())
+            {
+                return Err(anyhow!(
+                    "output.format system can't be used with umd or cjs",
+                ));
+            }
+
+            // the runtime chunk is a script which the entries are pushed to
+            if config.runtime_chunk.is_some()
+                && (config.cjs
+                    || config.umd.is_some()
+                    || config.output.format == OutputFormat::System
+                    || config.platform != Platform::Browser)
+            {
+                return Err(anyhow!(
+                    "runtimeChunk can only be used with the browser platform, and without umd, cjs or output.format system",
+                ));
+            }
+
+            if config
+                .output
+                .compression
+                .as_ref()
+                .is_some_and(|compression| compression.algorithms.is_empty())
+            {
+                return Err(anyhow!("output.compression.algorithms should not be empty",));
+            }
+
             if config.hmr.is_some() && config.dev_server.is_none() {
                 return Err(anyhow!("hmr can only be used with devServer",));
             }
 
+            if let Some(dev_server) = &config.dev_server {
+                dev_server.tls_files()?;
+                for (prefix, proxy) in &dev_server.proxy {
+                    // there's no tls client to connect to the https backends
+                    if !proxy.target().starts_with("http://") {
+                        return Err(anyhow!(
+                            "devServer.proxy target of {} must be an http url, but got {}",
+                            prefix,
+                            proxy.target()
+                        ));
+                    }
+                }
+                if let Some(HistoryApiFallback::Rewrites { rewrites }) =
+                    &dev_server.history_api_fallback
+                {
+                    for rewrite in rewrites {
+                        Regex::new(&rewrite.from).map_err(|e| {
+                            anyhow!(
+                                "devServer.historyApiFallback.rewrites has an invalid regex {}: {}",
+                                rewrite.from,
+                                e
+                            )
+                        })?;
+                    }
+                }
+            }
+
             if config.inline_css.is_some() && config.umd.is_none() {
                 return Err(anyhow!("inlineCSS can only be used with umd",));
             }
 
+            if config.differential.is_some()
+                && (config.umd.is_some() || config.cjs || matches!(config.platform, Platform::Node))
+            {
+                return Err(anyhow!(
+                    "differential can only be used with browser platform and can't be used with umd or cjs",
+                ));
+            }
+
+            // emitDecoratorMetadata is kept for compatibility
+            if config.emit_decorator_metadata {
+                config.transform.decorators.emit_metadata = true;
+            }
+            if config.transform.decorators.emit_metadata && !config.transform.decorators.legacy {
+                return Err(anyhow!(
+                    "transform.decorators.emitMetadata can only be used with legacy decorators",
+                ));
+            }
+
+            if let Some(mangle_props) = &config.js_minify.mangle_props
+                && Regex::new(&mangle_props.regex).is_err()
+            {
+                return Err(anyhow!(
+                    "jsMinify.mangleProps.regex must be a valid regex, but we found {}",
+                    mangle_props.regex
+                ));
+            }
+
             let mode = format!("\"{}\"", config.mode);
             config
                 .define
@@ -828,6 +1641,32 @@ impl Config {
                 return Err(anyhow!("public_path must end with '/' or be 'runtime'"));
             }
 
+            let mut root_file_names = HashSet::new();
+            for file in config.root_files.iter() {
+                let name = Path::new(file)
+                    .file_name()
+                    .ok_or_else(|| anyhow!("rootFiles {} must be a file", file))?;
+                if !root_file_names.insert(name.to_os_string()) {
+                    return Err(anyhow!(
+                        "duplicated rootFiles name {}",
+                        name.to_string_lossy()
+                    ));
+                }
+            }
+
+            let mut chunk_group_names = HashSet::new();
+            for chunk_group in config.chunk_groups.iter() {
+                if chunk_group.test.is_none() {
+                    return Err(anyhow!(
+                        "chunkGroups.{}.test must be a valid regex",
+                        chunk_group.name
+                    ));
+                }
+                if !chunk_group_names.insert(&chunk_group.name) {
+                    return Err(anyhow!("duplicated chunkGroups name {}", chunk_group.name));
+                }
+            }
+
             // 暂不支持 remote external
             // 如果 config.externals 中有值是以「script 」开头，则 panic 报错
             let basic_external_values = config
@@ -877,6 +1716,19 @@ impl Config {
                 .collect::<Result<Vec<_>>>()?;
             config.entry = entry_tuples.into_iter().collect();
 
+            for (name, html) in config.html.iter() {
+                if !config.entry.contains_key(name) {
+                    return Err(anyhow!("html.{} does not match any entry", name));
+                }
+                if !root.join(&html.template).is_file() {
+                    return Err(anyhow!(
+                        "html.{}.template {} not found",
+                        name,
+                        html.template
+                    ));
+                }
+            }
+
             // support relative alias
             config.resolve.alias = config
                 .resolve
@@ -900,9 +1752,59 @@ impl Config {
 
             // configure node platform
             Node::modify_config(config);
+
+            // load .env files
+            Env::modify_config(config, root)?;
         }
         ret.map_err(|e| anyhow!("{}: {}", "config error".red(), e.to_string().red()))
     }
+
+    pub fn config_file(root: &Path) -> PathBuf {
+        root.join(CONFIG_FILE)
+    }
+
+    /// Resolve the config again with the same default and cli config, e.g.
+    /// after `mako.config.json` is changed in watch mode
+    pub fn reload(&self, root: &Path) -> Result<Self> {
+        Config::new(
+            root,
+            self.inputs.default_config.as_deref(),
+            self.inputs.cli_config.as_deref(),
+        )
+    }
+
+    /// The first changed value which can only be applied by restarting mako
+    pub fn restart_required(&self, next: &Config) -> Option<String> {
+        let prev = serde_json::to_value(self).ok()?;
+        let next = serde_json::to_value(next).ok()?;
+        RESTART_REQUIRED_CONFIG
+            .iter()
+            .find(|pointer| prev.pointer(pointer) != next.pointer(pointer))
+            .map(|pointer| pointer.trim_start_matches('/').replace('/', "."))
+    }
+
+    /// The first top level option which differs in `next` and is used by the
+    /// build stage, the modules must be built again if there's one
+    pub fn rebuild_required(&self, next: &Config) -> Option<String> {
+        let without_generate_only = |config: &Config| {
+            let mut value = serde_json::to_value(config).ok()?;
+            for pointer in GENERATE_ONLY_CONFIG {
+                if let Some(v) = value.pointer_mut(pointer) {
+                    *v = Value::Null;
+                }
+            }
+            Some(value)
+        };
+        let prev = without_generate_only(self)?;
+        let next = without_generate_only(next)?;
+        let (Value::Object(prev), Value::Object(next)) = (prev, next) else {
+            return None;
+        };
+        prev.keys()
+            .chain(next.keys())
+            .find(|key| prev.get(*key) != next.get(*key))
+            .cloned()
+    }
 }
 
 impl Default for Config {
@@ -954,8 +1856,10 @@ impl<const U: usize> GenericUsizeDefault<U> {
 
 #[cfg(test)]
 mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
     use crate::config::config::GenericUsizeDefault;
-    use crate::config::{Config, Mode, Platform};
+    use crate::config::{Config, CopyConfig, Mode, Platform};
 
     #[test]
     fn test_config() {
@@ -991,6 +1895,26 @@ mod tests {
         assert_eq!(config.platform, Platform::Browser);
     }
 
+    #[test]
+    fn test_deprecated_persistent_cache() {
+        let current_dir = std::env::current_dir().unwrap();
+        let config = Config::new(
+            &current_dir.join("test/config/normal"),
+            None,
+            Some(r#"{"persistentCache":true}"#),
+        )
+        .unwrap();
+        assert_eq!(config.cache.unwrap().dir, "node_modules/.cache_mako");
+
+        let config = Config::new(
+            &current_dir.join("test/config/normal"),
+            None,
+            Some(r#"{"persistentCache":true,"cache":{"dir":".cache/mako"}}"#),
+        )
+        .unwrap();
+        assert_eq!(config.cache.unwrap().dir, ".cache/mako");
+    }
+
     #[test]
     fn test_node_env_conflicts_with_mode() {
         let current_dir = std::env::current_dir().unwrap();
@@ -1006,6 +1930,13 @@ mod tests {
         );
     }
 
+    #[test]
+    #[should_panic(expected = "output.path must be a string")]
+    fn test_config_invalid_type() {
+        let current_dir = std::env::current_dir().unwrap();
+        Config::new(&current_dir.join("test/config/invalid"), None, None).unwrap();
+    }
+
     #[test]
     #[should_panic(expected = "public_path must end with '/' or be 'runtime'")]
     fn test_config_invalid_public_path() {
@@ -1018,6 +1949,195 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn test_public_dir_copy() {
+        let current_dir = std::env::current_dir().unwrap();
+        let copy = |cli_config: &str| {
+            let config = Config::new(
+                &current_dir.join("test/config/normal"),
+                None,
+                Some(cli_config),
+            )
+            .unwrap();
+            config
+                .copy
+                .iter()
+                .map(|copy| match copy {
+                    CopyConfig::Basic(src) => src.clone(),
+                    CopyConfig::Advanced(copy) => copy.from.clone(),
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(copy("{}"), vec!["public"]);
+        // the dir is copied by publicDir
+        assert!(copy(r#"{"publicDir":"public"}"#).is_empty());
+        assert!(copy(r#"{"publicDir":"./public"}"#).is_empty());
+        assert_eq!(copy(r#"{"publicDir":"static"}"#), vec!["public"]);
+        // the set copy is kept
+        assert_eq!(
+            copy(r#"{"publicDir":"public","copy":["public","assets"]}"#),
+            vec!["public", "assets"]
+        );
+    }
+
+    #[test]
+    fn test_dev_server_host() {
+        let current_dir = std::env::current_dir().unwrap();
+        let config = |host: &str| {
+            Config::new(
+                &current_dir.join("test/config/normal"),
+                None,
+                Some(&format!(
+                    r#"{{"devServer":{{"host":"{}","port":3000}}}}"#,
+                    host
+                )),
+            )
+        };
+        // only the host names are resolved
+        let ip = |host: &str| {
+            config(host)
+                .unwrap()
+                .dev_server
+                .unwrap()
+                .ip_with(|host, port| match (host, port) {
+                    ("dev.test", 3000) => Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))),
+                    ("0.0.0.0" | "localhost" | "::1", _) => panic!("{} is resolved", host),
+                    _ => None,
+                })
+        };
+        assert_eq!(ip("0.0.0.0").unwrap(), IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+        assert_eq!(ip("localhost").unwrap(), IpAddr::V4(Ipv4Addr::LOCALHOST));
+        assert_eq!(ip("::1").unwrap(), IpAddr::V6(Ipv6Addr::LOCALHOST));
+        assert_eq!(
+            ip("dev.test").unwrap(),
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))
+        );
+        assert_eq!(
+            ip("mako.invalid").unwrap_err().to_string(),
+            "devServer.host must be an IP address or a host name which resolves, but we found mako.invalid"
+        );
+    }
+
+    #[test]
+    fn test_dev_server_https() {
+        let current_dir = std::env::current_dir().unwrap();
+        let config = |https: &str| {
+            Config::new(
+                &current_dir.join("test/config/normal"),
+                None,
+                Some(&format!(
+                    r#"{{"devServer":{{"host":"127.0.0.1","port":3000,"https":{}}}}}"#,
+                    https
+                )),
+            )
+        };
+        let config_ok = config(r#"{"cert":"cert.pem","key":"key.pem"}"#).unwrap();
+        assert_eq!(
+            config_ok.dev_server.unwrap().tls_files().unwrap(),
+            Some(("cert.pem", "key.pem"))
+        );
+        assert!(config(r#"{"cert":"cert.pem"}"#)
+            .unwrap_err()
+            .to_string()
+            .contains("devServer.https requires both cert and key, but key is missing"));
+        assert!(config(r#"{"key":"key.pem"}"#)
+            .unwrap_err()
+            .to_string()
+            .contains("but cert is missing"));
+    }
+
+    #[test]
+    fn test_dev_server_proxy() {
+        let current_dir = std::env::current_dir().unwrap();
+        let config = |proxy: &str| {
+            Config::new(
+                &current_dir.join("test/config/normal"),
+                None,
+                Some(&format!(
+                    r#"{{"devServer":{{"host":"127.0.0.1","port":3000,"proxy":{}}}}}"#,
+                    proxy
+                )),
+            )
+        };
+        let dev_server = config(
+            r#"{"/api":"http://localhost:8080","/api/v2":{"target":"http://localhost:8082","changeOrigin":true}}"#,
+        )
+        .unwrap()
+        .dev_server
+        .unwrap();
+        let proxy = dev_server.proxy("/api/v2/users").unwrap();
+        assert_eq!(proxy.target(), "http://localhost:8082");
+        assert!(proxy.change_origin());
+        let proxy = dev_server.proxy("/api/users").unwrap();
+        assert_eq!(proxy.target(), "http://localhost:8080");
+        assert!(!proxy.change_origin());
+        assert!(dev_server.proxy("/users").is_none());
+        assert!(config(r#"{"/api":"https://localhost:8080"}"#)
+            .unwrap_err()
+            .to_string()
+            .contains("devServer.proxy target of /api must be an http url"));
+    }
+
+    #[test]
+    fn test_history_api_fallback_rewrites() {
+        let current_dir = std::env::current_dir().unwrap();
+        let config = |fallback: &str| {
+            Config::new(
+                &current_dir.join("test/config/normal"),
+                None,
+                Some(&format!(
+                    r#"{{"devServer":{{"host":"127.0.0.1","port":3000,"historyApiFallback":{}}}}}"#,
+                    fallback
+                )),
+            )
+        };
+        let dev_server = config(r#"{"rewrites":[{"from":"^/admin","to":"/admin.html"}]}"#)
+            .unwrap()
+            .dev_server
+            .unwrap();
+        assert_eq!(
+            dev_server.history_api_fallback("/admin/users"),
+            Some("admin.html".to_string())
+        );
+        assert_eq!(
+            dev_server.history_api_fallback("/users"),
+            Some("index.html".to_string())
+        );
+        let dev_server = config("false").unwrap().dev_server.unwrap();
+        assert_eq!(dev_server.history_api_fallback("/users"), None);
+        assert!(config(r#"{"rewrites":[{"from":"(","to":"index.html"}]}"#)
+            .unwrap_err()
+            .to_string()
+            .contains("devServer.historyApiFallback.rewrites has an invalid regex ("));
+    }
+
+    #[test]
+    fn test_emit_decorator_metadata_compat() {
+        let current_dir = std::env::current_dir().unwrap();
+        let config = Config::new(
+            &current_dir.join("test/config/normal"),
+            None,
+            Some(r#"{"emitDecoratorMetadata":true}"#),
+        )
+        .unwrap();
+        assert!(config.transform.decorators.legacy);
+        assert!(config.transform.decorators.emit_metadata);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "transform.decorators.emitMetadata can only be used with legacy decorators"
+    )]
+    fn test_decorators_metadata_without_legacy() {
+        let current_dir = std::env::current_dir().unwrap();
+        Config::new(
+            &current_dir.join("test/config/normal"),
+            None,
+            Some(r#"{"transform":{"decorators":{"legacy":false,"emitMetadata":true}}}"#),
+        )
+        .unwrap();
+    }
+
     #[test]
     fn test_node_platform() {
         let current_dir = std::env::current_dir().unwrap();
@@ -1,3 +1,7 @@
 #[allow(clippy::module_inception)]
 mod config;
+mod resolved;
+mod schema;
 pub use config::*;
+pub use resolved::ConfigSource;
+pub use schema::{validate_config, SchemaIssues, CONFIG_SCHEMA};
@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use super::Config;
+
+/// Where a value of the resolved config comes from
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// The builtin defaults, or the default config passed by the caller
+    #[serde(rename = "default")]
+    Default,
+    /// `mako.config.json`
+    #[serde(rename = "user")]
+    User,
+    /// The cli args, e.g. `--mode`, or the config passed by the node binding
+    #[serde(rename = "cli")]
+    Cli,
+    /// The env vars exposed with `define`, from the `.env` files or the
+    /// process env, see `envPrefix`
+    #[serde(rename = "env")]
+    Env,
+}
+
+pub(super) fn load_source<T>(source: T) -> Result<Value>
+where
+    T: config::Source + Send + Sync + 'static,
+{
+    Ok(config::Config::builder()
+        .add_source(source)
+        .build()?
+        .try_deserialize::<Value>()?)
+}
+
+// the paths of the values set by each source, the later source wins
+pub(super) fn collect_sources(layers: &[(ConfigSource, Value)]) -> BTreeMap<String, ConfigSource> {
+    let mut sources = BTreeMap::new();
+    for (source, value) in layers {
+        let mut paths = vec![];
+        collect_paths(value, "", &mut paths);
+        for path in paths {
+            sources.insert(path, *source);
+        }
+    }
+    sources
+}
+
+// paths of the leaf values, arrays and empty objects are leaves as a whole
+fn collect_paths(value: &Value, prefix: &str, paths: &mut Vec<String>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, value) in map {
+                collect_paths(value, &join_path(prefix, key), paths);
+            }
+        }
+        _ if !prefix.is_empty() => paths.push(prefix.to_string()),
+        _ => {}
+    }
+}
+
+fn join_path(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+// `a.b` is inside `a`
+fn is_within(path: &str, parent: &str) -> bool {
+    path == parent
+        || path
+            .strip_prefix(parent)
+            .is_some_and(|rest| rest.starts_with('.'))
+}
+
+impl Config {
+    /// The effective config as json, with the source of every value, e.g.
+    /// `{ "config": { "mode": "production", .. }, "sources": { "mode": "cli", .. } }`
+    pub fn resolved(&self) -> Value {
+        let config = serde_json::to_value(self).unwrap_or(Value::Null);
+        let mut paths = vec![];
+        collect_paths(&config, "", &mut paths);
+        let sources = paths
+            .into_iter()
+            .map(|path| {
+                // the set value may be a whole object, or a part of the
+                // resolved value, e.g. `hmr: false` resolves to `hmr: null`
+                let source = self
+                    .sources
+                    .iter()
+                    .filter(|(set, _)| is_within(&path, set) || is_within(set, &path))
+                    .max_by_key(|(set, _)| set.len())
+                    .map_or(ConfigSource::Default, |(_, source)| *source);
+                (path, json!(source))
+            })
+            .collect::<Map<_, _>>();
+        json!({
+            "config": config,
+            "sources": sources,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Config;
+
+    #[test]
+    fn test_resolved_config() {
+        let current_dir = std::env::current_dir().unwrap();
+        let config = Config::new(
+            &current_dir.join("test/config/resolved"),
+            None,
+            Some(r#"{"mode":"production"}"#),
+        )
+        .unwrap();
+        let resolved = config.resolved();
+        let sources = &resolved["sources"];
+
+        assert_eq!(resolved["config"]["mode"], "production");
+        assert_eq!(sources["mode"], "cli");
+        assert_eq!(resolved["config"]["publicPath"], "/static/");
+        assert_eq!(sources["publicPath"], "user");
+        assert_eq!(sources["output.chunkLoadingGlobal"], "user");
+        // the defaults fill in the unset fields
+        assert_eq!(resolved["config"]["output"]["esVersion"], "es2022");
+        assert_eq!(sources["output.esVersion"], "default");
+        assert_eq!(resolved["config"]["minify"], true);
+        assert_eq!(sources["minify"], "default");
+        // the prefixed vars of `.env`
+        assert_eq!(
+            resolved["config"]["define"]["process.env.MAKO_APP_NAME"],
+            "\"resolved\""
+        );
+        assert_eq!(sources["define.process.env.MAKO_APP_NAME"], "env");
+    }
+}
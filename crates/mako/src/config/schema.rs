@@ -0,0 +1,250 @@
+use serde_json::Value;
+
+/// JSON schema of mako.config.json, also shipped with the npm package for editors.
+pub const CONFIG_SCHEMA: &str = include_str!("../../../../packages/mako/config.schema.json");
+
+#[derive(Debug, Default)]
+pub struct SchemaIssues {
+    /// Values of the wrong type or not in the allowed values.
+    pub errors: Vec<String>,
+    /// Unknown keys, usually typos, and deprecated options.
+    pub warnings: Vec<String>,
+}
+
+pub fn validate_config(config: &Value) -> SchemaIssues {
+    let schema: Value = serde_json::from_str(CONFIG_SCHEMA).unwrap();
+    let mut issues = SchemaIssues::default();
+    validate(config, &schema, "", &mut issues);
+    issues
+}
+
+// a small subset of json schema: type, enum, required, properties and additionalProperties
+fn validate(value: &Value, schema: &Value, path: &str, issues: &mut SchemaIssues) {
+    let name = if path.is_empty() { "config" } else { path };
+    if let Some(types) = schema_types(schema)
+        && !types.iter().any(|t| is_type(value, t))
+    {
+        issues
+            .errors
+            .push(format!("{} must be {}", name, describe_types(&types)));
+        return;
+    }
+    if let Some(Value::Array(allowed)) = schema.get("enum")
+        && !allowed.contains(value)
+    {
+        let allowed = allowed
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        issues.errors.push(format!(
+            "{} must be one of {}, but got {}",
+            name, allowed, value
+        ));
+        return;
+    }
+    if schema.get("deprecated") == Some(&Value::Bool(true)) {
+        let message = match schema.get("deprecationMessage").and_then(|m| m.as_str()) {
+            Some(message) => format!("{} is deprecated, {}", name, message),
+            None => format!("{} is deprecated", name),
+        };
+        issues.warnings.push(message);
+    }
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::Array(required)) = schema.get("required") {
+                for key in required.iter().filter_map(|k| k.as_str()) {
+                    if !map.contains_key(key) {
+                        issues
+                            .errors
+                            .push(format!("{} is required", join_path(path, key)));
+                    }
+                }
+            }
+            let properties = schema.get("properties").and_then(|p| p.as_object());
+            for (key, value) in map.iter() {
+                let key_path = join_path(path, key);
+                if let Some(property) = properties.and_then(|p| p.get(key)) {
+                    validate(value, property, &key_path, issues);
+                    continue;
+                }
+                match schema.get("additionalProperties") {
+                    Some(Value::Bool(false)) => {
+                        let known = properties
+                            .map(|p| p.keys().map(|k| k.as_str()).collect::<Vec<_>>())
+                            .unwrap_or_default();
+                        let message = match suggest(key, &known) {
+                            Some(suggestion) => format!(
+                                "unknown config {}, did you mean {}?",
+                                key_path,
+                                join_path(path, suggestion)
+                            ),
+                            None => format!("unknown config {}", key_path),
+                        };
+                        issues.warnings.push(message);
+                    }
+                    Some(additional @ Value::Object(_)) => {
+                        validate(value, additional, &key_path, issues);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    validate(item, item_schema, &format!("{}[{}]", name, index), issues);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn schema_types(schema: &Value) -> Option<Vec<&str>> {
+    match schema.get("type") {
+        Some(Value::String(t)) => Some(vec![t.as_str()]),
+        Some(Value::Array(types)) => Some(types.iter().filter_map(|t| t.as_str()).collect()),
+        _ => None,
+    }
+}
+
+fn is_type(value: &Value, t: &str) -> bool {
+    match t {
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_u64() || value.is_i64(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn describe_types(types: &[&str]) -> String {
+    types
+        .iter()
+        .map(|t| match *t {
+            "object" | "array" | "integer" => format!("an {}", t),
+            _ => format!("a {}", t),
+        })
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
+
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+// the closest known key within a small edit distance
+fn suggest<'a>(key: &str, known: &[&'a str]) -> Option<&'a str> {
+    let key = key.to_lowercase();
+    let max_distance = (key.len() / 3).clamp(2, 3);
+    known
+        .iter()
+        .map(|k| (*k, edit_distance(&key, &k.to_lowercase())))
+        .filter(|(_, distance)| *distance <= max_distance)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(k, _)| k)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn sorted(mut messages: Vec<String>) -> Vec<String> {
+        messages.sort();
+        messages
+    }
+
+    #[test]
+    fn test_wrong_type() {
+        let issues = validate_config(&json!({ "output": { "path": 1 }, "minify": "yes" }));
+        assert_eq!(
+            sorted(issues.errors),
+            vec!["minify must be a boolean", "output.path must be a string"]
+        );
+    }
+
+    #[test]
+    fn test_enum_and_false_or_object() {
+        let issues = validate_config(&json!({
+            "mode": "prod",
+            "devServer": false,
+            "hmr": "yes",
+        }));
+        assert_eq!(
+            sorted(issues.errors),
+            vec![
+                "hmr must be a boolean or an object",
+                r#"mode must be one of "development", "production", but got "prod""#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_key() {
+        let issues = validate_config(&json!({
+            "publicpath": "/",
+            "output": { "paht": "dist" },
+            "foo": true,
+        }));
+        assert!(issues.errors.is_empty());
+        assert_eq!(
+            sorted(issues.warnings),
+            vec![
+                "unknown config foo",
+                "unknown config output.paht, did you mean output.path?",
+                "unknown config publicpath, did you mean publicPath?",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deprecated() {
+        let issues = validate_config(&json!({ "persistentCache": true }));
+        assert!(issues.errors.is_empty());
+        assert_eq!(
+            issues.warnings,
+            vec!["persistentCache is deprecated, use cache instead"]
+        );
+    }
+
+    #[test]
+    fn test_nested_values() {
+        let issues = validate_config(&json!({
+            "html": { "index": { "filename": "index.html" } },
+            "targets": { "chrome": true },
+            "copy": ["public", 1],
+        }));
+        assert_eq!(
+            sorted(issues.errors),
+            vec![
+                "copy[1] must be a string or an object",
+                "html.index.template is required",
+                "targets.chrome must be a number or a string",
+            ]
+        );
+    }
+}
@@ -1,36 +1,71 @@
+mod proxy;
+mod tls;
 pub(crate) mod update;
 mod watch;
 
-use std::net::{SocketAddr, TcpListener};
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hasher;
+use std::io::ErrorKind;
+use std::net::{IpAddr, SocketAddr, TcpListener};
 use std::path::PathBuf;
-use std::sync::{mpsc, Arc};
-use std::time::{Duration, Instant};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, OnceLock, RwLock};
+use std::time::{Duration, Instant, SystemTime};
 
-use anyhow::{self, Result};
+use anyhow::{self, anyhow, Result};
+use chrono::{DateTime, Utc};
 use colored::Colorize;
-use futures::{SinkExt, StreamExt};
+use futures::{future, stream, Sink, SinkExt, Stream, StreamExt};
 use get_if_addrs::get_if_addrs;
-use hyper::header::CONTENT_TYPE;
+use hyper::header::{
+    HeaderName, HeaderValue, ACCEPT, ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS,
+    ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_REQUEST_HEADERS, CACHE_CONTROL, CONTENT_LENGTH,
+    CONTENT_TYPE, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED, VARY,
+};
+use hyper::server::accept;
 use hyper::service::{make_service_fn, service_fn};
-use hyper::{Body, Request, Server};
+use hyper::{Body, Method, Request, Server};
 use notify_debouncer_full::new_debouncer;
+use regex::Regex;
 use tokio::sync::broadcast;
 use tracing::debug;
 use tungstenite::Message;
+use twox_hash::XxHash64;
 use {hyper, hyper_staticfile, hyper_tungstenite, open};
 
 use crate::compiler::{Compiler, Context};
-use crate::plugin::PluginGenerateEndParams;
-use crate::utils::{process_req_url, tokio_runtime};
+use crate::config::Config;
+use crate::plugin::{PluginGenerateEndParams, PluginUpdateParams};
+use crate::plugins::copy;
+use crate::plugins::public_dir::get_public_dir;
+use crate::utils::{process_req_url, thread_pool, tokio_runtime};
+
+// the compiler is replaced by a new one when the config file is changed
+type SharedCompiler = Arc<RwLock<Arc<Compiler>>>;
+
+type ServerFuture = Pin<Box<dyn Future<Output = hyper::Result<()>> + Send>>;
 
 pub struct DevServer {
     root: PathBuf,
-    compiler: Arc<Compiler>,
+    compiler: SharedCompiler,
+    port: OnceLock<u16>,
 }
 
 impl DevServer {
     pub fn new(root: PathBuf, compiler: Arc<Compiler>) -> Self {
-        Self { root, compiler }
+        Self {
+            root,
+            compiler: Arc::new(RwLock::new(compiler)),
+            port: OnceLock::new(),
+        }
+    }
+
+    /// The port the server listens on, the next ports are used when
+    /// `devServer.port` is in use, `None` before the server is started
+    pub fn port(&self) -> Option<u16> {
+        self.port.get().copied()
     }
 
     pub async fn serve(&self) {
@@ -39,46 +74,113 @@ impl DevServer {
         // watch
         let root = self.root.clone();
         let compiler = self.compiler.clone();
+        let initial_compiler = self.compiler.read().unwrap().clone();
         let txws_watch = txws.clone();
 
-        if self.compiler.context.config.dev_server.is_some() {
+        if initial_compiler.context.config.dev_server.is_some() {
             std::thread::spawn(move || {
-                if let Err(e) = Self::watch_for_changes(root, compiler, txws_watch) {
+                let rebuild = Self::hmr_rebuilder(compiler.clone(), txws_watch);
+                if let Err(e) = Self::watch_for_changes(root, compiler, rebuild) {
                     eprintln!("Error watching files: {:?}", e);
                 }
             });
-        } else if let Err(e) = Self::watch_for_changes(root, compiler, txws_watch) {
-            eprintln!("Error watching files: {:?}", e);
+        } else {
+            let rebuild = Self::hmr_rebuilder(compiler.clone(), txws_watch);
+            if let Err(e) = Self::watch_for_changes(root, compiler, rebuild) {
+                eprintln!("Error watching files: {:?}", e);
+            }
         }
 
         // server
-        if self.compiler.context.config.dev_server.is_some() {
-            let config_port = self
-                .compiler
-                .context
-                .config
-                .dev_server
-                .as_ref()
-                .unwrap()
-                .port;
-            let port = Self::find_available_port("127.0.0.1".to_string(), config_port);
-            let addr: SocketAddr = ([127, 0, 0, 1], port).into();
-            let context = self.compiler.context.clone();
+        if initial_compiler.context.config.dev_server.is_some() {
+            let dev_server = initial_compiler.context.config.dev_server.as_ref().unwrap();
+            let config_port = dev_server.port;
+            let ip = match dev_server.ip() {
+                Ok(ip) => ip,
+                Err(e) => {
+                    eprintln!("Error starting server: {}", e);
+                    return;
+                }
+            };
+            let (listener, port) =
+                match Self::bind_available_port(ip, config_port, dev_server.max_port_attempts) {
+                    Ok(bound) => bound,
+                    Err(e) => {
+                        eprintln!("Error starting server: {}", e);
+                        return;
+                    }
+                };
+            let _ = self.port.set(port);
+            let addr = SocketAddr::new(ip, port);
+            // the unspecified and loopback addresses are reachable as localhost
+            let local_host = match ip {
+                _ if ip.is_unspecified() || ip.is_loopback() => "localhost".to_string(),
+                IpAddr::V4(ip) => ip.to_string(),
+                IpAddr::V6(ip) => format!("[{}]", ip),
+            };
+            let tls_acceptor = dev_server.tls_files().and_then(|files| {
+                files
+                    .map(|(cert, key)| {
+                        tls::tls_acceptor(&self.root.join(cert), &self.root.join(key))
+                    })
+                    .transpose()
+            });
+            let tls_acceptor = match tls_acceptor {
+                Ok(tls_acceptor) => tls_acceptor,
+                Err(e) => {
+                    eprintln!("Error starting server: {:?}", e);
+                    return;
+                }
+            };
+            let scheme = if tls_acceptor.is_some() {
+                "https"
+            } else {
+                "http"
+            };
+            let compiler = self.compiler.clone();
             let txws = txws.clone();
-            let make_svc = make_service_fn(move |_conn| {
-                let context = context.clone();
-                let txws = txws.clone();
-                async move {
-                    Ok::<_, hyper::Error>(service_fn(move |req| {
-                        let context = context.clone();
+            let server: ServerFuture = match tls_acceptor {
+                // the websocket of hmr is upgraded from the https connections
+                // as well, i.e. `wss://`
+                Some(acceptor) => {
+                    let incoming = match tls::tls_incoming(listener, acceptor) {
+                        Ok(incoming) => incoming,
+                        Err(e) => {
+                            eprintln!("Error starting server: {:?}", e);
+                            return;
+                        }
+                    };
+                    let make_svc = make_service_fn(move |_conn| {
+                        let compiler = compiler.clone();
                         let txws = txws.clone();
-                        let staticfile =
-                            hyper_staticfile::Static::new(context.config.output.path.clone());
-                        async move { Self::handle_requests(req, context, staticfile, txws).await }
-                    }))
+                        async move {
+                            Ok::<_, hyper::Error>(service_fn(move |req| {
+                                Self::handle_shared_requests(req, &compiler, &txws)
+                            }))
+                        }
+                    });
+                    Box::pin(Server::builder(accept::from_stream(incoming)).serve(make_svc))
                 }
-            });
-            let server = Server::bind(&addr).serve(make_svc);
+                None => {
+                    let builder = match Server::from_tcp(listener) {
+                        Ok(builder) => builder,
+                        Err(e) => {
+                            eprintln!("Error starting server: {:?}", e);
+                            return;
+                        }
+                    };
+                    let make_svc = make_service_fn(move |_conn| {
+                        let compiler = compiler.clone();
+                        let txws = txws.clone();
+                        async move {
+                            Ok::<_, hyper::Error>(service_fn(move |req| {
+                                Self::handle_shared_requests(req, &compiler, &txws)
+                            }))
+                        }
+                    });
+                    Box::pin(builder.serve(make_svc))
+                }
+            };
             // TODO: print when mako is run standalone
             if std::env::var("MAKO_CLI").is_ok() {
                 println!();
@@ -92,9 +194,17 @@ impl DevServer {
                 }
                 println!(
                     "Local:   {}",
-                    format!("http://localhost:{}/", port).to_string().cyan()
+                    format!("{}://{}:{}/", scheme, local_host, port)
+                        .to_string()
+                        .cyan()
                 );
-                let ips = Self::get_ips();
+                // only listening on all the interfaces is reachable from
+                // the network
+                let ips = if ip.is_unspecified() {
+                    Self::get_ips()
+                } else {
+                    vec![]
+                };
                 let ips = ips
                     .iter()
                     .filter(|ip| !ip.starts_with("127."))
@@ -102,24 +212,87 @@ impl DevServer {
                 for ip in ips {
                     println!(
                         "Network: {}",
-                        format!("http://{}:{}/", ip, port).to_string().cyan()
+                        format!("{}://{}:{}/", scheme, ip, port).to_string().cyan()
                     );
                 }
                 println!();
-                open::that(format!("http://localhost:{}/", port)).unwrap();
+                open::that(format!("{}://{}:{}/", scheme, local_host, port)).unwrap();
             }
-            debug!("Listening on http://{:?}", addr);
+            debug!("Listening on {}://{:?}", scheme, addr);
             if let Err(e) = server.await {
                 eprintln!("Error starting server: {:?}", e);
             }
         }
     }
 
+    fn handle_shared_requests(
+        req: Request<Body>,
+        compiler: &SharedCompiler,
+        txws: &broadcast::Sender<WsMessage>,
+    ) -> impl Future<Output = Result<hyper::Response<Body>>> {
+        let context = compiler.read().unwrap().context.clone();
+        let staticfile = hyper_staticfile::Static::new(context.config.output.path.clone());
+        Self::handle_requests(req, context, staticfile, txws.clone())
+    }
+
+    // the preflight requests are answered here, and all the responses get the
+    // CORS headers with `devServer.cors`
     async fn handle_requests(
         req: Request<Body>,
         context: Arc<Context>,
         staticfile: hyper_staticfile::Static,
         txws: broadcast::Sender<WsMessage>,
+    ) -> Result<hyper::Response<Body>> {
+        let Some(cors_headers) = Self::cors_headers(&req, &context) else {
+            return Self::route_requests(req, context, staticfile, txws).await;
+        };
+        let mut res = if req.method() == Method::OPTIONS {
+            hyper::Response::builder()
+                .status(hyper::StatusCode::NO_CONTENT)
+                .body(Body::empty())
+                .unwrap()
+        } else {
+            Self::route_requests(req, context, staticfile, txws).await?
+        };
+        for (name, value) in cors_headers {
+            res.headers_mut().entry(name).or_insert(value);
+        }
+        Ok(res)
+    }
+
+    fn cors_headers(
+        req: &Request<Body>,
+        context: &Context,
+    ) -> Option<Vec<(HeaderName, HeaderValue)>> {
+        let cors = context.config.dev_server.as_ref()?.cors.as_ref()?;
+        let origin = cors.origin()?;
+        let mut headers = vec![(
+            ACCESS_CONTROL_ALLOW_ORIGIN,
+            HeaderValue::from_str(origin).ok()?,
+        )];
+        if origin != "*" {
+            headers.push((VARY, HeaderValue::from_static("Origin")));
+        }
+        if req.method() == Method::OPTIONS {
+            if let Ok(methods) = HeaderValue::from_str(&cors.methods()) {
+                headers.push((ACCESS_CONTROL_ALLOW_METHODS, methods));
+            }
+            let allowed_headers = match cors.headers() {
+                Some(allowed_headers) => HeaderValue::from_str(&allowed_headers).ok(),
+                None => req.headers().get(ACCESS_CONTROL_REQUEST_HEADERS).cloned(),
+            };
+            if let Some(allowed_headers) = allowed_headers {
+                headers.push((ACCESS_CONTROL_ALLOW_HEADERS, allowed_headers));
+            }
+        }
+        Some(headers)
+    }
+
+    async fn route_requests(
+        req: Request<Body>,
+        context: Arc<Context>,
+        staticfile: hyper_staticfile::Static,
+        txws: broadcast::Sender<WsMessage>,
     ) -> Result<hyper::Response<Body>> {
         let mut path = req.uri().path().to_string();
         let public_path = &context.config.public_path;
@@ -142,31 +315,49 @@ impl DevServer {
                 .unwrap()
         };
         match path.as_str() {
-            "/__/hmr-ws" => {
+            "/__/hmr-ws" if context.config.hmr.is_some() => {
                 if hyper_tungstenite::is_upgrade_request(&req) {
                     debug!("new websocket connection");
                     let (response, websocket) = hyper_tungstenite::upgrade(req, None).unwrap();
                     let txws = txws.clone();
+                    let ping_interval = Duration::from_millis(
+                        context.config.hmr.as_ref().unwrap().ping_interval as u64,
+                    );
                     tokio_runtime::spawn(async move {
                         let receiver = txws.subscribe();
-                        Self::handle_websocket(websocket, receiver).await.unwrap();
+                        Self::handle_websocket(websocket, receiver, ping_interval)
+                            .await
+                            .unwrap();
                     });
                     Ok(response)
                 } else {
                     Ok(not_found_response())
                 }
             }
+            // the reload client of the entries when hmr is disabled
+            "/__/reload" if context.config.hmr.is_none() => {
+                debug!("new reload connection");
+                Ok(Self::reload_response(txws.subscribe()))
+            }
             _ => {
+                // for the backends, with the path before the publicPath is stripped
+                if let Some(proxy) = context
+                    .config
+                    .dev_server
+                    .as_ref()
+                    .and_then(|dev_server| dev_server.proxy(req.uri().path()))
+                {
+                    debug!("serve with proxy: {}", req.uri());
+                    return Ok(proxy::proxy_request(req, proxy).await);
+                }
+
                 // for bundle outputs
 
-                let ext = path.rsplit('.').next();
-                let content_type = match ext {
-                    None => "text/plain; charset=utf-8",
-                    Some("js") => "application/javascript; charset=utf-8",
-                    Some("css") => "text/css; charset=utf-8",
-                    Some("map") | Some("json") => "application/json; charset=utf-8",
-                    Some(_) => "text/plain; charset=utf-8",
-                };
+                let mime_types = context.config.dev_server.as_ref().map(|c| &c.mime_types);
+                let matched_content_type = Self::get_content_type(&path, mime_types);
+                let content_type = matched_content_type
+                    .clone()
+                    .unwrap_or_else(|| "text/plain; charset=utf-8".to_string());
 
                 // staticfile has 302 problems when modify tooooo fast in 1 second
                 // it will response 302 and we will get the old file
@@ -174,11 +365,7 @@ impl DevServer {
                 if let Some(res) = context.get_static_content(path_without_slash_start) {
                     debug!("serve with context.get_static_content: {}", path);
 
-                    return Ok(hyper::Response::builder()
-                        .status(hyper::StatusCode::OK)
-                        .header(CONTENT_TYPE, content_type)
-                        .body(hyper::Body::from(res))
-                        .unwrap());
+                    return Ok(Self::content_response(&req, res, content_type, None));
                 }
                 // for cached dep
                 let abs_path = context
@@ -186,23 +373,219 @@ impl DevServer {
                     .join("node_modules/.cache_mako/chunks")
                     .join(path_without_slash_start);
                 if !path_without_slash_start.is_empty() && abs_path.exists() {
+                    let last_modified = abs_path.metadata().and_then(|m| m.modified()).ok();
                     return std::fs::read(abs_path).map_or(Ok(not_found_response()), |bytes| {
-                        Ok(hyper::Response::builder()
-                            .status(hyper::StatusCode::OK)
-                            .header(CONTENT_TYPE, content_type)
-                            .body(hyper::Body::from(bytes))
-                            .unwrap())
+                        Ok(Self::content_response(
+                            &req,
+                            bytes,
+                            content_type,
+                            last_modified,
+                        ))
                     });
                 }
 
+                // for publicDir files, output files take precedence
+                if let Some(public_dir) = get_public_dir(&context) {
+                    let output_path = context.config.output.path.join(path_without_slash_start);
+                    let in_output =
+                        output_path.is_file() || output_path.join("index.html").is_file();
+                    if !in_output && public_dir.join(path_without_slash_start).exists() {
+                        debug!("serve with publicDir: {}", path);
+                        let res = hyper_staticfile::Static::new(public_dir).serve(req).await;
+                        return res
+                            .map(|res| Self::with_content_type(res, matched_content_type))
+                            .map_err(anyhow::Error::from);
+                    }
+                }
+
+                // for hmr files in hmr.hotUpdateDir
+                if let Some(hot_update_dir) = context
+                    .config
+                    .hmr
+                    .as_ref()
+                    .and_then(|hmr| hmr.hot_update_dir.as_ref())
+                    && path_without_slash_start.contains(".hot-update")
+                    && hot_update_dir.join(path_without_slash_start).is_file()
+                {
+                    debug!("serve with hot update dir: {}", path);
+                    let res = hyper_staticfile::Static::new(hot_update_dir)
+                        .serve(req)
+                        .await;
+                    return res
+                        .map(|res| Self::with_content_type(res, matched_content_type))
+                        .map_err(anyhow::Error::from);
+                }
+
+                // for client side routes of the spa
+                if let Some(fallback) = Self::history_api_fallback(&req, &path, &context) {
+                    let content = context
+                        .get_static_content(&fallback)
+                        .or_else(|| std::fs::read(context.config.output.path.join(&fallback)).ok());
+                    if let Some(content) = content {
+                        debug!("serve with historyApiFallback: {} -> {}", path, fallback);
+                        return Ok(Self::content_response(
+                            &req,
+                            content,
+                            "text/html; charset=utf-8".to_string(),
+                            None,
+                        ));
+                    }
+                }
+
                 // for hmr files
                 debug!("serve with staticfile server: {}", path);
                 let res = staticfile.serve(req).await;
-                res.map_err(anyhow::Error::from)
+                res.map(|res| Self::with_content_type(res, matched_content_type))
+                    .map_err(anyhow::Error::from)
             }
         }
     }
 
+    // the html file to serve for a page navigation which doesn't match an
+    // output file, the paths with an extension are assets and still get 404
+    fn history_api_fallback(req: &Request<Body>, path: &str, context: &Context) -> Option<String> {
+        let fallback = context
+            .config
+            .dev_server
+            .as_ref()?
+            .history_api_fallback(path)?;
+        if !matches!(*req.method(), Method::GET | Method::HEAD) {
+            return None;
+        }
+        let accept_html = req
+            .headers()
+            .get(ACCEPT)
+            .and_then(|accept| accept.to_str().ok())
+            .is_some_and(|accept| accept.contains("text/html"));
+        let last_segment = path.rsplit('/').next().unwrap_or_default();
+        if !accept_html || last_segment.contains('.') {
+            return None;
+        }
+        let relative_path = path.trim_start_matches('/');
+        let output_path = context.config.output.path.join(relative_path);
+        if output_path.is_file() || output_path.join("index.html").is_file() {
+            return None;
+        }
+        Some(fallback)
+    }
+
+    // contents which are not served by hyper_staticfile, the ETag is the hash
+    // of the content so that conditional requests can get 304, and HEAD
+    // requests only get the headers
+    fn content_response(
+        req: &Request<Body>,
+        content: Vec<u8>,
+        content_type: String,
+        last_modified: Option<SystemTime>,
+    ) -> hyper::Response<Body> {
+        let etag = {
+            let mut hasher: XxHash64 = Default::default();
+            hasher.write(&content);
+            format!("\"{:x}\"", hasher.finish())
+        };
+        let last_modified = last_modified.map(|time| {
+            DateTime::<Utc>::from(time)
+                .format("%a, %d %b %Y %H:%M:%S GMT")
+                .to_string()
+        });
+        let header = |name: HeaderName| {
+            req.headers()
+                .get(name)
+                .and_then(|value: &HeaderValue| value.to_str().ok())
+        };
+        // If-Modified-Since is ignored when If-None-Match is present
+        let not_modified = match header(IF_NONE_MATCH) {
+            Some(if_none_match) => if_none_match
+                .split(',')
+                .map(|tag| tag.trim().trim_start_matches("W/"))
+                .any(|tag| tag == "*" || tag == etag),
+            None => match (header(IF_MODIFIED_SINCE), &last_modified) {
+                (Some(since), Some(last_modified)) => {
+                    match (
+                        DateTime::parse_from_rfc2822(since),
+                        DateTime::parse_from_rfc2822(last_modified),
+                    ) {
+                        (Ok(since), Ok(last_modified)) => last_modified <= since,
+                        _ => false,
+                    }
+                }
+                _ => false,
+            },
+        };
+
+        let mut builder = hyper::Response::builder()
+            .header(CONTENT_TYPE, content_type)
+            .header(ETAG, &etag);
+        if let Some(last_modified) = &last_modified {
+            builder = builder.header(LAST_MODIFIED, last_modified);
+        }
+        if not_modified {
+            return builder
+                .status(hyper::StatusCode::NOT_MODIFIED)
+                .body(Body::empty())
+                .unwrap();
+        }
+        let builder = builder
+            .status(hyper::StatusCode::OK)
+            .header(CONTENT_LENGTH, content.len());
+        if req.method() == Method::HEAD {
+            builder.body(Body::empty()).unwrap()
+        } else {
+            builder.body(Body::from(content)).unwrap()
+        }
+    }
+
+    // user configured mime types take precedence over the defaults
+    fn get_content_type(
+        path: &str,
+        mime_types: Option<&HashMap<String, String>>,
+    ) -> Option<String> {
+        let ext = path.rsplit_once('.').map(|(_, ext)| ext.to_lowercase())?;
+        if let Some(mime) = mime_types.and_then(|m| {
+            m.iter()
+                .find(|(k, _)| k.trim_start_matches('.').eq_ignore_ascii_case(&ext))
+                .map(|(_, v)| v)
+        }) {
+            return Some(mime.clone());
+        }
+        let mime = match ext.as_str() {
+            "js" => "application/javascript; charset=utf-8",
+            "mjs" | "cjs" => "text/javascript; charset=utf-8",
+            "css" => "text/css; charset=utf-8",
+            "map" | "json" => "application/json; charset=utf-8",
+            "html" | "htm" => "text/html; charset=utf-8",
+            "txt" => "text/plain; charset=utf-8",
+            "wasm" => "application/wasm",
+            "webmanifest" => "application/manifest+json",
+            "svg" => "image/svg+xml",
+            "png" => "image/png",
+            "jpg" | "jpeg" => "image/jpeg",
+            "gif" => "image/gif",
+            "webp" => "image/webp",
+            "avif" => "image/avif",
+            "ico" => "image/x-icon",
+            "woff" => "font/woff",
+            "woff2" => "font/woff2",
+            "ttf" => "font/ttf",
+            "otf" => "font/otf",
+            _ => return None,
+        };
+        Some(mime.to_string())
+    }
+
+    fn with_content_type(
+        mut res: hyper::Response<Body>,
+        content_type: Option<String>,
+    ) -> hyper::Response<Body> {
+        if res.status() == hyper::StatusCode::OK
+            && let Some(content_type) = content_type
+            && let Ok(value) = HeaderValue::from_str(&content_type)
+        {
+            res.headers_mut().insert(CONTENT_TYPE, value);
+        }
+        res
+    }
+
     fn get_ips() -> Vec<String> {
         let mut ips = vec![];
         match get_if_addrs() {
@@ -219,91 +602,389 @@ impl DevServer {
         ips
     }
 
-    fn find_available_port(host: String, port: u16) -> u16 {
-        let mut port = port;
-        if TcpListener::bind((host.clone(), port)).is_ok() {
-            port
-        } else {
-            port += 1;
-            Self::find_available_port(host, port)
+    // the listener is kept for the server, so that the port can't be taken
+    // between the check and the bind
+    fn bind_available_port(
+        ip: IpAddr,
+        port: u16,
+        max_attempts: usize,
+    ) -> Result<(TcpListener, u16)> {
+        for port in (port..=u16::MAX).take(max_attempts.max(1)) {
+            match TcpListener::bind((ip, port)) {
+                Ok(listener) => {
+                    let port = listener.local_addr()?.port();
+                    return Ok((listener, port));
+                }
+                Err(e) if e.kind() == ErrorKind::AddrInUse => {
+                    debug!("port {} is in use", port);
+                }
+                Err(e) => return Err(anyhow!("failed to bind {}:{}: {}", ip, port, e)),
+            }
         }
+        Err(anyhow!(
+            "no available port in {} attempts from {}, change devServer.port or devServer.maxPortAttempts",
+            max_attempts.max(1),
+            port
+        ))
+    }
+
+    // server-sent events, a message is sent after each rebuild
+    fn reload_response(mut receiver: broadcast::Receiver<WsMessage>) -> hyper::Response<Body> {
+        let (mut sender, body) = Body::channel();
+        tokio_runtime::spawn(async move {
+            loop {
+                let hash = match receiver.recv().await {
+                    Ok(
+                        WsMessage::Update(hash)
+                        | WsMessage::CssUpdate { hash, .. }
+                        | WsMessage::Reload(hash),
+                    ) => hash,
+                    // the errors are for the websocket clients only
+                    Ok(WsMessage::Errors(_)) => continue,
+                    // a reload for the latest one is enough
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+                let event = format!("data: {}\n\n", hash);
+                if sender.send_data(event.into()).await.is_err() {
+                    break;
+                }
+            }
+            debug!("reload connection disconnected");
+        });
+        hyper::Response::builder()
+            .header(CONTENT_TYPE, "text/event-stream")
+            .header(CACHE_CONTROL, "no-cache")
+            .body(body)
+            .unwrap()
     }
 
-    // TODO: refact socket message data structure
     async fn handle_websocket(
         websocket: hyper_tungstenite::HyperWebsocket,
-        mut receiver: broadcast::Receiver<WsMessage>,
+        receiver: broadcast::Receiver<WsMessage>,
+        ping_interval: Duration,
     ) -> Result<()> {
         let websocket = websocket.await?;
-        let (mut sender, mut ws_recv) = websocket.split();
-        let task = tokio_runtime::spawn(async move {
+        let (sender, ws_recv) = websocket.split();
+        Self::forward_messages(sender, ws_recv, receiver, ping_interval).await;
+        debug!("websocket connection disconnected");
+        Ok(())
+    }
+
+    // forward the updates to the client until the connection is closed, a
+    // client silently gone (sleep, network change) is closed when the pong of
+    // the last ping isn't received before the next one, so that it's not
+    // counted as a receiver of the updates anymore
+    async fn forward_messages<S, R>(
+        mut sender: S,
+        mut ws_recv: R,
+        receiver: broadcast::Receiver<WsMessage>,
+        ping_interval: Duration,
+    ) where
+        S: Sink<Message> + Unpin,
+        R: Stream<Item = Result<Message, tungstenite::Error>> + Unpin,
+    {
+        let pong_received = AtomicBool::new(true);
+
+        let updates = stream::unfold(receiver, |mut receiver| async move {
             loop {
-                if let Ok(msg) = receiver.recv().await {
-                    if sender
-                        .send(Message::text(format!(r#"{{"hash":"{}"}}"#, msg.hash)))
-                        .await
-                        .is_err()
-                    {
-                        break;
-                    }
+                match receiver.recv().await {
+                    Ok(msg) => return Some((Outgoing::Update(msg), receiver)),
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
                 }
             }
         });
-        while let Some(message) = ws_recv.next().await {
-            if let Ok(Message::Close(_)) = message {
-                break;
+        let pings = if ping_interval.is_zero() {
+            stream::pending().boxed()
+        } else {
+            let start = tokio::time::Instant::now() + ping_interval;
+            stream::unfold(
+                tokio::time::interval_at(start, ping_interval),
+                |mut interval| async move {
+                    interval.tick().await;
+                    Some((Outgoing::Ping, interval))
+                },
+            )
+            .boxed()
+        };
+        let mut outgoing = stream::select(updates.boxed(), pings);
+
+        let send = async {
+            while let Some(outgoing) = outgoing.next().await {
+                let message = match outgoing {
+                    Outgoing::Update(msg) => Message::text(msg.to_json()),
+                    Outgoing::Ping => {
+                        if !pong_received.swap(false, Ordering::Relaxed) {
+                            debug!("websocket pong timeout");
+                            break;
+                        }
+                        Message::Ping(vec![])
+                    }
+                };
+                if sender.send(message).await.is_err() {
+                    break;
+                }
             }
-        }
-        debug!("websocket connection disconnected");
-        task.abort();
-        Ok(())
+        };
+        let recv = async {
+            while let Some(message) = ws_recv.next().await {
+                match message {
+                    Ok(Message::Pong(_)) => pong_received.store(true, Ordering::Relaxed),
+                    Ok(Message::Close(_)) | Err(_) => break,
+                    Ok(_) => {}
+                }
+            }
+        };
+        future::select(Box::pin(send), Box::pin(recv)).await;
     }
 
-    fn watch_for_changes(
-        root: PathBuf,
-        compiler: Arc<Compiler>,
+    // rebuild on changes and write the output to disk like the production
+    // build does, without dev server and hmr
+    pub fn watch_build(&self) -> Result<()> {
+        let compiler = self.compiler.clone();
+        Self::watch_for_changes(self.root.clone(), compiler.clone(), move |mut paths| {
+            if Self::reload_config(&compiler, &mut paths, true).is_some() || paths.is_empty() {
+                return Ok(());
+            }
+            let current = compiler.read().unwrap().clone();
+            Self::rebuild_to_disk(paths, &current)
+        })
+    }
+
+    fn hmr_rebuilder(
+        compiler: SharedCompiler,
         txws: broadcast::Sender<WsMessage>,
-    ) -> Result<()> {
+    ) -> impl FnMut(Vec<PathBuf>) -> Result<()> {
+        let initial_hash = compiler.read().unwrap().full_hash();
+        let mut snapshot_hash = Box::new(initial_hash);
+        let mut hmr_hash = Box::new(initial_hash);
+        let mut has_errors = false;
+        move |mut paths| {
+            if let Some(next) = Self::reload_config(&compiler, &mut paths, false) {
+                let hash = next.full_hash();
+                *snapshot_hash = hash;
+                *hmr_hash = hash;
+                // the clients run the runtime of the replaced compiler
+                if txws.receiver_count() > 0 {
+                    txws.send(WsMessage::Reload(hash)).unwrap();
+                }
+                return Ok(());
+            }
+            if paths.is_empty() {
+                return Ok(());
+            }
+            let current = compiler.read().unwrap().clone();
+            Self::rebuild(
+                paths,
+                current,
+                txws.clone(),
+                &mut snapshot_hash,
+                &mut hmr_hash,
+                &mut has_errors,
+            )
+        }
+    }
+
+    // take the config file out of the changed paths, and compile with a new
+    // compiler if the config is changed, the other paths are covered by the
+    // new compilation then
+    fn reload_config(
+        compiler: &SharedCompiler,
+        paths: &mut Vec<PathBuf>,
+        watch_build: bool,
+    ) -> Option<Arc<Compiler>> {
+        let current = compiler.read().unwrap().clone();
+        let config_file = Config::config_file(&current.context.root);
+        let len = paths.len();
+        paths.retain(|path| path != &config_file);
+        if paths.len() == len {
+            return None;
+        }
+        match Self::compile_with_reloaded_config(&current, watch_build) {
+            Ok(Some(next)) => {
+                let next = Arc::new(next);
+                *compiler.write().unwrap() = next.clone();
+                Some(next)
+            }
+            Ok(None) => None,
+            Err(e) => {
+                // keep the current compiler
+                eprintln!("{}", e);
+                None
+            }
+        }
+    }
+
+    fn compile_with_reloaded_config(
+        compiler: &Compiler,
+        watch_build: bool,
+    ) -> Result<Option<Compiler>> {
+        let prev = &compiler.context.config;
+        let mut config = prev.reload(&compiler.context.root)?;
+        if watch_build {
+            config.hmr = None;
+            config.dev_server = None;
+        }
+        if let Some(name) = prev.restart_required(&config) {
+            println!(
+                "{}",
+                format!("{} is changed, restart mako to apply it", name).yellow()
+            );
+            return Ok(None);
+        }
+        if serde_json::to_value(prev)? == serde_json::to_value(&config)? {
+            debug!("config is not changed");
+            return Ok(None);
+        }
+
+        println!("Config changed, rebuilding...");
+        let t_compiler = Instant::now();
+        let next = compiler.with_config(config)?;
+        next.compile()?;
+        println!(
+            "Rebuilt in {}",
+            format!("{}ms", t_compiler.elapsed().as_millis()).bold()
+        );
+        Ok(Some(next))
+    }
+
+    fn watch_for_changes<F>(root: PathBuf, compiler: SharedCompiler, mut rebuild: F) -> Result<()>
+    where
+        F: FnMut(Vec<PathBuf>) -> Result<()>,
+    {
         let (tx, rx) = mpsc::channel();
         // let mut watcher = RecommendedWatcher::new(tx, notify::Config::default())?;
         let mut debouncer = new_debouncer(Duration::from_millis(10), None, tx).unwrap();
-        let mut watcher = watch::Watcher::new(&root, debouncer.watcher(), &compiler);
+        let mut watcher = watch::Watcher::new(&root, debouncer.watcher(), compiler);
         watcher.watch()?;
 
-        let initial_hash = compiler.full_hash();
-        let mut snapshot_hash = Box::new(initial_hash);
-        let mut hmr_hash = Box::new(initial_hash);
+        let recovery = watch::WatchRecovery::new(Duration::from_millis(500), 5);
 
         for result in rx {
-            if result.is_err() {
-                eprintln!("Error watching files: {:?}", result.err().unwrap());
-                continue;
-            }
-            let paths = watch::Watcher::normalize_events(result.unwrap());
+            let events = match result {
+                Ok(events) => events,
+                Err(errors) => {
+                    errors.iter().for_each(|e| {
+                        eprintln!(
+                            "Error watching files: {}",
+                            watch::Watcher::describe_error(e)
+                        );
+                    });
+                    recovery.recover(&root, || watcher.rewatch())?;
+                    continue;
+                }
+            };
+            let paths = watcher.normalize_events(events);
             if !paths.is_empty() {
-                let compiler = compiler.clone();
-                let txws = txws.clone();
-                if let Err(e) =
-                    Self::rebuild(paths, compiler, txws, &mut snapshot_hash, &mut hmr_hash)
-                {
+                if let Err(e) = rebuild(paths) {
                     eprintln!("Error rebuilding: {:?}", e);
                 }
             }
-            watcher.refresh_watch()?;
+            if let Err(e) = watcher.refresh_watch() {
+                eprintln!("Error refreshing watch: {:?}", e);
+                recovery.recover(&root, || watcher.rewatch())?;
+            }
         }
         Ok(())
     }
 
+    fn rebuild_to_disk(paths: Vec<PathBuf>, compiler: &Arc<Compiler>) -> Result<()> {
+        debug!("watch paths detected: {:?}", paths);
+        let (paths, _) = Self::copy_changed(compiler, paths);
+        if paths.is_empty() {
+            return Ok(());
+        }
+        println!("Checking...");
+        let t_compiler = Instant::now();
+        let start_time = chrono::Local::now().timestamp_millis();
+        let res = match compiler.update(paths) {
+            Ok(res) => res,
+            Err(e) => {
+                eprintln!("{}", e);
+                // do not return error, since it's already printed
+                return Ok(());
+            }
+        };
+        if !res.is_updated() {
+            println!("No changes");
+            return Ok(());
+        }
+        let t_update = t_compiler.elapsed();
+
+        compiler.context.stats_info.clear_assets();
+
+        let t_generate = Instant::now();
+        let mut stats = thread_pool::scope(|_| compiler.emit_watch_build_chunks())?;
+        let t_generate = t_generate.elapsed();
+
+        stats.start_time = start_time;
+        stats.end_time = chrono::Local::now().timestamp_millis();
+
+        println!(
+            "Rebuilt in {} (update: {}ms, generate: {}ms)",
+            format!("{}ms", t_compiler.elapsed().as_millis()).bold(),
+            t_update.as_millis(),
+            t_generate.as_millis()
+        );
+
+        let params = PluginGenerateEndParams {
+            is_first_compile: false,
+            time: t_compiler.elapsed().as_millis() as i64,
+            stats,
+        };
+        compiler
+            .context
+            .plugin_driver
+            .generate_end(&params, &compiler.context)?;
+
+        Ok(())
+    }
+
+    // copy the changed files of the copy sources again, returns the paths to
+    // update, i.e. the ones which are not copied or are modules too, and
+    // whether any file is copied
+    fn copy_changed(compiler: &Compiler, paths: Vec<PathBuf>) -> (Vec<PathBuf>, bool) {
+        let copied = match copy::copy_changed(&compiler.context, &paths) {
+            Ok(copied) => copied,
+            Err(e) => {
+                eprintln!("Error copying: {:?}", e);
+                return (paths, false);
+            }
+        };
+        if copied.is_empty() {
+            return (paths, false);
+        }
+        debug!("copied: {:?}", copied);
+        let module_graph = compiler.context.module_graph.read().unwrap();
+        let paths = paths
+            .into_iter()
+            .filter(|path| !copied.contains(path) || module_graph.has_module(&path.clone().into()))
+            .collect();
+        (paths, true)
+    }
+
     fn rebuild(
         paths: Vec<PathBuf>,
         compiler: Arc<Compiler>,
         txws: broadcast::Sender<WsMessage>,
         last_snapshot_hash: &mut Box<u64>,
         hmr_hash: &mut Box<u64>,
+        has_errors: &mut bool,
     ) -> Result<()> {
         debug!("watch paths detected: {:?}", paths);
+        // the copied files are not in the module graph, the clients reload
+        // to pick them up
+        let (paths, copied) = Self::copy_changed(&compiler, paths);
+        if copied && txws.receiver_count() > 0 {
+            txws.send(WsMessage::Reload(**hmr_hash)).unwrap();
+        }
+        if paths.is_empty() {
+            return Ok(());
+        }
         debug!("checking update status...");
         println!("Checking...");
+        let t_update = Instant::now();
         let update_result = compiler.update(paths);
         let has_missing_deps = {
             compiler
@@ -320,6 +1001,12 @@ impl DevServer {
         if let Err(e) = update_result {
             debug!("checking update status... failed");
             eprintln!("{}", e);
+            // shown in the browser until the next successful rebuild
+            if txws.receiver_count() > 0 {
+                let errors = vec![strip_ansi_colors(&e.to_string())];
+                txws.send(WsMessage::Errors(errors)).unwrap();
+            }
+            *has_errors = true;
             // do not return error, since it's already printed
             return Ok(());
         }
@@ -329,12 +1016,14 @@ impl DevServer {
         debug!("update status is ok, is_updated: {}", is_updated);
         if !is_updated {
             println!("No changes");
+            Self::clear_errors(&txws, hmr_hash, has_errors);
             return Ok(());
         }
 
         let t_compiler = Instant::now();
         let start_time = chrono::Local::now().timestamp_millis();
-        let next_hash = compiler.generate_hot_update_chunks(res, **last_snapshot_hash, **hmr_hash);
+        let next_hash =
+            compiler.generate_hot_update_chunks(res.clone(), **last_snapshot_hash, **hmr_hash);
         debug!(
             "hot update chunks generated, next_full_hash: {:?}",
             next_hash
@@ -350,6 +1039,19 @@ impl DevServer {
             return Err(e);
         }
         let (next_snapshot_hash, next_hmr_hash, current_hmr_hash) = next_hash.unwrap();
+
+        let params = PluginUpdateParams {
+            update_result: &res,
+            time: t_update.elapsed().as_millis() as u64,
+        };
+        if let Err(e) = compiler
+            .context
+            .plugin_driver
+            .on_update(&params, &compiler.context)
+        {
+            // do not break the dev loop because of plugins
+            eprintln!("{}", e);
+        }
         debug!(
             "hash info, next: {:?}, last: {:?}, is_equal: {}",
             next_snapshot_hash,
@@ -358,6 +1060,7 @@ impl DevServer {
         );
         if next_snapshot_hash == **last_snapshot_hash {
             debug!("hash equals, will not do full rebuild");
+            Self::clear_errors(&txws, hmr_hash, has_errors);
             return Ok(());
         } else {
             **last_snapshot_hash = next_snapshot_hash;
@@ -400,15 +1103,546 @@ impl DevServer {
         let receiver_count = txws.receiver_count();
         debug!("receiver count: {}", receiver_count);
         if receiver_count > 0 {
-            txws.send(WsMessage { hash: **hmr_hash }).unwrap();
+            let message = match res.css_only_modules() {
+                Some(module_ids) => WsMessage::CssUpdate {
+                    hash: **hmr_hash,
+                    module_ids: module_ids
+                        .iter()
+                        .map(|module_id| module_id.generate(&compiler.context))
+                        .collect(),
+                },
+                None => WsMessage::Update(**hmr_hash),
+            };
+            txws.send(message).unwrap();
             debug!("send message to clients");
         }
+        *has_errors = false;
 
         Ok(())
     }
+
+    // the errors shown in the browser are fixed by a change which doesn't
+    // lead to a new hot update, e.g. the broken file is reverted
+    fn clear_errors(
+        txws: &broadcast::Sender<WsMessage>,
+        hmr_hash: &mut Box<u64>,
+        has_errors: &mut bool,
+    ) {
+        if *has_errors && txws.receiver_count() > 0 {
+            txws.send(WsMessage::Update(**hmr_hash)).unwrap();
+        }
+        *has_errors = false;
+    }
+}
+
+fn strip_ansi_colors(text: &str) -> String {
+    static ANSI_COLOR_REGEX: OnceLock<Regex> = OnceLock::new();
+
+    ANSI_COLOR_REGEX
+        .get_or_init(|| Regex::new(r"\x1b\[[0-9;]*m").unwrap())
+        .replace_all(text, "")
+        .to_string()
 }
 
 #[derive(Clone, Debug)]
-struct WsMessage {
-    hash: u64,
+enum WsMessage {
+    // the hot update of the hash is ready, the errors shown are cleared
+    Update(u64),
+    // only the stylesheets of the modules are changed in the hot update, the
+    // clients swap them without running the modules and their parents
+    CssUpdate { hash: u64, module_ids: Vec<String> },
+    // the clients should reload instead of applying the hot update
+    Reload(u64),
+    // the rebuild is failed, shown in the browser until the next update
+    Errors(Vec<String>),
+}
+
+impl WsMessage {
+    fn to_json(&self) -> String {
+        match self {
+            WsMessage::Update(hash) => format!(r#"{{"hash":"{}","reload":false}}"#, hash),
+            WsMessage::CssUpdate { hash, module_ids } => serde_json::json!({
+                "hash": hash.to_string(),
+                "reload": false,
+                "css": module_ids,
+            })
+            .to_string(),
+            WsMessage::Reload(hash) => format!(r#"{{"hash":"{}","reload":true}}"#, hash),
+            WsMessage::Errors(errors) => serde_json::json!({ "errors": errors }).to_string(),
+        }
+    }
+}
+
+enum Outgoing {
+    Update(WsMessage),
+    Ping,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::sync::{Arc, RwLock};
+    use std::time::Duration;
+
+    use futures::channel::mpsc;
+    use futures::{future, StreamExt};
+    use hyper::body::HttpBody;
+    use hyper::header::{
+        ACCEPT, ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS,
+        ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_REQUEST_HEADERS, CONTENT_LENGTH, ETAG,
+        IF_NONE_MATCH, VARY,
+    };
+    use hyper::{Body, Method, Request, StatusCode};
+    use tokio::sync::broadcast;
+    use tungstenite::Message;
+
+    use super::{DevServer, WsMessage};
+    use crate::compiler::{Args, Compiler};
+    use crate::config::Config;
+    use crate::utils::test_helper::PluginTestBuilder;
+    use crate::utils::tokio_runtime;
+
+    fn respond(req: Request<Body>) -> hyper::Response<Body> {
+        DevServer::content_response(
+            &req,
+            b"console.log(1);".to_vec(),
+            "application/javascript".to_string(),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_head_request() {
+        let res = respond(
+            Request::builder()
+                .method(Method::HEAD)
+                .uri("/index.js")
+                .body(Body::empty())
+                .unwrap(),
+        );
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers()[CONTENT_LENGTH], "15");
+        assert!(res.headers().contains_key(ETAG));
+        assert!(res.body().is_end_stream());
+    }
+
+    #[test]
+    fn test_conditional_request() {
+        let res = respond(Request::get("/index.js").body(Body::empty()).unwrap());
+        assert_eq!(res.status(), StatusCode::OK);
+        assert!(!res.body().is_end_stream());
+        let etag = res.headers()[ETAG].clone();
+
+        let res = respond(
+            Request::get("/index.js")
+                .header(IF_NONE_MATCH, etag.clone())
+                .body(Body::empty())
+                .unwrap(),
+        );
+        assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(res.headers()[ETAG], etag);
+        assert!(res.body().is_end_stream());
+
+        let res = respond(
+            Request::get("/index.js")
+                .header(IF_NONE_MATCH, "\"stale\"")
+                .body(Body::empty())
+                .unwrap(),
+        );
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn test_dev_bundle_without_hmr() {
+        let root = std::env::current_dir()
+            .unwrap()
+            .join("test/build/hmr-disabled");
+        let config = Config::new(&root, None, None).unwrap();
+        assert!(config.hmr.is_none());
+        let compiler = Compiler::new(config, root, Args { watch: true }, None).unwrap();
+        compiler.compile().unwrap();
+
+        let content = compiler.context.get_static_content("index.js").unwrap();
+        let content = String::from_utf8(content).unwrap();
+        // neither the hmr runtime nor the websocket client
+        assert!(!content.contains("applyHotUpdate"));
+        assert!(!content.contains("__/hmr-ws"));
+        assert!(content.contains("__/reload"));
+    }
+
+    #[test]
+    fn test_rebuild_on_config_change() {
+        let compiler = PluginTestBuilder::new("config-reload")
+            .file("src/index.js", "console.log(VERSION);")
+            .config(r#"{ "define": { "VERSION": "\"1.0.0\"" } }"#)
+            .watch()
+            .build();
+        compiler.compile().unwrap();
+        let content = |compiler: &Compiler| {
+            String::from_utf8(compiler.context.get_static_content("index.js").unwrap()).unwrap()
+        };
+        assert!(content(&compiler).contains(r#"console.log("1.0.0")"#));
+
+        let config_file = Config::config_file(&compiler.context.root);
+        let shared = Arc::new(RwLock::new(Arc::new(compiler)));
+        fs::write(&config_file, r#"{ "define": { "VERSION": "\"2.0.0\"" } }"#).unwrap();
+        let mut paths = vec![config_file.clone()];
+        let next = DevServer::reload_config(&shared, &mut paths, true).unwrap();
+        assert!(paths.is_empty());
+        assert!(Arc::ptr_eq(&next, &shared.read().unwrap()));
+        assert!(content(&next).contains(r#"console.log("2.0.0")"#));
+
+        // the output dir is served as is, restart is required
+        fs::write(
+            &config_file,
+            r#"{ "define": { "VERSION": "\"3.0.0\"" }, "output": { "path": "build" } }"#,
+        )
+        .unwrap();
+        let mut paths = vec![config_file];
+        assert!(DevServer::reload_config(&shared, &mut paths, true).is_none());
+        assert!(Arc::ptr_eq(&next, &shared.read().unwrap()));
+    }
+
+    #[test]
+    fn test_broadcast_build_errors() {
+        let compiler = PluginTestBuilder::new("hmr-build-errors")
+            .file("src/index.js", "console.log(1);")
+            .watch()
+            .build();
+        compiler.compile().unwrap();
+        let index = compiler.context.root.join("src/index.js");
+        let shared = Arc::new(RwLock::new(Arc::new(compiler)));
+        let (txws, mut receiver) = broadcast::channel::<WsMessage>(16);
+        let mut rebuild = DevServer::hmr_rebuilder(shared, txws);
+
+        fs::write(&index, "console.log(1;").unwrap();
+        rebuild(vec![index.clone()]).unwrap();
+        let message = receiver.try_recv().unwrap();
+        assert!(matches!(&message, WsMessage::Errors(errors) if errors.len() == 1));
+        assert!(message.to_json().starts_with(r#"{"errors":["#));
+        assert!(!message.to_json().contains("\\u001b"));
+
+        // the errors are cleared by the fix, even if it's the same as before
+        fs::write(&index, "console.log(1);").unwrap();
+        rebuild(vec![index]).unwrap();
+        assert!(matches!(receiver.try_recv().unwrap(), WsMessage::Update(_)));
+    }
+
+    #[test]
+    fn test_broadcast_css_update() {
+        let compiler = PluginTestBuilder::new("hmr-css-update")
+            .file("src/index.js", "import './a.css';\nimport './b.js';")
+            .file("src/a.css", ".a { color: red; }")
+            .file("src/b.js", "console.log(1);")
+            .watch()
+            .build();
+        compiler.compile().unwrap();
+        let root = compiler.context.root.clone();
+        let shared = Arc::new(RwLock::new(Arc::new(compiler)));
+        let (txws, mut receiver) = broadcast::channel::<WsMessage>(16);
+        let mut rebuild = DevServer::hmr_rebuilder(shared, txws);
+
+        fs::write(root.join("src/a.css"), ".a { color: blue; }").unwrap();
+        rebuild(vec![root.join("src/a.css")]).unwrap();
+        let message = receiver.try_recv().unwrap();
+        assert!(matches!(
+            &message,
+            WsMessage::CssUpdate { module_ids, .. } if module_ids == &vec!["src/a.css".to_string()]
+        ));
+        assert!(message.to_json().contains(r#""css":["src/a.css"]"#));
+
+        // the js is run again along with the css
+        fs::write(root.join("src/a.css"), ".a { color: green; }").unwrap();
+        fs::write(root.join("src/b.js"), "console.log(2);").unwrap();
+        rebuild(vec![root.join("src/a.css"), root.join("src/b.js")]).unwrap();
+        assert!(matches!(receiver.try_recv().unwrap(), WsMessage::Update(_)));
+    }
+
+    #[test]
+    fn test_reload_on_copied_file_change() {
+        let compiler = PluginTestBuilder::new("hmr-copy")
+            .file("src/index.js", "console.log(1);")
+            .file("public/robots.txt", "v1")
+            .watch()
+            .build();
+        compiler.compile().unwrap();
+        let root = compiler.context.root.clone();
+        let output = compiler.context.config.output.path.clone();
+        let shared = Arc::new(RwLock::new(Arc::new(compiler)));
+        let (txws, mut receiver) = broadcast::channel::<WsMessage>(16);
+        let mut rebuild = DevServer::hmr_rebuilder(shared, txws);
+
+        fs::write(root.join("public/robots.txt"), "v2").unwrap();
+        rebuild(vec![root.join("public/robots.txt")]).unwrap();
+        assert_eq!(fs::read_to_string(output.join("robots.txt")).unwrap(), "v2");
+        assert!(matches!(receiver.try_recv().unwrap(), WsMessage::Reload(_)));
+        // no hot update since no module is changed
+        assert!(receiver.try_recv().is_err());
+
+        // the removed copy source is removed from the output too
+        fs::remove_file(root.join("public/robots.txt")).unwrap();
+        rebuild(vec![root.join("public/robots.txt")]).unwrap();
+        assert!(!output.join("robots.txt").exists());
+        assert!(matches!(receiver.try_recv().unwrap(), WsMessage::Reload(_)));
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_reload_response_ends_when_closed() {
+        let (txws, receiver) = broadcast::channel::<WsMessage>(16);
+        let body = tokio_runtime::block_on(async move {
+            let response = DevServer::reload_response(receiver);
+            txws.send(WsMessage::Errors(vec!["error".to_string()]))
+                .unwrap();
+            txws.send(WsMessage::Update(1)).unwrap();
+            drop(txws);
+            hyper::body::to_bytes(response.into_body()).await.unwrap()
+        });
+        assert_eq!(body, "data: 1\n\n");
+    }
+
+    #[test]
+    fn test_history_api_fallback() {
+        let compiler = PluginTestBuilder::new("history-api-fallback")
+            .file("src/index.js", "console.log(1);")
+            .config(
+                r#"{ "devServer": { "host": "127.0.0.1", "port": 3000, "historyApiFallback": true } }"#,
+            )
+            .watch()
+            .build();
+        let output = compiler.context.config.output.path.clone();
+        fs::create_dir_all(&output).unwrap();
+        fs::write(output.join("index.html"), "<div id=\"root\"></div>").unwrap();
+        let context = compiler.context.clone();
+        let request = |path: &str, accept: &str| {
+            let req = Request::get(path)
+                .header(ACCEPT, accept)
+                .body(Body::empty())
+                .unwrap();
+            let staticfile = hyper_staticfile::Static::new(&output);
+            let (txws, _) = broadcast::channel::<WsMessage>(16);
+            tokio_runtime::block_on(async {
+                let res = DevServer::handle_requests(req, context.clone(), staticfile, txws)
+                    .await
+                    .unwrap();
+                let status = res.status();
+                let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
+                (status, String::from_utf8(body.to_vec()).unwrap())
+            })
+        };
+
+        let (status, body) = request("/users/42", "text/html,application/xhtml+xml");
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "<div id=\"root\"></div>");
+        // assets and the requests which are not page navigations
+        let not_found = |path: &str, accept: &str| request(path, accept).0 == StatusCode::NOT_FOUND;
+        assert!(not_found("/users/42.png", "text/html"));
+        assert!(not_found("/users/42", "application/json"));
+    }
+
+    #[test]
+    fn test_public_dir() {
+        let compiler = PluginTestBuilder::new("dev-public-dir")
+            .file("src/index.js", "console.log(1);")
+            .file("public/robots.txt", "User-agent: *")
+            .file("public/index.js", "console.log('public');")
+            .config(
+                r#"{ "devServer": { "host": "127.0.0.1", "port": 3000 }, "publicDir": "public" }"#,
+            )
+            .watch()
+            .build();
+        compiler.compile().unwrap();
+        let context = compiler.context.clone();
+        let request = |path: &str| {
+            let req = Request::get(path).body(Body::empty()).unwrap();
+            let staticfile = hyper_staticfile::Static::new(&context.config.output.path);
+            let (txws, _) = broadcast::channel::<WsMessage>(16);
+            tokio_runtime::block_on(async {
+                let res = DevServer::handle_requests(req, context.clone(), staticfile, txws)
+                    .await
+                    .unwrap();
+                let status = res.status();
+                let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
+                (status, String::from_utf8(body.to_vec()).unwrap())
+            })
+        };
+
+        // the publicDir files are served in place in watch mode
+        assert!(!context.config.output.path.join("robots.txt").exists());
+        assert_eq!(
+            request("/robots.txt"),
+            (StatusCode::OK, "User-agent: *".to_string())
+        );
+        // the output files take precedence
+        let (status, body) = request("/index.js");
+        assert_eq!(status, StatusCode::OK);
+        assert!(!body.contains("public"));
+        assert_eq!(request("/missing.txt").0, StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_cors() {
+        let respond = |name: &str, cors: &str, method: Method| {
+            let compiler = PluginTestBuilder::new(name)
+                .file("src/index.js", "console.log(1);")
+                .config(&format!(
+                    r#"{{ "devServer": {{ "host": "127.0.0.1", "port": 3000{} }} }}"#,
+                    cors
+                ))
+                .watch()
+                .build();
+            compiler.compile().unwrap();
+            let context = compiler.context.clone();
+            let req = Request::builder()
+                .method(method)
+                .uri("/index.js")
+                .header(ACCESS_CONTROL_REQUEST_HEADERS, "x-foo")
+                .body(Body::empty())
+                .unwrap();
+            let staticfile = hyper_staticfile::Static::new(&context.config.output.path);
+            let (txws, _) = broadcast::channel::<WsMessage>(16);
+            tokio_runtime::block_on(DevServer::handle_requests(req, context, staticfile, txws))
+                .unwrap()
+        };
+
+        let res = respond("cors-disabled", "", Method::GET);
+        assert_eq!(res.status(), StatusCode::OK);
+        assert!(!res.headers().contains_key(ACCESS_CONTROL_ALLOW_ORIGIN));
+
+        let res = respond("cors-any", r#", "cors": true"#, Method::GET);
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers()[ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+        assert!(!res.headers().contains_key(VARY));
+
+        let res = respond(
+            "cors-origin",
+            r#", "cors": "http://localhost:6006""#,
+            Method::OPTIONS,
+        );
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+        assert_eq!(
+            res.headers()[ACCESS_CONTROL_ALLOW_ORIGIN],
+            "http://localhost:6006"
+        );
+        assert_eq!(res.headers()[VARY], "Origin");
+        assert_eq!(
+            res.headers()[ACCESS_CONTROL_ALLOW_METHODS],
+            "GET, HEAD, OPTIONS"
+        );
+        assert_eq!(res.headers()[ACCESS_CONTROL_ALLOW_HEADERS], "x-foo");
+    }
+
+    #[test]
+    fn test_port_in_use() {
+        let occupied = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let occupied_port = occupied.local_addr().unwrap().port();
+        let ip = "127.0.0.1".parse().unwrap();
+        let err = DevServer::bind_available_port(ip, occupied_port, 1).unwrap_err();
+        assert!(err.to_string().contains("no available port in 1 attempts"));
+
+        let compiler = PluginTestBuilder::new("dev-server-port-in-use")
+            .file("src/index.js", "console.log(1);")
+            .config(&format!(
+                r#"{{ "devServer": {{ "host": "127.0.0.1", "port": {} }} }}"#,
+                occupied_port
+            ))
+            .watch()
+            .build();
+        compiler.compile().unwrap();
+        let root = compiler.context.root.clone();
+        let server = Arc::new(DevServer::new(root, Arc::new(compiler)));
+        assert_eq!(server.port(), None);
+        let serving = server.clone();
+        tokio_runtime::spawn(async move { serving.serve().await });
+
+        let start = std::time::Instant::now();
+        let port = loop {
+            if let Some(port) = server.port() {
+                break port;
+            }
+            assert!(start.elapsed() < Duration::from_secs(5));
+            std::thread::sleep(Duration::from_millis(10));
+        };
+        assert_ne!(port, occupied_port);
+        assert!(std::net::TcpStream::connect(("127.0.0.1", port)).is_ok());
+    }
+
+    // the two ends of a websocket connection, the server sends `Message`s
+    // and receives what the mock client sends
+    fn websocket_pair() -> (
+        (
+            mpsc::UnboundedSender<Message>,
+            mpsc::UnboundedReceiver<Result<Message, tungstenite::Error>>,
+        ),
+        (
+            mpsc::UnboundedSender<Result<Message, tungstenite::Error>>,
+            mpsc::UnboundedReceiver<Message>,
+        ),
+    ) {
+        let (server_sender, client_recv) = mpsc::unbounded();
+        let (client_sender, server_recv) = mpsc::unbounded();
+        ((server_sender, server_recv), (client_sender, client_recv))
+    }
+
+    #[test]
+    fn test_websocket_ping_timeout() {
+        let (txws, receiver) = broadcast::channel::<WsMessage>(16);
+        let ((sender, ws_recv), (_client_sender, mut client_recv)) = websocket_pair();
+        tokio_runtime::block_on(async {
+            let forward =
+                DevServer::forward_messages(sender, ws_recv, receiver, Duration::from_millis(20));
+            // the client never answers the ping
+            tokio::time::timeout(Duration::from_secs(5), forward)
+                .await
+                .unwrap();
+        });
+        assert!(matches!(
+            client_recv.try_next().unwrap(),
+            Some(Message::Ping(_))
+        ));
+        assert_eq!(txws.receiver_count(), 0);
+    }
+
+    #[test]
+    fn test_websocket_ping_pong() {
+        let (txws, receiver) = broadcast::channel::<WsMessage>(16);
+        let ((sender, ws_recv), (client_sender, mut client_recv)) = websocket_pair();
+        let update = tokio_runtime::block_on(async {
+            let forward =
+                DevServer::forward_messages(sender, ws_recv, receiver, Duration::from_millis(20));
+            let client = async {
+                let mut pings = 0;
+                let mut update = None;
+                while let Some(message) = client_recv.next().await {
+                    match message {
+                        Message::Ping(payload) => {
+                            client_sender
+                                .unbounded_send(Ok(Message::Pong(payload)))
+                                .unwrap();
+                            pings += 1;
+                            // alive after several intervals
+                            if pings == 3 {
+                                txws.send(WsMessage::Update(1)).unwrap();
+                            }
+                        }
+                        Message::Text(text) => {
+                            update = Some(text);
+                            client_sender
+                                .unbounded_send(Ok(Message::Close(None)))
+                                .unwrap();
+                        }
+                        _ => {}
+                    }
+                }
+                update
+            };
+            let (_, update) =
+                tokio::time::timeout(Duration::from_secs(5), future::join(forward, client))
+                    .await
+                    .unwrap();
+            update
+        });
+        assert_eq!(update.unwrap(), r#"{"hash":"1","reload":false}"#);
+        assert_eq!(txws.receiver_count(), 0);
+    }
 }
@@ -0,0 +1,115 @@
+use std::sync::OnceLock;
+
+use hyper::client::HttpConnector;
+use hyper::header::{HeaderValue, HOST};
+use hyper::{Body, Client, Request, Response, StatusCode, Uri};
+use tracing::debug;
+
+use crate::config::ProxyConfig;
+
+// shared so that the connections to the backends are pooled
+static CLIENT: OnceLock<Client<HttpConnector>> = OnceLock::new();
+
+/// Forward the request to the target of `devServer.proxy` with the headers,
+/// the response is streamed back, and the upstream errors get 502
+pub async fn proxy_request(mut req: Request<Body>, proxy: &ProxyConfig) -> Response<Body> {
+    let path_and_query = req
+        .uri()
+        .path_and_query()
+        .map_or("/", |path_and_query| path_and_query.as_str());
+    let uri = format!("{}{}", proxy.target().trim_end_matches('/'), path_and_query);
+    let uri = match uri.parse::<Uri>() {
+        Ok(uri) => uri,
+        Err(e) => return bad_gateway(e),
+    };
+    if proxy.change_origin()
+        && let Some(authority) = uri.authority()
+        && let Ok(host) = HeaderValue::from_str(authority.as_str())
+    {
+        req.headers_mut().insert(HOST, host);
+    }
+    debug!("proxy {} to {}", req.uri(), uri);
+    *req.uri_mut() = uri;
+
+    let client = CLIENT.get_or_init(Client::new);
+    client.request(req).await.unwrap_or_else(bad_gateway)
+}
+
+fn bad_gateway(e: impl std::fmt::Display) -> Response<Body> {
+    Response::builder()
+        .status(StatusCode::BAD_GATEWAY)
+        .body(Body::from(e.to_string()))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+
+    use hyper::header::HOST;
+    use hyper::service::{make_service_fn, service_fn};
+    use hyper::{Body, Request, Response, Server, StatusCode};
+
+    use super::proxy_request;
+    use crate::config::{ProxyConfig, ProxyOptions};
+    use crate::utils::tokio_runtime;
+
+    // the backend answers with the Host header and the path it got
+    fn mock_server() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let make_svc = make_service_fn(|_conn| async {
+            Ok::<_, hyper::Error>(service_fn(|req: Request<Body>| async move {
+                let host = req.headers()[HOST].to_str().unwrap().to_string();
+                Ok::<_, hyper::Error>(Response::new(Body::from(format!("{} {}", host, req.uri()))))
+            }))
+        });
+        let server = Server::from_tcp(listener).unwrap().serve(make_svc);
+        tokio_runtime::spawn(async move {
+            server.await.unwrap();
+        });
+        format!("http://{}", addr)
+    }
+
+    fn request(proxy: &ProxyConfig) -> (StatusCode, String) {
+        let req = Request::get("/api/users?id=1")
+            .header(HOST, "localhost:3000")
+            .body(Body::empty())
+            .unwrap();
+        tokio_runtime::block_on(async {
+            let res = proxy_request(req, proxy).await;
+            let status = res.status();
+            let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
+            (status, String::from_utf8(body.to_vec()).unwrap())
+        })
+    }
+
+    #[test]
+    fn test_proxy_request() {
+        let target = tokio_runtime::block_on(async { mock_server() });
+        let authority = target.trim_start_matches("http://").to_string();
+
+        let (status, body) = request(&ProxyConfig::Target(target.clone()));
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "localhost:3000 /api/users?id=1");
+
+        let (status, body) = request(&ProxyConfig::Options(ProxyOptions {
+            target,
+            change_origin: true,
+        }));
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, format!("{} /api/users?id=1", authority));
+    }
+
+    #[test]
+    fn test_proxy_bad_gateway() {
+        // nothing listens on the port after the listener is dropped
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let (status, body) = request(&ProxyConfig::Target(format!("http://{}", addr)));
+        assert_eq!(status, StatusCode::BAD_GATEWAY);
+        assert!(!body.is_empty());
+    }
+}
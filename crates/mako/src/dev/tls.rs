@@ -0,0 +1,145 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context, Result};
+use futures::{future, stream, Stream, StreamExt};
+use rustls_pemfile::Item;
+use tokio::net::{TcpListener, TcpStream};
+use tokio_rustls::rustls::{Certificate, PrivateKey, ServerConfig};
+use tokio_rustls::server::TlsStream;
+use tokio_rustls::TlsAcceptor;
+use tracing::debug;
+
+// the handshakes in progress at the same time, a slow client doesn't block
+// the others
+const CONCURRENT_HANDSHAKES: usize = 64;
+
+/// Load the PEM encoded certificate chain and private key of `devServer.https`
+pub fn tls_acceptor(cert: &Path, key: &Path) -> Result<TlsAcceptor> {
+    let certs = read_pem(cert)?
+        .into_iter()
+        .filter_map(|item| match item {
+            Item::X509Certificate(cert) => Some(Certificate(cert)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if certs.is_empty() {
+        return Err(anyhow!("no certificate found in {}", cert.display()));
+    }
+    let private_key = read_pem(key)?
+        .into_iter()
+        .find_map(|item| match item {
+            Item::PKCS8Key(key) | Item::RSAKey(key) | Item::ECKey(key) => Some(PrivateKey(key)),
+            _ => None,
+        })
+        .ok_or_else(|| anyhow!("no private key found in {}", key.display()))?;
+
+    let config = ServerConfig::builder()
+        .with_safe_defaults()
+        .with_no_client_auth()
+        .with_single_cert(certs, private_key)
+        .context("invalid devServer.https cert or key")?;
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+fn read_pem(path: &Path) -> Result<Vec<Item>> {
+    let file = File::open(path).with_context(|| format!("failed to read {}", path.display()))?;
+    rustls_pemfile::read_all(&mut BufReader::new(file))
+        .with_context(|| format!("failed to parse {}", path.display()))
+}
+
+/// The accepted connections after the TLS handshakes, the failed handshakes,
+/// e.g. a plain http request to the https port, are dropped
+pub fn tls_incoming(
+    listener: std::net::TcpListener,
+    acceptor: TlsAcceptor,
+) -> std::io::Result<impl Stream<Item = std::io::Result<TlsStream<TcpStream>>>> {
+    listener.set_nonblocking(true)?;
+    let listener = TcpListener::from_std(listener)?;
+    let connections = stream::unfold(listener, |listener| async move {
+        let connection = listener.accept().await.map(|(stream, _)| stream);
+        Some((connection, listener))
+    });
+    Ok(connections
+        .map(move |connection| {
+            let acceptor = acceptor.clone();
+            async move {
+                match connection {
+                    Ok(stream) => acceptor.accept(stream).await.map_err(|e| {
+                        debug!("TLS handshake failed: {:?}", e);
+                        e
+                    }),
+                    Err(e) => Err(e),
+                }
+            }
+        })
+        .buffer_unordered(CONCURRENT_HANDSHAKES)
+        .filter(|connection| future::ready(connection.is_ok())))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use futures::StreamExt;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpStream;
+    use tokio_rustls::rustls::{Certificate, ClientConfig, RootCertStore, ServerName};
+    use tokio_rustls::TlsConnector;
+
+    use super::{read_pem, tls_acceptor, tls_incoming};
+    use crate::utils::tokio_runtime;
+
+    #[test]
+    fn test_tls_handshake() {
+        let fixture = std::env::current_dir()
+            .unwrap()
+            .join("test/fixture/dev-server-https");
+        let acceptor = tls_acceptor(&fixture.join("cert.pem"), &fixture.join("key.pem")).unwrap();
+
+        let mut roots = RootCertStore::empty();
+        for item in read_pem(&fixture.join("cert.pem")).unwrap() {
+            if let rustls_pemfile::Item::X509Certificate(cert) = item {
+                roots.add(&Certificate(cert)).unwrap();
+            }
+        }
+        let connector = TlsConnector::from(Arc::new(
+            ClientConfig::builder()
+                .with_safe_defaults()
+                .with_root_certificates(roots)
+                .with_no_client_auth(),
+        ));
+
+        tokio_runtime::block_on(async move {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            let addr = listener.local_addr().unwrap();
+            let incoming = tls_incoming(listener, acceptor).unwrap();
+            let server = tokio::spawn(async move {
+                let mut incoming = Box::pin(incoming);
+                let mut stream = incoming.next().await.unwrap().unwrap();
+                let mut buf = [0; 4];
+                stream.read_exact(&mut buf).await.unwrap();
+                stream.write_all(&buf).await.unwrap();
+                stream.flush().await.unwrap();
+            });
+
+            // a failed handshake doesn't stop the server
+            let mut plain = TcpStream::connect(addr).await.unwrap();
+            plain.write_all(b"GET / HTTP/1.1\r\n\r\n").await.unwrap();
+            drop(plain);
+
+            let stream = TcpStream::connect(addr).await.unwrap();
+            let mut stream = connector
+                .connect(ServerName::try_from("localhost").unwrap(), stream)
+                .await
+                .unwrap();
+            stream.write_all(b"ping").await.unwrap();
+            let mut buf = [0; 4];
+            stream.read_exact(&mut buf).await.unwrap();
+            assert_eq!(&buf, b"ping");
+            server.await.unwrap();
+        });
+    }
+}
@@ -1,18 +1,22 @@
-use std::collections::{HashMap, HashSet};
-use std::fmt;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
 use std::fmt::Debug;
+use std::hash::Hasher;
 use std::path::PathBuf;
+use std::{fmt, fs};
 
 use anyhow::{anyhow, Ok, Result};
 use rayon::prelude::*;
+use serde::{Serialize, Serializer};
 use tracing::debug;
+use twox_hash::XxHash64;
 
 use crate::build::BuildError;
 use crate::compiler::Compiler;
 use crate::generate::transform::transform_modules;
-use crate::module::{Dependency, Module, ModuleId, ResolveTypeFlags};
+use crate::module::{Dependency, Module, ModuleId, ResolveType, ResolveTypeFlags};
 use crate::module_graph::ModuleGraph;
 use crate::resolve::{self, clear_resolver_cache};
+use crate::visitors::virtual_css_modules::is_css_path;
 
 #[derive(Debug, Clone)]
 pub enum UpdateType {
@@ -21,16 +25,39 @@ pub enum UpdateType {
     Modify,
 }
 
-#[derive(Default, Debug)]
+// use BTreeSet to keep the iteration order stable, the order of modules
+// influences the generated hot update chunks and hashes
+#[derive(Default, Debug, Clone, Serialize)]
 pub struct UpdateResult {
     // 新增的模块Id
-    pub added: HashSet<ModuleId>,
+    #[serde(serialize_with = "serialize_module_ids")]
+    pub added: BTreeSet<ModuleId>,
     // 删除的模块Id
-    pub removed: HashSet<ModuleId>,
+    #[serde(serialize_with = "serialize_module_ids")]
+    pub removed: BTreeSet<ModuleId>,
     // 修改的模块Id
-    pub modified: HashSet<ModuleId>,
+    #[serde(serialize_with = "serialize_module_ids")]
+    pub modified: BTreeSet<ModuleId>,
     // 依赖变更，典型的如 async import 变成 import
-    pub dep_changed: HashSet<ModuleId>,
+    #[serde(skip)]
+    pub dep_changed: BTreeSet<ModuleId>,
+}
+
+// the ids are sorted since they are in BTreeSet
+fn serialize_module_ids<S>(
+    module_ids: &BTreeSet<ModuleId>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(module_ids.iter().map(|module_id| &module_id.id))
+}
+
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher: XxHash64 = Default::default();
+    hasher.write(bytes);
+    hasher.finish()
 }
 
 impl UpdateResult {
@@ -40,6 +67,25 @@ impl UpdateResult {
             || !self.removed.is_empty()
             || !self.dep_changed.is_empty()
     }
+
+    /// The modified modules when only the stylesheets are changed, the
+    /// clients swap them without running the modules again
+    pub fn css_only_modules(&self) -> Option<Vec<ModuleId>> {
+        let css_only = self.added.is_empty()
+            && self.removed.is_empty()
+            && self.dep_changed.is_empty()
+            && !self.modified.is_empty()
+            && self.modified.iter().all(|module_id| {
+                // the css modules, e.g. foo.css?modules, export the class
+                // names, so they are run again like the scripts
+                let (path, search) = module_id
+                    .id
+                    .split_once('?')
+                    .unwrap_or((module_id.id.as_str(), ""));
+                is_css_path(path) && !search.split('&').any(|param| param == "modules")
+            });
+        css_only.then(|| self.modified.iter().cloned().collect())
+    }
 }
 
 impl fmt::Display for UpdateResult {
@@ -79,6 +125,32 @@ dep_changed:{:?}
 
 impl Compiler {
     pub fn update(&self, paths: Vec<PathBuf>) -> Result<UpdateResult> {
+        // the module graph is mutated across the remove, modify and add
+        // phases, the modules and edges they touch are logged and restored
+        // when any of them fails, e.g. with a syntax error of a modified
+        // module, so that the next update starts from the graph of the last
+        // successful one, the other side effects are not rolled back, i.e.
+        // the cleared resolver cache, the assets emitted while building the
+        // modules, and the import rewrites recorded in the stats
+        self.context.module_graph.write().unwrap().begin_undo_log();
+        let modules_with_missing_deps = self
+            .context
+            .modules_with_missing_deps
+            .read()
+            .unwrap()
+            .clone();
+        let result = self.update_modules(paths);
+        if result.is_err() {
+            debug!("update failed, restore the module graph");
+            *self.context.modules_with_missing_deps.write().unwrap() = modules_with_missing_deps;
+            self.context.module_graph.write().unwrap().rollback();
+        } else {
+            self.context.module_graph.write().unwrap().commit_undo_log();
+        }
+        result
+    }
+
+    fn update_modules(&self, paths: Vec<PathBuf>) -> Result<UpdateResult> {
         let module_graph = self.context.module_graph.read().unwrap();
         let paths = paths
             .into_iter()
@@ -103,6 +175,30 @@ impl Compiler {
         debug!("update: {:?}", &paths);
         let mut update_result: UpdateResult = Default::default();
 
+        // a removed file and an added one with the same content in a batch is
+        // a rename, the module is moved to the new path in place so that its
+        // dependents are not rebuilt, it is rebuilt itself since its relative
+        // deps may resolve differently
+        let moves = self.detect_moves(&paths);
+        let paths = if moves.is_empty() {
+            paths
+        } else {
+            debug!("moves: {:?}", &moves);
+            update_result.removed.extend(self.build_by_move(&moves));
+            paths
+                .into_iter()
+                .filter_map(|(path, update_type)| {
+                    if moves.iter().any(|(from, _)| from == &path) {
+                        None
+                    } else if moves.iter().any(|(_, to)| to == &path) {
+                        Some((path, UpdateType::Modify))
+                    } else {
+                        Some((path, update_type))
+                    }
+                })
+                .collect()
+        };
+
         let mut modified = vec![];
         let mut removed = vec![];
         let mut added = vec![];
@@ -211,8 +307,24 @@ impl Compiler {
 
         // 分析修改的模块，结果中会包含新增的模块
         debug!("modify: {:?}", &modified);
-        let (modified_module_ids, dep_changed_module_ids, add_paths) =
+        let was_async = self.async_modules(&modified);
+        let (mut modified_module_ids, mut dep_changed_module_ids, mut add_paths) =
             self.build_by_modify(modified).map_err(|err| anyhow!(err))?;
+
+        // the importers of the modules which become async are async too, and
+        // the ones of the modules which are not async anymore may be sync
+        // again, they are rebuilt since their asts are already transformed
+        // with or without the async wrapper
+        let async_importers = self.find_async_importers(&modified_module_ids, &was_async);
+        if !async_importers.is_empty() {
+            debug!("async importers: {:?}", &async_importers);
+            let (importer_ids, importer_dep_changed_ids, importer_add_paths) = self
+                .build_by_modify(async_importers.iter().map(|id| id.to_path()).collect())
+                .map_err(|err| anyhow!(err))?;
+            modified_module_ids.extend(importer_ids);
+            dep_changed_module_ids.extend(importer_dep_changed_ids);
+            add_paths.extend(importer_add_paths);
+        }
         debug!("after build_by_modify");
         debug!("  > modified_module_ids: {:?}", &modified_module_ids);
         debug!(
@@ -233,14 +345,33 @@ impl Compiler {
             added
                 .into_iter()
                 .map(ModuleId::from_path)
-                .collect::<HashSet<_>>(),
+                .collect::<BTreeSet<_>>(),
         );
         update_result.added.extend(added_module_ids);
 
+        // the chunks follow the moves once the update succeeds
+        if !moves.is_empty() {
+            let mut chunk_graph = self.context.chunk_graph.write().unwrap();
+            for (from, to) in &moves {
+                let from = ModuleId::from_path(from.clone());
+                let to = ModuleId::from_path(to.clone());
+                for chunk in chunk_graph.mut_chunks() {
+                    chunk.rename_module(&from, &to);
+                }
+            }
+        }
+
         debug!("update_result: {:?}", &update_result);
         Result::Ok(update_result)
     }
 
+    /// [Compiler::update] with the result serialized as json, e.g.
+    /// `{"added":[...],"removed":[...],"modified":[...]}`
+    pub fn update_json(&self, paths: Vec<PathBuf>) -> Result<String> {
+        let update_result = self.update(paths)?;
+        Ok(serde_json::to_string(&update_result)?)
+    }
+
     pub fn transform_for_change(&self, update_result: &UpdateResult) -> Result<()> {
         let mut changes: Vec<ModuleId> = vec![];
         for module_id in &update_result.added {
@@ -253,10 +384,77 @@ impl Compiler {
         Ok(())
     }
 
+    // the async ones of the modules of paths, they are marked async by the
+    // last generate if they have async deps
+    fn async_modules(&self, paths: &[PathBuf]) -> HashSet<ModuleId> {
+        let module_graph = self.context.module_graph.read().unwrap();
+        paths
+            .iter()
+            .map(|path| ModuleId::from_path(path.clone()))
+            .filter(|module_id| {
+                module_graph
+                    .get_module(module_id)
+                    .and_then(|module| module.info.as_ref())
+                    .is_some_and(|info| info.is_async)
+            })
+            .collect()
+    }
+
+    // the importers of the modules in module_ids whose async state is changed
+    // since was_async, through the sync esm deps, the ones which are not async
+    // yet for the modules which become async, and the ones which are async
+    // for the modules which are not async anymore
+    fn find_async_importers(
+        &self,
+        module_ids: &BTreeSet<ModuleId>,
+        was_async: &HashSet<ModuleId>,
+    ) -> Vec<ModuleId> {
+        let module_graph = self.context.module_graph.read().unwrap();
+        let is_async = |module_id: &ModuleId| {
+            module_graph
+                .get_module(module_id)
+                .and_then(|module| module.info.as_ref())
+                .is_some_and(|info| info.is_async)
+        };
+        // a module is async with top level await or async deps
+        let (became_async, became_sync): (Vec<_>, Vec<_>) = module_ids
+            .iter()
+            .filter_map(|module_id| {
+                let now_async = is_async(module_id)
+                    || module_graph
+                        .get_dependencies_info(module_id)
+                        .iter()
+                        .any(|(_, dep, is_async)| dep.resolve_type.is_sync_esm() && *is_async);
+                (now_async != was_async.contains(module_id)).then(|| (module_id, now_async))
+            })
+            .partition(|(_, now_async)| *now_async);
+        let mut visited = HashSet::new();
+        let mut importers = vec![];
+        for (changed, to_async) in [(became_async, true), (became_sync, false)] {
+            let mut queue = changed
+                .into_iter()
+                .map(|(module_id, _)| module_id.clone())
+                .collect::<VecDeque<_>>();
+            while let Some(module_id) = queue.pop_front() {
+                for (importer, dep) in module_graph.get_dependents(&module_id) {
+                    if dep.resolve_type.is_sync_esm()
+                        && !module_ids.contains(importer)
+                        && is_async(importer) != to_async
+                        && visited.insert(importer.clone())
+                    {
+                        importers.push(importer.clone());
+                        queue.push_back(importer.clone());
+                    }
+                }
+            }
+        }
+        importers
+    }
+
     fn build_by_modify(
         &self,
         modified: Vec<PathBuf>,
-    ) -> Result<(HashSet<ModuleId>, HashSet<ModuleId>, Vec<PathBuf>)> {
+    ) -> Result<(BTreeSet<ModuleId>, BTreeSet<ModuleId>, Vec<PathBuf>)> {
         let result = modified
             .par_iter()
             .map(|entry| {
@@ -330,8 +528,8 @@ impl Compiler {
         let modified_results = result?;
 
         let mut added = vec![];
-        let mut modified_module_ids = HashSet::new();
-        let mut dep_changed_module_ids = HashSet::new();
+        let mut modified_module_ids = BTreeSet::new();
+        let mut dep_changed_module_ids = BTreeSet::new();
 
         let mut module_graph = self.context.module_graph.write().unwrap();
         for (modified_module, diff, mut add_modules, dependencies) in modified_results {
@@ -396,7 +594,7 @@ impl Compiler {
         Result::Ok((modified_module_ids, dep_changed_module_ids, added))
     }
 
-    fn build_by_add(&self, added: &[PathBuf]) -> Result<HashSet<ModuleId>> {
+    fn build_by_add(&self, added: &[PathBuf]) -> Result<BTreeSet<ModuleId>> {
         let files = added
             .iter()
             .map(|path| {
@@ -406,13 +604,106 @@ impl Compiler {
                 )
             })
             .collect();
-        self.build(files)
+        Ok(self.build(files)?.into_iter().collect())
     }
 
-    fn build_by_remove(&self, removed: Vec<PathBuf>) -> (HashSet<ModuleId>, HashSet<ModuleId>) {
+    // pairs the removed and added paths with the same content, the modules
+    // which root a chunk, i.e. the entries and the async ones, are not moved
+    // since the chunks are named after them, neither are the ones with an
+    // import that doesn't resolve to the new path, they are removed and added
+    // instead so that the broken import is reported
+    fn detect_moves(&self, paths: &[(PathBuf, UpdateType)]) -> Vec<(PathBuf, PathBuf)> {
+        let module_graph = self.context.module_graph.read().unwrap();
+        let mut removed = paths
+            .iter()
+            .filter(|(_, update_type)| matches!(update_type, UpdateType::Remove))
+            .filter_map(|(path, _)| {
+                let module_id = ModuleId::from_path(path.clone());
+                let module = module_graph.get_module(&module_id)?;
+                let info = module.info.as_ref()?;
+                let dependents = module_graph.get_dependents(&module_id);
+                let roots_chunk = module.is_entry
+                    || dependents.iter().any(|(_, dep)| {
+                        matches!(
+                            dep.resolve_type,
+                            ResolveType::DynamicImport | ResolveType::Worker
+                        )
+                    });
+                if roots_chunk {
+                    return None;
+                }
+                let imports = dependents
+                    .into_iter()
+                    .map(|(id, dep)| {
+                        let from = module_graph
+                            .get_module(id)
+                            .and_then(|module| module.info.as_ref())
+                            .map_or_else(
+                                || id.id.clone(),
+                                |info| info.file.resolve_from(&self.context),
+                            );
+                        (from, dep.clone())
+                    })
+                    .collect::<Vec<_>>();
+                Some((path.clone(), hash_bytes(info.raw.as_bytes()), imports))
+            })
+            .collect::<Vec<_>>();
+        drop(module_graph);
+        if removed.is_empty() {
+            return vec![];
+        }
+
+        // the imports are resolved again with the new files
+        clear_resolver_cache(&self.context.resolvers);
+        let mut moves = vec![];
+        for (path, update_type) in paths {
+            if !matches!(update_type, UpdateType::Add) {
+                continue;
+            }
+            // compared with the raw content of the removed module, the added
+            // file is not loaded since loading has side effects, e.g. the
+            // assets are emitted
+            let Result::Ok(content) = fs::read(path) else {
+                continue;
+            };
+            let raw_hash = hash_bytes(&content);
+            let to = path.to_string_lossy();
+            let index = removed.iter().position(|(_, hash, imports)| {
+                *hash == raw_hash
+                    && imports.iter().all(|(from, dep)| {
+                        resolve::resolve(from, dep, &self.context.resolvers, &self.context)
+                            .is_ok_and(|resolved| resolved.get_resolved_path() == to)
+                    })
+            });
+            if let Some(index) = index {
+                let (from, _, _) = removed.remove(index);
+                moves.push((from, path.clone()));
+            }
+        }
+        moves
+    }
+
+    fn build_by_move(&self, moves: &[(PathBuf, PathBuf)]) -> BTreeSet<ModuleId> {
+        let mut module_graph = self.context.module_graph.write().unwrap();
+        let mut modules_with_missing_deps = self.context.modules_with_missing_deps.write().unwrap();
+        let mut moved_module_ids = BTreeSet::new();
+        for (from, to) in moves {
+            let from = ModuleId::from_path(from.clone());
+            let to = ModuleId::from_path(to.clone());
+            module_graph.rename_module(&from, to.clone());
+            modules_with_missing_deps
+                .iter_mut()
+                .filter(|id| **id == from.id)
+                .for_each(|id| *id = to.id.clone());
+            moved_module_ids.insert(from);
+        }
+        moved_module_ids
+    }
+
+    fn build_by_remove(&self, removed: Vec<PathBuf>) -> (BTreeSet<ModuleId>, BTreeSet<ModuleId>) {
         let mut module_graph = self.context.module_graph.write().unwrap();
-        let mut removed_module_ids = HashSet::new();
-        let mut affected_module_ids = HashSet::new();
+        let mut removed_module_ids = BTreeSet::new();
+        let mut affected_module_ids = BTreeSet::new();
         for path in removed {
             let module_id = ModuleId::from_path(path);
             let dependants = module_graph.dependant_module_ids(&module_id);
@@ -425,9 +716,9 @@ impl Compiler {
 }
 
 pub struct Diff {
-    added: HashSet<ModuleId>,
-    removed: HashSet<ModuleId>,
-    modified: HashSet<ModuleId>,
+    added: BTreeSet<ModuleId>,
+    removed: BTreeSet<ModuleId>,
+    modified: BTreeSet<ModuleId>,
 }
 
 impl Diff {
@@ -479,26 +770,26 @@ fn diff(origin: &[(ModuleId, Dependency)], new_deps: &[(ModuleId, Dependency)])
     let origin_module_ids = origin
         .iter()
         .map(|(module_id, _dep)| module_id.clone())
-        .collect::<HashSet<_>>();
+        .collect::<BTreeSet<_>>();
     let target_module_ids = new_deps
         .iter()
         .map(|(module_id, _dep)| module_id.clone())
-        .collect::<HashSet<_>>();
+        .collect::<BTreeSet<_>>();
 
     let removed = origin_module_ids
         .difference(&target_module_ids)
         .cloned()
-        .collect::<HashSet<_>>();
+        .collect::<BTreeSet<_>>();
 
     let added = target_module_ids
         .difference(&origin_module_ids)
         .cloned()
-        .collect::<HashSet<_>>();
+        .collect::<BTreeSet<_>>();
 
     let modified = origin_module_ids
         .intersection(&target_module_ids)
         .cloned()
-        .collect::<HashSet<_>>();
+        .collect::<BTreeSet<_>>();
 
     Diff {
         added,
@@ -506,3 +797,245 @@ fn diff(origin: &[(ModuleId, Dependency)], new_deps: &[(ModuleId, Dependency)])
         modified,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    use super::UpdateResult;
+    use crate::module::ModuleId;
+    use crate::utils::test_helper::{get_module, HookRecorder, PluginTestBuilder};
+
+    #[test]
+    fn test_css_only_modules() {
+        let mut result = UpdateResult::default();
+        let css = ModuleId::new("/src/a.css".to_string());
+        result.modified.insert(css.clone());
+        assert_eq!(result.css_only_modules(), Some(vec![css]));
+
+        result
+            .modified
+            .insert(ModuleId::new("/src/b.module.css?modules".to_string()));
+        assert_eq!(result.css_only_modules(), None);
+    }
+
+    #[test]
+    fn test_update_skips_tree_shaking() {
+        let recorder = Arc::new(HookRecorder::default());
+        let compiler = PluginTestBuilder::new("update-tree-shaking")
+            .file(
+                "src/index.js",
+                "import { used } from './leaf';\nimport { other } from './other';\nconsole.log(used, other);",
+            )
+            .file(
+                "src/leaf.js",
+                "export const used = 1;\nexport const unused = 2;",
+            )
+            .file("src/other.js", "export const other = 3;")
+            .config(r#"{ "_treeShaking": "basic" }"#)
+            .plugin(recorder.clone())
+            .watch()
+            .build();
+        compiler.compile().unwrap();
+
+        let leaf = compiler.context.root.join("src/leaf.js");
+        fs::write(&leaf, "export const used = 10;\nexport const unused = 20;").unwrap();
+        let result = compiler.update(vec![leaf.clone()]).unwrap();
+        compiler.emit_dev_chunks(1, 0).unwrap();
+
+        // only the changed module is rebuilt
+        assert_eq!(
+            result.modified.into_iter().collect::<Vec<_>>(),
+            vec![ModuleId::from(leaf)]
+        );
+        // neither the initial build nor the update shakes the graph
+        assert!(recorder.calls_of("optimize_module_graph").is_empty());
+        let module = get_module(&compiler, "src/leaf.js");
+        let ast = module.info.as_ref().unwrap().ast.as_script_ast();
+        assert_eq!(ast.body.len(), 2);
+    }
+
+    #[test]
+    fn test_update_async_importers() {
+        let compiler = PluginTestBuilder::new("update-async-importers")
+            .file("src/index.js", "import { a } from './a';\nconsole.log(a);")
+            .file("src/a.js", "import { b } from './b';\nexport const a = b;")
+            .file("src/b.js", "export const b = 1;")
+            .watch()
+            .build();
+        compiler.compile().unwrap();
+
+        let b = compiler.context.root.join("src/b.js");
+        let update = |content: &str| {
+            fs::write(&b, content).unwrap();
+            let result = compiler.update(vec![b.clone()]).unwrap();
+            compiler.transform_for_change(&result).unwrap();
+            result.modified
+        };
+        let all = ["src/a.js", "src/b.js", "src/index.js"]
+            .iter()
+            .map(|path| ModuleId::from(compiler.context.root.join(path)))
+            .collect::<Vec<_>>();
+        let is_async = |path: &str| get_module(&compiler, path).info.unwrap().is_async;
+
+        // the importers become async with the top level await
+        let modified = update("export const b = await Promise.resolve(1);");
+        assert_eq!(modified.into_iter().collect::<Vec<_>>(), all);
+        assert!(is_async("src/a.js"));
+        assert!(is_async("src/index.js"));
+
+        // and are sync again without it
+        let modified = update("export const b = 1;");
+        assert_eq!(modified.into_iter().collect::<Vec<_>>(), all);
+        assert!(!is_async("src/b.js"));
+        assert!(!is_async("src/a.js"));
+        assert!(!is_async("src/index.js"));
+    }
+
+    #[test]
+    fn test_update_json() {
+        let compiler = PluginTestBuilder::new("update-json")
+            .file(
+                "src/index.js",
+                "import './c';\nimport './b';\nimport './a';",
+            )
+            .file("src/a.js", "console.log('a');")
+            .file("src/b.js", "console.log('b');")
+            .file("src/c.js", "console.log('c');")
+            .watch()
+            .build();
+        compiler.compile().unwrap();
+
+        let paths = ["src/c.js", "src/a.js", "src/b.js"]
+            .iter()
+            .map(|path| {
+                let path = compiler.context.root.join(path);
+                fs::write(&path, "console.log('changed');").unwrap();
+                path
+            })
+            .collect::<Vec<_>>();
+        let json = compiler.update_json(paths).unwrap();
+
+        let root = compiler.context.root.to_string_lossy().to_string();
+        let json = json.replace(&root, "<root>");
+        assert_eq!(
+            json,
+            r#"{"added":[],"removed":[],"modified":["<root>/src/a.js","<root>/src/b.js","<root>/src/c.js"]}"#
+        );
+    }
+
+    #[test]
+    fn test_update_restores_graph_on_error() {
+        let compiler = PluginTestBuilder::new("update-rollback")
+            .file(
+                "src/index.js",
+                "import './a';\nimport './b';\nconsole.log('index');",
+            )
+            .file("src/a.js", "import './c';\nconsole.log('a');")
+            .file("src/b.js", "console.log('b');")
+            .file("src/c.js", "console.log('c');")
+            .watch()
+            .build();
+        compiler.compile().unwrap();
+        let snapshot = |compiler: &crate::compiler::Compiler| {
+            let module_graph = compiler.context.module_graph.read().unwrap();
+            let mut module_ids = module_graph.get_module_ids();
+            module_ids.sort();
+            module_ids
+                .into_iter()
+                .map(|module_id| {
+                    let mut dependencies = module_graph.dependence_module_ids(&module_id);
+                    dependencies.sort();
+                    (module_id, dependencies)
+                })
+                .collect::<Vec<_>>()
+        };
+        let before = snapshot(&compiler);
+
+        // c is removed, then a fails to build after it dropped the import of c
+        let a = compiler.context.root.join("src/a.js");
+        let c = compiler.context.root.join("src/c.js");
+        fs::remove_file(&c).unwrap();
+        fs::write(&a, "console.log('a'").unwrap();
+        assert!(compiler.update(vec![c, a.clone()]).is_err());
+        assert_eq!(snapshot(&compiler), before);
+        // the touched modules are restored as well, not only the edges
+        let module_graph = compiler.context.module_graph.read().unwrap();
+        let module = module_graph.get_module(&a.clone().into()).unwrap();
+        assert_eq!(
+            module.info.as_ref().unwrap().raw,
+            "import './c';\nconsole.log('a');"
+        );
+        drop(module_graph);
+
+        // the next update starts from the restored graph
+        fs::write(&a, "console.log('a');").unwrap();
+        let result = compiler.update(vec![a.clone()]).unwrap();
+        assert_eq!(
+            result.modified.into_iter().collect::<Vec<_>>(),
+            vec![ModuleId::from(a)]
+        );
+    }
+
+    #[test]
+    fn test_update_moves_renamed_module() {
+        let compiler = PluginTestBuilder::new("update-move")
+            .file(
+                "src/index.js",
+                "import { leaf } from './leaf';\nconsole.log(leaf);",
+            )
+            .file("src/leaf.js", "export const leaf = 1;")
+            .watch()
+            .build();
+        compiler.compile().unwrap();
+
+        // `./leaf` resolves to the new path, so the module is moved
+        let leaf = compiler.context.root.join("src/leaf.js");
+        let moved = compiler.context.root.join("src/leaf/index.js");
+        fs::create_dir_all(moved.parent().unwrap()).unwrap();
+        fs::rename(&leaf, &moved).unwrap();
+        let result = compiler.update(vec![leaf.clone(), moved.clone()]).unwrap();
+
+        let index = ModuleId::from(compiler.context.root.join("src/index.js"));
+        let moved = ModuleId::from(moved);
+        // the dependent is not rebuilt, only the moved module is
+        assert!(result.added.is_empty());
+        assert_eq!(
+            result.modified.into_iter().collect::<Vec<_>>(),
+            vec![moved.clone()]
+        );
+        assert_eq!(
+            result.removed.into_iter().collect::<Vec<_>>(),
+            vec![ModuleId::from(leaf.clone())]
+        );
+        {
+            let module_graph = compiler.context.module_graph.read().unwrap();
+            assert!(!module_graph.has_module(&ModuleId::from(leaf)));
+            assert_eq!(
+                module_graph.dependant_module_ids(&moved),
+                vec![index.clone()]
+            );
+            let chunk_graph = compiler.context.chunk_graph.read().unwrap();
+            assert!(chunk_graph.get_chunk_for_module(&moved).is_some());
+        }
+
+        // `./leaf` is broken by this rename, so the module is removed and
+        // the dependent is rebuilt to report the missing import
+        let renamed = compiler.context.root.join("src/renamed.js");
+        fs::rename(PathBuf::from(&moved.id), &renamed).unwrap();
+        let result = compiler
+            .update(vec![PathBuf::from(&moved.id), renamed.clone()])
+            .unwrap();
+        assert_eq!(
+            result.modified.into_iter().collect::<Vec<_>>(),
+            vec![index.clone()]
+        );
+        assert_eq!(result.removed.into_iter().collect::<Vec<_>>(), vec![moved]);
+        let index = get_module(&compiler, "src/index.js");
+        assert!(index.info.unwrap().deps.missing_deps.contains_key("./leaf"));
+        let module_graph = compiler.context.module_graph.read().unwrap();
+        assert!(!module_graph.has_module(&ModuleId::from(renamed)));
+    }
+}
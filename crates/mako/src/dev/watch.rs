@@ -1,7 +1,8 @@
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::Instant;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use anyhow::{self, Ok};
 use colored::Colorize;
@@ -10,23 +11,28 @@ use notify_debouncer_full::DebouncedEvent;
 use regex::Regex;
 use tracing::debug;
 
+use super::SharedCompiler;
 use crate::compiler::Compiler;
+use crate::plugins::copy;
 use crate::resolve::ResolverResource;
 
 pub struct Watcher<'a> {
     pub watcher: &'a mut dyn NotifyWatcher,
     pub root: &'a PathBuf,
-    pub compiler: &'a Compiler,
+    // replaced after the config file is changed, so the config, the copy
+    // sources and the module graph are read from the current compiler
+    pub compiler: SharedCompiler,
     pub watched_files: HashSet<PathBuf>,
     pub watched_dirs: HashSet<PathBuf>,
-    node_modules_regexes: Vec<Regex>,
+    // the dirs of the packages outside the root, watched as well
+    package_dirs: HashSet<PathBuf>,
 }
 
 impl<'a> Watcher<'a> {
     pub fn new(
         root: &'a PathBuf,
         watcher: &'a mut notify::RecommendedWatcher,
-        compiler: &'a Arc<Compiler>,
+        compiler: SharedCompiler,
     ) -> Self {
         Self {
             root,
@@ -34,27 +40,50 @@ impl<'a> Watcher<'a> {
             compiler,
             watched_dirs: HashSet::new(),
             watched_files: HashSet::new(),
-            node_modules_regexes: compiler
-                .context
-                .config
-                .watch
-                .node_modules_regexes
-                .clone()
-                .unwrap_or_default()
-                .iter()
-                .map(|s| Regex::new(s).unwrap())
-                .collect::<Vec<Regex>>(),
+            package_dirs: HashSet::new(),
         }
     }
 
+    fn current_compiler(&self) -> Arc<Compiler> {
+        self.compiler.read().unwrap().clone()
+    }
+
+    fn node_modules_regexes(compiler: &Compiler) -> Vec<Regex> {
+        compiler
+            .context
+            .config
+            .watch
+            .node_modules_regexes
+            .as_deref()
+            .unwrap_or(&[])
+            .iter()
+            .map(|s| Regex::new(s).unwrap())
+            .collect()
+    }
+
     // pub fn watch(root: &PathBuf, watcher: &mut notify::RecommendedWatcher) -> anyhow::Result<()> {
     pub fn watch(&mut self) -> anyhow::Result<()> {
         let t_watch = Instant::now();
 
-        self.watch_dir_recursive(self.root.into(), &self.get_ignore_list(true))?;
+        let compiler = self.current_compiler();
+        let node_modules_regexes = Self::node_modules_regexes(&compiler);
+        let ignore_list = self.get_ignore_list(true);
+        self.watch_dir_recursive(self.root.into(), &ignore_list)?;
+
+        // the copy sources which are not watched with the root, e.g. the ones
+        // in node_modules
+        for path in copy::watch_paths(&compiler.context) {
+            if path.exists()
+                && (path.strip_prefix(self.root).is_err()
+                    || Self::should_ignore_watch(&path, &ignore_list))
+            {
+                self.watch_file_or_dir(path, &[])?;
+            }
+        }
 
-        let module_graph = self.compiler.context.module_graph.read().unwrap();
+        let module_graph = compiler.context.module_graph.read().unwrap();
         let mut dirs = HashSet::new();
+        let mut node_module_files = vec![];
         module_graph.modules().iter().for_each(|module| {
             if let Some(ResolverResource::Resolved(resource)) = module
                 .info
@@ -69,26 +98,27 @@ impl<'a> Watcher<'a> {
                         dirs.insert(dir);
                     }
                 }
-                if !self.node_modules_regexes.is_empty() {
-                    let file_path = resource.0.path().to_str().unwrap();
-                    let is_match = file_path.contains("node_modules")
-                        && self
-                            .node_modules_regexes
-                            .iter()
-                            .any(|regex| regex.is_match(file_path));
-                    if is_match {
-                        let _ = self.watcher.watch(
-                            resource.0.path().to_path_buf().as_path(),
-                            notify::RecursiveMode::NonRecursive,
-                        );
-                    }
+                if Self::is_watched_node_module(resource.0.path(), &node_modules_regexes) {
+                    node_module_files.push(resource.0.path().to_path_buf());
                 }
             }
         });
+        for path in node_module_files {
+            if !self.watched_files.contains(&path)
+                && self
+                    .watcher
+                    .watch(path.as_path(), notify::RecursiveMode::NonRecursive)
+                    .is_ok()
+            {
+                self.watched_files.insert(path);
+            }
+        }
         dirs.iter().try_for_each(|dir| {
             self.watch_dir_recursive(dir.into(), &self.get_ignore_list(false))?;
             Ok(())
         })?;
+        self.package_dirs
+            .extend(dirs.into_iter().map(|dir| dir.to_path_buf()));
 
         let t_watch_duration = t_watch.elapsed();
         debug!(
@@ -103,6 +133,18 @@ impl<'a> Watcher<'a> {
         Ok(())
     }
 
+    // re-establish all watches from scratch, the os watcher may have dropped
+    // some (or all) of them after an error
+    pub fn rewatch(&mut self) -> anyhow::Result<()> {
+        self.watched_files
+            .drain()
+            .chain(self.watched_dirs.drain())
+            .for_each(|path| {
+                let _ = self.watcher.unwatch(path.as_path());
+            });
+        self.watch()
+    }
+
     pub fn refresh_watch(&mut self) -> anyhow::Result<()> {
         let t_refresh_watch = Instant::now();
         self.watch()?;
@@ -119,12 +161,13 @@ impl<'a> Watcher<'a> {
     }
 
     fn get_ignore_list(&self, with_output_dir: bool) -> Vec<PathBuf> {
+        let compiler = self.current_compiler();
         let mut ignore_list = vec![".git", "node_modules", ".DS_Store", ".node"];
         if with_output_dir {
-            ignore_list.push(self.compiler.context.config.output.path.to_str().unwrap());
+            ignore_list.push(compiler.context.config.output.path.to_str().unwrap());
         }
         ignore_list.extend(
-            self.compiler
+            compiler
                 .context
                 .config
                 .watch
@@ -184,6 +227,15 @@ impl<'a> Watcher<'a> {
         Ok(())
     }
 
+    // the node_modules files matched by `watch.nodeModulesRegexes`
+    fn is_watched_node_module(path: &Path, node_modules_regexes: &[Regex]) -> bool {
+        let path = path.to_string_lossy();
+        path.contains("node_modules")
+            && node_modules_regexes
+                .iter()
+                .any(|regex| regex.is_match(&path))
+    }
+
     fn should_ignore_watch(path: &Path, ignore_list: &[PathBuf]) -> bool {
         let path = path.to_string_lossy();
         ignore_list
@@ -208,13 +260,46 @@ impl<'a> Watcher<'a> {
         ignore_list.iter().any(|ignored| path.ends_with(ignored))
     }
 
-    pub fn normalize_events(events: Vec<DebouncedEvent>) -> Vec<PathBuf> {
+    pub fn describe_error(error: &notify::Error) -> String {
+        match &error.kind {
+            notify::ErrorKind::MaxFilesWatch => {
+                "the limit of watched files is reached, try to raise it, e.g. `sudo sysctl fs.inotify.max_user_watches=524288` on linux".to_string()
+            }
+            notify::ErrorKind::PathNotFound => {
+                format!("watched path {:?} is removed", error.paths)
+            }
+            notify::ErrorKind::Io(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                format!("watched path {:?} is removed", error.paths)
+            }
+            _ => format!("{:?}", error),
+        }
+    }
+
+    // the dirs are watched recursively, so the events of the ignored paths
+    // in them, e.g. the output dir or `watch.ignorePaths`, are dropped here
+    // before they reach the update, unless they are watched on purpose, i.e.
+    // the copy sources, the packages outside the root and the node_modules
+    // files matched by `watch.nodeModulesRegexes`
+    pub fn normalize_events(&self, events: Vec<DebouncedEvent>) -> Vec<PathBuf> {
+        let ignore_list = self.get_ignore_list(true);
+        let package_ignore_list = self.get_ignore_list(false);
+        let compiler = self.current_compiler();
+        let copy_sources = copy::watch_paths(&compiler.context);
+        let node_modules_regexes = Self::node_modules_regexes(&compiler);
+        let is_ignored = |path: &PathBuf| {
+            if self.package_dirs.iter().any(|dir| path.starts_with(dir)) {
+                return Self::should_ignore_watch(path, &package_ignore_list);
+            }
+            Self::should_ignore_watch(path, &ignore_list)
+                && copy_sources.iter().all(|base| !path.starts_with(base))
+                && !Self::is_watched_node_module(path, &node_modules_regexes)
+        };
         let mut paths = vec![];
         let mut create_paths = HashMap::new();
         events.iter().for_each(|debounced_event| {
             let kind = &debounced_event.event.kind;
             debounced_event.event.paths.iter().for_each(|path| {
-                if Self::should_ignore_event(path, kind) {
+                if Self::should_ignore_event(path, kind) || is_ignored(path) {
                     return;
                 }
                 paths.push(path.clone());
@@ -230,3 +315,240 @@ impl<'a> Watcher<'a> {
         paths
     }
 }
+
+pub struct WatchRecovery {
+    interval: Duration,
+    max_retries: usize,
+}
+
+impl WatchRecovery {
+    pub fn new(interval: Duration, max_retries: usize) -> Self {
+        Self {
+            interval,
+            max_retries,
+        }
+    }
+
+    // wait for the root to (re)appear, then try to re-establish the watch
+    pub fn recover<F>(&self, root: &Path, mut rewatch: F) -> anyhow::Result<()>
+    where
+        F: FnMut() -> anyhow::Result<()>,
+    {
+        if !root.exists() {
+            eprintln!(
+                "{}",
+                format!(
+                    "Watch root {:?} is removed, waiting for it to reappear",
+                    root
+                )
+                .yellow()
+            );
+            while !root.exists() {
+                thread::sleep(self.interval);
+            }
+        }
+        let mut retries = 0;
+        loop {
+            match rewatch() {
+                Result::Ok(()) => {
+                    debug!("re-watch succeeded after {} retries", retries);
+                    return Ok(());
+                }
+                Err(e) if retries < self.max_retries => {
+                    retries += 1;
+                    debug!("re-watch failed: {:?}, retry {}", e, retries);
+                    thread::sleep(self.interval * retries as u32);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::RwLock;
+    use std::time::SystemTime;
+
+    use notify::event::{CreateKind, DataChange, ModifyKind};
+    use notify::Event;
+
+    use super::*;
+    use crate::config::Config;
+    use crate::dev::DevServer;
+    use crate::utils::test_helper::PluginTestBuilder;
+
+    fn temp_root(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!("mako-watch-{}-{}", name, nanos))
+    }
+
+    #[test]
+    fn test_describe_max_files_watch_error() {
+        let error = notify::Error::new(notify::ErrorKind::MaxFilesWatch);
+        assert!(Watcher::describe_error(&error).contains("fs.inotify.max_user_watches"));
+    }
+
+    #[test]
+    fn test_recover_retries_rewatch() {
+        let root = temp_root("retry");
+        std::fs::create_dir_all(&root).unwrap();
+        let mut attempts = 0;
+        let recovery = WatchRecovery::new(Duration::from_millis(1), 3);
+        recovery
+            .recover(&root, || {
+                attempts += 1;
+                if attempts < 2 {
+                    Err(anyhow::anyhow!("watch error"))
+                } else {
+                    Ok(())
+                }
+            })
+            .unwrap();
+        assert_eq!(attempts, 2);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_recover_waits_for_removed_root() {
+        let root = temp_root("removed");
+        let root_clone = root.clone();
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            std::fs::create_dir_all(&root_clone).unwrap();
+        });
+        let mut attempts = 0;
+        let recovery = WatchRecovery::new(Duration::from_millis(5), 0);
+        recovery
+            .recover(&root, || {
+                attempts += 1;
+                Ok(())
+            })
+            .unwrap();
+        handle.join().unwrap();
+        assert_eq!(attempts, 1);
+        assert!(root.exists());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_normalize_events_drops_ignored_paths() {
+        let compiler = Arc::new(
+            PluginTestBuilder::new("watch-ignore-paths")
+                .file("src/index.js", "console.log('index');")
+                .file("generated/types.js", "export {};")
+                .config(r#"{ "watch": { "ignorePaths": ["generated"] } }"#)
+                .watch()
+                .build(),
+        );
+        compiler.compile().unwrap();
+        let root = compiler.context.root.clone();
+        let mut notify_watcher =
+            notify::recommended_watcher(|_: notify::Result<Event>| {}).unwrap();
+        let watcher = Watcher::new(
+            &root,
+            &mut notify_watcher,
+            Arc::new(RwLock::new(compiler.clone())),
+        );
+
+        let event = |kind: EventKind, path: &str| {
+            DebouncedEvent::new(Event::new(kind).add_path(root.join(path)), Instant::now())
+        };
+        let modify = EventKind::Modify(ModifyKind::Data(DataChange::Content));
+        let create = EventKind::Create(CreateKind::File);
+
+        // no rebuild is triggered by the changes of the ignored paths
+        let paths = watcher.normalize_events(vec![
+            event(modify, "generated/types.js"),
+            event(create, "dist/index.js"),
+            event(create, ".git/index.lock"),
+        ]);
+        assert!(paths.is_empty());
+
+        let paths = watcher.normalize_events(vec![
+            event(modify, "generated/types.js"),
+            event(modify, "src/index.js"),
+        ]);
+        assert_eq!(paths, vec![root.join("src/index.js")]);
+    }
+
+    #[test]
+    fn test_normalize_events_keeps_watched_node_modules() {
+        let compiler = Arc::new(
+            PluginTestBuilder::new("watch-node-modules-regexes")
+                .file("src/index.js", "import 'foo';\nimport 'bar';")
+                .file("node_modules/foo/index.js", "console.log('foo');")
+                .file("node_modules/bar/index.js", "console.log('bar');")
+                .config(r#"{ "watch": { "_nodeModulesRegexes": ["node_modules/foo"] } }"#)
+                .watch()
+                .build(),
+        );
+        compiler.compile().unwrap();
+        let root = compiler.context.root.clone();
+        let mut notify_watcher =
+            notify::recommended_watcher(|_: notify::Result<Event>| {}).unwrap();
+        let mut watcher = Watcher::new(
+            &root,
+            &mut notify_watcher,
+            Arc::new(RwLock::new(compiler.clone())),
+        );
+        watcher.watch().unwrap();
+
+        let modify = EventKind::Modify(ModifyKind::Data(DataChange::Content));
+        let paths = watcher.normalize_events(vec![
+            DebouncedEvent::new(
+                Event::new(modify).add_path(root.join("node_modules/foo/index.js")),
+                Instant::now(),
+            ),
+            DebouncedEvent::new(
+                Event::new(modify).add_path(root.join("node_modules/bar/index.js")),
+                Instant::now(),
+            ),
+        ]);
+        assert_eq!(paths, vec![root.join("node_modules/foo/index.js")]);
+    }
+
+    #[test]
+    fn test_watch_with_reloaded_config() {
+        let compiler = PluginTestBuilder::new("watch-reloaded-config")
+            .file("src/index.js", "console.log('index');")
+            .file("src/other.js", "import 'foo';")
+            .file("node_modules/foo/index.js", "console.log('foo');")
+            .watch()
+            .build();
+        compiler.compile().unwrap();
+        let root = compiler.context.root.clone();
+        let foo = root.join("node_modules/foo/index.js");
+        let shared = Arc::new(RwLock::new(Arc::new(compiler)));
+        let mut notify_watcher =
+            notify::recommended_watcher(|_: notify::Result<Event>| {}).unwrap();
+        let mut watcher = Watcher::new(&root, &mut notify_watcher, shared.clone());
+        watcher.watch().unwrap();
+        assert!(!watcher.watched_files.contains(&foo));
+
+        // only the graph of the reloaded config depends on foo
+        let config_file = Config::config_file(&root);
+        std::fs::write(
+            &config_file,
+            r#"{
+                "entry": { "index": "src/index.js", "other": "src/other.js" },
+                "watch": { "_nodeModulesRegexes": ["node_modules/foo"] }
+            }"#,
+        )
+        .unwrap();
+        let mut paths = vec![config_file];
+        assert!(DevServer::reload_config(&shared, &mut paths, true).is_some());
+        watcher.refresh_watch().unwrap();
+        assert!(watcher.watched_files.contains(&foo));
+
+        let modify = EventKind::Modify(ModifyKind::Data(DataChange::Content));
+        let paths = watcher.normalize_events(vec![DebouncedEvent::new(
+            Event::new(modify).add_path(foo.clone()),
+            Instant::now(),
+        )]);
+        assert_eq!(paths, vec![foo]);
+    }
+}
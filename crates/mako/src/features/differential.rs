@@ -0,0 +1,450 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use regex::Regex;
+use serde_json::{json, Value};
+use swc_core::base::sourcemap::SourceMap;
+use swc_core::ecma::ast::Module;
+
+use crate::ast::file::Content;
+use crate::compiler::{Args, Compiler, Context};
+use crate::config::Config;
+use crate::features::sri;
+use crate::generate::chunk::ChunkType;
+use crate::generate::chunk_graph::ChunkGraph;
+use crate::generate::compression;
+use crate::module::{Dependency, ModuleAst, ModuleId};
+use crate::module_graph::ModuleGraph;
+use crate::plugin::{
+    NextBuildParam, Plugin, PluginLoadParam, PluginParseParam, PluginTransformJsParam,
+};
+
+const LEGACY_SUFFIX: &str = ".legacy";
+
+// the same polyfill as vite's, for browsers which support es modules but
+// not `<link rel="modulepreload">`
+const MODULE_PRELOAD_POLYFILL: &str = r#"<script type="module">/* mako/modulepreload-polyfill */
+(function () {
+  var relList = document.createElement('link').relList;
+  if (relList && relList.supports && relList.supports('modulepreload')) return;
+  function getFetchOpts(link) {
+    var fetchOpts = {};
+    if (link.integrity) fetchOpts.integrity = link.integrity;
+    if (link.referrerPolicy) fetchOpts.referrerPolicy = link.referrerPolicy;
+    if (link.crossOrigin === 'use-credentials') fetchOpts.credentials = 'include';
+    else if (link.crossOrigin === 'anonymous') fetchOpts.credentials = 'omit';
+    else fetchOpts.credentials = 'same-origin';
+    return fetchOpts;
+  }
+  function processPreload(link) {
+    if (link.ep) return;
+    link.ep = true;
+    fetch(link.href, getFetchOpts(link));
+  }
+  document.querySelectorAll('link[rel="modulepreload"]').forEach(processPreload);
+  new MutationObserver(function (mutations) {
+    mutations.forEach(function (mutation) {
+      if (mutation.type !== 'childList') return;
+      mutation.addedNodes.forEach(function (node) {
+        if (node.tagName === 'LINK' && node.rel === 'modulepreload') processPreload(node);
+      });
+    });
+  }).observe(document, { childList: true, subtree: true });
+})();
+</script>"#;
+
+pub struct Differential {}
+
+impl Compiler {
+    // build the entries again with legacy targets into the same output dir,
+    // and wire the module/nomodule scripts into the html files
+    pub fn compile_legacy(
+        &self,
+        default_config: Option<&str>,
+        cli_config: Option<&str>,
+        plugins: Option<Vec<Arc<dyn Plugin>>>,
+    ) -> Result<()> {
+        let context = &self.context;
+        if context.args.watch || context.config.differential.is_none() {
+            return Ok(());
+        }
+        let config = Differential::legacy_config(&context.root, default_config, cli_config)?;
+        let plugins = plugins.map(|plugins| {
+            plugins
+                .into_iter()
+                .map(|plugin| Arc::new(LegacyPlugin(plugin)) as Arc<dyn Plugin>)
+                .collect()
+        });
+        let legacy = Compiler::new(config, context.root.clone(), Args { watch: false }, plugins)?;
+        legacy.compile()?;
+        Differential::inject_html(context, &legacy.context)
+    }
+}
+
+impl Differential {
+    fn legacy_config(
+        root: &Path,
+        default_config: Option<&str>,
+        cli_config: Option<&str>,
+    ) -> Result<Config> {
+        let config = Config::new(root, default_config, cli_config)?;
+        let legacy_targets = &config.differential.as_ref().unwrap().legacy_targets;
+        let mut cli_config = match cli_config {
+            Some(cli_config) => serde_json::from_str::<Value>(cli_config)?,
+            None => json!({}),
+        };
+        let overrides = json!({
+            "targets": legacy_targets,
+            "output": { "esVersion": "es5" },
+            "differential": { "_isLegacy": true },
+            // assets are emitted by the modern build with the same names
+            "emitAssets": false,
+            "clean": false,
+            "copy": [],
+            "publicDir": false,
+            "preloadChunks": [],
+        });
+        let cli_config_map = cli_config
+            .as_object_mut()
+            .ok_or_else(|| anyhow!("invalid cli config"))?;
+        for (key, value) in overrides.as_object().unwrap() {
+            cli_config_map.insert(key.clone(), value.clone());
+        }
+        let mut config = Config::new(root, default_config, Some(&cli_config.to_string()))?;
+        // the files of the modern build which are not per chunk are kept, the
+        // maps are merged with the user config, so they are cleared here
+        config.html.clear();
+        config.manifest = None;
+        config.analyze = None;
+        config.stats = None;
+        Ok(config)
+    }
+
+    fn inject_html(modern: &Arc<Context>, legacy: &Arc<Context>) -> Result<()> {
+        let public_path = match modern.config.public_path.as_str() {
+            "runtime" => "",
+            public_path => public_path,
+        };
+        let modern_files = entry_files(modern);
+        let legacy_files = entry_files(legacy);
+        let module_preload_polyfill = modern
+            .config
+            .differential
+            .as_ref()
+            .is_some_and(|differential| differential.module_preload_polyfill);
+        for entry in fs::read_dir(&modern.config.output.path)? {
+            let path = entry?.path();
+            if !path.extension().is_some_and(|ext| ext == "html") {
+                continue;
+            }
+            let mut html = fs::read_to_string(&path)?;
+            for (name, modern_file) in modern_files.iter() {
+                if let Some(legacy_file) = legacy_files.get(name) {
+                    html = inject_scripts(
+                        &html,
+                        public_path,
+                        (modern_file, &sri::tag_attributes(modern, modern_file)?),
+                        (legacy_file, &sri::tag_attributes(modern, legacy_file)?),
+                    );
+                }
+            }
+            if module_preload_polyfill {
+                html = inject_module_preload_polyfill(&html);
+            }
+            fs::write(&path, html)?;
+            if let Some(compression) = &modern.config.output.compression {
+                compression::compress_file(&path, compression)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// entry name -> emitted js file of the entry chunk
+fn entry_files(context: &Arc<Context>) -> HashMap<String, String> {
+    let chunk_graph = context.chunk_graph.read().unwrap();
+    let assets = context.stats_info.get_assets();
+    chunk_graph
+        .get_chunks()
+        .into_iter()
+        .filter_map(|chunk| match &chunk.chunk_type {
+            ChunkType::Entry(_, name, false) => assets
+                .iter()
+                .find(|asset| asset.chunk_id == chunk.id.id && asset.hashname.ends_with(".js"))
+                .map(|asset| (name.clone(), asset.hashname.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+// the files come with the attributes of their tags
+fn inject_scripts(
+    html: &str,
+    public_path: &str,
+    (modern_file, modern_attributes): (&str, &str),
+    (legacy_file, legacy_attributes): (&str, &str),
+) -> String {
+    let scripts = format!(
+        r#"<script type="module" src="{0}{1}"{2}></script><script nomodule src="{0}{3}"{4}></script>"#,
+        public_path, modern_file, modern_attributes, legacy_file, legacy_attributes
+    );
+    // replace the existing script tag of the entry if any
+    let re = Regex::new(&format!(
+        r#"<script[^>]*\ssrc="[^"]*{}"[^>]*>\s*</script>"#,
+        regex::escape(modern_file)
+    ))
+    .unwrap();
+    if re.is_match(html) {
+        return re.replace(html, scripts.as_str()).to_string();
+    }
+    match html.find("</body>") {
+        Some(index) => format!("{}{}{}", &html[..index], scripts, &html[index..]),
+        None => format!("{}{}", html, scripts),
+    }
+}
+
+// inline the polyfill once, before any other scripts
+fn inject_module_preload_polyfill(html: &str) -> String {
+    if html.contains("/* mako/modulepreload-polyfill */") {
+        return html.to_string();
+    }
+    let index = html
+        .find("<script")
+        .or_else(|| html.find("</head>"))
+        .or_else(|| html.find("</body>"));
+    match index {
+        Some(index) => format!(
+            "{}{}{}",
+            &html[..index],
+            MODULE_PRELOAD_POLYFILL,
+            &html[index..]
+        ),
+        None => format!("{}{}", MODULE_PRELOAD_POLYFILL, html),
+    }
+}
+
+pub fn legacy_file_name(file_name: String, config: &Config) -> String {
+    if !config
+        .differential
+        .as_ref()
+        .is_some_and(|differential| differential.is_legacy)
+    {
+        return file_name;
+    }
+    match file_name.strip_suffix(".js") {
+        Some(stem) => format!("{}{}.js", stem, LEGACY_SUFFIX),
+        None => file_name,
+    }
+}
+
+// the plugins of the legacy build only shape the modules and the chunks, so
+// the legacy chunks match the modern ones, the hooks of the build lifecycle,
+// e.g. build_start, generate_end and before_write_fs, are run by the modern
+// build only
+struct LegacyPlugin(Arc<dyn Plugin>);
+
+impl Plugin for LegacyPlugin {
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn cache_key(&self) -> Option<String> {
+        self.0.cache_key()
+    }
+
+    fn modify_config(&self, config: &mut Config, root: &Path, args: &Args) -> Result<()> {
+        self.0.modify_config(config, root, args)
+    }
+
+    fn load(&self, param: &PluginLoadParam, context: &Arc<Context>) -> Result<Option<Content>> {
+        self.0.load(param, context)
+    }
+
+    fn next_build(&self, next_build_param: &NextBuildParam) -> bool {
+        self.0.next_build(next_build_param)
+    }
+
+    fn parse(&self, param: &PluginParseParam, context: &Arc<Context>) -> Result<Option<ModuleAst>> {
+        self.0.parse(param, context)
+    }
+
+    fn transform_js(
+        &self,
+        param: &PluginTransformJsParam,
+        ast: &mut Module,
+        context: &Arc<Context>,
+    ) -> Result<()> {
+        self.0.transform_js(param, ast, context)
+    }
+
+    fn after_generate_transform_js(
+        &self,
+        param: &PluginTransformJsParam,
+        ast: &mut Module,
+        context: &Arc<Context>,
+    ) -> Result<()> {
+        self.0.after_generate_transform_js(param, ast, context)
+    }
+
+    fn before_resolve(&self, deps: &mut Vec<Dependency>, context: &Arc<Context>) -> Result<()> {
+        self.0.before_resolve(deps, context)
+    }
+
+    fn optimize_module_graph(
+        &self,
+        module_graph: &mut ModuleGraph,
+        context: &Arc<Context>,
+    ) -> Result<()> {
+        self.0.optimize_module_graph(module_graph, context)
+    }
+
+    fn before_optimize_chunk(&self, context: &Arc<Context>) -> Result<()> {
+        self.0.before_optimize_chunk(context)
+    }
+
+    fn optimize_chunk(
+        &self,
+        chunk_graph: &mut ChunkGraph,
+        module_graph: &mut ModuleGraph,
+        context: &Arc<Context>,
+    ) -> Result<()> {
+        self.0.optimize_chunk(chunk_graph, module_graph, context)
+    }
+
+    fn transform_source_map(
+        &self,
+        map: &[u8],
+        asset_name: &str,
+        context: &Arc<Context>,
+    ) -> Result<Option<SourceMap>> {
+        self.0.transform_source_map(map, asset_name, context)
+    }
+
+    fn runtime_plugins(&self, context: &Arc<Context>) -> Result<Vec<String>> {
+        self.0.runtime_plugins(context)
+    }
+
+    fn transform_module_prelude(
+        &self,
+        module_id: &ModuleId,
+        prelude: &mut String,
+        context: &Arc<Context>,
+    ) -> Result<()> {
+        self.0.transform_module_prelude(module_id, prelude, context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_helper::{HookRecorder, PluginTestBuilder};
+
+    #[test]
+    fn test_inject_scripts_replace() {
+        assert_eq!(
+            inject_scripts(
+                r#"<body><script src="/index.js"></script></body>"#,
+                "/",
+                ("index.js", ""),
+                ("index.legacy.js", "")
+            ),
+            r#"<body><script type="module" src="/index.js"></script><script nomodule src="/index.legacy.js"></script></body>"#
+        );
+    }
+
+    #[test]
+    fn test_inject_scripts_append() {
+        assert_eq!(
+            inject_scripts(
+                "<body></body>",
+                "/",
+                ("index.js", ""),
+                ("index.legacy.js", "")
+            ),
+            r#"<body><script type="module" src="/index.js"></script><script nomodule src="/index.legacy.js"></script></body>"#
+        );
+    }
+
+    #[test]
+    fn test_inject_scripts_with_attributes() {
+        assert_eq!(
+            inject_scripts(
+                r#"<body><script src="/index.js" integrity="sha384-a" crossorigin="anonymous"></script></body>"#,
+                "/",
+                (
+                    "index.js",
+                    r#" integrity="sha384-a" crossorigin="anonymous""#
+                ),
+                (
+                    "index.legacy.js",
+                    r#" integrity="sha384-b" crossorigin="anonymous""#
+                )
+            ),
+            r#"<body><script type="module" src="/index.js" integrity="sha384-a" crossorigin="anonymous"></script><script nomodule src="/index.legacy.js" integrity="sha384-b" crossorigin="anonymous"></script></body>"#
+        );
+    }
+
+    #[test]
+    fn test_inject_module_preload_polyfill() {
+        let html = inject_module_preload_polyfill(
+            r#"<head></head><body><script src="/a.js"></script><script src="/b.js"></script></body>"#,
+        );
+        assert_eq!(html.matches("mako/modulepreload-polyfill").count(), 1);
+        assert!(html.starts_with(r#"<head></head><body><script type="module">"#));
+        assert!(html.ends_with(
+            r#"</script><script src="/a.js"></script><script src="/b.js"></script></body>"#
+        ));
+        assert_eq!(inject_module_preload_polyfill(&html), html);
+    }
+
+    #[test]
+    fn test_compile_legacy_keeps_modern_files() {
+        let recorder = Arc::new(HookRecorder::default());
+        let compiler = PluginTestBuilder::new("differential")
+            .file("src/index.js", "import './index.css';\nconsole.log(1);")
+            .file("src/index.css", ".a { display: flex; }")
+            .config(
+                r#"{
+                    "entry": { "index": "src/index.js" },
+                    "mode": "production",
+                    "minify": false,
+                    "hmr": false,
+                    "manifest": {},
+                    "differential": { "legacyTargets": { "ie": 11 } }
+                }"#,
+            )
+            .plugin(recorder.clone())
+            .build();
+        compiler.compile().unwrap();
+        let output_path = &compiler.context.config.output.path;
+        let read = |file: &str| fs::read_to_string(output_path.join(file)).unwrap();
+        let manifest = read("asset-manifest.json");
+        let css = read("index.css");
+
+        compiler
+            .compile_legacy(None, None, Some(vec![recorder.clone() as Arc<dyn Plugin>]))
+            .unwrap();
+
+        assert!(output_path.join("index.legacy.js").exists());
+        assert!(output_path.join("index.legacy.css").exists());
+        assert_eq!(read("asset-manifest.json"), manifest);
+        assert_eq!(read("index.css"), css);
+        // the modules are loaded by both builds, the lifecycle hooks are run
+        // by the modern one only
+        let loads = recorder
+            .calls_of("load")
+            .into_iter()
+            .filter(|call| call.path.as_deref() == Some("src/index.js"))
+            .count();
+        assert_eq!(loads, 2);
+        // so are the hooks which shape the chunks
+        assert_eq!(recorder.calls_of("optimize_module_graph").len(), 2);
+        assert_eq!(recorder.calls_of("before_optimize_chunk").len(), 2);
+        assert_eq!(recorder.calls_of("optimize_chunk").len(), 2);
+        assert_eq!(recorder.calls_of("build_start").len(), 1);
+        assert_eq!(recorder.calls_of("generate_end").len(), 1);
+    }
+}
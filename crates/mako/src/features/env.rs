@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use indexmap::IndexMap;
+use serde_json::Value;
+
+use crate::config::{Config, ConfigSource};
+
+pub struct Env {}
+
+impl Env {
+    // load .env files and expose the allowed variables with define
+    pub fn modify_config(config: &mut Config, root: &Path) -> Result<()> {
+        let mode = config.mode.to_string();
+        let vars = Self::load(root, &mode)?;
+        for (key, value) in vars {
+            if !Self::is_exposed(&key, &config.env_prefix) {
+                continue;
+            }
+            // define values are treated as expressions, so they have to be quoted
+            let value = Value::String(serde_json::to_string(&value)?);
+            for define_key in [
+                format!("process.env.{}", key),
+                format!("import.meta.env.{}", key),
+            ] {
+                // the define set by the user wins
+                if config.define.contains_key(&define_key) {
+                    continue;
+                }
+                config
+                    .sources
+                    .insert(format!("define.{}", define_key), ConfigSource::Env);
+                config.define.insert(define_key, value.clone());
+            }
+        }
+        Ok(())
+    }
+
+    // .env < .env.local < .env.[mode] < .env.[mode].local < process env
+    pub fn load(root: &Path, mode: &str) -> Result<IndexMap<String, String>> {
+        let files = [
+            ".env".to_string(),
+            ".env.local".to_string(),
+            format!(".env.{}", mode),
+            format!(".env.{}.local", mode),
+        ];
+        let process_env: HashMap<String, String> = std::env::vars().collect();
+        let mut vars = IndexMap::new();
+        for file in files.iter() {
+            let path = root.join(file);
+            if !path.exists() {
+                continue;
+            }
+            let content = std::fs::read_to_string(&path)?;
+            Self::parse(&content, &mut vars, &process_env)
+                .map_err(|e| anyhow!("failed to parse {}: {}", path.display(), e))?;
+        }
+        for (key, value) in vars.iter_mut() {
+            if let Some(v) = process_env.get(key) {
+                *value = v.clone();
+            }
+        }
+        Ok(vars)
+    }
+
+    fn parse(
+        content: &str,
+        vars: &mut IndexMap<String, String>,
+        process_env: &HashMap<String, String>,
+    ) -> Result<()> {
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| anyhow!("invalid line {}: {}", index + 1, line))?;
+            let key = key.trim();
+            if key.is_empty()
+                || !key
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+            {
+                return Err(anyhow!("invalid key at line {}: {}", index + 1, key));
+            }
+            let value = value.trim();
+            let value = if let Some(v) = Self::unquote(value, '\'') {
+                // single quoted values are kept literally
+                v.to_string()
+            } else if let Some(v) = Self::unquote(value, '"') {
+                Self::interpolate(&v.replace("\\n", "\n"), vars, process_env)
+            } else {
+                // strip inline comments of unquoted values
+                let v = value.split(" #").next().unwrap_or("").trim_end();
+                Self::interpolate(v, vars, process_env)
+            };
+            vars.insert(key.to_string(), value);
+        }
+        Ok(())
+    }
+
+    fn unquote(value: &str, quote: char) -> Option<&str> {
+        if value.len() >= 2 && value.starts_with(quote) && value.ends_with(quote) {
+            Some(&value[1..value.len() - 1])
+        } else {
+            None
+        }
+    }
+
+    // support $VAR and ${VAR}, use \$ to escape
+    fn interpolate(
+        value: &str,
+        vars: &IndexMap<String, String>,
+        process_env: &HashMap<String, String>,
+    ) -> String {
+        let lookup = |name: &str| {
+            process_env
+                .get(name)
+                .or_else(|| vars.get(name))
+                .cloned()
+                .unwrap_or_default()
+        };
+        let mut result = String::new();
+        let mut chars = value.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' if chars.peek() == Some(&'$') => {
+                    result.push('$');
+                    chars.next();
+                }
+                '$' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    let name: String = chars.by_ref().take_while(|c| *c != '}').collect();
+                    result.push_str(&lookup(&name));
+                }
+                '$' if chars
+                    .peek()
+                    .map_or(false, |c| c.is_ascii_alphabetic() || *c == '_') =>
+                {
+                    let mut name = String::new();
+                    while let Some(c) = chars.peek() {
+                        if c.is_ascii_alphanumeric() || *c == '_' {
+                            name.push(*c);
+                            chars.next();
+                        } else {
+                            break;
+                        }
+                    }
+                    result.push_str(&lookup(&name));
+                }
+                _ => result.push(c),
+            }
+        }
+        result
+    }
+
+    fn is_exposed(key: &str, prefixes: &[String]) -> bool {
+        prefixes
+            .iter()
+            .any(|prefix| !prefix.is_empty() && key.starts_with(prefix.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup(files: &[(&str, &str)]) -> std::path::PathBuf {
+        let root = std::env::temp_dir().join(format!("mako-env-{}", nanoid::nanoid!(8)));
+        std::fs::create_dir_all(&root).unwrap();
+        for (name, content) in files {
+            std::fs::write(root.join(name), content).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn test_mode_file_overrides_base_file() {
+        let root = setup(&[
+            (".env", "MAKO_APP_NAME=base\nMAKO_APP_KEEP=keep"),
+            (".env.local", "MAKO_APP_NAME=local"),
+            (".env.production", "MAKO_APP_NAME=production"),
+        ]);
+        let vars = Env::load(&root, "production").unwrap();
+        assert_eq!(vars.get("MAKO_APP_NAME").unwrap(), "production");
+        assert_eq!(vars.get("MAKO_APP_KEEP").unwrap(), "keep");
+        let vars = Env::load(&root, "development").unwrap();
+        assert_eq!(vars.get("MAKO_APP_NAME").unwrap(), "local");
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_interpolation() {
+        let root = setup(&[
+            (".env", "MAKO_APP_HOST=localhost\nMAKO_APP_PORT=8000"),
+            (
+                ".env.development",
+                "MAKO_APP_URL=\"http://${MAKO_APP_HOST}:$MAKO_APP_PORT\"\nMAKO_APP_RAW='$MAKO_APP_HOST'\nMAKO_APP_ESCAPED=\\$MAKO_APP_HOST",
+            ),
+        ]);
+        let vars = Env::load(&root, "development").unwrap();
+        assert_eq!(vars.get("MAKO_APP_URL").unwrap(), "http://localhost:8000");
+        assert_eq!(vars.get("MAKO_APP_RAW").unwrap(), "$MAKO_APP_HOST");
+        assert_eq!(vars.get("MAKO_APP_ESCAPED").unwrap(), "$MAKO_APP_HOST");
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_only_prefixed_vars_are_exposed() {
+        let root = setup(&[(".env", "MAKO_APP_FOO=foo\nSECRET=bar")]);
+        let mut config = Config::default();
+        Env::modify_config(&mut config, &root).unwrap();
+        assert_eq!(
+            config.define.get("process.env.MAKO_APP_FOO"),
+            Some(&Value::String("\"foo\"".to_string()))
+        );
+        assert_eq!(
+            config.define.get("import.meta.env.MAKO_APP_FOO"),
+            Some(&Value::String("\"foo\"".to_string()))
+        );
+        assert!(config.define.get("process.env.SECRET").is_none());
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -1,2 +1,6 @@
+pub mod differential;
+pub mod env;
 pub mod node;
+pub mod regenerate;
 pub mod rsc;
+pub mod sri;
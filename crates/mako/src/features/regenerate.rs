@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Instant;
+
+use anyhow::{anyhow, Result};
+use colored::Colorize;
+
+use crate::ast::file::File;
+use crate::compiler::{Args, Compiler};
+use crate::config::Config;
+use crate::module_graph::ModuleGraph;
+
+/// The state right after the build stage, the generate stage transforms the
+/// asts of the module graph in place, so a copy is kept to generate again
+pub struct RetainedBuild {
+    module_graph: ModuleGraph,
+    assets_info: HashMap<String, String>,
+    // the source files with their content hash when they were built
+    sources: Vec<(File, String)>,
+}
+
+impl RetainedBuild {
+    fn changed_source(&self) -> Option<&PathBuf> {
+        self.sources
+            .iter()
+            .find(|(file, hash)| file.get_content_hash().ok().as_ref() != Some(hash))
+            .map(|(file, _)| &file.pathname)
+    }
+}
+
+impl Compiler {
+    /// Keep a copy of the module graph after the build stage, so that
+    /// [Compiler::regenerate] can generate it again with other output options
+    pub fn retain_build(mut self) -> Self {
+        self.retain_build = true;
+        self
+    }
+
+    pub(crate) fn retain_module_graph(&self) {
+        if !self.retain_build {
+            return;
+        }
+        let module_graph = self.context.module_graph.read().unwrap();
+        let sources = module_graph
+            .modules()
+            .into_iter()
+            .filter_map(|module| {
+                let file = &module.info.as_ref()?.file;
+                if file.is_virtual {
+                    return None;
+                }
+                let hash = file.get_content_hash().ok()?;
+                Some((file.clone(), hash))
+            })
+            .collect();
+        let retained = RetainedBuild {
+            module_graph: module_graph.clone(),
+            assets_info: self.context.assets_info.lock().unwrap().clone(),
+            sources,
+        };
+        *self.retained_build.lock().unwrap() = Some(Arc::new(retained));
+    }
+
+    /// Run the generate stage again with `config`, the module graph and the
+    /// transformed modules of the last build are reused. Only the options of
+    /// the generate stage may differ, see [Config::rebuild_required], and the
+    /// source files must not be changed since the build.
+    pub fn regenerate(&self, config: Config) -> Result<Compiler> {
+        let retained = self
+            .retained_build
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| anyhow!("regenerate requires a build with retain_build()"))?;
+        if let Some(key) = self.context.config.rebuild_required(&config) {
+            return Err(anyhow!("{} is changed, a full build is required", key));
+        }
+        if let Some(path) = retained.changed_source() {
+            return Err(anyhow!(
+                "{} is changed since the last build, a full build is required",
+                path.display()
+            ));
+        }
+
+        let mut compiler = Compiler::new_with_meta(
+            config,
+            self.context.root.clone(),
+            Args {
+                watch: self.context.args.watch,
+            },
+            Some(self.extra_plugins.clone()),
+            self.context.meta.clone(),
+        )?;
+        *compiler.context.module_graph.write().unwrap() = retained.module_graph.clone();
+        *compiler.context.assets_info.lock().unwrap() = retained.assets_info.clone();
+        compiler.retain_build = true;
+        *compiler.retained_build.lock().unwrap() = Some(retained);
+
+        if compiler.context.config.clean {
+            compiler.clean_dist()?;
+        }
+        println!(
+            "{}",
+            format!(
+                "Regenerating with {} for {}...",
+                "mako".to_string().cyan(),
+                compiler.context.config.mode
+            )
+            .green()
+        );
+        compiler.generate_stage(Instant::now(), chrono::Local::now().timestamp_millis())?;
+        Ok(compiler)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::sync::Arc;
+
+    use swc_core::ecma::ast::EsVersion;
+
+    use crate::compiler::Compiler;
+    use crate::config::{Config, Mode};
+    use crate::utils::test_helper::{setup_compiler, HookRecorder, PluginTestBuilder};
+
+    // the same config as PluginTestBuilder and setup_compiler, with `hash`
+    // toggled
+    fn hashed_config(compiler: &Compiler) -> Config {
+        let mut config = Config::new(
+            &compiler.context.root,
+            Some(r#"{ "entry": { "index": "src/index.js" } }"#),
+            Some(r#"{ "hash": true }"#),
+        )
+        .unwrap();
+        config.hmr = None;
+        config.minify = false;
+        config.mode = Mode::Production;
+        config.optimization = None;
+        config
+    }
+
+    #[test]
+    fn test_regenerate() {
+        let recorder = Arc::new(HookRecorder::default());
+        let compiler = PluginTestBuilder::new("regenerate")
+            .file(
+                "src/index.js",
+                "import { a } from './a';\nconsole.log(a);\n",
+            )
+            .file("src/a.js", "export const a = 1;\n")
+            .plugin(recorder.clone())
+            .build()
+            .retain_build();
+        compiler.compile().unwrap();
+        let loaded = recorder.calls_of("load").len();
+        let transformed = recorder.calls_of("transform_js").len();
+
+        let regenerated = compiler.regenerate(hashed_config(&compiler)).unwrap();
+
+        // the modules are not loaded or transformed again
+        assert_eq!(recorder.calls_of("load").len(), loaded);
+        assert_eq!(recorder.calls_of("transform_js").len(), transformed);
+        assert_eq!(recorder.calls_of("generate_end").len(), 2);
+        let hashnames = regenerated
+            .context
+            .stats_info
+            .get_assets()
+            .into_iter()
+            .map(|asset| asset.hashname)
+            .collect::<Vec<_>>();
+        assert!(hashnames
+            .iter()
+            .any(|name| name.starts_with("index.") && name.ends_with(".js") && name != "index.js"));
+        let output_path = &regenerated.context.config.output.path;
+        assert!(!output_path.join("index.js").exists());
+    }
+
+    #[test]
+    fn test_regenerate_changed_source() {
+        let compiler = PluginTestBuilder::new("regenerate-changed-source")
+            .file("src/index.js", "console.log(1);\n")
+            .build()
+            .retain_build();
+        compiler.compile().unwrap();
+
+        fs::write(
+            compiler.context.root.join("src/index.js"),
+            "console.log(2);\n",
+        )
+        .unwrap();
+        let err = compiler
+            .regenerate(hashed_config(&compiler))
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("index.js is changed"));
+    }
+
+    #[test]
+    fn test_regenerate_build_option_changed() {
+        let compiler =
+            setup_compiler("test/build/regenerate-build-option-changed", false).retain_build();
+        compiler.compile().unwrap();
+
+        let mut config = hashed_config(&compiler);
+        config.mode = Mode::Development;
+        let err = compiler.regenerate(config).err().unwrap().to_string();
+        assert_eq!(err, "mode is changed, a full build is required");
+
+        // the output options of the transforms require a rebuild too
+        let mut config = hashed_config(&compiler);
+        config.output.es_version = EsVersion::Es5;
+        let err = compiler.regenerate(config).err().unwrap().to_string();
+        assert_eq!(err, "output is changed, a full build is required");
+    }
+}
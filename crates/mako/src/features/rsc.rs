@@ -1,7 +1,10 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
 use std::sync::Arc;
 
 use anyhow::{anyhow, Result};
 use serde::Serialize;
+use swc_core::common::DUMMY_SP;
 use swc_core::ecma::ast::{Expr, ExprStmt, Lit, Module, ModuleItem, Stmt, Str};
 
 use crate::ast::css_ast::CssAst;
@@ -27,6 +30,17 @@ pub struct RscCssModules {
     pub modules: bool,
 }
 
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientReference {
+    pub id: String,
+    pub chunks: Vec<String>,
+}
+
+pub const CLIENT_REFERENCE_MANIFEST: &str = "client-reference-manifest.json";
+
+const RSC_DIRECTIVES: [&str; 2] = ["use client", "use server"];
+
 pub struct Rsc {}
 
 impl Rsc {
@@ -42,6 +56,9 @@ impl Rsc {
             }
         }
         if let Some(rsc_client) = &context.config.rsc_client {
+            if rsc_client.client_reference_manifest && Rsc::is_client(ast)? {
+                Rsc::emit_client(file, context.clone());
+            }
             let is_server = Rsc::is_server(ast)?;
             if is_server && matches!(rsc_client.log_server_component, LogServerComponent::Error) {
                 return Err(anyhow!(ParseError::UnsupportedServerAction {
@@ -72,6 +89,7 @@ impl Rsc {
     }
 
     fn emit_client(file: &File, context: Arc<Context>) {
+        context.skip_persistent_cache(&file.path.to_string_lossy());
         let stats_info = &context.stats_info;
         let module_id = ModuleId::from_path(file.path.clone()).generate(&context);
         stats_info.add_rsc_client_component(RscClientInfo {
@@ -109,6 +127,110 @@ impl Rsc {
         )
     }
 
+    pub fn should_preserve_directives(context: &Arc<Context>) -> bool {
+        context
+            .config
+            .rsc_client
+            .as_ref()
+            .is_some_and(|rsc_client| rsc_client.preserve_directives)
+    }
+
+    // collect the rsc directives before transforms which may drop them
+    // (e.g. simplifier) or insert statements above them (e.g. common_js)
+    pub fn collect_directives(ast: &Module) -> Vec<String> {
+        ast.body
+            .iter()
+            .map_while(|stmt| match stmt {
+                ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+                    expr: box Expr::Lit(Lit::Str(Str { value, .. })),
+                    ..
+                })) => Some(value.to_string()),
+                _ => None,
+            })
+            .filter(|directive| RSC_DIRECTIVES.contains(&directive.as_str()))
+            .collect()
+    }
+
+    pub fn restore_directives(ast: &mut Module, directives: &[String]) {
+        if directives.is_empty() {
+            return;
+        }
+        ast.body.retain(|stmt| {
+            !matches!(stmt, ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+                expr: box Expr::Lit(Lit::Str(Str { value, .. })),
+                ..
+            })) if directives.iter().any(|directive| value == directive.as_str()))
+        });
+        let stmts = directives.iter().map(|directive| {
+            ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+                span: DUMMY_SP,
+                expr: Box::new(Expr::Lit(Lit::Str(Str {
+                    span: DUMMY_SP,
+                    value: directive.as_str().into(),
+                    raw: None,
+                }))),
+            }))
+        });
+        ast.body.splice(0..0, stmts);
+    }
+
+    /// The `"use client"` modules which are the roots of their own async
+    /// chunks, only with `rscClient.clientReferenceManifest`
+    pub fn client_boundaries(context: &Arc<Context>) -> HashSet<ModuleId> {
+        if !context
+            .config
+            .rsc_client
+            .as_ref()
+            .is_some_and(|rsc_client| rsc_client.client_reference_manifest)
+        {
+            return HashSet::new();
+        }
+        context
+            .stats_info
+            .get_rsc_client_components()
+            .into_iter()
+            .map(|client| ModuleId::from_path(context.root.join(&client.path)))
+            .collect()
+    }
+
+    pub fn write_client_reference_manifest(context: &Arc<Context>) -> Result<()> {
+        let chunk_graph = context.chunk_graph.read().unwrap();
+        let manifest = context
+            .stats_info
+            .get_rsc_client_components()
+            .into_iter()
+            .map(|client| {
+                let module_id = ModuleId::from_path(context.root.join(&client.path));
+                // the chunk of the client boundary, after the sync chunks
+                // split from it
+                let chunk_id = module_id.generate_chunk_id(context).into();
+                let chunks = match chunk_graph.chunk(&chunk_id) {
+                    Some(chunk) => {
+                        let mut chunks = chunk_graph.sync_dependencies_chunk(&chunk.id);
+                        chunks.push(chunk.id.clone());
+                        chunks.into_iter().map(|chunk_id| chunk_id.id).collect()
+                    }
+                    None => chunk_graph
+                        .get_chunk_for_module(&module_id)
+                        .map(|chunk| vec![chunk.id.id.clone()])
+                        .unwrap_or_default(),
+                };
+                (
+                    client.path,
+                    ClientReference {
+                        id: client.module_id,
+                        chunks,
+                    },
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+        fs::write(
+            context.output_path().join(CLIENT_REFERENCE_MANIFEST),
+            serde_json::to_string_pretty(&manifest)?,
+        )?;
+        Ok(())
+    }
+
     pub fn generate_resolve_conditions(config: &Config, conditions: Vec<String>) -> Vec<String> {
         let mut conditions = conditions;
         if config.rsc_server.is_some() {
@@ -150,6 +272,8 @@ fn contains_directive(ast: &Module, directive: &str) -> Result<bool> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::generate::chunk::ChunkType;
+    use crate::utils::test_helper::setup_compiler;
 
     #[test]
     fn test_is_client() {
@@ -170,6 +294,47 @@ mod tests {
         assert!(Rsc::is_server(&build_ast(r#""use server""#)).unwrap());
     }
 
+    #[test]
+    fn test_restore_directives() {
+        let mut ast = build_ast(r#""use strict";"use client";foo();"#).ast;
+        let directives = Rsc::collect_directives(&ast);
+        assert_eq!(directives, vec!["use client".to_string()]);
+
+        // simulate a transform which inserts statements above the directive
+        let stmt = build_ast("bar();").ast.body.remove(0);
+        ast.body.insert(0, stmt);
+        assert!(Rsc::collect_directives(&ast).is_empty());
+
+        Rsc::restore_directives(&mut ast, &directives);
+        assert_eq!(Rsc::collect_directives(&ast), directives);
+        assert_eq!(ast.body.len(), 4);
+    }
+
+    #[test]
+    fn test_client_boundary_chunk() {
+        let compiler = setup_compiler("test/build/rsc-client-boundary", false);
+        compiler.compile().unwrap();
+        let context = &compiler.context;
+        let button = ModuleId::from(context.root.join("src/button.ts"));
+        let button_chunk_id: ModuleId = button.generate_chunk_id(context).into();
+        {
+            let chunk_graph = context.chunk_graph.read().unwrap();
+            let chunk = chunk_graph.chunk(&button_chunk_id).unwrap();
+            assert!(matches!(chunk.chunk_type, ChunkType::Async));
+            assert!(chunk.has_module(&button));
+            // still bundled with the entry for the static import
+            assert_eq!(chunk_graph.get_chunks_for_module(&button).len(), 2);
+        }
+
+        let manifest =
+            fs::read_to_string(context.config.output.path.join(CLIENT_REFERENCE_MANIFEST)).unwrap();
+        let manifest: serde_json::Value = serde_json::from_str(&manifest).unwrap();
+        assert_eq!(
+            manifest["src/button.ts"]["chunks"],
+            serde_json::json!([button_chunk_id.id])
+        );
+    }
+
     fn build_ast(content: &str) -> JsAst {
         JsAst::build("test.ts", content, Default::default()).unwrap()
     }
@@ -0,0 +1,109 @@
+use std::fs;
+
+use anyhow::Result;
+use base64::engine::general_purpose;
+use base64::Engine;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+use crate::compiler::Context;
+use crate::config::{CrossOrigin, SriAlgorithm};
+
+pub(crate) fn integrity(algorithm: SriAlgorithm, content: &[u8]) -> String {
+    let (name, digest) = match algorithm {
+        SriAlgorithm::Sha256 => ("sha256", Sha256::digest(content).to_vec()),
+        SriAlgorithm::Sha384 => ("sha384", Sha384::digest(content).to_vec()),
+        SriAlgorithm::Sha512 => ("sha512", Sha512::digest(content).to_vec()),
+    };
+    format!("{}-{}", name, general_purpose::STANDARD.encode(digest))
+}
+
+// the ` integrity="..." crossorigin="..."` attributes of the tag of an emitted
+// file, cross-origin files are not allowed to be checked without cors, so
+// `anonymous` is used when only `sri` is set
+pub(crate) fn tag_attributes(context: &Context, file: &str) -> Result<String> {
+    let config = &context.config;
+    let mut attributes = String::new();
+    if let Some(sri) = &config.sri {
+        // in memory when not written to disk, e.g. the dev server
+        let content = match context.get_static_content(file) {
+            Some(content) => content,
+            None => fs::read(context.output_path().join(file))?,
+        };
+        attributes.push_str(&format!(
+            r#" integrity="{}""#,
+            integrity(sri.algorithm, &content)
+        ));
+    }
+    let cross_origin = match (config.cross_origin, &config.sri) {
+        (Some(cross_origin), _) => Some(cross_origin),
+        (None, Some(_)) => Some(CrossOrigin::Anonymous),
+        (None, None) => None,
+    };
+    match cross_origin {
+        Some(CrossOrigin::Anonymous) => attributes.push_str(r#" crossorigin="anonymous""#),
+        Some(CrossOrigin::UseCredentials) => {
+            attributes.push_str(r#" crossorigin="use-credentials""#)
+        }
+        None => {}
+    }
+    Ok(attributes)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::integrity;
+    use crate::config::SriAlgorithm;
+    use crate::utils::test_helper::{setup_compiler, PluginTestBuilder};
+
+    #[test]
+    fn test_integrity() {
+        // echo -n "alert('Hello, world.');" | openssl dgst -sha384 -binary | openssl base64 -A
+        assert_eq!(
+            integrity(SriAlgorithm::Sha384, b"alert('Hello, world.');"),
+            "sha384-H8BRh8j48O9oYatfu5AZzq6A9RINhZO5H16dQZngK7T62em8MUt1FLm52t+eX6xO"
+        );
+    }
+
+    #[test]
+    fn test_html_tags_with_integrity() {
+        let compiler = setup_compiler("test/build/sri-html-tags", false);
+        compiler.compile().unwrap();
+
+        let output_path = &compiler.context.config.output.path;
+        let html = fs::read_to_string(output_path.join("index.html")).unwrap();
+        let js = fs::read(output_path.join("index.js")).unwrap();
+        assert!(html.contains(&format!(
+            r#"<script src="/index.js" integrity="{}" crossorigin="use-credentials"></script>"#,
+            integrity(SriAlgorithm::Sha384, &js)
+        )));
+    }
+
+    #[test]
+    fn test_html_tags_with_integrity_in_memory() {
+        let compiler = PluginTestBuilder::new("sri-html-tags-in-memory")
+            .file("src/index.js", "console.log(1);")
+            .file("index.html", "<html><head></head><body></body></html>")
+            .config(
+                r#"{
+                    "html": { "index": { "template": "index.html" } },
+                    "sri": {},
+                    "writeToDisk": false
+                }"#,
+            )
+            .watch()
+            .build();
+        compiler.compile().unwrap();
+
+        // the chunks are served from memory, so is the digest
+        let output_path = &compiler.context.config.output.path;
+        assert!(!output_path.join("index.js").exists());
+        let html = fs::read_to_string(output_path.join("index.html")).unwrap();
+        let js = compiler.context.get_static_content("index.js").unwrap();
+        assert!(html.contains(&format!(
+            r#"<script src="/index.js" integrity="{}" crossorigin="anonymous"></script>"#,
+            integrity(SriAlgorithm::Sha384, &js)
+        )));
+    }
+}
@@ -8,6 +8,7 @@ use hashlink::LinkedHashSet;
 use twox_hash::XxHash64;
 
 use crate::ast::file::parse_path;
+use crate::config::RuntimeChunk;
 use crate::module::ModuleId;
 use crate::module_graph::ModuleGraph;
 
@@ -28,12 +29,31 @@ pub enum ChunkType {
     Worker(ModuleId),
 }
 
+impl ChunkType {
+    /// The id of the runtime chunk which executes the entry chunk with
+    /// `runtimeChunk`, `None` when the runtime is inlined into the chunk
+    pub fn runtime_chunk_id(&self, runtime_chunk: Option<RuntimeChunk>) -> Option<ChunkId> {
+        match (self, runtime_chunk?) {
+            (ChunkType::Entry(_, _, false), RuntimeChunk::Single) => {
+                Some(ChunkId::new("runtime".to_string()))
+            }
+            (ChunkType::Entry(_, name, false), RuntimeChunk::Multiple) => {
+                Some(ChunkId::new(format!("runtime~{}", name)))
+            }
+            _ => None,
+        }
+    }
+}
+
 pub struct Chunk {
     pub id: ChunkId,
     pub chunk_type: ChunkType,
     pub modules: LinkedHashSet<ModuleId>,
     pub content: Option<String>,
     pub source_map: Option<String>,
+    /// Appended to the file name when it collides with another chunk's, see
+    /// [ChunkGraph::add_chunk](crate::generate::chunk_graph::ChunkGraph::add_chunk)
+    pub name_hash: Option<String>,
 }
 
 impl Debug for Chunk {
@@ -57,6 +77,7 @@ impl Chunk {
             chunk_type,
             content: None,
             source_map: None,
+            name_hash: None,
         }
     }
 
@@ -78,17 +99,17 @@ impl Chunk {
                         Component::Prefix(_) => "ps_".to_string(),
                         Component::RootDir => "".to_string(),
                         Component::CurDir => "".to_string(),
-                        Component::Normal(seg) => {
-                            seg.to_string_lossy().replace(['.', '?', '@'], "_")
-                        }
+                        Component::Normal(seg) => sanitize_name(&seg.to_string_lossy()),
                     })
                     .collect::<Vec<String>>()
                     .join("_");
 
                 if !search.is_empty() {
-                    let search_hash =
-                        general_purpose::URL_SAFE.encode(md5::compute(search).0)[..4].to_string();
-                    name = format!("{}_q_{}", name, search_hash);
+                    name = format!("{}_q_{}", name, short_hash(&search));
+                }
+
+                if let Some(name_hash) = &self.name_hash {
+                    name = format!("{}_{}", name, name_hash);
                 }
 
                 format!(
@@ -104,6 +125,12 @@ impl Chunk {
         }
     }
 
+    // the paths may become the same after sanitized, e.g. foo/a.b.js and
+    // foo/a_b.js, the later added one is told apart by the hash of its id
+    pub fn set_name_hash(&mut self) {
+        self.name_hash = Some(short_hash(&self.id.id));
+    }
+
     pub fn add_module(&mut self, module_id: ModuleId) {
         self.modules.insert(module_id);
     }
@@ -116,6 +143,23 @@ impl Chunk {
         self.modules.remove(module_id);
     }
 
+    /// Replaces the module with the renamed one at the same position
+    pub fn rename_module(&mut self, from: &ModuleId, to: &ModuleId) {
+        if self.modules.contains(from) {
+            self.modules = self
+                .modules
+                .iter()
+                .map(|module_id| {
+                    if module_id == from {
+                        to.clone()
+                    } else {
+                        module_id.clone()
+                    }
+                })
+                .collect();
+        }
+    }
+
     pub fn has_module(&self, module_id: &ModuleId) -> bool {
         self.modules.contains(module_id)
     }
@@ -140,10 +184,25 @@ impl Chunk {
     }
 }
 
+// keep only the chars that are safe in file names and urls, e.g. the `@`
+// of scoped packages, spaces and non-ASCII chars are replaced
+fn sanitize_name(name: &str) -> String {
+    name.replace(
+        |c: char| !c.is_ascii_alphanumeric() && c != '_' && c != '-',
+        "_",
+    )
+}
+
+fn short_hash(content: &str) -> String {
+    general_purpose::URL_SAFE.encode(md5::compute(content).0)[..4].to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::generate::chunk::{Chunk, ChunkType};
+    use crate::generate::chunk_graph::ChunkGraph;
     use crate::module::ModuleId;
+    use crate::utils::test_helper::setup_compiler;
 
     #[test]
     fn test_filename() {
@@ -160,4 +219,61 @@ mod tests {
         let chunk = Chunk::new(ModuleId::new("foo/bar.tsx".into()), ChunkType::Runtime);
         assert_eq!(chunk.filename(), "runtime.js");
     }
+
+    #[test]
+    fn test_filename_sanitized() {
+        let chunk = Chunk::new(
+            ModuleId::new("node_modules/@scope/pkg/index.js".into()),
+            ChunkType::Async,
+        );
+        assert_eq!(
+            chunk.filename(),
+            "node_modules__scope_pkg_index_js-async.js"
+        );
+
+        let chunk = Chunk::new(
+            ModuleId::new("src/my page/页面.js".into()),
+            ChunkType::Async,
+        );
+        assert_eq!(chunk.filename(), "src_my_page____js-async.js");
+    }
+
+    #[test]
+    fn test_filename_collision() {
+        let mut chunk_graph = ChunkGraph::new();
+        chunk_graph.add_chunk(Chunk::new(
+            ModuleId::new("src/a.b.js".into()),
+            ChunkType::Async,
+        ));
+        chunk_graph.add_chunk(Chunk::new(
+            ModuleId::new("src/a_b.js".into()),
+            ChunkType::Async,
+        ));
+
+        let first = chunk_graph.chunk(&"src/a.b.js".into()).unwrap().filename();
+        let second = chunk_graph.chunk(&"src/a_b.js".into()).unwrap().filename();
+        assert_eq!(first, "src_a_b_js-async.js");
+        assert_ne!(first, second);
+        assert!(second.starts_with("src_a_b_js_"));
+        assert!(second.ends_with("-async.js"));
+    }
+
+    #[test]
+    fn test_named_chunk_ids() {
+        let compiler = setup_compiler("test/build/named-chunk-ids", false);
+        compiler.compile().unwrap();
+        let output = compiler.context.config.output.path.clone();
+        let index = std::fs::read_to_string(output.join("index.js")).unwrap();
+
+        assert!(index.contains(r#"__mako_require__.ensure("src/pages/user.js")"#));
+        assert!(output.join("src_pages_user_js-async.js").is_file());
+        // the module ids are still hashed
+        let user_module_id = ModuleId::from(compiler.context.root.join("src/pages/user.js"))
+            .generate(&compiler.context);
+        assert_ne!(user_module_id, "src/pages/user.js");
+        assert!(index.contains(&format!(
+            r#"__mako_require__.bind(__mako_require__, "{}")"#,
+            user_module_id
+        )));
+    }
 }
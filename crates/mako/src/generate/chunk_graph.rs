@@ -29,7 +29,18 @@ impl ChunkGraph {
         self.id_index_map.clear();
     }
 
-    pub fn add_chunk(&mut self, chunk: Chunk) {
+    pub fn add_chunk(&mut self, mut chunk: Chunk) {
+        // the entry names are given by the config, no need to tell them apart
+        if !matches!(chunk.chunk_type, ChunkType::Entry(..)) {
+            let filename = chunk.filename();
+            if self
+                .graph
+                .node_weights()
+                .any(|c| c.id != chunk.id && c.filename() == filename)
+            {
+                chunk.set_name_hash();
+            }
+        }
         let chunk_id = chunk.id.clone();
         let node_index = self.graph.add_node(chunk);
         self.id_index_map.insert(chunk_id, node_index);
@@ -63,6 +74,13 @@ impl ChunkGraph {
         self.graph.node_weights().find(|c| c.has_module(module_id))
     }
 
+    pub fn get_chunks_for_module(&self, module_id: &ModuleId) -> Vec<&Chunk> {
+        self.graph
+            .node_weights()
+            .filter(|c| c.has_module(module_id))
+            .collect()
+    }
+
     // pub fn get_chunk_by_id(&self, id: &String) -> Option<&Chunk> {
     //     self.graph.node_weights().find(|c| c.id.id.eq(id))
     // }
@@ -188,6 +206,62 @@ impl ChunkGraph {
         ret
     }
 
+    /// The longest chain of the chunks requested one after another from the
+    /// given chunk, an async chunk is requested only after its parent is
+    /// executed, while the sync chunks are requested along with the chunk
+    /// depending on them. `step_size` is the size of requesting a chunk with
+    /// its sync chunks, the heavier chain wins when they are equally long.
+    pub fn critical_request_chain(
+        &self,
+        chunk_id: &ChunkId,
+        step_size: impl Fn(&ChunkId) -> u64,
+    ) -> Vec<ChunkId> {
+        fn walk(
+            graph: &StableDiGraph<Chunk, ()>,
+            idx: NodeIndex,
+            step_size: &impl Fn(&ChunkId) -> u64,
+            on_path: &mut HashSet<NodeIndex>,
+            chains: &mut HashMap<NodeIndex, (u64, Vec<NodeIndex>)>,
+        ) -> (u64, Vec<NodeIndex>) {
+            if let Some(chain) = chains.get(&idx) {
+                return chain.clone();
+            }
+            on_path.insert(idx);
+            let mut longest: (u64, Vec<NodeIndex>) = (0, vec![]);
+            for next in graph.neighbors_directed(idx, Direction::Outgoing) {
+                // the sync chunks are not requested afterwards, and the async
+                // chunks may import each other
+                if !matches!(graph[next].chunk_type, ChunkType::Async) || on_path.contains(&next) {
+                    continue;
+                }
+                let chain = walk(graph, next, step_size, on_path, chains);
+                if (chain.1.len(), chain.0) > (longest.1.len(), longest.0) {
+                    longest = chain;
+                }
+            }
+            on_path.remove(&idx);
+
+            let mut chain = vec![idx];
+            chain.extend(longest.1);
+            let chain = (step_size(&graph[idx].id) + longest.0, chain);
+            chains.insert(idx, chain.clone());
+            chain
+        }
+
+        let idx = *self.id_index_map.get(chunk_id).unwrap();
+        let (_, chain) = walk(
+            &self.graph,
+            idx,
+            &step_size,
+            &mut HashSet::new(),
+            &mut HashMap::new(),
+        );
+        chain
+            .into_iter()
+            .map(|idx| self.graph[idx].id.clone())
+            .collect()
+    }
+
     pub fn remove_chunk(&mut self, chunk_id: &ChunkId) {
         let idx = self.id_index_map.remove(chunk_id).unwrap();
         self.graph.remove_node(idx);
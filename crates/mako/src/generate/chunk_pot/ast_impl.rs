@@ -9,20 +9,24 @@ use swc_core::css::ast::Stylesheet;
 use swc_core::css::codegen::writer::basic::{BasicCssWriter, BasicCssWriterConfig};
 use swc_core::css::codegen::{CodeGenerator, CodegenConfig, Emit};
 use swc_core::ecma::ast::{
-    BlockStmt, FnExpr, Function, KeyValueProp, Lit, Module as SwcModule, Number, ObjectLit, Prop,
-    PropOrSpread, Stmt, UnaryExpr, UnaryOp, VarDeclKind,
+    BlockStmt, Expr, ExprStmt, FnExpr, Function, KeyValueProp, Lit, Module as SwcModule, Number,
+    ObjectLit, Prop, PropOrSpread, Stmt, UnaryExpr, UnaryOp, VarDeclKind,
 };
 use swc_core::ecma::utils::{quote_ident, quote_str, ExprFactory};
+use swc_core::ecma::visit::{VisitMut, VisitMutWith};
 
 use crate::ast::js_ast::JsAst;
 use crate::ast::sourcemap::{build_source_map_to_buf, merge_source_map};
 use crate::compiler::Context;
 use crate::config::Mode;
-use crate::generate::chunk::{Chunk, ChunkType};
+use crate::features::differential::legacy_file_name;
+use crate::generate::chunk::{Chunk, ChunkId, ChunkType};
 use crate::generate::chunk_pot::util::{
     file_content_hash, pot_to_chunk_module, pot_to_module_object, runtime_code,
+    system_register_wrapper,
 };
 use crate::generate::chunk_pot::{get_css_chunk_filename, util, ChunkPot};
+use crate::generate::css_media::{get_css_media_filename, split_css_media};
 use crate::generate::generate_chunks::{ChunkFile, ChunkFileType};
 use crate::generate::minify::{minify_css, minify_js};
 use crate::generate::transform::transform_css_generate;
@@ -41,14 +45,53 @@ pub(crate) fn render_css_chunk(
     context: &Arc<Context>,
 ) -> Result<ChunkFile> {
     crate::mako_profile_function!(&chunk_pot.js_name);
-    let mut css_code = String::new();
-    let mut source_map = Vec::new();
-    let css_writer = BasicCssWriter::new(
-        &mut css_code,
-        Some(&mut source_map),
-        BasicCssWriterConfig::default(),
-    );
+    let mut stylesheet = chunk_stylesheet(chunk_pot, context);
+    if should_split_css_media(chunk, context) {
+        split_css_media(&mut stylesheet, &context.config.split_css_media);
+    }
+    render_stylesheet(
+        stylesheet,
+        chunk_pot,
+        chunk,
+        context,
+        get_css_chunk_filename(&chunk_pot.js_name),
+        None,
+    )
+}
+
+// the css of the entry chunks split out by media with `splitCSSMedia`, which
+// is left out of the css of `render_css_chunk`
+pub(crate) fn render_css_media_chunks(
+    chunk_pot: &ChunkPot,
+    chunk: &Chunk,
+    context: &Arc<Context>,
+) -> Result<Vec<ChunkFile>> {
+    if !should_split_css_media(chunk, context) {
+        return Ok(vec![]);
+    }
+    crate::mako_profile_function!(&chunk_pot.js_name);
+    let mut stylesheet = chunk_stylesheet(chunk_pot, context);
+    let css_filename = get_css_chunk_filename(&chunk_pot.js_name);
+    split_css_media(&mut stylesheet, &context.config.split_css_media)
+        .into_iter()
+        .map(|(media, stylesheet)| {
+            render_stylesheet(
+                stylesheet,
+                chunk_pot,
+                chunk,
+                context,
+                get_css_media_filename(&css_filename, &media),
+                Some(media),
+            )
+        })
+        .collect()
+}
+
+fn should_split_css_media(chunk: &Chunk, context: &Arc<Context>) -> bool {
+    !context.config.split_css_media.is_empty() && matches!(chunk.chunk_type, ChunkType::Entry(..))
+}
 
+fn chunk_stylesheet(chunk_pot: &ChunkPot, context: &Arc<Context>) -> Stylesheet {
     let ast = &mut chunk_pot.stylesheet.as_ref().unwrap();
 
     let mut stylesheet = Stylesheet {
@@ -65,6 +108,25 @@ pub(crate) fn render_css_chunk(
         transform_css_generate(&mut stylesheet, context);
     }
 
+    stylesheet
+}
+
+fn render_stylesheet(
+    mut stylesheet: Stylesheet,
+    chunk_pot: &ChunkPot,
+    chunk: &Chunk,
+    context: &Arc<Context>,
+    file_name: String,
+    media: Option<String>,
+) -> Result<ChunkFile> {
+    let mut css_code = String::new();
+    let mut source_map = Vec::new();
+    let css_writer = BasicCssWriter::new(
+        &mut css_code,
+        Some(&mut source_map),
+        BasicCssWriterConfig::default(),
+    );
+
     if context.config.minify && matches!(context.config.mode, Mode::Production) {
         minify_css(&mut stylesheet, context)?;
     }
@@ -108,13 +170,15 @@ pub(crate) fn render_css_chunk(
     };
 
     Ok(ChunkFile {
-        raw_hash: ast.raw_hash,
+        raw_hash: chunk_pot.stylesheet.as_ref().unwrap().raw_hash,
         content: css_code.into(),
         hash: css_hash,
         source_map,
-        file_name: get_css_chunk_filename(&chunk_pot.js_name),
+        file_name,
         chunk_id: chunk_pot.chunk_id.clone(),
         file_type: ChunkFileType::Css,
+        legal_comments: vec![],
+        media,
     })
 }
 
@@ -149,7 +213,7 @@ pub(crate) fn render_normal_js_chunk(
         minify_js(&mut ast, context)?;
     }
 
-    let (buf, source_map) = util::render_module_js(&ast.ast, context)?;
+    let (buf, source_map, legal_comments) = util::render_module_js(&ast.ast, context)?;
 
     let hash = if context.config.hash {
         Some(file_content_hash(&buf))
@@ -165,6 +229,8 @@ pub(crate) fn render_normal_js_chunk(
         file_name: chunk_pot.js_name.clone(),
         chunk_id: chunk_pot.chunk_id.clone(),
         file_type: ChunkFileType::JS,
+        legal_comments,
+        media: None,
     })
 }
 
@@ -182,6 +248,7 @@ pub(crate) fn render_entry_js_chunk(
         content,
         source_map,
         hash,
+        legal_comments,
     } = ternary!(
         context.args.watch,
         render_entry_chunk_js_without_full_hash,
@@ -204,6 +271,8 @@ pub(crate) fn render_entry_js_chunk(
         file_name: pot.js_name.clone(),
         chunk_id: pot.chunk_id.clone(),
         file_type: ChunkFileType::JS,
+        legal_comments,
+        media: None,
     })
 }
 
@@ -270,7 +339,7 @@ fn render_entry_chunk_js_without_full_hash(
     let mut ast = {
         crate::mako_profile_scope!("parse_runtime_entry");
 
-        let runtime_content = runtime_code(context)?;
+        let runtime_content = runtime_code(context, false)?;
 
         JsAst::build(
             "_mako_internal/runtime_entry.js",
@@ -298,13 +367,16 @@ fn render_entry_chunk_js_without_full_hash(
             .splice(0..0, stmts.into_iter().map(|s| s.into()));
 
         ast.ast = wrap_in_iife(ast.ast);
+        if let Some(wrapper) = system_register_wrapper(context) {
+            ast.ast = wrap_in_system_register(ast.ast, wrapper, context)?;
+        }
     }
 
     if context.config.minify && matches!(context.config.mode, Mode::Production) {
         minify_js(&mut ast, context)?;
     }
 
-    let (buf, source_map_buf) = util::render_module_js(&ast.ast, context)?;
+    let (buf, source_map_buf, legal_comments) = util::render_module_js(&ast.ast, context)?;
 
     let hash = if context.config.hash {
         crate::mako_profile_scope!("entryHash");
@@ -317,6 +389,99 @@ fn render_entry_chunk_js_without_full_hash(
         content: buf,
         source_map: source_map_buf,
         hash,
+        legal_comments,
+    })
+}
+
+/// The runtime chunk of `runtimeChunk`, it has no modules of its own, the
+/// entry chunks which use it are pushed to the chunk loading global
+pub(crate) fn render_runtime_js_chunk(
+    chunk_id: &ChunkId,
+    entry_chunk_ids: &[ChunkId],
+    js_map: &HashMap<String, String>,
+    css_map: &HashMap<String, String>,
+    context: &Arc<Context>,
+    hmr_hash: u64,
+) -> Result<ChunkFile> {
+    crate::mako_profile_function!(&chunk_id.id);
+
+    let (js_map_stmt, css_map_stmt) = chunk_map_decls(js_map, css_map);
+
+    // the css of the entries is loaded along with them
+    let init_install_css_chunks: Stmt = ObjectLit {
+        span: DUMMY_SP,
+        props: entry_chunk_ids
+            .iter()
+            .map(|entry_chunk_id| {
+                Prop::KeyValue(KeyValueProp {
+                    key: quote_str!(entry_chunk_id.id.clone()).into(),
+                    value: Lit::Num(Number {
+                        span: DUMMY_SP,
+                        value: 0f64,
+                        raw: None,
+                    })
+                    .into(),
+                })
+                .into()
+            })
+            .collect(),
+    }
+    .into_var_decl(VarDeclKind::Var, quote_ident!("cssInstalledChunks").into())
+    .into();
+
+    let modules_lit: Stmt = ObjectLit {
+        span: DUMMY_SP,
+        props: vec![],
+    }
+    .into_var_decl(VarDeclKind::Var, quote_ident!("m").into())
+    .into();
+
+    let main_id_decl: Stmt = quote_ident!("undefined")
+        .into_var_decl(VarDeclKind::Var, quote_ident!("e").into())
+        .into();
+
+    let mut ast = JsAst::build(
+        "_mako_internal/runtime_entry.js",
+        runtime_code(context, true)?.as_str(),
+        context.clone(),
+    )?;
+    let stmts = vec![
+        js_map_stmt,
+        css_map_stmt,
+        init_install_css_chunks,
+        modules_lit,
+        main_id_decl,
+    ];
+    ast.ast
+        .body
+        .splice(0..0, stmts.into_iter().map(|s| s.into()));
+    ast.ast = wrap_in_iife(ast.ast);
+
+    if context.config.minify && matches!(context.config.mode, Mode::Production) {
+        minify_js(&mut ast, context)?;
+    }
+
+    let (buf, source_map, legal_comments) = util::render_module_js(&ast.ast, context)?;
+    let content = String::from_utf8(buf)?
+        .replace("_%full_hash%_", &hmr_hash.to_string())
+        .into_bytes();
+
+    let hash = if context.config.hash {
+        Some(file_content_hash(&content))
+    } else {
+        None
+    };
+
+    Ok(ChunkFile {
+        raw_hash: hmr_hash,
+        content,
+        hash,
+        source_map,
+        file_name: legacy_file_name(format!("{}.js", chunk_id.id), &context.config),
+        chunk_id: chunk_id.id.clone(),
+        file_type: ChunkFileType::JS,
+        legal_comments,
+        media: None,
     })
 }
 
@@ -325,6 +490,7 @@ struct RenderedChunk {
     content: Vec<u8>,
     source_map: Option<Vec<u8>>,
     hash: Option<String>,
+    legal_comments: Vec<String>,
 }
 
 fn chunk_map_decls(
@@ -399,3 +565,41 @@ fn wrap_in_iife(module: SwcModule) -> SwcModule {
         span: DUMMY_SP,
     }
 }
+
+const SYSTEM_EXECUTE_PLACEHOLDER: &str = "__mako_system_execute__";
+
+// put the iife into the execute function of `System.register`
+fn wrap_in_system_register(
+    module: SwcModule,
+    (prefix, suffix): (String, String),
+    context: &Arc<Context>,
+) -> Result<SwcModule> {
+    let mut wrapper = JsAst::build(
+        "_mako_internal/system_register.js",
+        &format!("{}{};{}", prefix, SYSTEM_EXECUTE_PLACEHOLDER, suffix),
+        context.clone(),
+    )?;
+    let mut replacer = SystemExecuteReplacer {
+        stmt: module.body.into_iter().next().and_then(|item| item.stmt()),
+    };
+    wrapper.ast.visit_mut_with(&mut replacer);
+    Ok(wrapper.ast)
+}
+
+struct SystemExecuteReplacer {
+    stmt: Option<Stmt>,
+}
+
+impl VisitMut for SystemExecuteReplacer {
+    fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
+        if let Stmt::Expr(ExprStmt { expr, .. }) = stmt
+            && let Expr::Ident(ident) = &**expr
+            && ident.sym == *SYSTEM_EXECUTE_PLACEHOLDER
+            && let Some(replacement) = self.stmt.take()
+        {
+            *stmt = replacement;
+            return;
+        }
+        stmt.visit_mut_children_with(self);
+    }
+}
@@ -12,7 +12,9 @@ use swc_core::css::ast::Stylesheet;
 
 use crate::compiler::Context;
 use crate::config::Mode;
+use crate::features::differential::legacy_file_name;
 use crate::generate::chunk::{Chunk, ChunkType};
+pub(crate) use crate::generate::chunk_pot::ast_impl::render_runtime_js_chunk;
 pub use crate::generate::chunk_pot::util::CHUNK_FILE_NAME_HASH_LENGTH;
 use crate::generate::chunk_pot::util::{hash_hashmap, hash_vec};
 use crate::generate::generate_chunks::ChunkFile;
@@ -38,7 +40,7 @@ impl<'cp> ChunkPot<'cp> {
         let (js_modules, stylesheet) = ChunkPot::split_modules(chunk.get_modules(), mg, context);
 
         ChunkPot {
-            js_name: chunk.filename(),
+            js_name: legacy_file_name(chunk.filename(), &context.config),
             chunk_type: chunk.chunk_type.clone(),
             chunk_id: chunk.id.id.clone(),
             module_map: js_modules.module_map,
@@ -61,19 +63,7 @@ impl<'cp> ChunkPot<'cp> {
             return Ok(files);
         }
 
-        let js_chunk_file = ternary!(
-            self.use_chunk_parallel(context),
-            ternary!(
-                context.args.watch,
-                str_impl::render_normal_js_chunk,
-                str_impl::render_normal_js_chunk_no_cache
-            ),
-            ternary!(
-                context.args.watch,
-                ast_impl::render_normal_js_chunk,
-                ast_impl::render_normal_js_chunk_no_cache
-            )
-        )(self, context)?;
+        let js_chunk_file = self.render_normal_js_chunk(context)?;
 
         if js_chunk_file.content.is_empty() {
             panic!("Normal chunk {} output is empty.", chunk.id.id);
@@ -93,6 +83,22 @@ impl<'cp> ChunkPot<'cp> {
         Ok(files)
     }
 
+    fn render_normal_js_chunk(&self, context: &Arc<Context>) -> Result<ChunkFile> {
+        ternary!(
+            self.use_chunk_parallel(context),
+            ternary!(
+                context.args.watch,
+                str_impl::render_normal_js_chunk,
+                str_impl::render_normal_js_chunk_no_cache
+            ),
+            ternary!(
+                context.args.watch,
+                ast_impl::render_normal_js_chunk,
+                ast_impl::render_normal_js_chunk_no_cache
+            )
+        )(self, context)
+    }
+
     pub fn to_entry_chunk_files(
         &self,
         context: &Arc<Context>,
@@ -105,12 +111,31 @@ impl<'cp> ChunkPot<'cp> {
 
         let mut files = vec![];
 
+        // the runtime and the chunk maps are in the runtime chunk
+        if self
+            .chunk_type
+            .runtime_chunk_id(context.config.runtime_chunk)
+            .is_some()
+        {
+            if self.stylesheet.is_some() {
+                files.push(ast_impl::render_css_chunk(self, chunk, context)?);
+                files.extend(ast_impl::render_css_media_chunks(self, chunk, context)?);
+            }
+            let js_chunk_file = self.render_normal_js_chunk(context)?;
+            if js_chunk_file.content.is_empty() {
+                panic!("Entry chunk {} output is empty.", chunk.id.id);
+            }
+            files.push(js_chunk_file);
+            return Ok(files);
+        }
+
         let js_chunk_file = if self.stylesheet.is_some() {
             let css_chunk_file = ast_impl::render_css_chunk(self, chunk, context)?;
 
             let mut css_map = css_map.clone();
             css_map.insert(css_chunk_file.chunk_id.clone(), css_chunk_file.disk_name());
             files.push(css_chunk_file);
+            files.extend(ast_impl::render_css_media_chunks(self, chunk, context)?);
 
             if self.use_chunk_parallel(context) {
                 str_impl::render_entry_js_chunk(self, js_map, &css_map, chunk, context, hmr_hash)?
@@ -14,7 +14,9 @@ use crate::ast::sourcemap::{build_source_map, RawSourceMap};
 use crate::compiler::Context;
 use crate::generate::chunk::Chunk;
 use crate::generate::chunk_pot::ast_impl::{render_css_chunk, render_css_chunk_no_cache};
-use crate::generate::chunk_pot::util::runtime_code;
+use crate::generate::chunk_pot::util::{
+    runtime_chunk_entry_id, runtime_code, system_register_wrapper,
+};
 use crate::generate::chunk_pot::ChunkPot;
 use crate::generate::generate_chunks::{ChunkFile, ChunkFileType};
 use crate::module::{Module, ModuleAst};
@@ -70,9 +72,14 @@ pub(super) fn render_entry_js_chunk(
     lines.push(init_install_css_chunk);
     lines.push(format!("var e = \"{}\";", pot.chunk_id));
 
-    let runtime_content = runtime_code(context)?.replace("_%full_hash%_", &hmr_hash.to_string());
+    let runtime_content =
+        runtime_code(context, false)?.replace("_%full_hash%_", &hmr_hash.to_string());
 
-    let entry_prefix_code = "!(function(){\n";
+    let system_wrapper = system_register_wrapper(context);
+    let entry_prefix_code = match &system_wrapper {
+        Some((prefix, _)) => format!("{}!(function(){{\n", prefix),
+        None => "!(function(){\n".to_string(),
+    };
 
     let (chunk_content, chunk_raw_sourcemap) =
         pot_to_chunk_module_object_string(pot, context, entry_prefix_code.lines().count() as u32)?;
@@ -84,6 +91,9 @@ pub(super) fn render_entry_js_chunk(
         content.extend(lines.join("\n").into_bytes());
         content.extend(runtime_content.into_bytes());
         content.extend("\n})();".as_bytes());
+        if let Some((_, suffix)) = &system_wrapper {
+            content.extend(suffix.as_bytes());
+        }
     }
 
     let mut source_map_buf: Vec<u8> = vec![];
@@ -97,6 +107,8 @@ pub(super) fn render_entry_js_chunk(
         file_name: pot.js_name.clone(),
         chunk_id: pot.chunk_id.clone(),
         file_type: ChunkFileType::JS,
+        legal_comments: vec![],
+        media: None,
     })
 }
 
@@ -130,8 +142,16 @@ pub(super) fn render_normal_js_chunk(
         let mut source_map_buf: Vec<u8> = vec![];
         sourcemap::SourceMap::from(chunk_raw_sourcemap).to_writer(&mut source_map_buf)?;
 
+        let chunk_suffix_code = match runtime_chunk_entry_id(pot, context) {
+            Some(entry_id) => format!(",\n{}]);", serde_json::to_string(&entry_id)?),
+            None => "]);".to_string(),
+        };
+
         (
-            format!("{}\n{}]);", chunk_prefix_code, chunk_content),
+            format!(
+                "{}\n{}{}",
+                chunk_prefix_code, chunk_content, chunk_suffix_code
+            ),
             source_map_buf,
         )
     };
@@ -144,6 +164,8 @@ pub(super) fn render_normal_js_chunk(
         file_name: chunk_pot.js_name.clone(),
         chunk_id: chunk_pot.chunk_id.clone(),
         file_type: ChunkFileType::JS,
+        legal_comments: vec![],
+        media: None,
     })
 }
 
@@ -21,15 +21,19 @@ use twox_hash::XxHash64;
 
 use crate::ast::sourcemap::build_source_map_to_buf;
 use crate::compiler::Context;
-use crate::config::{get_pkg_name, Mode};
+use crate::config::{get_pkg_name, LegalCommentsMode, Mode, OutputFormat};
+use crate::generate::chunk::ChunkType;
 use crate::generate::chunk_pot::ChunkPot;
+use crate::generate::legal_comments::{collect_legal_comments, LegalCommentsFilter};
 use crate::generate::runtime::AppRuntimeTemplate;
 use crate::module::{relative_to_root, Module, ModuleAst};
 
+// the legal comments are returned with `legalComments: "external"` or
+// `"linked"`
 pub(crate) fn render_module_js(
     ast: &SwcModule,
     context: &Arc<Context>,
-) -> Result<(Vec<u8>, Option<Vec<u8>>)> {
+) -> Result<(Vec<u8>, Option<Vec<u8>>, Vec<String>)> {
     crate::mako_profile_function!();
 
     let mut buf = vec![];
@@ -39,21 +43,53 @@ pub(crate) fn render_module_js(
     let comments = context.meta.script.origin_comments.read().unwrap();
     let swc_comments = comments.get_swc_comments();
 
-    let mut emitter = Emitter {
-        cfg: JsCodegenConfig::default()
-            .with_minify(with_minify)
-            .with_target(context.config.output.es_version)
-            .with_ascii_only(with_minify)
-            .with_omit_last_semi(true),
-        cm: cm.clone(),
-        comments: if with_minify {
-            None
-        } else {
-            Some(swc_comments)
-        },
-        wr: Box::new(JsWriter::new(cm, "\n", &mut buf, Some(&mut source_map_buf))),
+    let legal_comments_mode = context.config.transform.legal_comments;
+    // collected before emitting, the emitter takes the comments it writes
+    let legal_comments = match legal_comments_mode {
+        Some(LegalCommentsMode::Eof | LegalCommentsMode::External | LegalCommentsMode::Linked) => {
+            collect_legal_comments(ast, swc_comments)
+        }
+        _ => vec![],
+    };
+    let only_legal = LegalCommentsFilter {
+        comments: swc_comments,
+        legal: true,
+    };
+    let without_legal = LegalCommentsFilter {
+        comments: swc_comments,
+        legal: false,
+    };
+
+    {
+        let mut emitter = Emitter {
+            cfg: JsCodegenConfig::default()
+                .with_minify(with_minify)
+                .with_target(context.config.output.es_version)
+                .with_ascii_only(with_minify)
+                .with_omit_last_semi(true),
+            cm: cm.clone(),
+            comments: match (with_minify, legal_comments_mode) {
+                (true, Some(LegalCommentsMode::Inline)) => Some(&only_legal as &dyn Comments),
+                (true, _) => None,
+                (false, None | Some(LegalCommentsMode::Inline)) => {
+                    Some(swc_comments as &dyn Comments)
+                }
+                (false, _) => Some(&without_legal as &dyn Comments),
+            },
+            wr: Box::new(JsWriter::new(cm, "\n", &mut buf, Some(&mut source_map_buf))),
+        };
+        emitter.emit_module(ast)?;
+    }
+
+    let legal_comments = match legal_comments_mode {
+        Some(LegalCommentsMode::Eof) => {
+            if !legal_comments.is_empty() {
+                buf.extend(format!("\n{}", legal_comments.join("\n")).into_bytes());
+            }
+            vec![]
+        }
+        _ => legal_comments,
     };
-    emitter.emit_module(ast)?;
 
     let cm = &context.meta.script.cm;
     let source_map = {
@@ -64,7 +100,7 @@ pub(crate) fn render_module_js(
         }
     };
 
-    Ok((buf, source_map))
+    Ok((buf, source_map, legal_comments))
 }
 
 pub(crate) fn empty_module_fn_expr() -> FnExpr {
@@ -91,17 +127,21 @@ pub(crate) fn empty_module_fn_expr() -> FnExpr {
     }
 }
 
-pub(crate) fn runtime_code(context: &Arc<Context>) -> Result<String> {
+// `runtime_chunk` is true for the runtime chunks of `runtimeChunk`, which
+// don't inline the modules of an entry
+pub(crate) fn runtime_code(context: &Arc<Context>, runtime_chunk: bool) -> Result<String> {
     let umd = context.config.umd.clone();
     let chunk_graph = context.chunk_graph.read().unwrap();
-    let has_dynamic_chunks = chunk_graph.get_all_chunks().len() > 1;
-    let has_hmr = context.args.watch;
+    // the entries are pushed to the runtime chunk like the other chunks
+    let has_dynamic_chunks = chunk_graph.get_all_chunks().len() > 1 || runtime_chunk;
+    let has_hmr = context.args.watch && context.config.hmr.is_some();
     let app_runtime = AppRuntimeTemplate {
         has_dynamic_chunks,
         has_hmr,
         umd,
         is_browser: matches!(context.config.platform, crate::config::Platform::Browser),
         cjs: context.config.cjs,
+        system: context.config.output.format == OutputFormat::System,
         chunk_loading_global: context.config.output.chunk_loading_global.clone(),
         pkg_name: get_pkg_name(&context.root),
         concatenate_enabled: context
@@ -109,6 +149,9 @@ pub(crate) fn runtime_code(context: &Arc<Context>) -> Result<String> {
             .optimization
             .as_ref()
             .map_or(false, |o| o.concatenate_modules.unwrap_or(false)),
+        circular_warning: context.config.experimental.circular_runtime_warning
+            && matches!(context.config.mode, Mode::Development),
+        runtime_chunk,
     };
     let app_runtime = app_runtime.render_once()?;
     let app_runtime = app_runtime.replace(
@@ -118,6 +161,46 @@ pub(crate) fn runtime_code(context: &Arc<Context>) -> Result<String> {
     Ok(app_runtime)
 }
 
+// prefix and suffix of `System.register` which wraps the entry chunks, the
+// externals are the deps of the register and exposed to the modules with
+// `__mako_require__.systemDeps`
+pub(crate) fn system_register_wrapper(context: &Arc<Context>) -> Option<(String, String)> {
+    if context.config.output.format != OutputFormat::System {
+        return None;
+    }
+    let mut deps = {
+        let module_graph = context.module_graph.read().unwrap();
+        module_graph
+            .modules()
+            .into_iter()
+            .filter(|module| {
+                module
+                    .info
+                    .as_ref()
+                    .is_some_and(|info| info.external.is_some())
+            })
+            .map(|module| module.id.id.clone())
+            .collect::<Vec<_>>()
+    };
+    deps.sort();
+    let setters = deps
+        .iter()
+        .map(|dep| {
+            format!(
+                "function (m) {{ systemDeps[{}] = m.__useDefault ? m.default : m; }}",
+                serde_json::to_string(dep).unwrap()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let prefix = format!(
+        "System.register({}, function (_export, _context) {{\nvar systemDeps = {{}};\nreturn {{ setters: [{}], execute: function () {{\n",
+        serde_json::to_string(&deps).unwrap(),
+        setters
+    );
+    Some((prefix, "\n} };\n});".to_string()))
+}
+
 pub(crate) fn hash_hashmap<K, V>(map: &HashMap<K, V>) -> u64
 where
     K: Hash + Eq + Ord,
@@ -187,6 +270,25 @@ pub(crate) fn pot_to_module_object(pot: &ChunkPot, context: &Arc<Context>) -> Re
                             text: id.into(),
                         },
                     );
+                    let annotation = context
+                        .export_annotations
+                        .lock()
+                        .unwrap()
+                        .get(&module.0.id)
+                        .cloned();
+                    if let Some(annotation) = annotation
+                        && !annotation.is_empty()
+                    {
+                        comments.add_leading(
+                            span.hi,
+                            Comment {
+                                kind: CommentKind::Block,
+                                span: DUMMY_SP,
+                                text: format!("exports: {}", annotation.replace("*/", "*\\/"))
+                                    .into(),
+                            },
+                        );
+                    }
                     let pv: PropOrSpread = Prop::KeyValue(KeyValueProp {
                         key: quote_str!(span, module_id_str.clone()).into(),
                         value: fn_expr.into(),
@@ -206,6 +308,17 @@ pub(crate) fn pot_to_module_object(pot: &ChunkPot, context: &Arc<Context>) -> Re
     })
 }
 
+// the entry chunks which use the runtime chunk of `runtimeChunk` are pushed
+// with the id of the entry module, which is executed once pushed
+pub(crate) fn runtime_chunk_entry_id(pot: &ChunkPot, context: &Arc<Context>) -> Option<String> {
+    match &pot.chunk_type {
+        ChunkType::Entry(module_id, _, false) if context.config.runtime_chunk.is_some() => {
+            Some(module_id.generate(context))
+        }
+        _ => None,
+    }
+}
+
 pub(crate) fn pot_to_chunk_module(
     pot: &ChunkPot,
     global: String,
@@ -243,16 +356,16 @@ pub(crate) fn pot_to_chunk_module(
         .make_assign_to(AssignOp::Assign, chunk_global_expr.clone().as_pat_or_expr())
         .wrap_with_paren()
         .make_member::<Ident>(quote_ident!("push"));
+    // [[ "module id"], { module object }, "entry module id"?]
+    let mut chunk_data = vec![
+        to_array_lit(vec![quote_str!(pot.chunk_id.clone()).as_arg()]).as_arg(),
+        module_object.as_arg(),
+    ];
+    if let Some(entry_id) = runtime_chunk_entry_id(pot, context) {
+        chunk_data.push(quote_str!(entry_id).as_arg());
+    }
     let chunk_register_stmt = chunk_global_obj
-        .as_call(
-            DUMMY_SP,
-            // [[ "module id"], { module object }]
-            vec![to_array_lit(vec![
-                to_array_lit(vec![quote_str!(pot.chunk_id.clone()).as_arg()]).as_arg(),
-                module_object.as_arg(),
-            ])
-            .as_arg()],
-        )
+        .as_call(DUMMY_SP, vec![to_array_lit(chunk_data).as_arg()])
         .into_stmt();
 
     Ok(SwcModule {
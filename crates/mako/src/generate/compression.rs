@@ -0,0 +1,217 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use rayon::prelude::*;
+use tracing::debug;
+
+use crate::compiler::Compiler;
+use crate::config::{CompressionAlgorithm, CompressionConfig};
+
+pub(crate) fn default_compression_algorithms() -> Vec<CompressionAlgorithm> {
+    vec![CompressionAlgorithm::Gzip]
+}
+
+pub(crate) fn default_compression_threshold() -> u64 {
+    1024
+}
+
+pub(crate) fn default_compression_extensions() -> Vec<String> {
+    ["js", "css", "html", "svg", "json"]
+        .iter()
+        .map(|ext| ext.to_string())
+        .collect()
+}
+
+impl CompressionAlgorithm {
+    fn extension(&self) -> &'static str {
+        match self {
+            CompressionAlgorithm::Gzip => "gz",
+            CompressionAlgorithm::Brotli => "br",
+        }
+    }
+
+    fn compress(&self, content: &[u8]) -> Result<Vec<u8>> {
+        match self {
+            CompressionAlgorithm::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+                encoder.write_all(content)?;
+                Ok(encoder.finish()?)
+            }
+            CompressionAlgorithm::Brotli => {
+                // quality 11 with a 4MB window, same as the brotli cli
+                let mut encoder = brotli::CompressorWriter::new(Vec::new(), 4096, 11, 22);
+                encoder.write_all(content)?;
+                Ok(encoder.into_inner())
+            }
+        }
+    }
+}
+
+struct CompressedAsset {
+    name: String,
+    chunk_id: String,
+    path: String,
+    hashname: String,
+    size: u64,
+}
+
+impl Compiler {
+    // write `<file>.gz` and `<file>.br` next to the files of this build, the
+    // emitted assets and the files written by the build_success hooks, e.g.
+    // the html files, the copied ones and the ones of publicDir and
+    // rootFiles, it runs after the hooks so that they are compressed with
+    // their final content, e.g. with the sri attributes. The other files in
+    // the output, e.g. the ones left with `clean: false`, are not touched
+    pub(super) fn compress_assets(&self, written_files: &[PathBuf]) -> Result<()> {
+        let config = &self.context.config;
+        let Some(compression) = &config.output.compression else {
+            return Ok(());
+        };
+        if config.output.skip_write {
+            return Ok(());
+        }
+
+        // the name and the chunk_id of the chunk files and the assets
+        let mut assets = HashMap::new();
+        let mut hashnames = vec![];
+        for asset in self.context.stats_info.get_assets() {
+            hashnames.push(asset.hashname.clone());
+            assets.insert(asset.hashname, (asset.name, asset.chunk_id));
+        }
+
+        let output_path = self.context.output_path();
+        hashnames.extend(written_files.iter().filter_map(|path| {
+            Some(
+                path.strip_prefix(&output_path)
+                    .ok()?
+                    .to_string_lossy()
+                    .replace('\\', "/"),
+            )
+        }));
+        let mut seen = HashSet::new();
+        let files = hashnames
+            .into_iter()
+            .filter(|hashname| should_compress(hashname, compression))
+            .filter(|hashname| seen.insert(hashname.clone()))
+            .filter(|hashname| output_path.join(hashname).is_file())
+            .collect::<Vec<_>>();
+        let compressed = files
+            .par_iter()
+            .map(|hashname| {
+                let content = fs::read(output_path.join(hashname))?;
+                if (content.len() as u64) < compression.threshold {
+                    return Ok(vec![]);
+                }
+                let (name, chunk_id) = assets
+                    .get(hashname)
+                    .cloned()
+                    .unwrap_or_else(|| (hashname.clone(), "".to_string()));
+                compression
+                    .algorithms
+                    .iter()
+                    .map(|algorithm| {
+                        let hashname = format!("{}.{}", hashname, algorithm.extension());
+                        let compressed = algorithm.compress(&content)?;
+                        fs::write(output_path.join(&hashname), &compressed)?;
+                        let path = config.output.path.join(&hashname);
+                        Ok(CompressedAsset {
+                            name: format!("{}.{}", name, algorithm.extension()),
+                            chunk_id: chunk_id.clone(),
+                            path: path.to_string_lossy().to_string(),
+                            hashname,
+                            size: compressed.len() as u64,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let compressed = compressed.into_iter().flatten().collect::<Vec<_>>();
+        debug!("compressed {} files", compressed.len());
+        for asset in compressed {
+            self.context.stats_info.add_assets(
+                asset.size,
+                asset.name,
+                asset.chunk_id,
+                asset.path,
+                asset.hashname,
+            );
+        }
+        Ok(())
+    }
+}
+
+// writes the sidecar files of a file which is changed after the build, e.g.
+// an html file of a differential build
+pub(crate) fn compress_file(path: &Path, compression: &CompressionConfig) -> Result<()> {
+    if !should_compress(&path.to_string_lossy(), compression) {
+        return Ok(());
+    }
+    let content = fs::read(path)?;
+    if (content.len() as u64) < compression.threshold {
+        return Ok(());
+    }
+    for algorithm in &compression.algorithms {
+        let mut sidecar = path.as_os_str().to_owned();
+        sidecar.push(format!(".{}", algorithm.extension()));
+        fs::write(sidecar, algorithm.compress(&content)?)?;
+    }
+    Ok(())
+}
+
+fn should_compress(hashname: &str, compression: &CompressionConfig) -> bool {
+    Path::new(hashname)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| compression.extensions.iter().any(|e| e == ext))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::utils::test_helper::setup_compiler;
+
+    #[test]
+    fn test_compress_assets() {
+        let compiler = setup_compiler("test/build/compression", false);
+        let output_path = &compiler.context.config.output.path;
+        // left by a previous build
+        fs::create_dir_all(output_path).unwrap();
+        fs::write(
+            output_path.join("stale.js"),
+            "console.log('stale');\n".repeat(1024),
+        )
+        .unwrap();
+        compiler.compile().unwrap();
+
+        assert!(output_path.join("index.js.gz").exists());
+        assert!(output_path.join("index.js.br").exists());
+        // written by the build_success hooks
+        assert!(output_path.join("messages.json.gz").exists());
+        // smaller than the threshold
+        assert!(!output_path.join("tiny.js.gz").exists());
+        // not in the extensions
+        assert!(!output_path.join("index.js.map.gz").exists());
+        // not written by this build
+        assert!(output_path.join("stale.js").exists());
+        assert!(!output_path.join("stale.js.gz").exists());
+
+        let hashnames = compiler
+            .context
+            .stats_info
+            .get_assets()
+            .into_iter()
+            .map(|asset| asset.hashname)
+            .collect::<Vec<_>>();
+        assert!(hashnames.contains(&"index.js.gz".to_string()));
+        assert!(hashnames.contains(&"index.js.br".to_string()));
+        assert!(hashnames.contains(&"messages.json.gz".to_string()));
+        assert!(!hashnames.contains(&"tiny.js.gz".to_string()));
+    }
+}
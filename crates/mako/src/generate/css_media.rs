@@ -0,0 +1,159 @@
+use swc_core::common::DUMMY_SP;
+use swc_core::css::ast::{AtRule, AtRulePrelude, Rule, Stylesheet};
+use swc_core::css::codegen::writer::basic::{BasicCssWriter, BasicCssWriterConfig};
+use swc_core::css::codegen::{CodeGenerator, CodegenConfig, Emit};
+
+// `screen and (max-width: 100px)` and `screen and (max-width:100px)` are the
+// same media
+fn normalize(media: &str) -> String {
+    media.split_whitespace().collect::<String>().to_lowercase()
+}
+
+fn media_of(rule: &Rule) -> Option<String> {
+    let Rule::AtRule(box AtRule {
+        prelude: Some(box AtRulePrelude::MediaPrelude(media)),
+        ..
+    }) = rule
+    else {
+        return None;
+    };
+    let mut code = String::new();
+    let writer = BasicCssWriter::new(&mut code, None, BasicCssWriterConfig::default());
+    let mut gen = CodeGenerator::new(writer, CodegenConfig { minify: true });
+    gen.emit(media).ok()?;
+    Some(normalize(&code))
+}
+
+/// Move the top level `@media` rules of the given media out of the
+/// stylesheet, one stylesheet per media in the given order, the rules of each
+/// keep their order. The media without rules are left out.
+pub(crate) fn split_css_media(
+    stylesheet: &mut Stylesheet,
+    media: &[String],
+) -> Vec<(String, Stylesheet)> {
+    let keys = media.iter().map(|m| normalize(m)).collect::<Vec<_>>();
+    let mut groups = media
+        .iter()
+        .map(|m| {
+            (
+                m.clone(),
+                Stylesheet {
+                    span: DUMMY_SP,
+                    rules: vec![],
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+    for rule in std::mem::take(&mut stylesheet.rules) {
+        match media_of(&rule).and_then(|m| keys.iter().position(|key| *key == m)) {
+            Some(index) => groups[index].1.rules.push(rule),
+            None => stylesheet.rules.push(rule),
+        }
+    }
+    groups.retain(|(_, stylesheet)| !stylesheet.rules.is_empty());
+    groups
+}
+
+// index.css + print -> index.print.css
+// index.css + screen and (max-width: 100px) -> index.screen-and-max-width-100px.css
+pub(crate) fn get_css_media_filename(css_filename: &str, media: &str) -> String {
+    let stem = css_filename.strip_suffix(".css").unwrap_or(css_filename);
+    let name = media
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase();
+    format!("{}.{}.css", stem, name)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{get_css_media_filename, split_css_media};
+    use crate::ast::tests::TestUtils;
+    use crate::utils::test_helper::setup_compiler;
+
+    fn split(code: &str, media: &[&str]) -> (String, Vec<(String, String)>) {
+        let mut test_utils = TestUtils::gen_css_ast(code.to_string(), true);
+        let media = media.iter().map(|m| m.to_string()).collect::<Vec<_>>();
+        let groups = split_css_media(&mut test_utils.ast.css_mut().ast, &media);
+        let groups = groups
+            .into_iter()
+            .map(|(media, stylesheet)| {
+                let mut test_utils = TestUtils::gen_css_ast(String::new(), true);
+                test_utils.ast.css_mut().ast = stylesheet;
+                (media, test_utils.css_ast_to_code())
+            })
+            .collect();
+        (test_utils.css_ast_to_code(), groups)
+    }
+
+    #[test]
+    fn test_split_css_media() {
+        let (code, groups) = split(
+            r#".a{color:red}
+@media print{.a{color:black}}
+@media (max-width: 100px){.a{color:blue}}
+@media print{.b{display:none}}
+.b{color:green}"#,
+            &["print"],
+        );
+        assert_eq!(
+            code,
+            ".a{color:red}@media(max-width:100px){.a{color:blue}}.b{color:green}"
+        );
+        assert_eq!(
+            groups,
+            vec![(
+                "print".to_string(),
+                "@media print{.a{color:black}}@media print{.b{display:none}}".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_split_css_media_normalized() {
+        let (code, groups) = split(
+            r#".a{color:red}@media screen and (max-width:100px){.a{color:blue}}"#,
+            &["print", "screen and (max-width: 100px)"],
+        );
+        assert_eq!(code, ".a{color:red}");
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].0, "screen and (max-width: 100px)");
+    }
+
+    #[test]
+    fn test_get_css_media_filename() {
+        assert_eq!(
+            get_css_media_filename("index.css", "print"),
+            "index.print.css"
+        );
+        assert_eq!(
+            get_css_media_filename("index.css", "screen and (max-width: 100px)"),
+            "index.screen-and-max-width-100px.css"
+        );
+    }
+
+    #[test]
+    fn test_split_css_media_build() {
+        let compiler = setup_compiler("test/build/split-css-media", false);
+        compiler.compile().unwrap();
+
+        let output_path = &compiler.context.config.output.path;
+        let css = fs::read_to_string(output_path.join("index.css")).unwrap();
+        let print_css = fs::read_to_string(output_path.join("index.print.css")).unwrap();
+        let html = fs::read_to_string(output_path.join("index.html")).unwrap();
+        assert!(!css.contains("@media print"));
+        assert!(print_css.contains("@media print"));
+        assert!(print_css.contains("black"));
+        let css_tag = html
+            .find(r#"<link rel="stylesheet" href="/index.css" />"#)
+            .unwrap();
+        let print_css_tag = html
+            .find(r#"<link rel="stylesheet" href="/index.print.css" media="print" />"#)
+            .unwrap();
+        assert!(css_tag < print_css_tag);
+    }
+}
@@ -3,7 +3,7 @@ use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::{anyhow, Result};
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 use nanoid::nanoid;
 use rayon::prelude::*;
 use swc_core::common::DUMMY_SP;
@@ -12,9 +12,11 @@ use swc_core::ecma::ast::{Expr, KeyValueProp, Prop, PropName, PropOrSpread, Str}
 use tracing::warn;
 
 use crate::compiler::{Compiler, Context};
-use crate::generate::chunk::{Chunk, ChunkType};
+use crate::generate::chunk::{Chunk, ChunkId, ChunkType};
 use crate::generate::chunk_pot::util::file_content_hash;
-use crate::generate::chunk_pot::{get_css_chunk_filename, ChunkPot, CHUNK_FILE_NAME_HASH_LENGTH};
+use crate::generate::chunk_pot::{
+    get_css_chunk_filename, render_runtime_js_chunk, ChunkPot, CHUNK_FILE_NAME_HASH_LENGTH,
+};
 use crate::generate::transform::transform_css_generate;
 use crate::module::{ModuleAst, ModuleId};
 use crate::utils::thread_pool;
@@ -34,6 +36,10 @@ pub struct ChunkFile {
     pub file_name: String,
     pub chunk_id: String,
     pub file_type: ChunkFileType,
+    /// Moved out of the content with `legalComments: "external"`
+    pub legal_comments: Vec<String>,
+    /// The media of the css split out of the entry css with `splitCSSMedia`
+    pub media: Option<String>,
 }
 
 impl ChunkFile {
@@ -125,7 +131,24 @@ impl Compiler {
             .flat_map(|e| e.0)
             .collect();
 
-        Ok([entry_chunk_files, normal_chunk_files].concat())
+        let mut chunk_files = [entry_chunk_files, normal_chunk_files].concat();
+        // after the hashes are settled, so that the hook gets the final names
+        chunk_files
+            .par_iter_mut()
+            .try_for_each(|chunk_file| -> Result<()> {
+                if let Some(source_map) = &chunk_file.source_map
+                    && let Some(source_map) = self.context.plugin_driver.transform_source_map(
+                        source_map,
+                        &chunk_file.source_map_disk_name(),
+                        &self.context,
+                    )?
+                {
+                    chunk_file.source_map = Some(source_map);
+                }
+                Ok(())
+            })?;
+
+        Ok(chunk_files)
     }
 
     fn generate_entry_chunk_files(
@@ -137,51 +160,19 @@ impl Compiler {
             .par_iter()
             .map(|chunk| {
                 let context = self.context.clone();
-                let module_graph = context.module_graph.read().unwrap();
-                let chunk_graph = self.context.chunk_graph.read().unwrap();
 
-                let (js_chunks_hash_placeholder, css_chunks_hash_placeholder) = chunk_graph
-                    .installable_descendants_chunk(&chunk.id)
-                    .iter()
-                    .fold(
-                        (ChunksHashPlaceholder::new(), ChunksHashPlaceholder::new()),
-                        |(mut acc_js, mut acc_css), descendant_chunk_id| {
-                            let descendant_chunk = chunk_graph.chunk(descendant_chunk_id).unwrap();
-                            // TODO: maybe we can split chunks to chunk pots before generate, because normal chunks will be
-                            // split here and fn generate_normal_chunk_files twice
-                            let chunk_pot =
-                                ChunkPot::from(descendant_chunk, &module_graph, &context);
-
-                            if self.context.config.hash {
-                                let placeholder = nanoid!(CHUNK_FILE_NAME_HASH_LENGTH);
-
-                                let js_filename = chunk_pot.js_name;
-
-                                if chunk_pot.stylesheet.is_some() {
-                                    let css_filename = get_css_chunk_filename(&js_filename);
-                                    acc_css.insert(
-                                        descendant_chunk_id.id.clone(),
-                                        hash_file_name(&css_filename, &placeholder),
-                                    );
-                                }
-
-                                acc_js.insert(
-                                    descendant_chunk_id.id.clone(),
-                                    hash_file_name(&js_filename, &placeholder),
-                                );
-                            } else {
-                                let js_filename = chunk_pot.js_name;
-
-                                if chunk_pot.stylesheet.is_some() {
-                                    let css_filename = get_css_chunk_filename(&js_filename);
-                                    acc_css.insert(descendant_chunk_id.id.clone(), css_filename);
-                                }
+                // the chunk maps are in the runtime chunk with `runtimeChunk`
+                let (js_chunks_hash_placeholder, css_chunks_hash_placeholder) = if chunk
+                    .chunk_type
+                    .runtime_chunk_id(context.config.runtime_chunk)
+                    .is_some()
+                {
+                    (ChunksHashPlaceholder::new(), ChunksHashPlaceholder::new())
+                } else {
+                    self.chunks_hash_placeholder(&chunk.id)
+                };
 
-                                acc_js.insert(descendant_chunk_id.id.clone(), js_filename);
-                            }
-                            (acc_js, acc_css)
-                        },
-                    );
+                let module_graph = context.module_graph.read().unwrap();
 
                 let chunk_files = {
                     let chunk_pot = ChunkPot::from(chunk, &module_graph, &context);
@@ -204,6 +195,7 @@ impl Compiler {
 
                 chunk_files
             })
+            .chain(self.generate_runtime_chunk_files(&chunks, hmr_hash))
             .collect();
 
         let (chunk_files, errors) = chunk_file_results.into_iter().fold(
@@ -228,6 +220,112 @@ impl Compiler {
         Ok(chunk_files)
     }
 
+    // the runtime chunks of `runtimeChunk`, with the chunk maps of all the
+    // entry chunks which use them
+    fn generate_runtime_chunk_files(
+        &self,
+        chunks: &[&Chunk],
+        hmr_hash: u64,
+    ) -> Vec<Result<(Vec<ChunkFile>, ChunksHashPlaceholder, ChunksHashPlaceholder)>> {
+        let mut entry_chunks_of_runtime: IndexMap<ChunkId, Vec<ChunkId>> = IndexMap::new();
+        for chunk in chunks {
+            if let Some(runtime_chunk_id) = chunk
+                .chunk_type
+                .runtime_chunk_id(self.context.config.runtime_chunk)
+            {
+                entry_chunks_of_runtime
+                    .entry(runtime_chunk_id)
+                    .or_default()
+                    .push(chunk.id.clone());
+            }
+        }
+
+        entry_chunks_of_runtime
+            .into_iter()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|(runtime_chunk_id, entry_chunk_ids)| {
+                let (js_chunks_hash_placeholder, css_chunks_hash_placeholder) =
+                    entry_chunk_ids.iter().fold(
+                        (ChunksHashPlaceholder::new(), ChunksHashPlaceholder::new()),
+                        |(mut acc_js, mut acc_css), entry_chunk_id| {
+                            let (js, css) = self.chunks_hash_placeholder(entry_chunk_id);
+                            acc_js.extend(js);
+                            acc_css.extend(css);
+                            (acc_js, acc_css)
+                        },
+                    );
+                let chunk_file = render_runtime_js_chunk(
+                    &runtime_chunk_id,
+                    &entry_chunk_ids,
+                    &js_chunks_hash_placeholder,
+                    &css_chunks_hash_placeholder,
+                    &self.context,
+                    hmr_hash,
+                )?;
+                Ok((
+                    vec![chunk_file],
+                    js_chunks_hash_placeholder,
+                    css_chunks_hash_placeholder,
+                ))
+            })
+            .collect()
+    }
+
+    // the file names of the chunks installable from the chunk, with the
+    // placeholders of the hashes which are replaced after the chunks are
+    // emitted
+    fn chunks_hash_placeholder(
+        &self,
+        chunk_id: &ChunkId,
+    ) -> (ChunksHashPlaceholder, ChunksHashPlaceholder) {
+        let context = &self.context;
+        let module_graph = context.module_graph.read().unwrap();
+        let chunk_graph = context.chunk_graph.read().unwrap();
+
+        chunk_graph
+            .installable_descendants_chunk(chunk_id)
+            .iter()
+            .fold(
+                (ChunksHashPlaceholder::new(), ChunksHashPlaceholder::new()),
+                |(mut acc_js, mut acc_css), descendant_chunk_id| {
+                    let descendant_chunk = chunk_graph.chunk(descendant_chunk_id).unwrap();
+                    // TODO: maybe we can split chunks to chunk pots before generate, because normal chunks will be
+                    // split here and fn generate_normal_chunk_files twice
+                    let chunk_pot = ChunkPot::from(descendant_chunk, &module_graph, context);
+
+                    if context.config.hash {
+                        let placeholder = nanoid!(CHUNK_FILE_NAME_HASH_LENGTH);
+
+                        let js_filename = chunk_pot.js_name;
+
+                        if chunk_pot.stylesheet.is_some() {
+                            let css_filename = get_css_chunk_filename(&js_filename);
+                            acc_css.insert(
+                                descendant_chunk_id.id.clone(),
+                                hash_file_name(&css_filename, &placeholder),
+                            );
+                        }
+
+                        acc_js.insert(
+                            descendant_chunk_id.id.clone(),
+                            hash_file_name(&js_filename, &placeholder),
+                        );
+                    } else {
+                        let js_filename = chunk_pot.js_name;
+
+                        if chunk_pot.stylesheet.is_some() {
+                            let css_filename = get_css_chunk_filename(&js_filename);
+                            acc_css.insert(descendant_chunk_id.id.clone(), css_filename);
+                        }
+
+                        acc_js.insert(descendant_chunk_id.id.clone(), js_filename);
+                    }
+                    (acc_js, acc_css)
+                },
+            )
+    }
+
     fn generate_normal_chunk_files(&self, chunks: Vec<&Chunk>) -> Result<Vec<ChunkFile>> {
         let chunk_file_results: Vec<_> = chunks
             .par_iter()
@@ -6,6 +6,7 @@ use tracing::debug;
 use crate::ast::file::parse_path;
 use crate::compiler::Compiler;
 use crate::dev::update::UpdateResult;
+use crate::features::rsc::Rsc;
 use crate::generate::chunk::{Chunk, ChunkId, ChunkType};
 use crate::generate::chunk_graph::ChunkGraph;
 use crate::module::{ModuleId, ResolveType};
@@ -29,7 +30,7 @@ impl Compiler {
             let mut entry_chunk_name = "index";
 
             for (key, value) in &self.context.config.entry {
-                // hmr entry id has query '?hmr'
+                // hmr entry id has query '?hmr' or '?reload'
                 if parse_path(&value.to_string_lossy()).unwrap().0
                     == parse_path(&entry.id).unwrap().0
                 {
@@ -50,7 +51,12 @@ impl Compiler {
                 [dynamic_dependencies.clone(), worker_dependencies.clone()]
                     .concat()
                     .into_iter()
-                    .map(|dep| (chunk.id.clone(), dep.generate(&self.context).into())),
+                    .map(|dep| {
+                        (
+                            chunk.id.clone(),
+                            dep.generate_chunk_id(&self.context).into(),
+                        )
+                    }),
             );
             chunk_graph.add_chunk(chunk);
 
@@ -107,7 +113,12 @@ impl Compiler {
                 [dynamic_dependencies.clone(), worker_dependencies.clone()]
                     .concat()
                     .into_iter()
-                    .map(|dep| (chunk.id.clone(), dep.generate(&self.context).into())),
+                    .map(|dep| {
+                        (
+                            chunk.id.clone(),
+                            dep.generate_chunk_id(&self.context).into(),
+                        )
+                    }),
             );
             chunk_graph.add_chunk(chunk);
 
@@ -147,7 +158,12 @@ impl Compiler {
                 [dynamic_dependencies.clone(), worker_dependencies.clone()]
                     .concat()
                     .into_iter()
-                    .map(|dep| (chunk.id.clone(), dep.generate(&self.context).into())),
+                    .map(|dep| {
+                        (
+                            chunk.id.clone(),
+                            dep.generate_chunk_id(&self.context).into(),
+                        )
+                    }),
             );
 
             chunk_graph.add_chunk(chunk);
@@ -195,7 +211,7 @@ impl Compiler {
             // remove chunk if it is the entry module of chunk
             for module_id in &update_result.removed {
                 let chunk_id = ChunkId {
-                    id: module_id.generate(&self.context),
+                    id: module_id.generate_chunk_id(&self.context),
                 };
 
                 if let Some(chunk) = chunk_graph.chunk(&chunk_id) {
@@ -386,11 +402,12 @@ impl Compiler {
         let mut dynamic_entries = vec![];
         let mut worker_entries = vec![];
 
-        let chunk_id = entry_module_id.generate(&self.context);
+        let chunk_id = entry_module_id.generate_chunk_id(&self.context);
         let mut chunk = Chunk::new(chunk_id.into(), chunk_type.clone());
         let mut visited_modules: Vec<&ModuleId> = vec![entry_module_id];
 
         let module_graph = self.context.module_graph.read().unwrap();
+        let client_boundaries = Rsc::client_boundaries(&self.context);
 
         visit_modules(vec![entry_module_id.clone()], None, |head| {
             let parent_index = visited_modules
@@ -416,6 +433,12 @@ impl Compiler {
                             chunk_graph,
                         ) =>
                     {
+                        // the client boundary is bundled for the static import
+                        // as well, and is the root of its own chunk for the
+                        // client reference manifest
+                        if client_boundaries.contains(dep_module_id) {
+                            dynamic_entries.push(dep_module_id.clone());
+                        }
                         next_module_ids.push(dep_module_id.clone());
                         // collect normal deps for current head
                         normal_deps.push(dep_module_id);
@@ -468,7 +491,7 @@ impl Compiler {
                                 // ref existing chunk
                                 Some(chunk) => chunk.id.clone(),
                                 // ref new chunk
-                                None => dep.generate(&self.context).into(),
+                                None => dep.generate_chunk_id(&self.context).into(),
                             },
                         )
                     }),
@@ -7,9 +7,11 @@ use swc_core::ecma::ast::{
 
 use crate::ast::js_ast::{JSAstGenerated, JsAst};
 use crate::compiler::Compiler;
+use crate::config::DevtoolConfig;
 use crate::generate::chunk::Chunk;
 use crate::generate::generate_chunks::modules_to_js_stmts;
 use crate::module::ModuleId;
+use crate::utils::base64_encode;
 
 impl Compiler {
     pub fn generate_hmr_chunk(
@@ -56,7 +58,31 @@ impl Compiler {
             }
         }
 
-        let JSAstGenerated { code, sourcemap } = js_ast.generate(self.context.clone()).unwrap();
+        let JSAstGenerated {
+            mut code,
+            mut sourcemap,
+        } = js_ast.generate(self.context.clone()).unwrap();
+        if !sourcemap.is_empty()
+            && let Some(transformed) = self.context.plugin_driver.transform_source_map(
+                sourcemap.as_bytes(),
+                &format!("{}.map", filename),
+                &self.context,
+            )?
+        {
+            sourcemap = String::from_utf8(transformed)?;
+            // the inline map is appended to the code by the generate
+            if matches!(
+                self.context.config.devtool,
+                Some(DevtoolConfig::InlineSourceMap)
+            ) && let Some(index) = code.rfind("\n//# sourceMappingURL=")
+            {
+                code.truncate(index);
+                code.push_str(&format!(
+                    "\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,{}",
+                    base64_encode(&sourcemap)
+                ));
+            }
+        }
         Ok((code, sourcemap))
     }
 }
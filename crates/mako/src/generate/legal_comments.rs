@@ -0,0 +1,389 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use delegate::delegate;
+use indexmap::IndexSet;
+use swc_core::base::sourcemap;
+use swc_core::common::comments::{Comment, CommentKind, Comments};
+use swc_core::common::{BytePos, Span};
+use swc_core::ecma::ast::Module as SwcModule;
+use swc_core::ecma::visit::{Visit, VisitWith};
+
+use crate::ast::comments::MakoComments;
+use crate::ast::sourcemap::RawSourceMap;
+use crate::compiler::Compiler;
+use crate::config::{LegalCommentsFile, LegalCommentsMode};
+use crate::generate::generate_chunks::ChunkFile;
+
+const COMBINED_FILE_NAME: &str = "LEGAL.txt";
+const LINKED_FILE_SUFFIX: &str = ".LICENSE.txt";
+
+// same as esbuild, `/*! ... */`, `//! ...` or the ones with `@license` or
+// `@preserve`
+pub(crate) fn is_legal_comment(comment: &Comment) -> bool {
+    comment.text.starts_with('!')
+        || comment.text.contains("@license")
+        || comment.text.contains("@preserve")
+}
+
+fn to_code(comment: &Comment) -> String {
+    match comment.kind {
+        CommentKind::Block => format!("/*{}*/", comment.text),
+        CommentKind::Line => format!("//{}", comment.text),
+    }
+}
+
+/// Only the legal comments, or only the other comments, are visible to the
+/// emitter
+pub(crate) struct LegalCommentsFilter<'a> {
+    pub comments: &'a MakoComments,
+    pub legal: bool,
+}
+
+impl LegalCommentsFilter<'_> {
+    fn filter(&self, comments: Option<Vec<Comment>>) -> Option<Vec<Comment>> {
+        comments
+            .map(|comments| {
+                comments
+                    .into_iter()
+                    .filter(|comment| is_legal_comment(comment) == self.legal)
+                    .collect::<Vec<_>>()
+            })
+            .filter(|comments| !comments.is_empty())
+    }
+}
+
+impl Comments for LegalCommentsFilter<'_> {
+    fn has_leading(&self, pos: BytePos) -> bool {
+        self.get_leading(pos).is_some()
+    }
+
+    fn take_leading(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        self.filter(self.comments.take_leading(pos))
+    }
+
+    fn get_leading(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        self.filter(self.comments.get_leading(pos))
+    }
+
+    fn has_trailing(&self, pos: BytePos) -> bool {
+        self.get_trailing(pos).is_some()
+    }
+
+    fn take_trailing(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        self.filter(self.comments.take_trailing(pos))
+    }
+
+    fn get_trailing(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        self.filter(self.comments.get_trailing(pos))
+    }
+
+    delegate! {
+        to self.comments {
+            fn add_leading(&self, pos: BytePos, cmt: Comment);
+            fn add_leading_comments(&self, pos: BytePos, comments: Vec<Comment>);
+            fn move_leading(&self, from: BytePos, to: BytePos);
+            fn add_trailing(&self, pos: BytePos, cmt: Comment);
+            fn add_trailing_comments(&self, pos: BytePos, comments: Vec<Comment>);
+            fn move_trailing(&self, from: BytePos, to: BytePos);
+            fn add_pure_comment(&self, pos: BytePos);
+        }
+    }
+}
+
+/// The legal comments attached to the nodes of the module, in the order they
+/// appear, the duplicated ones are kept once
+pub(crate) fn collect_legal_comments(ast: &SwcModule, comments: &MakoComments) -> Vec<String> {
+    let mut collector = LegalCommentsCollector {
+        comments,
+        visited: HashSet::new(),
+        legal_comments: IndexSet::new(),
+    };
+    ast.visit_with(&mut collector);
+    collector.legal_comments.into_iter().collect()
+}
+
+struct LegalCommentsCollector<'a> {
+    comments: &'a MakoComments,
+    visited: HashSet<BytePos>,
+    legal_comments: IndexSet<String>,
+}
+
+impl Visit for LegalCommentsCollector<'_> {
+    fn visit_span(&mut self, span: &Span) {
+        for pos in [span.lo, span.hi] {
+            if pos.is_dummy() || !self.visited.insert(pos) {
+                continue;
+            }
+            let leading = self.comments.get_leading(pos).unwrap_or_default();
+            let trailing = self.comments.get_trailing(pos).unwrap_or_default();
+            self.legal_comments.extend(
+                leading
+                    .iter()
+                    .chain(trailing.iter())
+                    .filter(|comment| is_legal_comment(comment))
+                    .map(to_code),
+            );
+        }
+    }
+}
+
+// `<chunk>.LEGAL.txt` or `<chunk>.LICENSE.txt` of the chunks with legal
+// comments, with the chunk id and the content
+fn chunk_legal_files(chunk_files: &[ChunkFile], suffix: &str) -> Vec<(String, String, String)> {
+    chunk_files
+        .iter()
+        .filter(|file| !file.legal_comments.is_empty())
+        .map(|file| {
+            (
+                format!("{}{}", file.disk_name(), suffix),
+                file.chunk_id.clone(),
+                file.legal_comments.join("\n"),
+            )
+        })
+        .collect()
+}
+
+// the banner takes one line, so the generated lines of the mappings are
+// shifted by one
+fn shift_source_map_lines(source_map: &[u8], lines: u32) -> Result<Vec<u8>> {
+    let mut raw_source_map = RawSourceMap::from(sourcemap::SourceMap::from_slice(source_map)?);
+    raw_source_map
+        .tokens
+        .iter_mut()
+        .for_each(|token| token.dst_line += lines);
+    let mut buf = vec![];
+    sourcemap::SourceMap::from(raw_source_map).to_writer(&mut buf)?;
+    Ok(buf)
+}
+
+impl Compiler {
+    // add the `/*! For license information please see <chunk>.LICENSE.txt */`
+    // banner of `legalComments: "linked"` to the chunks with legal comments,
+    // the file name is only known after the chunk is hashed
+    pub(super) fn link_legal_comments(&self, chunk_files: &mut [ChunkFile]) -> Result<()> {
+        if self.context.config.transform.legal_comments != Some(LegalCommentsMode::Linked) {
+            return Ok(());
+        }
+
+        for file in chunk_files
+            .iter_mut()
+            .filter(|file| !file.legal_comments.is_empty())
+        {
+            let disk_name = file.disk_name();
+            // relative to the chunk
+            let file_name = Path::new(&disk_name)
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or(disk_name);
+            let mut content = format!(
+                "/*! For license information please see {}{} */\n",
+                file_name, LINKED_FILE_SUFFIX
+            )
+            .into_bytes();
+            content.extend_from_slice(&file.content);
+            file.content = content;
+            if let Some(source_map) = &file.source_map {
+                file.source_map = Some(shift_source_map_lines(source_map, 1)?);
+            }
+        }
+        Ok(())
+    }
+
+    // write the legal comments of `legalComments: "external"` and
+    // `"linked"`, the chunk files only carry them in these modes
+    pub(super) fn write_legal_comments(&self, chunk_files: &[ChunkFile]) -> Result<()> {
+        let config = &self.context.config;
+        let files = match (
+            config.transform.legal_comments,
+            config.transform.legal_comments_file,
+        ) {
+            (Some(LegalCommentsMode::Linked), _) => {
+                chunk_legal_files(chunk_files, LINKED_FILE_SUFFIX)
+            }
+            (Some(LegalCommentsMode::External), LegalCommentsFile::Chunk) => {
+                chunk_legal_files(chunk_files, ".LEGAL.txt")
+            }
+            (Some(LegalCommentsMode::External), LegalCommentsFile::Combined) => {
+                let legal_comments = chunk_files
+                    .iter()
+                    .flat_map(|file| file.legal_comments.iter().cloned())
+                    .collect::<IndexSet<_>>();
+                if legal_comments.is_empty() {
+                    vec![]
+                } else {
+                    vec![(
+                        COMBINED_FILE_NAME.to_string(),
+                        "".to_string(),
+                        legal_comments.into_iter().collect::<Vec<_>>().join("\n"),
+                    )]
+                }
+            }
+            _ => return Ok(()),
+        };
+
+        for (name, chunk_id, content) in files {
+            let path = config.output.path.join(&name);
+            let content = format!("{}\n", content);
+            fs::write(self.context.output_path().join(&name), &content)?;
+            self.context.stats_info.add_assets(
+                content.len() as u64,
+                name.clone(),
+                chunk_id,
+                path.to_string_lossy().to_string(),
+                name,
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use swc_core::base::sourcemap::SourceMap;
+
+    use crate::compiler::Compiler;
+    use crate::utils::test_helper::{setup_compiler, PluginTestBuilder};
+
+    const INDEX: &str = r#"import { a } from "./a";
+/*! index v1.0.0 | MIT */
+console.log(a);
+// a normal comment
+console.log(2);
+"#;
+
+    const A: &str = r#"/**
+ * @license a v2.0.0
+ */
+const a = 1;
+export { a };
+"#;
+
+    fn build(name: &str, transform: &str) -> Compiler {
+        build_with_config(name, &format!(r#"{{ "transform": {} }}"#, transform))
+    }
+
+    fn build_with_config(name: &str, config: &str) -> Compiler {
+        let compiler = PluginTestBuilder::new(name)
+            .file("src/index.js", INDEX)
+            .file("src/a.js", A)
+            .config(config)
+            .build();
+        compiler.compile().unwrap();
+        compiler
+    }
+
+    fn read_output(compiler: &Compiler, name: &str) -> String {
+        fs::read_to_string(compiler.context.config.output.path.join(name)).unwrap()
+    }
+
+    #[test]
+    fn test_legal_comments_none() {
+        let compiler = build("legal-comments-none", r#"{ "legalComments": "none" }"#);
+        let output = read_output(&compiler, "index.js");
+
+        assert!(!output.contains("index v1.0.0"));
+        assert!(!output.contains("@license"));
+        assert!(output.contains("a normal comment"));
+    }
+
+    #[test]
+    fn test_legal_comments_eof() {
+        let compiler = build("legal-comments-eof", r#"{ "legalComments": "eof" }"#);
+        let output = read_output(&compiler, "index.js");
+        let code_end = output.find("console.log").unwrap();
+
+        assert!(output.rfind("/*! index v1.0.0 | MIT */").unwrap() > code_end);
+        assert!(output.rfind("@license a v2.0.0").unwrap() > code_end);
+        assert_eq!(output.matches("index v1.0.0").count(), 1);
+    }
+
+    #[test]
+    fn test_legal_comments_external() {
+        let compiler = build(
+            "legal-comments-external",
+            r#"{ "legalComments": "external" }"#,
+        );
+        let output = read_output(&compiler, "index.js");
+        let legal = read_output(&compiler, "index.js.LEGAL.txt");
+
+        assert!(!output.contains("index v1.0.0"));
+        assert!(!output.contains("@license"));
+        assert!(legal.contains("/*! index v1.0.0 | MIT */"));
+        assert!(legal.contains("@license a v2.0.0"));
+    }
+
+    #[test]
+    fn test_legal_comments_external_combined() {
+        let compiler = build(
+            "legal-comments-external-combined",
+            r#"{ "legalComments": "external", "legalCommentsFile": "combined" }"#,
+        );
+        let legal = read_output(&compiler, "LEGAL.txt");
+
+        assert!(legal.contains("/*! index v1.0.0 | MIT */"));
+        assert!(!compiler
+            .context
+            .config
+            .output
+            .path
+            .join("index.js.LEGAL.txt")
+            .exists());
+    }
+
+    #[test]
+    fn test_legal_comments_linked() {
+        let compiler = build_with_config(
+            "legal-comments-linked",
+            r#"{
+                "transform": { "legalComments": "linked" },
+                "hash": true,
+                "devtool": "source-map"
+            }"#,
+        );
+        let output_path = &compiler.context.config.output.path;
+        let chunk = fs::read_dir(output_path)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .find(|name| name.starts_with("index.") && name.ends_with(".js"))
+            .unwrap();
+        let output = read_output(&compiler, &chunk);
+        let license = read_output(&compiler, &format!("{}.LICENSE.txt", chunk));
+
+        assert!(output.starts_with(&format!(
+            "/*! For license information please see {}.LICENSE.txt */\n",
+            chunk
+        )));
+        assert!(!output.contains("index v1.0.0"));
+        assert!(license.contains("/*! index v1.0.0 | MIT */"));
+        assert!(license.contains("@license a v2.0.0"));
+
+        // the mappings are shifted by the banner line
+        let source_map = fs::read(output_path.join(format!("{}.map", chunk))).unwrap();
+        let source_map = SourceMap::from_slice(&source_map).unwrap();
+        let (line, col) = output
+            .lines()
+            .enumerate()
+            .find_map(|(line, code)| Some((line as u32, code.find("console.log(2)")? as u32)))
+            .unwrap();
+        let token = source_map.lookup_token(line, col).unwrap();
+        assert_eq!(token.get_dst_line(), line);
+        assert!(token.get_source().unwrap().ends_with("index.js"));
+        assert_eq!(token.get_src_line(), 4);
+    }
+
+    #[test]
+    fn test_legal_comments_inline_minified() {
+        let compiler = setup_compiler("test/build/legal-comments", false);
+        compiler.compile().unwrap();
+        let output = read_output(&compiler, "index.js");
+
+        assert!(output.contains("/*! index v1.0.0 | MIT */"));
+        assert!(output.contains("@license a v2.0.0"));
+        assert!(!output.contains("a normal comment"));
+    }
+}
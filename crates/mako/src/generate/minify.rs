@@ -1,12 +1,15 @@
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use swc_core::common::errors::HANDLER;
 use swc_core::common::GLOBALS;
 use swc_core::css::ast::Stylesheet;
 use swc_core::css::minifier;
 use swc_core::ecma::minifier::optimize;
-use swc_core::ecma::minifier::option::{ExtraOptions, MinifyOptions};
+use swc_core::ecma::minifier::option::{
+    CompressOptions, ExtraOptions, MangleOptions, ManglePropertiesOptions, MinifyOptions,
+    TopLevelOptions,
+};
 use swc_core::ecma::transforms::base::fixer::fixer;
 use swc_core::ecma::transforms::base::helpers::{Helpers, HELPERS};
 use swc_core::ecma::transforms::base::resolver;
@@ -15,6 +18,8 @@ use swc_error_reporters::handler::try_with_handler;
 
 use crate::ast::js_ast::JsAst;
 use crate::compiler::Context;
+use crate::config::JsMinifyConfig;
+use crate::features::rsc::Rsc;
 
 pub fn minify_js(ast: &mut JsAst, context: &Arc<Context>) -> Result<()> {
     crate::mako_profile_function!();
@@ -47,11 +52,7 @@ pub fn minify_js(ast: &mut JsAst, context: &Arc<Context>) -> Result<()> {
                                     .get_swc_comments(),
                             ),
                             None,
-                            &MinifyOptions {
-                                compress: Some(Default::default()),
-                                mangle: Some(Default::default()),
-                                ..Default::default()
-                            },
+                            &minify_options(context)?,
                             &ExtraOptions {
                                 unresolved_mark,
                                 top_level_mark,
@@ -78,6 +79,58 @@ pub fn minify_js(ast: &mut JsAst, context: &Arc<Context>) -> Result<()> {
     })
 }
 
+// the chunks are minified one by one, so the same property would be mangled
+// to different names in each of them
+pub fn check_mangle_props(context: &Arc<Context>) -> Result<()> {
+    if !context.config.minify || context.config.js_minify.mangle_props.is_none() {
+        return Ok(());
+    }
+    let chunk_count = context.chunk_graph.read().unwrap().get_chunks().len();
+    if chunk_count > 1 {
+        return Err(anyhow!(
+            "jsMinify.mangleProps only works with a single chunk, since the properties are mangled in each chunk separately, but {} chunks are found",
+            chunk_count
+        ));
+    }
+    Ok(())
+}
+
+fn minify_options(context: &Arc<Context>) -> Result<MinifyOptions> {
+    let JsMinifyConfig {
+        passes,
+        ecma,
+        toplevel,
+        mangle_props,
+    } = &context.config.js_minify;
+    // CachedRegex of swc is only constructable by deserializing
+    let props = mangle_props
+        .as_ref()
+        .map(|mangle_props| {
+            serde_json::from_value::<ManglePropertiesOptions>(serde_json::json!({
+                "regex": mangle_props.regex,
+                "reserved": mangle_props.reserved,
+            }))
+        })
+        .transpose()?;
+    Ok(MinifyOptions {
+        compress: Some(CompressOptions {
+            // non-standard directives like "use client" are
+            // dropped by default
+            directives: !Rsc::should_preserve_directives(context),
+            passes: *passes,
+            ecma: ecma.unwrap_or_default(),
+            top_level: toplevel.then_some(TopLevelOptions { functions: true }),
+            ..Default::default()
+        }),
+        mangle: Some(MangleOptions {
+            top_level: toplevel.then_some(true),
+            props,
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+}
+
 pub fn minify_css(stylesheet: &mut Stylesheet, context: &Arc<Context>) -> Result<()> {
     crate::mako_profile_function!();
     GLOBALS.set(&context.meta.css.globals, || {
@@ -91,3 +144,95 @@ pub fn minify_css(stylesheet: &mut Stylesheet, context: &Arc<Context>) -> Result
         })
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::minify_js;
+    use crate::ast::tests::{TestUtils, TestUtilsOpts};
+    use crate::compiler::{Compiler, Context};
+    use crate::config::{Config, JsMinifyConfig, ManglePropsConfig};
+
+    #[test]
+    fn test_multiple_passes() {
+        let code = r#"
+function a() { return { value: 1 }; }
+function b() { return a(); }
+function c() { return b(); }
+function d() { var x = c(); var y = x; return y.value; }
+console.log(d());
+        "#;
+        let one_pass = run(
+            code,
+            JsMinifyConfig {
+                passes: 1,
+                ecma: None,
+                toplevel: true,
+                mangle_props: None,
+            },
+        );
+        let three_passes = run(
+            code,
+            JsMinifyConfig {
+                passes: 3,
+                ecma: None,
+                toplevel: true,
+                mangle_props: None,
+            },
+        );
+        assert!(three_passes.len() < one_pass.len());
+    }
+
+    #[test]
+    fn test_mangle_props_reserved() {
+        let code = r#"
+var api = { _internalValue: 1, _publicValue: 2, name: 3 };
+console.log(api._internalValue, api._publicValue, api.name);
+        "#;
+        let code = run(
+            code,
+            JsMinifyConfig {
+                passes: 0,
+                ecma: None,
+                toplevel: false,
+                mangle_props: Some(ManglePropsConfig {
+                    regex: "^_".to_string(),
+                    reserved: vec!["_publicValue".to_string()],
+                }),
+            },
+        );
+        assert!(!code.contains("_internalValue"));
+        assert!(code.contains("_publicValue"));
+        assert!(code.contains("name"));
+    }
+
+    #[test]
+    fn test_mangle_props_multiple_chunks() {
+        // without the overrides of setup_compiler, which disable minify
+        let root = std::env::current_dir()
+            .unwrap()
+            .join("test/build/mangle-props-chunks");
+        let config = Config::new(&root, None, None).unwrap();
+        let compiler = Compiler::new(config, root, Default::default(), None).unwrap();
+        let err = compiler.compile().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("jsMinify.mangleProps only works with a single chunk"));
+    }
+
+    fn run(code: &str, js_minify: JsMinifyConfig) -> String {
+        let mut context = Context::default();
+        context.config.devtool = None;
+        context.config.js_minify = js_minify;
+        let mut test_utils = TestUtils::with_context(
+            TestUtilsOpts {
+                file: Some("test.js".to_string()),
+                content: Some(code.to_string()),
+            },
+            Arc::new(context),
+        );
+        minify_js(test_utils.ast.js_mut(), &test_utils.context).unwrap();
+        test_utils.js_ast_to_code()
+    }
+}
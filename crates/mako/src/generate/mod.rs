@@ -2,15 +2,18 @@ pub(crate) mod analyze;
 pub(crate) mod chunk;
 pub(crate) mod chunk_graph;
 pub(crate) mod chunk_pot;
+pub(crate) mod compression;
+pub(crate) mod css_media;
 pub(crate) mod generate_chunks;
 pub(crate) mod group_chunk;
 pub(crate) mod hmr;
+pub(crate) mod legal_comments;
 pub(crate) mod minify;
 pub(crate) mod optimize_chunk;
 pub(crate) mod runtime;
 pub(crate) mod swc_helpers;
 pub(crate) mod transform;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::ops::DerefMut;
 use std::path::PathBuf;
@@ -22,17 +25,18 @@ use anyhow::{anyhow, Result};
 use indexmap::IndexSet;
 use rayon::prelude::*;
 use serde::Serialize;
-use tracing::debug;
+use tracing::{debug, warn};
 
 use crate::compiler::{Compiler, Context};
 use crate::config::{DevtoolConfig, OutputMode, TreeShakingStrategy};
 use crate::dev::update::UpdateResult;
+use crate::features::rsc::Rsc;
 use crate::generate::generate_chunks::{ChunkFile, ChunkFileType};
 use crate::module::{Dependency, ModuleId};
 use crate::plugins::bundless_compiler::BundlessCompiler;
 use crate::stats::StatsJsonMap;
 use crate::utils::base64_encode;
-use crate::visitors::async_module::mark_async;
+use crate::visitors::async_module::{find_required_async_modules, mark_async};
 
 #[derive(Clone)]
 pub struct EmitFile {
@@ -44,8 +48,8 @@ pub struct EmitFile {
 
 #[derive(Serialize)]
 struct ChunksUrlMap {
-    js: HashMap<String, String>,
-    css: HashMap<String, String>,
+    js: BTreeMap<String, String>,
+    css: BTreeMap<String, String>,
 }
 
 impl Compiler {
@@ -70,7 +74,16 @@ impl Compiler {
             drop(module_graph);
             module_ids
         };
-        mark_async(&module_ids, &self.context)
+        let async_deps_by_module_id = mark_async(&module_ids, &self.context);
+        for (module_id, async_module_id) in find_required_async_modules(&module_ids, &self.context)
+        {
+            warn!(
+                "{} requires the async module {}, a Promise of the exports is returned, use import instead",
+                module_id.id,
+                async_module_id.id
+            );
+        }
+        async_deps_by_module_id
     }
 
     pub fn generate(&self) -> Result<StatsJsonMap> {
@@ -94,6 +107,9 @@ impl Compiler {
 
         // Disable tree shaking in watch mode temporarily
         // ref: https://github.com/umijs/mako/issues/396
+        // the shaking rewrites the asts in place, so an export removed here
+        // can't come back when a consumer starts to use it after an update,
+        // updates and emit_dev_chunks work on the unshaken module graph
         if !self.context.args.watch {
             match self.context.config._tree_shaking {
                 Some(TreeShakingStrategy::Basic) => {
@@ -142,6 +158,7 @@ impl Compiler {
                 &self.context,
             )?;
         }
+        minify::check_mangle_props(&self.context)?;
 
         // 为啥单独提前 transform modules？
         // 因为放 chunks 的循环里，一个 module 可能存在于多个 chunk 里，可能会被编译多遍
@@ -152,8 +169,9 @@ impl Compiler {
 
         // ensure output dir exists
         let config = &self.context.config;
-        if !config.output.path.exists() {
-            fs::create_dir_all(&config.output.path)?;
+        let output_path = self.context.output_path();
+        if !output_path.exists() {
+            fs::create_dir_all(&output_path)?;
         }
 
         let full_hash = self.full_hash();
@@ -163,22 +181,19 @@ impl Compiler {
         if config.emit_assets {
             let t_write_assets = Instant::now();
             debug!("write assets");
-            {
-                let assets_info = &(*self.context.assets_info.lock().unwrap());
-                for (k, v) in assets_info {
-                    let asset_path = &self.context.root.join(k);
-                    let asset_output_path = &config.output.path.join(v);
-                    if asset_path.exists() {
-                        fs::copy(asset_path, asset_output_path)?;
-                    } else {
-                        return Err(anyhow!("asset not found: {}", asset_path.display()));
-                    }
-                }
-            }
+            self.write_assets()?;
             let t_write_assets = t_write_assets.elapsed();
             debug!("  - write assets: {}ms", t_write_assets.as_millis());
         }
 
+        if config
+            .rsc_client
+            .as_ref()
+            .is_some_and(|rsc_client| rsc_client.client_reference_manifest)
+        {
+            Rsc::write_client_reference_manifest(&self.context)?;
+        }
+
         // generate stats
         let stats = self.create_stats_info();
 
@@ -187,13 +202,22 @@ impl Compiler {
             .plugin_driver
             .build_success(&stats, &self.context)?;
 
+        // the compressed files are added to the stats
+        let written_files = self.context.take_written_files();
+        let stats = if config.output.compression.is_some() {
+            self.compress_assets(&written_files)?;
+            self.create_stats_info()
+        } else {
+            stats
+        };
+
         // print stats
         if !self.context.args.watch {
             self.print_stats();
         }
 
         if self.context.config.analyze.is_some() {
-            Analyze::write_analyze(&stats, &self.context.config.output.path)?;
+            Analyze::write_analyze(&stats, &output_path)?;
         }
 
         debug!("generate done in {}ms", t_generate.elapsed().as_millis());
@@ -213,11 +237,99 @@ impl Compiler {
         Ok(stats)
     }
 
+    // regenerate all the chunks and write them to disk in watch-build mode,
+    // tree shaking is skipped and the modules are already transformed when
+    // updating, so it's the second half of generate
+    pub fn emit_watch_build_chunks(&self) -> Result<StatsJsonMap> {
+        crate::mako_profile_function!("emit_watch_build_chunks");
+        debug!("generate(watch-build)");
+        let t_generate = Instant::now();
+
+        if self.context.config.output.mode == OutputMode::Bundless {
+            return self.generate_bundless();
+        }
+
+        self.group_chunk();
+        self.context
+            .plugin_driver
+            .before_optimize_chunk(&self.context)?;
+        self.optimize_chunk();
+        {
+            let mut module_graph = self.context.module_graph.write().unwrap();
+            let mut chunk_graph = self.context.chunk_graph.write().unwrap();
+
+            self.context.plugin_driver.optimize_chunk(
+                &mut chunk_graph,
+                &mut module_graph,
+                &self.context,
+            )?;
+        }
+
+        let config = &self.context.config;
+        if !config.output.path.exists() {
+            fs::create_dir_all(&config.output.path)?;
+        }
+
+        let full_hash = self.full_hash();
+        self.write_chunk_files(full_hash)?;
+
+        if config.emit_assets {
+            self.write_assets()?;
+        }
+
+        if config
+            .rsc_client
+            .as_ref()
+            .is_some_and(|rsc_client| rsc_client.client_reference_manifest)
+        {
+            Rsc::write_client_reference_manifest(&self.context)?;
+        }
+
+        let stats = self.create_stats_info();
+
+        self.context
+            .plugin_driver
+            .build_success(&stats, &self.context)?;
+
+        let written_files = self.context.take_written_files();
+        let stats = if config.output.compression.is_some() {
+            self.compress_assets(&written_files)?;
+            self.create_stats_info()
+        } else {
+            stats
+        };
+
+        debug!(
+            "generate(watch-build) done in {}ms",
+            t_generate.elapsed().as_millis()
+        );
+
+        Ok(stats)
+    }
+
+    fn write_assets(&self) -> Result<()> {
+        let assets_info = &(*self.context.assets_info.lock().unwrap());
+        for (k, v) in assets_info {
+            let asset_path = &self.context.root.join(k);
+            let asset_output_path = &self.context.output_path().join(v);
+            if asset_path.exists() {
+                fs::copy(asset_path, asset_output_path)?;
+            } else {
+                return Err(anyhow!("asset not found: {}", asset_path.display()));
+            }
+        }
+        Ok(())
+    }
+
     fn write_chunk_files(&self, full_hash: u64) -> Result<(Duration, Duration)> {
         // generate chunks
         let t_generate_chunks = Instant::now();
         debug!("generate chunks");
-        let chunk_files = self.generate_chunk_files(full_hash)?;
+        let mut chunk_files = self.generate_chunk_files(full_hash)?;
+        // the license files are not written in watch mode
+        if !self.context.args.watch {
+            self.link_legal_comments(&mut chunk_files)?;
+        }
         self.context
             .plugin_driver
             .after_generate_chunk_files(&chunk_files, &self.context)?;
@@ -227,7 +339,9 @@ impl Compiler {
         let t_ast_to_code_and_write = if self.context.args.watch {
             self.generate_chunk_mem_file(&chunk_files)?
         } else {
-            self.generate_chunk_disk_file(&chunk_files)?
+            let t_ast_to_code_and_write = self.generate_chunk_disk_file(&chunk_files)?;
+            self.write_legal_comments(&chunk_files)?;
+            t_ast_to_code_and_write
         };
 
         Ok((t_generate_chunks, t_ast_to_code_and_write))
@@ -295,8 +409,8 @@ impl Compiler {
 
         if config.hmr.is_some() {
             let mut chunk_id_url_map = ChunksUrlMap {
-                js: HashMap::new(),
-                css: HashMap::new(),
+                js: BTreeMap::new(),
+                css: BTreeMap::new(),
             };
 
             chunk_files.iter().for_each(|c| match c.file_type {
@@ -312,7 +426,7 @@ impl Compiler {
                 }
             });
 
-            self.write_to_dist(
+            self.write_hot_update(
                 format!("{}.hot-update-url-map.json", last_hmr_hash),
                 serde_json::to_string(&chunk_id_url_map).unwrap(),
             );
@@ -441,10 +555,11 @@ impl Compiler {
                 (chunk_names, modified_chunks)
             };
 
-            let removed_chunks: Vec<String> = last_chunk_names
+            let mut removed_chunks: Vec<String> = last_chunk_names
                 .difference(&current_chunks)
                 .cloned()
                 .collect();
+            removed_chunks.sort();
 
             let t_generate_hmr_chunk = Instant::now();
             let cg = self.context.chunk_graph.read().unwrap();
@@ -461,13 +576,13 @@ impl Compiler {
                     let (code, sourcemap) =
                         self.generate_hmr_chunk(chunk, &filename, &merged_ids, current_hmr_hash)?;
                     // TODO the final format should be {name}.{full_hash}.hot-update.{ext}
-                    self.write_to_dist(&filename, code);
-                    self.write_to_dist(format!("{}.map", &filename), sourcemap);
+                    self.write_hot_update(&filename, code);
+                    self.write_hot_update(format!("{}.map", &filename), sourcemap);
                 }
             }
             let t_generate_hmr_chunk = t_generate_hmr_chunk.elapsed();
 
-            self.write_to_dist(
+            self.write_hot_update(
                 format!("{}.hot-update.json", last_hmr_hash),
                 serde_json::to_string(&HotUpdateManifest {
                     removed_chunks,
@@ -502,9 +617,28 @@ impl Compiler {
         filename: P,
         content: C,
     ) {
-        let to = self.context.config.output.path.join(filename);
+        let to = self.context.output_path().join(filename);
         std::fs::write(to, content).unwrap();
     }
+
+    // hot update files go to hmr.hotUpdateDir if configured
+    fn write_hot_update<P: AsRef<std::path::Path>, C: AsRef<[u8]>>(&self, filename: P, content: C) {
+        match self
+            .context
+            .config
+            .hmr
+            .as_ref()
+            .and_then(|hmr| hmr.hot_update_dir.as_ref())
+        {
+            Some(hot_update_dir) => {
+                if !hot_update_dir.exists() {
+                    fs::create_dir_all(hot_update_dir).unwrap();
+                }
+                std::fs::write(hot_update_dir.join(filename), content).unwrap();
+            }
+            None => self.write_to_dist(filename, content),
+        }
+    }
 }
 
 fn write_dev_chunk_file(context: &Arc<Context>, chunk: &ChunkFile) -> Result<()> {
@@ -556,7 +690,16 @@ fn emit_chunk_file(context: &Arc<Context>, chunk_file: &ChunkFile) {
 
     let dist_name = chunk_file.disk_name();
 
-    let to: PathBuf = context.config.output.path.join(dist_name.as_str());
+    let output_path = context.output_path();
+    let to: PathBuf = output_path.join(dist_name.as_str());
+    // the path in the stats is where the file ends up
+    let path = context
+        .config
+        .output
+        .path
+        .join(dist_name.as_str())
+        .to_string_lossy()
+        .to_string();
     let stats_info = &context.stats_info;
 
     match context.config.devtool {
@@ -570,15 +713,11 @@ fn emit_chunk_file(context: &Arc<Context>, chunk_file: &ChunkFile) {
                     size,
                     chunk_file.source_map_name(),
                     chunk_file.chunk_id.clone(),
-                    to.to_string_lossy().to_string(),
+                    path.clone(),
                     chunk_file.source_map_disk_name(),
                 );
                 fs::write(
-                    context
-                        .config
-                        .output
-                        .path
-                        .join(chunk_file.source_map_disk_name()),
+                    output_path.join(chunk_file.source_map_disk_name()),
                     source_map,
                 )
                 .unwrap();
@@ -605,7 +744,7 @@ fn emit_chunk_file(context: &Arc<Context>, chunk_file: &ChunkFile) {
                 size,
                 chunk_file.file_name.clone(),
                 chunk_file.chunk_id.clone(),
-                to.to_string_lossy().to_string(),
+                path,
                 dist_name.clone(),
             );
             fs::write(to, &code).unwrap();
@@ -629,7 +768,7 @@ fn emit_chunk_file(context: &Arc<Context>, chunk_file: &ChunkFile) {
                 size,
                 chunk_file.file_name.clone(),
                 chunk_file.chunk_id.clone(),
-                to.to_string_lossy().to_string(),
+                path,
                 dist_name.clone(),
             );
             fs::write(to, code).unwrap();
@@ -639,7 +778,7 @@ fn emit_chunk_file(context: &Arc<Context>, chunk_file: &ChunkFile) {
                 chunk_file.content.len() as u64,
                 chunk_file.file_name.clone(),
                 chunk_file.chunk_id.clone(),
-                to.to_string_lossy().to_string(),
+                path,
                 dist_name,
             );
 
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::string::String;
 
 use base64::engine::general_purpose;
@@ -66,7 +66,10 @@ impl Compiler {
         debug!("optimize hot update chunk");
 
         // skip if code splitting disabled or group result is invalid
-        if self.context.config.code_splitting.is_none() || group_result.is_none() {
+        if (self.context.config.code_splitting.is_none()
+            && self.context.config.chunk_groups.is_empty())
+            || group_result.is_none()
+        {
             return;
         }
 
@@ -178,7 +181,8 @@ impl Compiler {
                 }
 
                 // check min shared count of chunks
-                if optimize_info.group_options.min_chunks > 1
+                if !optimize_info.group_options.enforce
+                    && optimize_info.group_options.min_chunks > 1
                     && chunks
                         .iter()
                         .filter(|chunk| {
@@ -218,6 +222,7 @@ impl Compiler {
                     chunk_type: ChunkType::Sync,
                     content: None,
                     source_map: None,
+                    name_hash: None,
                 };
 
                 (
@@ -229,6 +234,9 @@ impl Compiler {
 
         // drop optimize infos if chunk size is less than min_size
         optimize_chunks_infos.retain(|info| {
+            if info.group_options.enforce {
+                return !info.module_to_chunks.is_empty();
+            }
             *chunk_size_map.get(&info.group_options.name).unwrap() >= info.group_options.min_size
         });
 
@@ -236,6 +244,9 @@ impl Compiler {
         let mut extra_optimize_infos = vec![];
         let module_graph = self.context.module_graph.read().unwrap();
         for info in &mut *optimize_chunks_infos {
+            if info.group_options.enforce {
+                continue;
+            }
             let mut split_chunk_count = 0;
             let mut chunk_size = *chunk_size_map.get(&info.group_options.name).unwrap();
 
@@ -332,8 +343,8 @@ impl Compiler {
             if let Some(name_suffix) = &info.group_options.name_suffix {
                 match name_suffix {
                     OptimizeChunkNameSuffixStrategy::PackageName => {
-                        let mut module_to_package_map: HashMap<String, Vec<ModuleId>> =
-                            HashMap::new();
+                        let mut module_to_package_map: BTreeMap<String, Vec<ModuleId>> =
+                            BTreeMap::new();
                         info.module_to_chunks.keys().for_each(|module_id| {
                             if let Some(package_name) = self.get_package_name(module_id) {
                                 let package_entry =
@@ -369,8 +380,8 @@ impl Compiler {
                             });
                     }
                     OptimizeChunkNameSuffixStrategy::DependentsHash => {
-                        let mut module_to_dependents_md5_map: HashMap<String, Vec<ModuleId>> =
-                            HashMap::new();
+                        let mut module_to_dependents_md5_map: BTreeMap<String, Vec<ModuleId>> =
+                            BTreeMap::new();
                         info.module_to_chunks
                             .iter()
                             .for_each(|(module_id, dependents)| {
@@ -419,7 +430,7 @@ impl Compiler {
     }
 
     fn apply_optimize_infos(&self, optimize_chunks_infos: &Vec<OptimizeChunksInfo>) {
-        let mut edges_map: HashMap<ModuleId, IndexSet<ModuleId>> = HashMap::new();
+        let mut edges_map: IndexMap<ModuleId, IndexSet<ModuleId>> = IndexMap::new();
         let mut chunk_graph = self.context.chunk_graph.write().unwrap();
 
         for info in optimize_chunks_infos {
@@ -443,6 +454,7 @@ impl Compiler {
                 chunk_type: info_chunk_type,
                 content: None,
                 source_map: None,
+                name_hash: None,
             };
 
             if chunk_graph.has_chunk(&info_chunk_id) {
@@ -478,7 +490,7 @@ impl Compiler {
     }
 
     fn apply_hot_update_optimize_infos(&self, optimize_chunks_infos: &Vec<OptimizeChunksInfo>) {
-        let mut edges = HashMap::new();
+        let mut edges = IndexMap::new();
         let mut chunk_graph = self.context.chunk_graph.write().unwrap();
         for info in optimize_chunks_infos {
             // update group chunk
@@ -560,6 +572,30 @@ impl Compiler {
     }
 
     fn get_optimize_chunk_options(&self) -> Option<CodeSplittingAdvancedOptions> {
+        let chunk_groups = &self.context.config.chunk_groups;
+        let options = self.get_code_splitting_options();
+        if chunk_groups.is_empty() {
+            return options;
+        }
+        // chunkGroups are applied with or without code splitting
+        let mut options = options.unwrap_or_default();
+        let mut groups = chunk_groups
+            .iter()
+            .map(|chunk_group| OptimizeChunkGroup {
+                name: chunk_group.name.clone(),
+                allow_chunks: OptimizeAllowChunks::All,
+                test: chunk_group.test.clone(),
+                priority: chunk_group.priority,
+                enforce: true,
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+        groups.append(&mut options.groups);
+        options.groups = groups;
+        Some(options)
+    }
+
+    fn get_code_splitting_options(&self) -> Option<CodeSplittingAdvancedOptions> {
         match &self.context.config.code_splitting {
             Some(CodeSplitting {
                 strategy: CodeSplittingStrategy::Auto,
@@ -7,8 +7,12 @@ pub struct AppRuntimeTemplate {
     pub has_hmr: bool,
     pub umd: Option<String>,
     pub cjs: bool,
+    pub system: bool,
     pub pkg_name: Option<String>,
     pub chunk_loading_global: String,
     pub is_browser: bool,
     pub concatenate_enabled: bool,
+    pub circular_warning: bool,
+    // the runtime of `runtimeChunk`, the entries are executed when pushed
+    pub runtime_chunk: bool,
 }
@@ -3,12 +3,13 @@ use std::sync::mpsc::channel;
 use std::sync::Arc;
 use std::time::Instant;
 
-use anyhow::{Error, Result};
+use anyhow::{anyhow, Error, Result};
 use regex::Regex;
 use swc_core::common::errors::HANDLER;
 use swc_core::common::GLOBALS;
 use swc_core::css::ast;
 use swc_core::css::visit::VisitMutWith as CSSVisitMutWith;
+use swc_core::ecma::ast::{Expr, ExprStmt, Lit, Module as SwcModule, ModuleItem, Stmt};
 use swc_core::ecma::transforms::base::fixer::fixer;
 use swc_core::ecma::transforms::base::helpers::{inject_helpers, Helpers, HELPERS};
 use swc_core::ecma::transforms::base::hygiene;
@@ -21,6 +22,7 @@ use tracing::debug;
 
 use crate::ast::js_ast::JsAst;
 use crate::compiler::{Compiler, Context};
+use crate::features::rsc::Rsc;
 use crate::module::{Dependency, ModuleAst, ModuleId, ModuleType, ResolveType};
 use crate::utils::thread_pool;
 use crate::visitors::async_module::{mark_async, AsyncModule};
@@ -31,6 +33,7 @@ use crate::visitors::dynamic_import::DynamicImport;
 use crate::visitors::mako_require::MakoRequire;
 use crate::visitors::meta_url_replacer::MetaUrlReplacer;
 use crate::visitors::optimize_define_utils::OptimizeDefineUtils;
+use crate::visitors::react::CleanSpan;
 
 impl Compiler {
     pub fn transform_all(&self, async_deps_map: HashMap<ModuleId, Vec<Dependency>>) -> Result<()> {
@@ -89,7 +92,7 @@ pub fn transform_modules_in_thread(
                         dep.source.clone(),
                         (
                             if dep.resolve_type == ResolveType::Worker {
-                                let chunk_id = id.generate(&context);
+                                let chunk_id = id.generate_chunk_id(&context);
                                 let chunk_graph = context.chunk_graph.read().unwrap();
                                 chunk_graph.chunk(&chunk_id.into()).unwrap().filename()
                             } else {
@@ -190,6 +193,12 @@ pub fn transform_js_generate(transform_js_param: TransformJsParam) -> Result<()>
                         let unresolved_mark = ast.unresolved_mark;
                         let top_level_mark = ast.top_level_mark;
 
+                        let directives = if Rsc::should_preserve_directives(context) {
+                            Rsc::collect_directives(&ast.ast)
+                        } else {
+                            vec![]
+                        };
+
                         let import_interop = ImportInterop::Swc;
                         ast.ast
                             .visit_mut_with(&mut import_analyzer(import_interop, true));
@@ -241,6 +250,8 @@ pub fn transform_js_generate(transform_js_param: TransformJsParam) -> Result<()>
                         };
                         ast.ast.visit_mut_with(&mut mako_require);
 
+                        Rsc::restore_directives(&mut ast.ast, &directives);
+
                         ast.ast
                             .visit_mut_with(&mut hygiene_with_config(hygiene::Config {
                                 top_level_mark,
@@ -251,6 +262,8 @@ pub fn transform_js_generate(transform_js_param: TransformJsParam) -> Result<()>
                         let swc_comments = origin_comments.get_swc_comments();
                         ast.ast.visit_mut_with(&mut fixer(Some(swc_comments)));
 
+                        insert_module_prelude(module_id, &mut ast.ast, context)?;
+
                         Ok(())
                     })
                 })
@@ -259,6 +272,52 @@ pub fn transform_js_generate(transform_js_param: TransformJsParam) -> Result<()>
     })
 }
 
+// after the directives, it's inserted as is after the hygiene, and the spans
+// are cleaned so that the source map of the module is not affected
+fn insert_module_prelude(
+    module_id: &ModuleId,
+    ast: &mut SwcModule,
+    context: &Arc<Context>,
+) -> Result<()> {
+    let prelude = context
+        .plugin_driver
+        .transform_module_prelude(module_id, context)?;
+    if prelude.is_empty() {
+        return Ok(());
+    }
+    let mut prelude_ast = JsAst::build(
+        "_mako_internal/module_prelude.js",
+        &prelude,
+        context.clone(),
+    )?;
+    if prelude_ast
+        .ast
+        .body
+        .iter()
+        .any(|item| matches!(item, ModuleItem::ModuleDecl(_)))
+    {
+        return Err(anyhow!(
+            "the module prelude of {} can't contain imports or exports",
+            module_id.id
+        ));
+    }
+    prelude_ast.ast.visit_mut_with(&mut CleanSpan {});
+
+    let index = ast
+        .body
+        .iter()
+        .take_while(|item| {
+            matches!(
+                item,
+                ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. }))
+                    if matches!(expr.as_ref(), Expr::Lit(Lit::Str(_)))
+            )
+        })
+        .count();
+    ast.body.splice(index..index, prelude_ast.ast.body);
+    Ok(())
+}
+
 pub fn transform_css_generate(ast: &mut ast::Stylesheet, _context: &Arc<Context>) {
     crate::mako_profile_function!();
     // replace deps
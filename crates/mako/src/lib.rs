@@ -20,6 +20,9 @@ pub mod stats;
 pub mod utils;
 mod visitors;
 
+// for `--log-format=json` of the cli
+pub use plugins::build_log;
+
 #[macro_export]
 macro_rules! mako_profile_scope {
     ($id:expr) => {
@@ -6,10 +6,12 @@ use std::sync::Arc;
 
 use anyhow::{anyhow, Result};
 use clap::Parser;
+use mako::build_log::BuildLogPlugin;
 use mako::compiler::{self, Args};
 #[cfg(not(feature = "profile"))]
 use mako::dev;
-use mako::utils::logger::init_logger;
+use mako::plugin::Plugin;
+use mako::utils::logger::{init_logger_with_format, LogFormat};
 #[cfg(feature = "profile")]
 use mako::utils::profile_gui::ProfileApp;
 use mako::utils::tokio_runtime;
@@ -35,11 +37,11 @@ fn main() -> Result<()> {
 }
 
 async fn run() -> Result<()> {
-    // logger
-    init_logger();
-
     // cli
     let cli = cli::Cli::parse();
+
+    // logger
+    init_logger_with_format(cli.log_format, cli.log_file.as_deref())?;
     debug!(
         "cli: watch = {}, mode = {}, root = {}",
         cli.watch,
@@ -69,10 +71,27 @@ async fn run() -> Result<()> {
 
     config.mode = cli.mode;
 
+    if cli.watch_build {
+        config.hmr = None;
+        config.dev_server = None;
+    }
+
     debug!("config: {:?}", config);
 
     // compiler
-    let compiler = compiler::Compiler::new(config, root.clone(), Args { watch: cli.watch }, None)?;
+    let args = Args {
+        watch: cli.watch || cli.watch_build,
+    };
+    let extra_plugins = (cli.log_format == LogFormat::Json)
+        .then(|| vec![Arc::new(BuildLogPlugin {}) as Arc<dyn Plugin>]);
+    let compiler = compiler::Compiler::new(config, root.clone(), args, extra_plugins)?;
+    if cli.print_config {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&compiler.resolved_config())?
+        );
+        return Ok(());
+    }
     let compiler = Arc::new(compiler);
 
     #[cfg(feature = "profile")]
@@ -90,10 +109,23 @@ async fn run() -> Result<()> {
     #[cfg(not(feature = "profile"))]
     {
         if let Err(e) = compiler.compile() {
+            if cli.log_format == LogFormat::Json {
+                mako::build_log::emit_error(&e.to_string());
+            }
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+        if let Err(e) = compiler.compile_legacy(None, Some(cli_args.as_str()), None) {
+            if cli.log_format == LogFormat::Json {
+                mako::build_log::emit_error(&e.to_string());
+            }
             eprintln!("{}", e);
             std::process::exit(1);
         }
-        if cli.watch {
+        if cli.watch_build {
+            let d = dev::DevServer::new(root.clone(), compiler);
+            d.watch_build()?;
+        } else if cli.watch {
             let d = dev::DevServer::new(root.clone(), compiler);
             // TODO: when in Dev Mode, Dev Server should start asap, and provider a loading  while in first compiling
             d.serve().await;
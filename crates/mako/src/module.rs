@@ -19,7 +19,7 @@ use crate::ast::file::File;
 use crate::ast::js_ast::JsAst;
 use crate::build::analyze_deps::AnalyzeDepsResult;
 use crate::compiler::Context;
-use crate::config::ModuleIdStrategy;
+use crate::config::{ChunkIdStrategy, ModuleIdStrategy};
 use crate::resolve::ResolverResource;
 
 pub type Dependencies = HashSet<Dependency>;
@@ -219,6 +219,26 @@ pub fn generate_module_id(origin_module_id: String, context: &Arc<Context>) -> S
     }
 }
 
+pub fn generate_chunk_id(origin_module_id: String, context: &Arc<Context>) -> String {
+    match context.config.chunk_ids {
+        None => generate_module_id(origin_module_id, context),
+        Some(ChunkIdStrategy::Hashed) => md5_hash(&origin_module_id, 8),
+        Some(ChunkIdStrategy::Named) => {
+            // the path relative to the root, with `/` on all platforms so that
+            // it's the same across builds, the virtual modules have no path
+            let absolute_path = PathBuf::from(&origin_module_id);
+            match diff_paths(&absolute_path, &context.root) {
+                Some(relative_path) => relative_path
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/"),
+                None => md5_hash(&origin_module_id, 8),
+            }
+        }
+    }
+}
+
 pub fn relative_to_root(module_path: &String, root: &PathBuf) -> String {
     let absolute_path = PathBuf::from(module_path);
     let relative_path = diff_paths(&absolute_path, root).unwrap_or(absolute_path);
@@ -261,6 +281,11 @@ impl ModuleId {
         generate_module_id(self.id.clone(), context)
     }
 
+    /// Id of the chunk split at the module, see `chunkIds`
+    pub fn generate_chunk_id(&self, context: &Arc<Context>) -> String {
+        generate_chunk_id(self.id.clone(), context)
+    }
+
     pub fn from_path(path_buf: PathBuf) -> Self {
         Self {
             id: path_buf.to_string_lossy().to_string(),
@@ -11,11 +11,21 @@ use tracing::debug;
 
 use crate::module::{Dependencies, Dependency, Module, ModuleId, ResolveType};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ModuleGraph {
     id_index_map: HashMap<ModuleId, NodeIndex<DefaultIx>>,
     pub graph: StableDiGraph<Module, Dependencies>,
     entries: HashSet<ModuleId>,
+    // the state of the modules before they are first touched since
+    // begin_undo_log, None for the ones which didn't exist
+    undo_log: Option<HashMap<ModuleId, Option<UndoEntry>>>,
+}
+
+#[derive(Debug, Clone)]
+struct UndoEntry {
+    module: Module,
+    dependencies: Vec<(ModuleId, Dependencies)>,
+    dependents: Vec<(ModuleId, Dependencies)>,
 }
 
 impl ModuleGraph {
@@ -24,9 +34,93 @@ impl ModuleGraph {
             id_index_map: HashMap::new(),
             graph: StableDiGraph::new(),
             entries: HashSet::new(),
+            undo_log: None,
+        }
+    }
+
+    /// Records the modules and edges touched from now on, so that
+    /// [ModuleGraph::rollback] brings them back, the log is dropped by
+    /// [ModuleGraph::commit_undo_log]
+    pub fn begin_undo_log(&mut self) {
+        self.undo_log = Some(HashMap::new());
+    }
+
+    pub fn commit_undo_log(&mut self) {
+        self.undo_log = None;
+    }
+
+    /// Restores the modules and edges touched since
+    /// [ModuleGraph::begin_undo_log], the other ones are left as they are
+    pub fn rollback(&mut self) {
+        let Some(undo_log) = self.undo_log.take() else {
+            return;
+        };
+        for module_id in undo_log.keys() {
+            if self.has_module(module_id) {
+                self.remove_module(module_id);
+            }
+        }
+        // the edges between two touched modules are restored with the
+        // dependencies of the dependent one
+        let mut edges = vec![];
+        for (module_id, entry) in undo_log.iter() {
+            let Some(entry) = entry else {
+                continue;
+            };
+            for (to, deps) in &entry.dependencies {
+                edges.push((module_id.clone(), to.clone(), deps.clone()));
+            }
+            for (from, deps) in &entry.dependents {
+                if !undo_log.contains_key(from) {
+                    edges.push((from.clone(), module_id.clone(), deps.clone()));
+                }
+            }
+        }
+        for entry in undo_log.into_values().flatten() {
+            self.add_module(entry.module);
+        }
+        for (from, to, deps) in edges {
+            for dep in deps {
+                self.add_dependency(&from, &to, dep);
+            }
         }
     }
 
+    // logs the module and its edges the first time it's touched, the edges
+    // are only changed after both ends are touched, so what's logged is the
+    // state before the undo log begins
+    fn touch(&mut self, module_id: &ModuleId) {
+        let Some(undo_log) = &self.undo_log else {
+            return;
+        };
+        if undo_log.contains_key(module_id) {
+            return;
+        }
+        let entry = self.id_index_map.get(module_id).map(|index| {
+            let edges = |direction: Direction| -> Vec<(ModuleId, Dependencies)> {
+                self.graph
+                    .edges_directed(*index, direction)
+                    .map(|edge| {
+                        let other = match direction {
+                            Direction::Outgoing => edge.target(),
+                            Direction::Incoming => edge.source(),
+                        };
+                        (self.graph[other].id.clone(), edge.weight().clone())
+                    })
+                    .collect()
+            };
+            UndoEntry {
+                module: self.graph[*index].clone(),
+                dependencies: edges(Direction::Outgoing),
+                dependents: edges(Direction::Incoming),
+            }
+        });
+        self.undo_log
+            .as_mut()
+            .unwrap()
+            .insert(module_id.clone(), entry);
+    }
+
     pub fn get_entry_modules(&self) -> Vec<ModuleId> {
         self.entries.iter().cloned().collect()
     }
@@ -36,6 +130,7 @@ impl ModuleGraph {
         let id_for_map = module.id.clone();
         let id_for_entry = module.id.clone();
         let is_entry = module.is_entry;
+        self.touch(&module.id);
         let idx = self.graph.add_node(module);
         self.id_index_map.insert(id_for_map, idx);
         if is_entry {
@@ -72,6 +167,7 @@ impl ModuleGraph {
 
     #[allow(dead_code)]
     pub fn remove_module(&mut self, module_id: &ModuleId) -> Module {
+        self.touch(module_id);
         let index = self
             .id_index_map
             .remove(module_id)
@@ -79,7 +175,24 @@ impl ModuleGraph {
         self.graph.remove_node(index).unwrap()
     }
 
+    /// Rewrites the id of a module in place, the edges of its dependents and
+    /// dependencies are kept since they are bound to the node
+    pub fn rename_module(&mut self, from: &ModuleId, to: ModuleId) {
+        self.touch(from);
+        self.touch(&to);
+        let index = self
+            .id_index_map
+            .remove(from)
+            .unwrap_or_else(|| panic!("module_id {:?} not found in the module graph", from));
+        if self.entries.remove(from) {
+            self.entries.insert(to.clone());
+        }
+        self.graph[index].id = to.clone();
+        self.id_index_map.insert(to, index);
+    }
+
     pub fn get_module_mut(&mut self, module_id: &ModuleId) -> Option<&mut Module> {
+        self.touch(module_id);
         self.id_index_map
             .get(module_id)
             .and_then(|i| self.graph.node_weight_mut(*i))
@@ -93,6 +206,7 @@ impl ModuleGraph {
     }
 
     pub fn replace_module(&mut self, module: Module) {
+        self.touch(&module.id);
         let i = self
             .id_index_map
             .get(&module.id)
@@ -102,10 +216,17 @@ impl ModuleGraph {
 
     #[allow(dead_code)]
     pub fn get_modules_mut(&mut self) -> Vec<&mut Module> {
+        if self.undo_log.is_some() {
+            for module_id in self.get_module_ids() {
+                self.touch(&module_id);
+            }
+        }
         self.graph.node_weights_mut().collect()
     }
 
     pub fn clear_dependency(&mut self, from: &ModuleId, to: &ModuleId) {
+        self.touch(from);
+        self.touch(to);
         let from_index = self.id_index_map.get(from).unwrap_or_else(|| {
             panic!(
                 r#"from node "{}" does not exist in the module graph when remove edge"#,
@@ -129,6 +250,8 @@ impl ModuleGraph {
     }
 
     pub fn remove_dependency(&mut self, from: &ModuleId, to: &ModuleId, dep: &Dependency) {
+        self.touch(from);
+        self.touch(to);
         let from_index = self.id_index_map.get(from).unwrap_or_else(|| {
             panic!(
                 r#"from node "{}" does not exist in the module graph when remove edge"#,
@@ -161,6 +284,8 @@ impl ModuleGraph {
     }
 
     pub fn add_dependency(&mut self, from: &ModuleId, to: &ModuleId, edge: Dependency) {
+        self.touch(from);
+        self.touch(to);
         let from = self
             .id_index_map
             .get(from)
@@ -281,7 +406,16 @@ impl ModuleGraph {
     ) {
         let mut edges = self.get_edges(module_id, Direction::Outgoing);
 
-        while let Some((edge_index, _node_index)) = edges.next(&self.graph) {
+        while let Some((edge_index, node_index)) = edges.next(&self.graph) {
+            if self.undo_log.is_some()
+                && self.graph[edge_index]
+                    .iter()
+                    .any(|dep| *source == dep.source && dep.resolve_type.same_enum(&resolve_type))
+            {
+                let to = self.graph[node_index].id.clone();
+                self.touch(module_id);
+                self.touch(&to);
+            }
             let dependencies = self.graph.edge_weight_mut(edge_index).unwrap();
 
             if let Some(to_del_dep) = dependencies
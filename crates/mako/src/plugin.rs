@@ -1,9 +1,11 @@
 use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
 use std::path::Path;
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use serde::Serialize;
+use swc_core::base::sourcemap::SourceMap;
 use swc_core::common::errors::Handler;
 use swc_core::common::Mark;
 use swc_core::ecma::ast::Module;
@@ -11,6 +13,7 @@ use swc_core::ecma::ast::Module;
 use crate::ast::file::{Content, File};
 use crate::compiler::{Args, Compiler, Context};
 use crate::config::Config;
+use crate::dev::update::UpdateResult;
 use crate::generate::chunk_graph::ChunkGraph;
 use crate::generate::generate_chunks::ChunkFile;
 use crate::module::{Dependency, ModuleAst, ModuleId};
@@ -41,6 +44,12 @@ pub struct PluginGenerateEndParams {
     pub stats: StatsJsonMap,
 }
 
+pub struct PluginUpdateParams<'a> {
+    pub update_result: &'a UpdateResult,
+    // time spent on update and hot update chunks generation, in ms
+    pub time: u64,
+}
+
 #[derive(Clone)]
 pub struct PluginGenerateStats {
     pub start_time: u64,
@@ -50,6 +59,13 @@ pub struct PluginGenerateStats {
 pub trait Plugin: Any + Send + Sync {
     fn name(&self) -> &str;
 
+    /// Part of the fingerprint of the persistent cache along with the name,
+    /// e.g. the version or the options of the plugin. The cached modules skip
+    /// `transform_js`, so it should change whenever the transform does.
+    fn cache_key(&self) -> Option<String> {
+        None
+    }
+
     fn modify_config(&self, _config: &mut Config, _root: &Path, _args: &Args) -> Result<()> {
         Ok(())
     }
@@ -70,6 +86,9 @@ pub trait Plugin: Any + Send + Sync {
         Ok(None)
     }
 
+    // not called for the modules restored from the persistent cache, a
+    // plugin with other side effects than the changes of the ast should call
+    // Context::skip_persistent_cache for the module
     fn transform_js(
         &self,
         _param: &PluginTransformJsParam,
@@ -104,6 +123,19 @@ pub trait Plugin: Any + Send + Sync {
         Ok(())
     }
 
+    // called with the final source map json of each chunk and hot update
+    // before it's written, asset_name is the file name of the map, e.g.
+    // `index.js.map`, returns the changed map or None to keep it, so that
+    // the map is only parsed for the plugins which change it
+    fn transform_source_map(
+        &self,
+        _map: &[u8],
+        _asset_name: &str,
+        _context: &Arc<Context>,
+    ) -> Result<Option<SourceMap>> {
+        Ok(None)
+    }
+
     fn build_success(&self, _stats: &StatsJsonMap, _context: &Arc<Context>) -> Result<()> {
         Ok(())
     }
@@ -124,10 +156,28 @@ pub trait Plugin: Any + Send + Sync {
         Ok(())
     }
 
+    // called after each successful hmr update
+    fn on_update(&self, _params: &PluginUpdateParams, _context: &Arc<Context>) -> Result<()> {
+        Ok(())
+    }
+
     fn runtime_plugins(&self, _context: &Arc<Context>) -> Result<Vec<String>> {
         Ok(Vec::new())
     }
 
+    // the code at the top of the factory function of each js module, e.g.
+    // instrumentation or a module scoped helper referring to
+    // `module_id.generate(context)`, plugins append to or rewrite the code
+    // of the plugins before them
+    fn transform_module_prelude(
+        &self,
+        _module_id: &ModuleId,
+        _prelude: &mut String,
+        _context: &Arc<Context>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
     fn optimize_module_graph(
         &self,
         _module_graph: &mut ModuleGraph,
@@ -170,6 +220,18 @@ impl PluginDriver {
         Self { plugins }
     }
 
+    /// The names and the cache keys of the plugins in order, see
+    /// [Plugin::cache_key]
+    pub fn cache_keys(&self) -> Vec<String> {
+        self.plugins
+            .iter()
+            .map(|plugin| match plugin.cache_key() {
+                Some(key) => format!("{}@{}", plugin.name(), key),
+                None => plugin.name().to_string(),
+            })
+            .collect()
+    }
+
     pub fn next_build(&self, param: &NextBuildParam) -> bool {
         self.plugins.iter().all(|p| p.next_build(param))
     }
@@ -269,6 +331,45 @@ impl PluginDriver {
         Ok(())
     }
 
+    // None if no plugin changes the map, the tokens of the returned maps
+    // must still point to their sources and names, or the build fails
+    // instead of writing a broken map
+    pub(crate) fn transform_source_map(
+        &self,
+        source_map: &[u8],
+        asset_name: &str,
+        context: &Arc<Context>,
+    ) -> Result<Option<Vec<u8>>> {
+        let mut transformed: Option<Vec<u8>> = None;
+        for plugin in &self.plugins {
+            let current = transformed.as_deref().unwrap_or(source_map);
+            let Some(map) = plugin.transform_source_map(current, asset_name, context)? else {
+                continue;
+            };
+
+            let (source_count, name_count) = (map.get_source_count(), map.get_name_count());
+            let invalid_token = map.tokens().find(|token| {
+                let raw = token.get_raw_token();
+                (raw.src_id != !0 && raw.src_id >= source_count)
+                    || (raw.name_id != !0 && raw.name_id >= name_count)
+            });
+            if let Some(token) = invalid_token {
+                return Err(anyhow!(
+                    "invalid source map of {} returned by transform_source_map of {}, the token at {}:{} refers to a missing source or name",
+                    asset_name,
+                    plugin.name(),
+                    token.get_dst_line(),
+                    token.get_dst_col()
+                ));
+            }
+
+            let mut buf = vec![];
+            map.to_writer(&mut buf)?;
+            transformed = Some(buf);
+        }
+        Ok(transformed)
+    }
+
     pub fn build_start(&self, context: &Arc<Context>) -> Result<()> {
         for plugin in &self.plugins {
             plugin.build_start(context)?;
@@ -301,6 +402,32 @@ impl PluginDriver {
         Ok(())
     }
 
+    // a failing or panicking plugin should not take down the dev loop, so
+    // errors are collected and returned after all plugins are called
+    pub fn on_update(&self, params: &PluginUpdateParams, context: &Arc<Context>) -> Result<()> {
+        let mut errors = vec![];
+        for plugin in &self.plugins {
+            let ret = panic::catch_unwind(AssertUnwindSafe(|| plugin.on_update(params, context)));
+            match ret {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => errors.push(format!("{}: {}", plugin.name(), e)),
+                Err(e) => {
+                    let message = e
+                        .downcast_ref::<&str>()
+                        .map(|s| s.to_string())
+                        .or_else(|| e.downcast_ref::<String>().cloned())
+                        .unwrap_or_else(|| "unknown panic".to_string());
+                    errors.push(format!("{} panicked: {}", plugin.name(), message));
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!("on_update hook failed\n{}", errors.join("\n")))
+        }
+    }
+
     pub fn runtime_plugins_code(&self, context: &Arc<Context>) -> Result<String> {
         let mut plugins = Vec::new();
         for plugin in &self.plugins {
@@ -309,6 +436,18 @@ impl PluginDriver {
         Ok(plugins.join("\n"))
     }
 
+    pub fn transform_module_prelude(
+        &self,
+        module_id: &ModuleId,
+        context: &Arc<Context>,
+    ) -> Result<String> {
+        let mut prelude = String::new();
+        for plugin in &self.plugins {
+            plugin.transform_module_prelude(module_id, &mut prelude, context)?;
+        }
+        Ok(prelude)
+    }
+
     pub fn optimize_module_graph(
         &self,
         module_graph: &mut ModuleGraph,
@@ -354,3 +493,259 @@ impl PluginDriver {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::utils::test_helper::{HookRecorder, PluginTestBuilder};
+
+    #[derive(Default)]
+    struct RecordPlugin {
+        calls: AtomicUsize,
+    }
+
+    impl Plugin for RecordPlugin {
+        fn name(&self) -> &str {
+            "record"
+        }
+
+        fn on_update(&self, params: &PluginUpdateParams, _context: &Arc<Context>) -> Result<()> {
+            assert!(params.update_result.is_updated());
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    struct PanicPlugin {}
+
+    impl Plugin for PanicPlugin {
+        fn name(&self) -> &str {
+            "panic"
+        }
+
+        fn on_update(&self, _params: &PluginUpdateParams, _context: &Arc<Context>) -> Result<()> {
+            panic!("boom");
+        }
+    }
+
+    fn update_result() -> UpdateResult {
+        let mut update_result = UpdateResult::default();
+        update_result
+            .modified
+            .insert(ModuleId::new("a.js".to_string()));
+        update_result
+    }
+
+    #[test]
+    fn test_on_update_called_once_per_update() {
+        let context: Arc<Context> = Arc::new(Default::default());
+        let record = Arc::new(RecordPlugin::default());
+        let driver = PluginDriver::new(vec![record.clone()]);
+        let update_result = update_result();
+        let params = PluginUpdateParams {
+            update_result: &update_result,
+            time: 1,
+        };
+        driver.on_update(&params, &context).unwrap();
+        assert_eq!(record.calls.load(Ordering::SeqCst), 1);
+        driver.on_update(&params, &context).unwrap();
+        assert_eq!(record.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_on_update_panicking_plugin() {
+        let context: Arc<Context> = Arc::new(Default::default());
+        let record = Arc::new(RecordPlugin::default());
+        let driver = PluginDriver::new(vec![Arc::new(PanicPlugin {}), record.clone()]);
+        let update_result = update_result();
+        let params = PluginUpdateParams {
+            update_result: &update_result,
+            time: 1,
+        };
+        let err = driver.on_update(&params, &context).unwrap_err();
+        assert!(err.to_string().contains("panic panicked: boom"));
+        assert_eq!(record.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[derive(Default)]
+    struct SourcePrefixPlugin {
+        asset_names: Mutex<Vec<String>>,
+    }
+
+    impl Plugin for SourcePrefixPlugin {
+        fn name(&self) -> &str {
+            "source_prefix"
+        }
+
+        fn transform_source_map(
+            &self,
+            map: &[u8],
+            asset_name: &str,
+            _context: &Arc<Context>,
+        ) -> Result<Option<SourceMap>> {
+            self.asset_names
+                .lock()
+                .unwrap()
+                .push(asset_name.to_string());
+            let mut map = SourceMap::from_slice(map)?;
+            for i in 0..map.get_source_count() {
+                let source = map.get_source(i).unwrap().to_string();
+                map.set_source(i, &format!("webpack://app/{}", source));
+            }
+            Ok(Some(map))
+        }
+    }
+
+    fn assert_prefixed_sources(path: &Path) {
+        let content = std::fs::read(path).unwrap();
+        let map = SourceMap::from_slice(&content).unwrap();
+        let sources = map.sources().collect::<Vec<_>>();
+        assert!(sources
+            .iter()
+            .all(|source| source.starts_with("webpack://app/")));
+        assert!(sources
+            .iter()
+            .any(|source| source.ends_with("src/index.js")));
+    }
+
+    #[test]
+    fn test_transform_source_map() {
+        let plugin = Arc::new(SourcePrefixPlugin::default());
+        let compiler = PluginTestBuilder::new("transform-source-map")
+            .file("src/index.js", "console.log(1);\n")
+            .plugin(plugin.clone())
+            .build();
+        compiler.compile().unwrap();
+
+        assert_eq!(*plugin.asset_names.lock().unwrap(), vec!["index.js.map"]);
+        assert_prefixed_sources(&compiler.context.config.output.path.join("index.js.map"));
+    }
+
+    #[test]
+    fn test_transform_hot_update_source_map() {
+        let plugin = Arc::new(SourcePrefixPlugin::default());
+        let compiler = PluginTestBuilder::new("transform-hot-update-source-map")
+            .file("src/index.js", "console.log(1);\n")
+            .plugin(plugin.clone())
+            .watch()
+            .hmr()
+            .build();
+        compiler.compile().unwrap();
+        let last_snapshot_hash = compiler.full_hash();
+
+        let index = compiler.context.root.join("src/index.js");
+        std::fs::write(&index, "console.log(2);\n").unwrap();
+        let result = compiler.update(vec![index]).unwrap();
+        compiler
+            .generate_hot_update_chunks(result, last_snapshot_hash, 0)
+            .unwrap();
+
+        assert_eq!(
+            *plugin.asset_names.lock().unwrap(),
+            vec!["index.js.map", "index.0.hot-update.js.map"]
+        );
+        assert_prefixed_sources(
+            &compiler
+                .context
+                .config
+                .output
+                .path
+                .join("index.0.hot-update.js.map"),
+        );
+    }
+
+    #[test]
+    fn test_build_hooks() {
+        let recorder = Arc::new(HookRecorder::default());
+        let compiler = PluginTestBuilder::new("build-hooks")
+            .file(
+                "src/index.js",
+                "import { a } from './a';\nconsole.log(a);\n",
+            )
+            .file("src/a.js", "export const a = 1;\n")
+            .plugin(recorder.clone())
+            .build();
+        compiler.compile().unwrap();
+
+        let position = |hook: &str| recorder.position(hook).unwrap();
+        assert_eq!(position("build_start"), 0);
+        assert!(position("load") < position("transform_js"));
+        assert!(position("transform_js") < position("generate_begin"));
+        assert!(position("generate_begin") < position("build_success"));
+        assert!(position("build_success") < position("generate_end"));
+
+        let mut paths = recorder
+            .calls_of("transform_js")
+            .into_iter()
+            .map(|call| call.path.unwrap())
+            .collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(paths, vec!["src/a.js", "src/index.js"]);
+        let transformed = recorder
+            .calls_of("transform_js")
+            .into_iter()
+            .find(|call| call.path.as_deref() == Some("src/a.js"))
+            .unwrap();
+        assert_eq!(transformed.code.unwrap(), "export const a = 1;\n");
+    }
+
+    struct PreludePlugin {}
+
+    impl Plugin for PreludePlugin {
+        fn name(&self) -> &str {
+            "prelude"
+        }
+
+        fn transform_module_prelude(
+            &self,
+            module_id: &ModuleId,
+            prelude: &mut String,
+            context: &Arc<Context>,
+        ) -> Result<()> {
+            prelude.push_str(&format!(
+                "globalThis.__visited = (globalThis.__visited || []).concat(\"{}\");\n",
+                module_id.generate(context)
+            ));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_transform_module_prelude() {
+        let compiler = PluginTestBuilder::new("module-prelude")
+            .file(
+                "src/index.js",
+                "import { a } from './a';\nconsole.log(a);\n",
+            )
+            .file("src/a.js", "export const a = 1;\nconsole.log('in a');\n")
+            .plugin(Arc::new(PreludePlugin {}))
+            .build();
+        compiler.compile().unwrap();
+
+        let output_path = &compiler.context.config.output.path;
+        let content = std::fs::read_to_string(output_path.join("index.js")).unwrap();
+        for id in ["src/index.js", "src/a.js"] {
+            assert!(content.contains(&format!(
+                "globalThis.__visited = (globalThis.__visited || []).concat(\"{}\");",
+                id
+            )));
+        }
+
+        // the code after the prelude is still mapped to its original position
+        let map = SourceMap::from_slice(&std::fs::read(output_path.join("index.js.map")).unwrap())
+            .unwrap();
+        let (line, column) = content
+            .lines()
+            .enumerate()
+            .find(|(_, code)| code.contains("in a"))
+            .map(|(line, code)| (line, code.find("console.log").unwrap()))
+            .unwrap();
+        let token = map.lookup_token(line as u32, column as u32).unwrap();
+        assert!(token.get_source().unwrap().ends_with("src/a.js"));
+        assert_eq!(token.get_src_line(), 1);
+        assert_eq!(token.get_src_col(), 0);
+    }
+}
@@ -0,0 +1,163 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use serde_json::{json, Value};
+use tracing::info;
+
+use crate::compiler::{Compiler, Context};
+use crate::plugin::{Plugin, PluginGenerateEndParams};
+use crate::utils::logger::BUILD_LOG_TARGET;
+
+/// Emits the events of the build log for `--log-format=json`, they are
+/// written as json lines by [crate::utils::logger::json_lines_layer]
+pub struct BuildLogPlugin {}
+
+pub fn emit(kind: &str, payload: Value) {
+    let payload = payload.to_string();
+    info!(target: BUILD_LOG_TARGET, kind, payload = payload.as_str());
+}
+
+pub fn emit_error(message: &str) {
+    emit("error", json!({ "message": message }));
+}
+
+fn emit_phase(name: &str, status: &str) {
+    emit("phase", json!({ "name": name, "status": status }));
+}
+
+impl Plugin for BuildLogPlugin {
+    fn name(&self) -> &str {
+        "build_log"
+    }
+
+    fn build_start(&self, _context: &Arc<Context>) -> Result<()> {
+        emit_phase("build", "start");
+        Ok(())
+    }
+
+    fn after_build(&self, context: &Arc<Context>, _compiler: &Compiler) -> Result<()> {
+        let module_graph = context.module_graph.read().unwrap();
+        for module in module_graph.modules() {
+            emit("module", json!({ "id": module.id.generate(context) }));
+        }
+        emit_phase("build", "end");
+        Ok(())
+    }
+
+    fn generate_begin(&self, _context: &Arc<Context>) -> Result<()> {
+        emit_phase("generate", "start");
+        Ok(())
+    }
+
+    fn generate_end(&self, params: &PluginGenerateEndParams, context: &Arc<Context>) -> Result<()> {
+        emit_phase("generate", "end");
+        let assets = context.stats_info.get_assets();
+        for asset in &assets {
+            emit(
+                "asset",
+                json!({
+                    "name": asset.hashname,
+                    "size": asset.size,
+                    "chunkId": asset.chunk_id,
+                }),
+            );
+        }
+        let modules = context.module_graph.read().unwrap().modules().len();
+        emit(
+            "done",
+            json!({
+                "isFirstCompile": params.is_first_compile,
+                "time": params.time,
+                "modules": modules,
+                "assets": assets.len(),
+                "size": assets.iter().map(|asset| asset.size).sum::<u64>(),
+            }),
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    use serde_json::Value;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::BuildLogPlugin;
+    use crate::utils::logger::json_lines_layer;
+    use crate::utils::test_helper::PluginTestBuilder;
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_build_log() {
+        let compiler = PluginTestBuilder::new("build-log")
+            .file("src/index.js", "import './foo';\nconsole.log('index');")
+            .file("src/foo.js", "console.log('foo');")
+            // warns that no chunk is found for the item
+            .config(r#"{ "preloadChunks": ["src/missing.js"] }"#)
+            .plugin(Arc::new(BuildLogPlugin {}))
+            .build();
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber =
+            tracing_subscriber::registry().with(json_lines_layer(move || writer.clone()));
+        tracing::subscriber::with_default(subscriber, || compiler.compile().unwrap());
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let events = output
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert!(events.iter().all(|event| event["timestamp"].is_i64()));
+        let of_type = |kind: &str| {
+            events
+                .iter()
+                .filter(|event| event["type"] == kind)
+                .map(|event| event["payload"].clone())
+                .collect::<Vec<_>>()
+        };
+        let phases = of_type("phase")
+            .iter()
+            .map(|payload| format!("{} {}", payload["name"], payload["status"]))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            phases,
+            vec![
+                r#""build" "start""#,
+                r#""build" "end""#,
+                r#""generate" "start""#,
+                r#""generate" "end""#,
+            ]
+        );
+        assert_eq!(of_type("module").len(), 2);
+        let assets = of_type("asset");
+        assert!(assets.iter().any(|asset| asset["name"] == "index.js"));
+        let warnings = of_type("warning");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0]["message"],
+            "no chunk found for preloadChunks item src/missing.js"
+        );
+
+        let last = events.last().unwrap();
+        assert_eq!(last["type"], "done");
+        assert_eq!(last["payload"]["modules"], 2);
+        assert_eq!(last["payload"]["assets"], assets.len());
+        assert_eq!(last["payload"]["warnings"], 1);
+        assert_eq!(last["payload"]["errors"], 0);
+    }
+}
@@ -118,7 +118,14 @@ impl BundlessCompiler {
     }
 
     fn write_to_dist<P: AsRef<std::path::Path>, C: AsRef<[u8]>>(&self, filename: P, content: C) {
-        let to = self.context.config.output.path.join(&filename);
+        // the dist paths are under output.path, the files go to the staged
+        // output if any
+        let output_path = &self.context.config.output.path;
+        let to = output_path.join(&filename);
+        let to = match to.strip_prefix(output_path) {
+            Ok(relative) => self.context.output_path().join(relative),
+            Err(_) => to,
+        };
         let to = normalize_extension(to);
 
         self.context
@@ -38,6 +38,7 @@ impl Plugin for ContextModulePlugin {
             let paths = glob(glob_pattern.to_str().unwrap())?;
 
             let mut key_values = vec![];
+            let is_async = param.file.has_param("async");
 
             for path in paths {
                 let path = path?;
@@ -84,8 +85,6 @@ impl Plugin for ContextModulePlugin {
                     }
                 }
 
-                let is_async = param.file.has_param("async");
-
                 for key in keys {
                     let load_by = if is_async { "import" } else { "require" };
                     key_values.push(format!(
@@ -97,6 +96,12 @@ impl Plugin for ContextModulePlugin {
                 }
             }
 
+            // async context is loaded by `import()`, so missing keys should reject
+            let not_found = if is_async {
+                "return Promise.reject(e);"
+            } else {
+                "throw e;"
+            };
             let content = format!(
                 r#"
 const map = {{
@@ -108,11 +113,12 @@ module.exports = (id) => {{
     else {{
         const e = new Error("Cannot find module '" + id + "'");
         e.code = 'MODULE_NOT_FOUND';
-        throw e;
+        {}
     }}
 }};
 "#,
-                key_values.join(",\n")
+                key_values.join(",\n"),
+                not_found
             );
             Ok(Some(Content::Js(JsContent {
                 content,
@@ -228,6 +234,10 @@ fn try_replace_context_arg(
 
         // handle prefix of `'./foo/' + bar + '.ext'`
         Expr::Lit(Lit::Str(str)) => {
+            // no static directory to search in, e.g. `'' + bar`
+            if str.value.is_empty() {
+                return None;
+            }
             let mut prefix = str.value.to_string();
             // replace first str with relative prefix
             let (pre_quasis, remainder) = if let Some(pos) = prefix.rfind('/') {
@@ -247,6 +257,10 @@ fn try_replace_context_arg(
         Expr::Tpl(tpl) => {
             if !tpl.exprs.is_empty() {
                 let pre_quasis = tpl.quasis.first().unwrap().raw.to_string();
+                // no static directory to search in, e.g. `${dir}/bar.js`
+                if pre_quasis.is_empty() {
+                    return None;
+                }
                 let (prefix, remainder) = if let Some(pos) = pre_quasis.rfind('/') {
                     (
                         pre_quasis[..=pos].to_string(),
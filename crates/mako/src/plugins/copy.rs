@@ -1,72 +1,40 @@
-use std::path::Path;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use fs_extra;
-use glob::glob;
-use notify::event::{CreateKind, DataChange, ModifyKind, RenameMode};
-use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
-use tokio::sync::mpsc::channel;
+use glob::{glob, MatchOptions, Pattern};
 use tracing::debug;
 
 use crate::compiler::Context;
+use crate::config::{CopyAdvancedConfig, CopyConfig};
 use crate::plugin::Plugin;
+use crate::plugins::public_dir::collect_files;
 use crate::stats::StatsJsonMap;
-use crate::utils::tokio_runtime;
 
 pub struct CopyPlugin {}
 
 impl CopyPlugin {
-    fn watch(context: &Arc<Context>) {
-        let context = context.clone();
-        tokio_runtime::spawn(async move {
-            let (tx, mut rx) = channel(2);
-            let mut watcher = RecommendedWatcher::new(
-                move |res| {
-                    tx.blocking_send(res).unwrap();
-                },
-                notify::Config::default(),
-            )
-            .unwrap();
-            for src in context.config.copy.iter() {
-                let src = context.root.join(src);
-                if src.exists() {
-                    debug!("watch {:?}", src);
-                    let mode = if src.is_dir() {
-                        RecursiveMode::Recursive
-                    } else {
-                        RecursiveMode::NonRecursive
-                    };
-                    watcher.watch(src.as_path(), mode).unwrap();
+    // returns the files written to the output
+    fn copy(context: &Arc<Context>) -> Result<Vec<PathBuf>> {
+        debug!("copy");
+        let dest = context.output_path();
+        let mut written = vec![];
+        for config in context.config.copy.iter() {
+            match config {
+                CopyConfig::Basic(src) => {
+                    let src = context.root.join(src);
+                    debug!("copy {:?} to {:?}", src, dest);
+                    written.extend(copy(src.as_path(), &dest)?);
                 }
-            }
-            while let Some(res) = rx.recv().await {
-                match res {
-                    Ok(event) => {
-                        if let EventKind::Create(CreateKind::File)
-                        | EventKind::Modify(ModifyKind::Data(DataChange::Any))
-                        | EventKind::Modify(ModifyKind::Name(RenameMode::Any)) = event.kind
-                        {
-                            CopyPlugin::copy(&context).unwrap();
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!("watch error: {:?}", e);
-                    }
+                CopyConfig::Advanced(config) => {
+                    debug!("copy {:?} to {:?}", config.from, dest.join(&config.to));
+                    written.extend(copy_advanced(&context.root, config, &dest)?);
                 }
             }
-        });
-    }
-
-    fn copy(context: &Arc<Context>) -> Result<()> {
-        debug!("copy");
-        let dest = context.config.output.path.as_path();
-        for src in context.config.copy.iter() {
-            let src = context.root.join(src);
-            debug!("copy {:?} to {:?}", src, dest);
-            copy(src.as_path(), dest)?;
         }
-        Ok(())
+        Ok(written)
     }
 }
 
@@ -76,17 +44,18 @@ impl Plugin for CopyPlugin {
     }
 
     fn build_success(&self, _stats: &StatsJsonMap, context: &Arc<Context>) -> Result<()> {
-        CopyPlugin::copy(context)?;
-        if context.args.watch {
-            CopyPlugin::watch(context);
-        }
+        // in watch mode, the changed files are copied again by the watcher of
+        // the dev server with [copy_changed]
+        let written = CopyPlugin::copy(context)?;
+        context.written_files.lock().unwrap().extend(written);
         Ok(())
     }
 }
 
-fn copy(src: &Path, dest: &Path) -> Result<()> {
+fn copy(src: &Path, dest: &Path) -> Result<Vec<PathBuf>> {
     let paths = glob(src.to_str().unwrap())?;
 
+    let mut written = vec![];
     for entry in paths {
         let entry = entry.unwrap();
 
@@ -96,13 +65,259 @@ fn copy(src: &Path, dest: &Path) -> Result<()> {
                 .skip_exist(false)
                 .overwrite(true);
             fs_extra::dir::copy(&entry, dest, &options)?;
+            written.extend(copied_files(&entry, dest)?);
         } else {
             let file_name = entry.file_name().unwrap();
             let options = fs_extra::file::CopyOptions::new()
                 .skip_exist(false)
                 .overwrite(true);
             fs_extra::file::copy(&entry, dest.join(file_name), &options)?;
+            written.push(dest.join(file_name));
+        }
+    }
+    Ok(written)
+}
+
+// the paths in `dest` of the files of `dir` whose content is copied there
+fn copied_files(dir: &Path, dest: &Path) -> Result<Vec<PathBuf>> {
+    collect_files(dir)?
+        .into_iter()
+        .map(|file| Ok(dest.join(file.strip_prefix(dir)?)))
+        .collect()
+}
+
+/// Copies the changed files of the copy sources again, the removed ones are
+/// removed from the output, returns the paths which are copy sources
+pub fn copy_changed(context: &Arc<Context>, paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let dest = context.config.output.path.as_path();
+    let mut copied = vec![];
+    for path in paths {
+        let Some(target) = context
+            .config
+            .copy
+            .iter()
+            .find_map(|config| copy_target(&context.root, config, dest, path))
+        else {
+            continue;
+        };
+        if path.is_file() {
+            debug!("copy {:?} to {:?}", path, target);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(path, &target)?;
+        } else if !path.exists() {
+            debug!("remove {:?}", target);
+            if target.is_dir() {
+                fs::remove_dir_all(&target)?;
+            } else if target.exists() {
+                fs::remove_file(&target)?;
+            }
         }
+        copied.push(path.clone());
+    }
+    Ok(copied)
+}
+
+/// The paths to watch for the copy sources, the dirs of the globs
+pub fn watch_paths(context: &Arc<Context>) -> Vec<PathBuf> {
+    context
+        .config
+        .copy
+        .iter()
+        .map(|config| match config {
+            CopyConfig::Basic(src) => context.root.join(glob_base(src)),
+            CopyConfig::Advanced(config) => context.root.join(glob_base(&config.from)),
+        })
+        .collect()
+}
+
+// the output path of a file of the copy source, the same as where `copy`
+// and `copy_advanced` put it
+fn copy_target(root: &Path, config: &CopyConfig, dest: &Path, path: &Path) -> Option<PathBuf> {
+    match config {
+        CopyConfig::Basic(src) => {
+            let src = root.join(src);
+            match path.strip_prefix(&src) {
+                // the content of the dir is copied
+                Ok(relative_path) if !relative_path.as_os_str().is_empty() => {
+                    Some(dest.join(relative_path))
+                }
+                _ if path == src || matches_glob(&src, path) => {
+                    path.file_name().map(|file_name| dest.join(file_name))
+                }
+                _ => None,
+            }
+        }
+        CopyConfig::Advanced(config) => {
+            let from = root.join(&config.from);
+            if !path.starts_with(&from) && !matches_glob(&from, path) {
+                return None;
+            }
+            let relative_path = path.strip_prefix(copy_context(root, config)).ok()?;
+            Some(dest.join(&config.to).join(relative_path))
+        }
+    }
+}
+
+fn matches_glob(pattern: &Path, path: &Path) -> bool {
+    let options = MatchOptions {
+        require_literal_separator: true,
+        ..Default::default()
+    };
+    pattern
+        .to_str()
+        .and_then(|pattern| Pattern::new(pattern).ok())
+        .is_some_and(|pattern| pattern.matches_path_with(path, options))
+}
+
+fn copy_context(root: &Path, config: &CopyAdvancedConfig) -> PathBuf {
+    match &config.context {
+        Some(context) => root.join(context),
+        None => {
+            let base = root.join(glob_base(&config.from));
+            if base.is_file() {
+                base.parent().map(Path::to_path_buf).unwrap_or(base)
+            } else {
+                base
+            }
+        }
+    }
+}
+
+// the files keep their paths relative to the context in `to`, e.g.
+// `assets/icons/a.png` matched by `assets/**/*.png` is copied to
+// `images/icons/a.png` with `to: "images"`
+fn copy_advanced(root: &Path, config: &CopyAdvancedConfig, dest: &Path) -> Result<Vec<PathBuf>> {
+    let context = copy_context(root, config);
+    let dest = dest.join(&config.to);
+    let pattern = root.join(&config.from);
+    let mut written = vec![];
+    for entry in glob(pattern.to_str().unwrap())? {
+        let entry = entry?;
+        let relative_path = entry.strip_prefix(&context).map_err(|_| {
+            anyhow!(
+                "copy: {} matched by {} is not under the context {}",
+                entry.display(),
+                config.from,
+                context.display()
+            )
+        })?;
+        let target = dest.join(relative_path);
+        if entry.is_dir() {
+            fs::create_dir_all(&target)?;
+            let options = fs_extra::dir::CopyOptions::new()
+                .content_only(true)
+                .skip_exist(false)
+                .overwrite(true);
+            fs_extra::dir::copy(&entry, &target, &options)?;
+            written.extend(copied_files(&entry, &target)?);
+        } else {
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(&entry, &target)?;
+            written.push(target);
+        }
+    }
+    Ok(written)
+}
+
+// the leading components of the glob without the special chars, e.g.
+// `assets` of `assets/**/*.png`
+fn glob_base(pattern: &str) -> PathBuf {
+    Path::new(pattern)
+        .components()
+        .take_while(|component| {
+            !component
+                .as_os_str()
+                .to_string_lossy()
+                .contains(['*', '?', '[', '{'])
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use super::{copy_changed, glob_base};
+    use crate::utils::test_helper::{setup_compiler, PluginTestBuilder};
+
+    #[test]
+    fn test_glob_base() {
+        assert_eq!(glob_base("assets/**/*.png"), PathBuf::from("assets"));
+        assert_eq!(glob_base("assets/icons"), PathBuf::from("assets/icons"));
+        assert_eq!(glob_base("*.txt"), PathBuf::new());
+    }
+
+    #[test]
+    fn test_copy_glob_to() {
+        let compiler = setup_compiler("test/build/copy-glob", false);
+        compiler.compile().unwrap();
+
+        let output = compiler.context.config.output.path.join("images");
+        let mut files = glob::glob(output.join("**/*").to_str().unwrap())
+            .unwrap()
+            .map(|entry| entry.unwrap())
+            .filter(|path| path.is_file())
+            .map(|path| {
+                path.strip_prefix(&output)
+                    .unwrap()
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect::<Vec<_>>();
+        files.sort();
+        assert_eq!(
+            files,
+            vec!["icons/add.png", "icons/small/remove.png", "logo.png"]
+        );
+    }
+
+    #[test]
+    fn test_copy_glob_context() {
+        let compiler = setup_compiler("test/build/copy-glob-context", false);
+        compiler.compile().unwrap();
+        let output = &compiler.context.config.output.path;
+        assert!(output.join("images/icons/small/remove.png").exists());
+
+        let compiler = setup_compiler("test/build/copy-glob-outside-context", false);
+        let err = compiler.compile().unwrap_err();
+        assert!(format!("{:?}", err).contains("is not under the context"));
+    }
+
+    #[test]
+    fn test_copy_changed() {
+        let compiler = PluginTestBuilder::new("copy-changed")
+            .file("src/index.js", "console.log('index');")
+            .file("public/robots.txt", "v1")
+            .file("assets/icons/add.png", "add")
+            .config(r#"{ "copy": ["public", { "from": "assets/**/*.png", "to": "images" }] }"#)
+            .watch()
+            .build();
+        compiler.compile().unwrap();
+        let root = compiler.context.root.clone();
+        let output = compiler.context.config.output.path.clone();
+        assert_eq!(fs::read_to_string(output.join("robots.txt")).unwrap(), "v1");
+
+        fs::write(root.join("public/robots.txt"), "v2").unwrap();
+        fs::write(root.join("assets/icons/remove.png"), "remove").unwrap();
+        let paths = vec![
+            root.join("public/robots.txt"),
+            root.join("assets/icons/remove.png"),
+            root.join("src/index.js"),
+        ];
+        let copied = copy_changed(&compiler.context, &paths).unwrap();
+        assert_eq!(copied, paths[..2].to_vec());
+        assert_eq!(fs::read_to_string(output.join("robots.txt")).unwrap(), "v2");
+        assert!(output.join("images/icons/remove.png").exists());
+
+        // the removed files are removed from the output
+        fs::remove_file(root.join("assets/icons/add.png")).unwrap();
+        let copied = copy_changed(&compiler.context, &[root.join("assets/icons/add.png")]).unwrap();
+        assert_eq!(copied.len(), 1);
+        assert!(!output.join("images/icons/add.png").exists());
     }
-    Ok(())
 }
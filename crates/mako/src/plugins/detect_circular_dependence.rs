@@ -2,8 +2,8 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use anyhow::Result;
-use colored::Colorize;
 use pathdiff::diff_paths;
+use tracing::warn;
 
 use crate::compiler::Context;
 use crate::plugin::Plugin;
@@ -63,7 +63,7 @@ impl Plugin for LoopDetector {
 
             if !loop_lines.is_empty() {
                 for l in &loop_lines {
-                    println!("{} Circular Dependencies: {}", "Warning".yellow(), l);
+                    warn!("Circular Dependencies: {}", l);
                 }
 
                 if detect_loop.graphviz {
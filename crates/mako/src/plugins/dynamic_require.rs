@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use swc_core::common::{Mark, Span};
+use swc_core::ecma::ast::{CallExpr, Expr, ExprOrSpread, Lit, Module};
+use swc_core::ecma::visit::{Visit, VisitWith};
+use tracing::warn;
+
+use crate::ast::utils::{is_commonjs_require, is_dynamic_import};
+use crate::compiler::Context;
+use crate::config::DynamicRequireLevel;
+use crate::plugin::{Plugin, PluginTransformJsParam};
+
+const MESSAGE: &str = "the argument of require or import can't be statically analyzed";
+
+// Report `require(x)` and `import(x)` which are left after the context module
+// expansion, they can't be bundled and fail at runtime
+pub struct DynamicRequirePlugin {}
+
+impl Plugin for DynamicRequirePlugin {
+    fn name(&self) -> &str {
+        "dynamic_require"
+    }
+
+    fn transform_js(
+        &self,
+        param: &PluginTransformJsParam,
+        ast: &mut Module,
+        context: &Arc<Context>,
+    ) -> Result<()> {
+        // the dynamic requires of the dependencies are usually guarded
+        // e.g. optional peer dependencies
+        if param.path.contains("node_modules") {
+            return Ok(());
+        }
+        let mut visitor = DynamicRequireVisitor {
+            unresolved_mark: param.unresolved_mark,
+            spans: vec![],
+        };
+        ast.visit_with(&mut visitor);
+
+        // the warnings are printed again by the next run
+        if !visitor.spans.is_empty() {
+            context.skip_persistent_cache(param.path);
+        }
+        for span in visitor.spans {
+            match context.config.dynamic_require {
+                DynamicRequireLevel::Error => {
+                    param.handler.struct_span_err(span, MESSAGE).emit();
+                }
+                DynamicRequireLevel::Warn => {
+                    let loc = context.meta.script.cm.lookup_char_pos(span.lo);
+                    warn!(
+                        "{} at {}:{}:{}",
+                        MESSAGE,
+                        param.path,
+                        loc.line,
+                        loc.col_display + 1
+                    );
+                }
+                DynamicRequireLevel::Ignore => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+struct DynamicRequireVisitor {
+    unresolved_mark: Mark,
+    spans: Vec<Span>,
+}
+
+impl Visit for DynamicRequireVisitor {
+    fn visit_call_expr(&mut self, expr: &CallExpr) {
+        if is_commonjs_require(expr, &self.unresolved_mark) || is_dynamic_import(expr) {
+            let is_static = matches!(
+                expr.args.first(),
+                None | Some(ExprOrSpread {
+                    expr: box Expr::Lit(Lit::Str(_)),
+                    spread: None,
+                })
+            );
+            if !is_static {
+                self.spans.push(expr.span);
+            }
+        }
+        expr.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::test_helper::setup_compiler;
+
+    #[test]
+    fn test_dynamic_require_error() {
+        let compiler = setup_compiler("test/build/dynamic-require", false);
+        let err = compiler.compile().unwrap_err().to_string();
+        assert!(err.contains(super::MESSAGE));
+        assert!(err.contains("index.js"));
+        // the context modules are expanded, only `require(someVar)` is reported
+        assert_eq!(err.matches(super::MESSAGE).count(), 1);
+    }
+}
@@ -13,7 +13,7 @@ impl Plugin for HMRRuntimePlugin {
     }
 
     fn runtime_plugins(&self, context: &Arc<Context>) -> anyhow::Result<Vec<String>> {
-        if context.args.watch {
+        if context.args.watch && context.config.hmr.is_some() {
             Ok(vec![include_str!("hmr_runtime/hmr_runtime.js").to_string()])
         } else {
             Ok(vec![])
@@ -8,13 +8,28 @@
 !(function () {
   let currentParents = [];
   let currentChildModule;
+  let currentDynamicModule;
+  let dynamicRequest = false;
+  // the plain css modules of a css only update, they export nothing and their
+  // stylesheets are swapped by hmrC.css, so the parents are not run again
+  let cssOnlyModules = [];
   requireModule.hmrC = {};
   const createHmrRequire = (require, moduleId) => {
     const me = modulesRegistry[moduleId];
     if (!me) return require;
     const fn = (request) => {
+      const isDynamic = dynamicRequest;
+      dynamicRequest = false;
       if (me.hot.active) {
-        if (modulesRegistry[request]) {
+        if (isDynamic) {
+          // the importer of import() is not a parent, the hot update stops at
+          // the dynamically imported module
+          if (!modulesRegistry[request]) {
+            currentParents = [];
+            currentChildModule = request;
+            currentDynamicModule = request;
+          }
+        } else if (modulesRegistry[request]) {
           const parents = modulesRegistry[request].parents;
           if (!parents.includes(moduleId)) {
             parents.push(moduleId);
@@ -31,13 +46,22 @@
       return require(request);
     };
     Object.assign(fn, require);
+    fn.dr = (interop, mr) =>
+      require.dr(interop, () => {
+        dynamicRequest = true;
+        return mr();
+      });
     return fn;
   };
+  const isBoundary = (module) =>
+    module.hot._selfAccepted ||
+    (module.hot._dynamic && module.parents.length === 0);
   const applyHotUpdate = (_chunkId, update) => {
     const { modules, removedModules } = update;
     const outdatedModules = [];
     for (const moduleId of Object.keys(modules)) {
       if (!modulesRegistry[moduleId]) continue;
+      if (cssOnlyModules.includes(moduleId)) continue;
       if (outdatedModules.includes(moduleId)) continue;
       outdatedModules.push(moduleId);
       const queue = [moduleId];
@@ -48,7 +72,7 @@
         if (module.hot._main) {
           location.reload();
         }
-        if (module.hot._selfAccepted) {
+        if (isBoundary(module)) {
           continue;
         }
         for (const parentModule of module.parents) {
@@ -61,7 +85,7 @@
     const outdatedSelfAcceptedModules = [];
     for (const moduleId of outdatedModules) {
       const module = modulesRegistry[moduleId];
-      if (module.hot._selfAccepted) {
+      if (isBoundary(module)) {
         outdatedSelfAcceptedModules.push(module);
       }
     }
@@ -88,6 +112,7 @@
   };
   const createModuleHotObject = (moduleId, me) => {
     const _main = currentChildModule !== moduleId;
+    const _dynamic = currentDynamicModule === moduleId;
     const hot = {
       _acceptedDependencies: {},
       _declinedDependencies: {},
@@ -98,9 +123,11 @@
       _requireSelf: function () {
         currentParents = me.parents.slice();
         currentChildModule = _main ? undefined : moduleId;
+        currentDynamicModule = _dynamic ? moduleId : undefined;
         requireModule(moduleId);
       },
       _main,
+      _dynamic,
       active: true,
       accept() {
         this._selfAccepted = true;
@@ -120,7 +147,8 @@
             Object.assign(cssChunksIdToUrlMap, chunksUrlMap.css);
           });
       },
-      check() {
+      check(cssModules) {
+        cssOnlyModules = cssModules || [];
         const current_hash = requireModule.currentHash();
         return fetch(
           `${requireModule.publicPath}${current_hash}.hot-update.json`,
@@ -154,6 +182,7 @@
       },
     };
     currentChildModule = undefined;
+    currentDynamicModule = undefined;
     return hot;
   };
   requireModule.hmrC.jsonp = (chunkId, update, promises) => {
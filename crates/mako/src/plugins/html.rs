@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+use std::fs;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, Result};
+use regex::{Captures, Regex};
+
+use crate::compiler::Context;
+use crate::features::sri;
+use crate::generate::chunk::ChunkType;
+use crate::generate::generate_chunks::ChunkFile;
+use crate::plugin::Plugin;
+use crate::stats::StatsJsonMap;
+
+#[derive(Default)]
+pub struct HtmlPlugin {
+    // emitted file -> media, of the css split out with `splitCSSMedia`
+    css_media: Mutex<HashMap<String, String>>,
+}
+
+impl HtmlPlugin {
+    // js and css files of the entry chunk, with its runtime chunk and the sync
+    // chunks it depends on first
+    fn collect_files(entry: &str, context: &Arc<Context>) -> Vec<String> {
+        let chunk_graph = context.chunk_graph.read().unwrap();
+        let chunk = chunk_graph.get_chunks().into_iter().find(
+            |chunk| matches!(&chunk.chunk_type, ChunkType::Entry(_, name, false) if name == entry),
+        );
+        let chunk = match chunk {
+            Some(chunk) => chunk,
+            None => return vec![],
+        };
+        let mut chunk_ids = chunk
+            .chunk_type
+            .runtime_chunk_id(context.config.runtime_chunk)
+            .into_iter()
+            .collect::<Vec<_>>();
+        chunk_ids.extend(chunk_graph.sync_dependencies_chunk(&chunk.id));
+        chunk_ids.push(chunk.id.clone());
+        let assets = context.stats_info.get_assets();
+        chunk_ids
+            .iter()
+            .flat_map(|chunk_id| {
+                assets
+                    .iter()
+                    .filter(|asset| asset.chunk_id == chunk_id.id)
+                    .filter(|asset| {
+                        asset.hashname.ends_with(".js") || asset.hashname.ends_with(".css")
+                    })
+                    .map(|asset| asset.hashname.clone())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+impl Plugin for HtmlPlugin {
+    fn name(&self) -> &str {
+        "html"
+    }
+
+    fn after_generate_chunk_files(
+        &self,
+        chunk_files: &[ChunkFile],
+        _context: &Arc<Context>,
+    ) -> Result<()> {
+        let mut css_media = self.css_media.lock().unwrap();
+        for file in chunk_files {
+            if let Some(media) = &file.media {
+                css_media.insert(file.disk_name(), media.clone());
+            }
+        }
+        Ok(())
+    }
+
+    fn build_success(&self, _stats: &StatsJsonMap, context: &Arc<Context>) -> Result<()> {
+        let public_path = match context.config.public_path.as_str() {
+            "runtime" => "",
+            public_path => public_path,
+        };
+        for (entry, html_config) in context.config.html.iter() {
+            let template_path = context.root.join(&html_config.template);
+            let template = fs::read_to_string(&template_path).map_err(|e| {
+                anyhow!(
+                    "failed to read html template {}: {}",
+                    template_path.display(),
+                    e
+                )
+            })?;
+            let css_media = self.css_media.lock().unwrap();
+            let mut files = HtmlPlugin::collect_files(entry, context);
+            // after the other css, which they override like the `@media` rules did
+            files.sort_by_key(|file| css_media.contains_key(file));
+            let tags = files
+                .iter()
+                .map(|file| {
+                    let mut attributes = match css_media.get(file) {
+                        Some(media) => format!(r#" media="{}""#, media),
+                        None => String::new(),
+                    };
+                    attributes.push_str(&sri::tag_attributes(context, file)?);
+                    Ok(tag(public_path, file, &attributes))
+                })
+                .collect::<Result<Vec<_>>>()?
+                .join("");
+            let html = render(&template, &html_config.variables);
+            let html = inject_tags(&html, &tags, html_config.inject.as_deref());
+            let filename = html_config
+                .filename
+                .clone()
+                .unwrap_or_else(|| format!("{}.html", entry));
+            let to = context.output_path().join(filename);
+            if let Some(parent) = to.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&to, html)?;
+            context.written_files.lock().unwrap().push(to);
+        }
+        Ok(())
+    }
+}
+
+fn tag(public_path: &str, file: &str, attributes: &str) -> String {
+    if file.ends_with(".css") {
+        format!(
+            r#"<link rel="stylesheet" href="{}{}"{} />"#,
+            public_path, file, attributes
+        )
+    } else {
+        format!(
+            r#"<script src="{}{}"{}></script>"#,
+            public_path, file, attributes
+        )
+    }
+}
+
+// replace <%= name %> placeholders, unknown ones are kept as-is
+fn render(template: &str, variables: &HashMap<String, String>) -> String {
+    let re = Regex::new(r"<%=\s*([\w.]+)\s*%>").unwrap();
+    re.replace_all(template, |caps: &Captures| match variables.get(&caps[1]) {
+        Some(value) => value.clone(),
+        None => caps[0].to_string(),
+    })
+    .to_string()
+}
+
+fn inject_tags(html: &str, tags: &str, marker: Option<&str>) -> String {
+    if let Some(marker) = marker
+        && html.contains(marker)
+    {
+        return html.replacen(marker, tags, 1);
+    }
+    match html.find("</body>") {
+        Some(index) => format!("{}{}{}", &html[..index], tags, &html[index..]),
+        None => format!("{}{}", html, tags),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let variables = HashMap::from([("title".to_string(), "Home".to_string())]);
+        assert_eq!(
+            render(
+                "<title><%= title %></title><%=title%><%= foo %>",
+                &variables
+            ),
+            "<title>Home</title>Home<%= foo %>"
+        );
+    }
+
+    #[test]
+    fn test_inject_tags() {
+        assert_eq!(
+            inject_tags("<body><div></div></body>", "<script></script>", None),
+            "<body><div></div><script></script></body>"
+        );
+        assert_eq!(
+            inject_tags(
+                "<head><!-- inject --></head><body></body>",
+                "<script></script>",
+                Some("<!-- inject -->")
+            ),
+            "<head><script></script></head><body></body>"
+        );
+        assert_eq!(
+            inject_tags("<div></div>", "<script></script>", Some("<!-- inject -->")),
+            "<div></div><script></script>"
+        );
+    }
+
+    #[test]
+    fn test_tag() {
+        assert_eq!(
+            tag("/", "index.css", ""),
+            r#"<link rel="stylesheet" href="/index.css" />"#
+        );
+        assert_eq!(
+            tag("", "index.js", ""),
+            r#"<script src="index.js"></script>"#
+        );
+        assert_eq!(
+            tag("/", "index.js", r#" crossorigin="anonymous""#),
+            r#"<script src="/index.js" crossorigin="anonymous"></script>"#
+        );
+    }
+}
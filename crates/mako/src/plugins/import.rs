@@ -1,4 +1,6 @@
 use anyhow;
+use pathdiff::diff_paths;
+use serde::Serialize;
 use swc_core::ecma::ast::{
     ImportDefaultSpecifier, ImportNamedSpecifier, ImportSpecifier, Module, ModuleDecl,
     ModuleExportName, ModuleItem, Str,
@@ -9,11 +11,31 @@ use swc_core::ecma::visit::{VisitMut, VisitMutWith};
 use crate::config::{TransformImportConfig, TransformImportStyle};
 use crate::plugin::Plugin;
 
+/// A named import rewritten by `transformImport`, e.g. `Button` of `antd` is
+/// rewritten to `antd/lib/button`
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ImportRewriteInfo {
+    pub path: String,
+    pub source: String,
+    pub imported: String,
+    pub rewritten: String,
+}
+
 pub struct ImportVisitor<'a> {
     config: &'a Vec<TransformImportConfig>,
+    path: String,
+    rewrites: Vec<ImportRewriteInfo>,
 }
 
 impl<'a> ImportVisitor<'a> {
+    pub fn new(config: &'a Vec<TransformImportConfig>, path: String) -> Self {
+        Self {
+            config,
+            path,
+            rewrites: vec![],
+        }
+    }
+
     fn find_import_config(&self, src: &str) -> Option<&TransformImportConfig> {
         self.config.iter().find(|config| config.library_name == src)
     }
@@ -95,6 +117,12 @@ impl<'a> VisitMut for ImportVisitor<'a> {
                                     acc
                                 })
                         );
+                        self.rewrites.push(ImportRewriteInfo {
+                            path: self.path.clone(),
+                            source: decl.src.value.to_string(),
+                            imported: imported.to_string(),
+                            rewritten: member_src.clone(),
+                        });
                         let member_specifier = ImportDefaultSpecifier {
                             span: member.span,
                             local: member.local.clone(),
@@ -178,9 +206,26 @@ impl Plugin for ImportPlugin {
             return Ok(());
         }
 
-        ast.visit_mut_with(&mut ImportVisitor {
-            config: &context.config.transform_import,
-        });
+        let path = diff_paths(param.path, &context.root)
+            .map(|path| path.to_string_lossy().to_string())
+            .unwrap_or_else(|| param.path.to_string());
+        let mut visitor = ImportVisitor::new(&context.config.transform_import, path);
+        ast.visit_mut_with(&mut visitor);
+
+        // only recorded for debugging since it may be noisy
+        if context
+            .config
+            .stats
+            .as_ref()
+            .is_some_and(|s| s.import_rewrites)
+        {
+            if !visitor.rewrites.is_empty() {
+                context.skip_persistent_cache(param.path);
+            }
+            for rewrite in visitor.rewrites {
+                context.stats_info.add_import_rewrite(rewrite);
+            }
+        }
 
         Ok(())
     }
@@ -195,7 +240,8 @@ mod tests {
     use crate::ast::js_ast::JsAst;
     use crate::compiler::Context;
     use crate::config::{TransformImportConfig, TransformImportStyle};
-    use crate::plugins::import::ImportVisitor;
+    use crate::plugins::import::{ImportRewriteInfo, ImportVisitor};
+    use crate::utils::test_helper::setup_compiler;
 
     #[test]
     fn test_multi() {
@@ -352,11 +398,27 @@ import DatePicker from "antd/lib/date-picker";
         );
     }
 
+    #[test]
+    fn test_import_rewrites() {
+        let compiler = setup_compiler("test/build/transform-import", false);
+        compiler.compile().unwrap();
+        assert_eq!(
+            compiler.context.stats_info.get_import_rewrites(),
+            vec![ImportRewriteInfo {
+                path: "index.ts".to_string(),
+                source: "foo".to_string(),
+                imported: "DatePicker".to_string(),
+                rewritten: "foo/lib/date-picker".to_string(),
+            }]
+        );
+    }
+
     fn generate(code: &str, config: &Vec<TransformImportConfig>) -> String {
         let path = "/test/path";
         let context: Arc<Context> = Arc::new(Default::default());
         let mut ast = JsAst::build(path, code, context.clone()).unwrap();
-        ast.ast.visit_mut_with(&mut ImportVisitor { config });
+        ast.ast
+            .visit_mut_with(&mut ImportVisitor::new(config, path.to_string()));
         ast.generate(context.clone()).unwrap().code
     }
 }
@@ -4,9 +4,10 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use regex::Regex;
-use serde_json;
+use serde_json::{json, Value};
 
 use crate::compiler::Context;
+use crate::features::sri;
 use crate::plugin::Plugin;
 use crate::stats::StatsJsonMap;
 
@@ -24,7 +25,7 @@ impl Plugin for ManifestPlugin {
     fn build_success(&self, _stats: &StatsJsonMap, context: &Arc<Context>) -> Result<()> {
         if let Some(manifest_config) = &context.config.manifest {
             let assets = &context.stats_info.get_assets();
-            let mut manifest: BTreeMap<String, String> = BTreeMap::new();
+            let mut manifest: BTreeMap<String, Value> = BTreeMap::new();
             let file_name = manifest_config.file_name.clone();
             let base_path = manifest_config.base_path.clone();
 
@@ -32,14 +33,30 @@ impl Plugin for ManifestPlugin {
 
             for asset in assets {
                 let key = format!("{}{}", path, remove_key_hash(&asset.hashname));
-                manifest.insert(key, asset.hashname.clone());
+                let value = match manifest_config.integrity {
+                    // the files are emitted already, so it's the digest of
+                    // the minified content which the browsers check
+                    Some(algorithm) => {
+                        let content = match context.get_static_content(&asset.hashname) {
+                            Some(content) => content,
+                            None => fs::read(context.output_path().join(&asset.hashname))?,
+                        };
+                        json!({
+                            "file": asset.hashname,
+                            "integrity": sri::integrity(algorithm, &content),
+                        })
+                    }
+                    None => Value::String(asset.hashname.clone()),
+                };
+                manifest.insert(key, value);
             }
 
             let manifest_json = serde_json::to_string_pretty(&manifest)?;
 
-            let output_path = context.config.output.path.join(file_name);
+            let output_path = context.output_path().join(file_name);
 
-            fs::write(output_path, manifest_json).unwrap();
+            fs::write(&output_path, manifest_json).unwrap();
+            context.written_files.lock().unwrap().push(output_path);
         }
         Ok(())
     }
@@ -58,3 +75,33 @@ fn remove_key_hash(key: &str) -> String {
     let val = reg.replace_all(key, "").to_string();
     val
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use base64::engine::general_purpose;
+    use base64::Engine;
+    use serde_json::Value;
+    use sha2::{Digest, Sha384};
+
+    use crate::utils::test_helper::setup_compiler;
+
+    #[test]
+    fn test_manifest_integrity() {
+        let compiler = setup_compiler("test/build/manifest-integrity", false);
+        compiler.compile().unwrap();
+
+        let output_path = &compiler.context.config.output.path;
+        let manifest = fs::read_to_string(output_path.join("asset-manifest.json")).unwrap();
+        let manifest: Value = serde_json::from_str(&manifest).unwrap();
+        for key in ["index.js", "index.css"] {
+            let entry = &manifest[key];
+            let file = entry["file"].as_str().unwrap();
+            assert_ne!(file, key);
+            let content = fs::read(output_path.join(file)).unwrap();
+            let digest = general_purpose::STANDARD.encode(Sha384::digest(&content));
+            assert_eq!(entry["integrity"], format!("sha384-{}", digest));
+        }
+    }
+}
@@ -1,19 +1,26 @@
 pub mod async_runtime;
+pub mod build_log;
 pub mod bundless_compiler;
 pub mod context_module;
 pub mod copy;
 pub mod detect_circular_dependence;
+pub mod dynamic_require;
 pub mod emotion;
 pub mod graphviz;
 pub mod hmr_runtime;
+pub mod html;
 pub mod ignore;
 pub mod import;
 pub mod invalid_webpack_syntax;
 pub mod manifest;
 pub mod minifish;
+pub mod preload;
 pub mod progress;
+pub mod public_dir;
 pub mod require_context;
+pub mod root_files;
 pub mod runtime;
 pub mod ssu;
 pub mod tree_shaking;
+pub mod unused_assets;
 pub mod wasm_runtime;
@@ -0,0 +1,142 @@
+use std::fs;
+use std::sync::Arc;
+
+use anyhow::Result;
+use indexmap::IndexSet;
+use tracing::warn;
+
+use crate::compiler::Context;
+use crate::features::sri;
+use crate::generate::chunk::ChunkId;
+use crate::module::ModuleId;
+use crate::plugin::Plugin;
+use crate::stats::StatsJsonMap;
+
+pub struct PreloadPlugin {}
+
+impl PreloadPlugin {
+    // find the files of chunks which contain the configured modules,
+    // including the sync chunks they depend on
+    fn collect_files(context: &Arc<Context>) -> Vec<String> {
+        let chunk_graph = context.chunk_graph.read().unwrap();
+        let mut chunk_ids: IndexSet<ChunkId> = IndexSet::new();
+        for path in context.config.preload_chunks.iter() {
+            let module_id = ModuleId::from_path(context.root.join(path));
+            match chunk_graph.get_chunk_for_module(&module_id) {
+                Some(chunk) => {
+                    chunk_ids.extend(chunk_graph.sync_dependencies_chunk(&chunk.id));
+                    chunk_ids.insert(chunk.id.clone());
+                }
+                None => {
+                    warn!("no chunk found for preloadChunks item {}", path);
+                }
+            }
+        }
+        let assets = context.stats_info.get_assets();
+        chunk_ids
+            .iter()
+            .flat_map(|chunk_id| {
+                assets
+                    .iter()
+                    .filter(|asset| asset.chunk_id == chunk_id.id)
+                    .filter(|asset| {
+                        asset.hashname.ends_with(".js") || asset.hashname.ends_with(".css")
+                    })
+                    .map(|asset| asset.hashname.clone())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+impl Plugin for PreloadPlugin {
+    fn name(&self) -> &str {
+        "preload"
+    }
+
+    fn build_success(&self, _stats: &StatsJsonMap, context: &Arc<Context>) -> Result<()> {
+        let files = PreloadPlugin::collect_files(context);
+        if files.is_empty() {
+            return Ok(());
+        }
+        let public_path = match context.config.public_path.as_str() {
+            "runtime" => "",
+            public_path => public_path,
+        };
+        let links = files
+            .iter()
+            .map(|file| {
+                Ok(preload_link(
+                    public_path,
+                    file,
+                    &sri::tag_attributes(context, file)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?
+            .join("");
+        for entry in fs::read_dir(context.output_path())? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "html") {
+                let html = fs::read_to_string(&path)?;
+                fs::write(&path, inject_links(&html, &links))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn preload_link(public_path: &str, file: &str, attributes: &str) -> String {
+    let as_type = if file.ends_with(".css") {
+        "style"
+    } else {
+        "script"
+    };
+    format!(
+        r#"<link rel="preload" href="{}{}" as="{}"{} />"#,
+        public_path, file, as_type, attributes
+    )
+}
+
+fn inject_links(html: &str, links: &str) -> String {
+    match html.find("</head>") {
+        Some(index) => format!("{}{}{}", &html[..index], links, &html[index..]),
+        None => format!("{}{}", links, html),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preload_link() {
+        assert_eq!(
+            preload_link("/", "src_home.12345678-async.js", ""),
+            r#"<link rel="preload" href="/src_home.12345678-async.js" as="script" />"#
+        );
+        assert_eq!(
+            preload_link("", "src_home-async.css", ""),
+            r#"<link rel="preload" href="src_home-async.css" as="style" />"#
+        );
+        assert_eq!(
+            preload_link(
+                "/",
+                "index.js",
+                r#" integrity="sha384-abc" crossorigin="anonymous""#
+            ),
+            r#"<link rel="preload" href="/index.js" as="script" integrity="sha384-abc" crossorigin="anonymous" />"#
+        );
+    }
+
+    #[test]
+    fn test_inject_links() {
+        assert_eq!(
+            inject_links("<html><head><title>a</title></head></html>", "<link />"),
+            "<html><head><title>a</title><link /></head></html>"
+        );
+        assert_eq!(
+            inject_links("<div></div>", "<link />"),
+            "<link /><div></div>"
+        );
+    }
+}
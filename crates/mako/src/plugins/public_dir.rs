@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::Result;
+use tracing::{debug, warn};
+
+use crate::compiler::Context;
+use crate::plugin::Plugin;
+use crate::stats::StatsJsonMap;
+
+pub struct PublicDirPlugin {}
+
+impl PublicDirPlugin {
+    // returns the files written to the output
+    fn copy(public_dir: &Path, context: &Arc<Context>) -> Result<Vec<PathBuf>> {
+        let dest = context.output_path();
+        let emitted = context
+            .stats_info
+            .get_assets()
+            .into_iter()
+            .map(|asset| asset.hashname)
+            .collect::<HashSet<_>>();
+        let mut written = vec![];
+        for file in collect_files(public_dir)? {
+            let relative = file.strip_prefix(public_dir)?;
+            let name = relative.to_string_lossy().replace('\\', "/");
+            if emitted.contains(&name) {
+                warn!(
+                    "{} in publicDir conflicts with the emitted asset, skipped",
+                    name
+                );
+                continue;
+            }
+            let to = dest.join(relative);
+            debug!("copy {:?} to {:?}", file, to);
+            if let Some(parent) = to.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(&file, &to)?;
+            written.push(to);
+        }
+        Ok(written)
+    }
+}
+
+impl Plugin for PublicDirPlugin {
+    fn name(&self) -> &str {
+        "public_dir"
+    }
+
+    fn build_success(&self, _stats: &StatsJsonMap, context: &Arc<Context>) -> Result<()> {
+        // in dev, files are served from publicDir directly by the dev server
+        if context.args.watch {
+            return Ok(());
+        }
+        if let Some(public_dir) = get_public_dir(context)
+            && public_dir.is_dir()
+        {
+            let written = PublicDirPlugin::copy(&public_dir, context)?;
+            context.written_files.lock().unwrap().extend(written);
+        }
+        Ok(())
+    }
+}
+
+pub fn get_public_dir(context: &Context) -> Option<PathBuf> {
+    context
+        .config
+        .public_dir
+        .as_ref()
+        .map(|public_dir| context.root.join(public_dir))
+}
+
+pub(crate) fn collect_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(collect_files(&path)?);
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
@@ -0,0 +1,68 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use tracing::debug;
+
+use crate::compiler::Context;
+use crate::plugin::Plugin;
+use crate::stats::StatsJsonMap;
+
+// Files like `_headers`, `_redirects` and `robots.txt` which static hosts
+// read from the root of the site, they are copied to the output root as is,
+// in the configured order, no matter what publicPath is.
+pub struct RootFilesPlugin {}
+
+impl RootFilesPlugin {
+    // returns the files written to the output
+    fn copy(context: &Arc<Context>) -> Result<Vec<PathBuf>> {
+        let dest = context.output_path();
+        fs::create_dir_all(&dest)?;
+        let mut written = vec![];
+        for file in context.config.root_files.iter() {
+            let src = context.root.join(file);
+            if !src.is_file() {
+                return Err(anyhow!("rootFiles {} is not found", file));
+            }
+            let to = dest.join(src.file_name().unwrap());
+            debug!("copy {:?} to {:?}", src, to);
+            fs::copy(&src, &to)?;
+            written.push(to);
+        }
+        Ok(written)
+    }
+}
+
+impl Plugin for RootFilesPlugin {
+    fn name(&self) -> &str {
+        "root_files"
+    }
+
+    fn build_success(&self, _stats: &StatsJsonMap, context: &Arc<Context>) -> Result<()> {
+        let written = RootFilesPlugin::copy(context)?;
+        context.written_files.lock().unwrap().extend(written);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::utils::test_helper::setup_compiler;
+
+    #[test]
+    fn test_root_files() {
+        let compiler = setup_compiler("test/build/root-files", false);
+        compiler.compile().unwrap();
+        let output = &compiler.context.config.output.path;
+        assert_eq!(compiler.context.config.public_path, "/app/");
+        assert_eq!(
+            fs::read_to_string(output.join("_redirects")).unwrap(),
+            "/old /new 301\n"
+        );
+        assert!(output.join("robots.txt").is_file());
+        assert!(!output.join("app/_redirects").exists());
+    }
+}
@@ -174,6 +174,7 @@ impl Plugin for SUPlus {
                             min_module_size: None,
                             priority: 10,
                             test: Regex::new(r"[/\\]node_modules[/\\]").ok(),
+                            enforce: false,
                         },
                         OptimizeChunkGroup {
                             name: "common".to_string(),
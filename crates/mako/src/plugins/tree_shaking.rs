@@ -1,9 +1,10 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use anyhow::Result;
 use swc_core::common::util::take::Take;
 use swc_core::ecma::ast::{Decl, Module, ModuleItem, Stmt, VarDecl};
 use swc_core::ecma::visit::{VisitMut, VisitMutWith};
+use tracing::warn;
 
 use crate::compiler::Context;
 use crate::module_graph::ModuleGraph;
@@ -15,7 +16,21 @@ mod remove_useless_stmts;
 mod shake;
 mod statement_graph;
 
-pub struct FarmTreeShake {}
+pub(crate) use module::TreeShakingInfo;
+pub use shake::TreeShakingWarning;
+
+#[derive(Default)]
+pub struct FarmTreeShake {
+    warnings: Mutex<Vec<TreeShakingWarning>>,
+}
+
+impl FarmTreeShake {
+    /// Warnings of the last tree shaking, like the modules which are not
+    /// fully tree-shaken because of ambiguous exports
+    pub fn warnings(&self) -> Vec<TreeShakingWarning> {
+        self.warnings.lock().unwrap().clone()
+    }
+}
 
 impl Plugin for FarmTreeShake {
     fn name(&self) -> &str {
@@ -39,7 +54,11 @@ impl Plugin for FarmTreeShake {
         module_graph: &mut ModuleGraph,
         context: &Arc<Context>,
     ) -> Result<()> {
-        shake::optimize_modules(module_graph, context)?;
+        let warnings = shake::optimize_modules(module_graph, context)?;
+        for warning in warnings.iter() {
+            warn!("{}", warning);
+        }
+        *self.warnings.lock().unwrap() = warnings;
         Ok(())
     }
 }
@@ -1,13 +1,17 @@
 use std::collections::{BTreeMap, HashSet};
 
-use swc_core::common::SyntaxContext;
+use serde::Serialize;
+use swc_core::common::{Spanned, SyntaxContext};
 use swc_core::ecma::ast::{Module as SwcModule, ModuleItem};
 
-use crate::module::{Module, ModuleId};
+use crate::ast::comments::MakoComments;
+use crate::config::TreeShakingLevel;
+use crate::module::{Module, ModuleId, ModuleInfo};
 use crate::plugins::tree_shaking::statement_graph::{
     ExportInfo, ExportInfoMatch, ExportSource, ExportSpecifierInfo, ImportInfo, StatementGraph,
     StatementId,
 };
+use crate::resolve::{ResolvedResource, ResolverResource};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum UsedIdent {
@@ -39,6 +43,37 @@ pub enum ModuleSystem {
     Custom,
 }
 
+// like node, `.mjs` is always esm and `.cjs` is always cjs, `.js` is esm in
+// the packages of `"type": "module"`, the others are told by the content
+fn described_module_system(module_info: &ModuleInfo) -> Option<ModuleSystem> {
+    match module_info.file.extname.as_str() {
+        "mjs" => Some(ModuleSystem::ESModule),
+        "cjs" => Some(ModuleSystem::CommonJS),
+        "js" => {
+            if let Some(ResolverResource::Resolved(ResolvedResource(source))) =
+                &module_info.resolved_resource
+                && let Some(desc) = source.package_json()
+                && desc.raw_json().get("type").and_then(|t| t.as_str()) == Some("module")
+            {
+                Some(ModuleSystem::ESModule)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// How much of a module is removed by tree shaking, it's in the stats as
+/// `treeShaking`
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TreeShakingInfo {
+    pub total_statements: usize,
+    pub retained_statements: usize,
+    pub removed_idents: usize,
+}
+
 #[derive(Debug, Clone)]
 pub enum UsedExports {
     All,
@@ -173,11 +208,18 @@ pub struct TreeShakeModule {
     pub updated_ast: Option<SwcModule>,
     pub side_effect_dep_sources: HashSet<String>,
     pub unresolved_ctxt: SyntaxContext,
+    pub level: TreeShakingLevel,
+    /// For the `/*#__PURE__*/` annotations
+    pub comments: MakoComments,
+    /// All the exports are kept for the code outside of the bundle, by the
+    /// `keepExports` config or the `/*@__KEEP_EXPORTS__*/` annotation
+    pub keep_exports: bool,
 }
 
 impl TreeShakeModule {
     pub fn update_stmt_graph(&mut self, module: &SwcModule) {
-        let stmt_graph = StatementGraph::new(module, self.unresolved_ctxt);
+        let stmt_graph =
+            StatementGraph::new(module, self.unresolved_ctxt, self.level, &self.comments);
 
         self.stmt_graph = stmt_graph;
     }
@@ -244,6 +286,20 @@ impl TreeShakeModule {
         self.used_exports.use_all()
     }
 
+    pub fn keep_all_exports(&mut self) {
+        self.keep_exports = true;
+        self.used_exports.use_all();
+    }
+
+    /// Replace the `sideEffects` of the package.json, `true` keeps the module
+    /// even if nothing of it is used
+    pub fn override_side_effects(&mut self, side_effects: bool) {
+        self.described_side_effects = Some(side_effects);
+        if side_effects {
+            self.side_effects = true;
+        }
+    }
+
     pub fn add_used_export(&mut self, used_export: Option<&dyn ToString>) -> bool {
         if let Some(used_export) = used_export {
             if self.side_effects {
@@ -277,7 +333,12 @@ impl TreeShakeModule {
         self.used_exports.is_empty()
     }
 
-    pub fn new(module: &Module, order: usize) -> Self {
+    pub fn new(
+        module: &Module,
+        order: usize,
+        level: TreeShakingLevel,
+        comments: MakoComments,
+    ) -> Self {
         let module_info = module.info.as_ref().unwrap();
 
         let mut unresolved_ctxt = SyntaxContext::empty();
@@ -285,15 +346,18 @@ impl TreeShakeModule {
         let mut module_system = ModuleSystem::CommonJS;
         let stmt_graph = match &module_info.ast {
             crate::module::ModuleAst::Script(module) => {
-                let is_esm = module
-                    .ast
-                    .body
-                    .iter()
-                    .any(|s| matches!(s, ModuleItem::ModuleDecl(_)));
+                let is_esm = match described_module_system(module_info) {
+                    Some(described) => described == ModuleSystem::ESModule,
+                    None => module
+                        .ast
+                        .body
+                        .iter()
+                        .any(|s| matches!(s, ModuleItem::ModuleDecl(_))),
+                };
                 if is_esm {
                     module_system = ModuleSystem::ESModule;
                     unresolved_ctxt = unresolved_ctxt.apply_mark(module.unresolved_mark);
-                    StatementGraph::new(&module.ast, unresolved_ctxt)
+                    StatementGraph::new(&module.ast, unresolved_ctxt, level, &comments)
                 } else {
                     StatementGraph::empty()
                 }
@@ -308,7 +372,18 @@ impl TreeShakeModule {
             }
         };
 
-        let used_exports = if module.is_entry {
+        // the annotation leads a top level statement, usually the first one,
+        // the helper imports injected by the transforms may come before it
+        let keep_exports = match &module_info.ast {
+            crate::module::ModuleAst::Script(module) => module
+                .ast
+                .body
+                .iter()
+                .any(|item| comments.has_keep_exports(item.span())),
+            _ => false,
+        };
+
+        let used_exports = if module.is_entry || keep_exports {
             UsedExports::All
         } else {
             UsedExports::Partial(Default::default())
@@ -318,7 +393,11 @@ impl TreeShakeModule {
             module_id: module.id.clone(),
             stmt_graph,
             used_exports,
-            described_side_effects: module.info.as_ref().unwrap().described_side_effect(),
+            // the sideEffects field may be wrong, don't trust it in the safe level
+            described_side_effects: match level {
+                TreeShakingLevel::Safe => None,
+                _ => module.info.as_ref().unwrap().described_side_effect(),
+            },
             side_effects: module_system != ModuleSystem::ESModule,
             side_effect_dep_sources: Default::default(),
             all_exports: match module_system {
@@ -331,6 +410,9 @@ impl TreeShakeModule {
             topo_order: order,
             updated_ast: None,
             unresolved_ctxt,
+            level,
+            comments,
+            keep_exports,
         }
     }
 
@@ -443,6 +525,75 @@ impl TreeShakeModule {
             .analyze_used_statements_and_idents(stmt_used_idents_map)
     }
 
+    pub fn tree_shaking_info(&self) -> TreeShakingInfo {
+        let stmts = self.stmt_graph.stmts();
+        let used_statements = if self.not_used() {
+            Default::default()
+        } else {
+            self.used_statements()
+        };
+        let removed_idents = stmts
+            .iter()
+            .map(|stmt| match used_statements.get(&stmt.id) {
+                Some(used_idents) => stmt.defined_idents.difference(used_idents).count(),
+                None => stmt.defined_idents.len(),
+            })
+            .sum();
+
+        TreeShakingInfo {
+            total_statements: stmts.len(),
+            retained_statements: used_statements.len(),
+            removed_idents,
+        }
+    }
+
+    /// The shaking decision of each export, e.g. `a (used), b (removed),
+    /// c (used, from "./c")`, for the `treeShakingAnnotations` comments
+    pub fn export_annotation(&self) -> String {
+        let is_used = |name: &str| match &self.used_exports {
+            UsedExports::All => true,
+            UsedExports::Partial(idents) | UsedExports::ReferredPartial(idents) => {
+                idents.iter().any(|ident| is_ident_equal(ident, name))
+            }
+        };
+        let decision = if self.keep_exports {
+            "kept by keepExports"
+        } else {
+            "used"
+        };
+
+        let mut annotations = vec![];
+        for export_info in self.exports() {
+            let names = export_info
+                .specifiers
+                .iter()
+                .flat_map(|sp| match sp {
+                    ExportSpecifierInfo::Default(_) => vec!["default".to_string()],
+                    ExportSpecifierInfo::Named { local, exported } => {
+                        vec![exported.as_ref().unwrap_or(local).clone()]
+                    }
+                    ExportSpecifierInfo::Namespace(ns) => vec![ns.clone()],
+                    ExportSpecifierInfo::All(idents) => idents.clone(),
+                    ExportSpecifierInfo::Ambiguous(_) => vec!["*".to_string()],
+                })
+                .collect::<Vec<_>>();
+            for name in names {
+                let used = name == "*" || is_used(&name);
+                let mut annotation = format!(
+                    "{} ({}",
+                    name.split('#').next().unwrap(),
+                    if used { decision } else { "removed" }
+                );
+                if let Some(source) = &export_info.source {
+                    annotation.push_str(&format!(", from \"{}\"", source));
+                }
+                annotation.push(')');
+                annotations.push(annotation);
+            }
+        }
+        annotations.join(", ")
+    }
+
     pub fn used_exports_idents(&self) -> Vec<(UsedIdent, StatementId)> {
         match &self.used_exports {
             UsedExports::All => {
@@ -589,3 +740,109 @@ pub fn is_ident_sym_equal(ident1: &str, ident2: &str) -> bool {
 
     split1[0] == split2[0]
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use swc_core::common::GLOBALS;
+
+    use super::{ModuleSystem, TreeShakeModule, UsedExports};
+    use crate::ast::file::{Content, File, JsContent};
+    use crate::ast::js_ast::JsAst;
+    use crate::compiler::Context;
+    use crate::config::TreeShakingLevel;
+    use crate::module::{Module, ModuleAst, ModuleInfo};
+
+    fn tsm_with_file(path: &str, code: &str) -> TreeShakeModule {
+        let context: Arc<Context> = Default::default();
+        let file = File::with_content(
+            path.to_string(),
+            Content::Js(JsContent {
+                content: code.to_string(),
+                ..Default::default()
+            }),
+            context.clone(),
+        );
+        let ast = JsAst::new(&file, context.clone()).unwrap();
+        let module = Module {
+            id: path.into(),
+            is_entry: false,
+            info: Some(ModuleInfo {
+                ast: ModuleAst::Script(ast),
+                file,
+                ..Default::default()
+            }),
+            side_effects: false,
+        };
+
+        let comments = context.meta.script.origin_comments.read().unwrap();
+        GLOBALS.set(&context.meta.script.globals, || {
+            TreeShakeModule::new(
+                &module,
+                0,
+                TreeShakingLevel::Recommended,
+                comments.get_swc_comments().clone(),
+            )
+        })
+    }
+
+    #[test]
+    fn test_module_system_by_content() {
+        let esm = tsm_with_file("a.js", "export const a = 1;");
+        assert_eq!(esm.module_system, ModuleSystem::ESModule);
+
+        let cjs = tsm_with_file("b.js", "exports.b = 1;");
+        assert_eq!(cjs.module_system, ModuleSystem::CommonJS);
+    }
+
+    #[test]
+    fn test_keep_exports_annotation() {
+        let kept = tsm_with_file("a.js", "/*@__KEEP_EXPORTS__*/\nexport const a = 1;");
+        assert!(kept.keep_exports);
+        assert!(matches!(kept.used_exports, UsedExports::All));
+
+        let shaken = tsm_with_file("b.js", "export const b = 1;");
+        assert!(!shaken.keep_exports);
+        assert!(shaken.not_used());
+    }
+
+    #[test]
+    fn test_cjs_extension() {
+        let tsm = tsm_with_file("a.cjs", "export const a = 1;");
+
+        assert_eq!(tsm.module_system, ModuleSystem::CommonJS);
+        assert!(tsm.stmt_graph.stmts().is_empty());
+    }
+
+    #[test]
+    fn test_mjs_extension() {
+        let tsm = tsm_with_file("a.mjs", "globalThis.a = 1;");
+
+        assert_eq!(tsm.module_system, ModuleSystem::ESModule);
+        assert_eq!(tsm.stmt_graph.stmts().len(), 1);
+    }
+
+    fn is_first_stmt_self_executed(code: &str) -> bool {
+        let tsm = tsm_with_file("a.mjs", code);
+        tsm.stmt_graph.stmt(&0).is_self_executed
+    }
+
+    #[test]
+    fn test_pure_annotation() {
+        assert!(is_first_stmt_self_executed("const x = f();"));
+        assert!(!is_first_stmt_self_executed("const x = /*#__PURE__*/ f();"));
+        assert!(!is_first_stmt_self_executed("/*#__PURE__*/ new Foo();"));
+        // the arguments are still checked
+        assert!(is_first_stmt_self_executed(
+            "const x = /*#__PURE__*/ f(g());"
+        ));
+        assert!(!is_first_stmt_self_executed(
+            "const x = /*#__PURE__*/ f(/*#__PURE__*/ g());"
+        ));
+        // only the call following the annotation is pure
+        assert!(is_first_stmt_self_executed(
+            "const x = /*#__PURE__*/ f() + g();"
+        ));
+    }
+}
@@ -33,6 +33,21 @@ impl ModuleInfo {
         }
     }
 
+    /// The name in the package.json of the package the module belongs to
+    pub fn package_name(&self) -> Option<String> {
+        if let Some(ResolverResource::Resolved(ResolvedResource(source))) = &self.resolved_resource
+        {
+            source
+                .package_json()?
+                .raw_json()
+                .get("name")?
+                .as_str()
+                .map(|name| name.to_string())
+        } else {
+            None
+        }
+    }
+
     /**
      * 获取当前的模块是否具备 sideEffects
      */
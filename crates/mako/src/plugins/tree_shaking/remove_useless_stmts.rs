@@ -39,6 +39,8 @@ pub fn remove_useless_stmts(
             module_item,
             Some(used_defined_idents.clone()),
             tree_shake_module.unresolved_ctxt,
+            tree_shake_module.level,
+            &tree_shake_module.comments,
         );
 
         if let Some(import_info) = import_info {
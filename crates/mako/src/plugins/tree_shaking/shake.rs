@@ -1,20 +1,30 @@
+mod class_member;
+mod class_method;
 mod find_export_source;
+mod inline_constants;
 mod module_concatenate;
+mod object_property;
 mod skip_module;
 
 use std::cell::RefCell;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::ops::DerefMut;
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use glob_match::glob_match;
 use rayon::prelude::*;
 use swc_core::common::util::take::Take;
 use swc_core::common::GLOBALS;
+use thiserror::Error;
 
+use self::class_member::remove_unused_class_members;
+use self::class_method::remove_unused_class_methods;
+use self::inline_constants::inline_constant_imports;
+use self::object_property::remove_unused_object_properties;
 use self::skip_module::skip_module_optimize;
 use crate::compiler::Context;
-use crate::module::{ModuleAst, ModuleId, ModuleType, ResolveType};
+use crate::module::{Module, ModuleAst, ModuleId, ModuleType, ResolveType};
 use crate::module_graph::ModuleGraph;
 use crate::plugins::tree_shaking::module::{AllExports, ModuleSystem, TreeShakeModule};
 use crate::plugins::tree_shaking::shake::module_concatenate::optimize_module_graph;
@@ -24,7 +34,68 @@ use crate::{mako_profile_function, mako_profile_scope};
 
 type TreeShakingModuleMap = HashMap<ModuleId, RefCell<TreeShakeModule>>;
 
-pub fn optimize_modules(module_graph: &mut ModuleGraph, context: &Arc<Context>) -> Result<()> {
+#[derive(Debug, Error)]
+pub enum TreeShakingError {
+    #[error("Duplicate export \"{name}\" in {module}, it's exported by both {first} and {second}")]
+    DuplicateExport {
+        module: String,
+        name: String,
+        first: String,
+        second: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TreeShakingWarning {
+    #[error(
+        "{module} is not fully tree-shaken, the `export *` of commonjs modules makes its exports ambiguous{}",
+        known_exports(.names)
+    )]
+    AmbiguousExports { module: String, names: Vec<String> },
+}
+
+// matches the `keepExports` globs
+fn is_exports_kept(module: &Module, context: &Context) -> bool {
+    let path = module
+        .info
+        .as_ref()
+        .unwrap()
+        .file
+        .relative_path
+        .to_string_lossy();
+    context
+        .config
+        .keep_exports
+        .iter()
+        .any(|pattern| glob_match(pattern.trim_start_matches("./"), &path))
+}
+
+// the most specific, i.e. the longest, of the matched package globs wins
+fn side_effects_override(module: &Module, context: &Context) -> Option<bool> {
+    let overrides = &context.config.side_effects_overrides;
+    if overrides.is_empty() {
+        return None;
+    }
+    let package_name = module.info.as_ref()?.package_name()?;
+    overrides
+        .iter()
+        .filter(|(pattern, _)| glob_match(pattern, &package_name))
+        .max_by_key(|(pattern, _)| pattern.len())
+        .map(|(_, side_effects)| *side_effects)
+}
+
+fn known_exports(names: &[String]) -> String {
+    if names.is_empty() {
+        "".to_string()
+    } else {
+        format!(", known exports: {}", names.join(", "))
+    }
+}
+
+pub fn optimize_modules(
+    module_graph: &mut ModuleGraph,
+    context: &Arc<Context>,
+) -> Result<Vec<TreeShakingWarning>> {
     let (topo_sorted_modules, _cyclic_modules) = {
         mako_profile_scope!("tree shake topo-sort");
         module_graph.toposort()
@@ -58,8 +129,28 @@ pub fn optimize_modules(module_graph: &mut ModuleGraph, context: &Arc<Context>)
             false
         });
 
+    // should be before the statement graphs are built
+    remove_unused_object_properties(module_graph, &tree_shake_modules_ids);
+    remove_unused_class_members(module_graph, &tree_shake_modules_ids);
+    if context
+        .config
+        .optimization
+        .as_ref()
+        .map_or(false, |o| o.class_methods.unwrap_or(false))
+    {
+        remove_unused_class_methods(module_graph, &tree_shake_modules_ids);
+    }
+
     let tree_shake_modules_map = {
         mako_profile_scope!("init_tree_shake_modules_map");
+        let comments = context
+            .meta
+            .script
+            .origin_comments
+            .read()
+            .unwrap()
+            .get_swc_comments()
+            .clone();
         tree_shake_modules_ids
             .par_iter()
             .enumerate()
@@ -68,14 +159,27 @@ pub fn optimize_modules(module_graph: &mut ModuleGraph, context: &Arc<Context>)
 
                 let module = module_graph.get_module(module_id).unwrap();
 
-                let tree_shake_module = GLOBALS.set(&context.meta.script.globals, || {
-                    TreeShakeModule::new(module, index)
+                let mut tree_shake_module = GLOBALS.set(&context.meta.script.globals, || {
+                    TreeShakeModule::new(
+                        module,
+                        index,
+                        context.config.tree_shaking_level,
+                        comments.clone(),
+                    )
                 });
+                if is_exports_kept(module, context) {
+                    tree_shake_module.keep_all_exports();
+                }
+                if let Some(side_effects) = side_effects_override(module, context) {
+                    tree_shake_module.override_side_effects(side_effects);
+                }
 
                 (module_id.clone(), RefCell::new(tree_shake_module))
             })
             .collect::<HashMap<_, _>>()
     };
+    check_duplicate_exports(&tree_shake_modules_ids, &tree_shake_modules_map)?;
+
     let mut current_index = (tree_shake_modules_ids.len() - 1) as i64;
 
     // update tree-shake module side_effects flag in reversed topo-sort order
@@ -114,13 +218,30 @@ pub fn optimize_modules(module_graph: &mut ModuleGraph, context: &Arc<Context>)
         module_graph,
     );
 
-    if let Some(optimization) = &context.config.optimization
-        && optimization.skip_modules.unwrap_or(false)
+    if context
+        .config
+        .optimization
+        .as_ref()
+        .map_or(false, |o| o.inline_constants.unwrap_or(false))
     {
+        inline_constant_imports(
+            module_graph,
+            &tree_shake_modules_ids,
+            &tree_shake_modules_map,
+        );
+    }
+
+    let skip_modules = context
+        .config
+        .optimization
+        .as_ref()
+        .map_or(false, |o| o.skip_modules.unwrap_or(false));
+    if skip_modules || context.config.optimize_package_imports {
         skip_module_optimize(
             module_graph,
             &tree_shake_modules_ids,
             &tree_shake_modules_map,
+            !skip_modules,
             context,
         )?;
         fill_all_export_start_export_info(
@@ -130,6 +251,8 @@ pub fn optimize_modules(module_graph: &mut ModuleGraph, context: &Arc<Context>)
         );
     }
 
+    let warnings = ambiguous_exports_warnings(&tree_shake_modules_ids, &tree_shake_modules_map);
+
     // traverse the tree_shake_modules
     let mut current_index: usize = 0;
     let len = tree_shake_modules_ids.len();
@@ -158,6 +281,19 @@ pub fn optimize_modules(module_graph: &mut ModuleGraph, context: &Arc<Context>)
             mako_profile_scope!("update ast", &module_id.id);
             let mut tsm = tsm.borrow_mut();
 
+            if tsm.module_system == ModuleSystem::ESModule {
+                context
+                    .stats_info
+                    .add_tree_shaking(module_id.id.clone(), tsm.tree_shaking_info());
+                if context.config.tree_shaking_annotations && !context.config.minify {
+                    context
+                        .export_annotations
+                        .lock()
+                        .unwrap()
+                        .insert(module_id.clone(), tsm.export_annotation());
+                }
+            }
+
             if tsm.not_used() {
                 module_graph.remove_module(module_id);
             } else if let Some(swc_module) = &mut tsm.updated_ast {
@@ -183,7 +319,36 @@ pub fn optimize_modules(module_graph: &mut ModuleGraph, context: &Arc<Context>)
         optimize_module_graph(module_graph, &tree_shake_modules_map, context)?;
     }
 
-    Ok(())
+    Ok(warnings)
+}
+
+// the `export *` of commonjs modules can't be told apart, so they are kept
+// as a whole, one warning per module with the names known to be exported
+fn ambiguous_exports_warnings(
+    tree_shake_modules_ids: &[ModuleId],
+    tree_shake_modules_map: &TreeShakingModuleMap,
+) -> Vec<TreeShakingWarning> {
+    let mut ambiguous_exports: BTreeMap<&ModuleId, BTreeSet<String>> = BTreeMap::new();
+    for module_id in tree_shake_modules_ids {
+        let tsm = tree_shake_modules_map.get(module_id).unwrap().borrow();
+        for export_info in tsm.exports() {
+            for specifier in export_info.specifiers {
+                if let ExportSpecifierInfo::Ambiguous(names) = specifier {
+                    ambiguous_exports
+                        .entry(module_id)
+                        .or_default()
+                        .extend(names.iter().map(|name| strip_context(name)));
+                }
+            }
+        }
+    }
+    ambiguous_exports
+        .into_iter()
+        .map(|(module_id, names)| TreeShakingWarning::AmbiguousExports {
+            module: module_id.id.clone(),
+            names: names.into_iter().collect(),
+        })
+        .collect()
 }
 
 // Add all imported to used_exports
@@ -609,6 +774,32 @@ pub fn strip_context(ident: &str) -> String {
     let ident_split = ident.split('#').collect::<Vec<_>>();
     ident_split[0].to_string()
 }
+
+// e.g. `export { default } from './a'; export { default } from './b'` in a
+// barrel file is a syntax error per spec
+fn check_duplicate_exports(
+    tree_shake_modules_ids: &[ModuleId],
+    tree_shake_modules_map: &TreeShakingModuleMap,
+) -> Result<()> {
+    let describe = |source: Option<String>| match source {
+        Some(source) => format!("\"{}\"", source),
+        None => "a local export".to_string(),
+    };
+
+    for module_id in tree_shake_modules_ids {
+        let tsm = tree_shake_modules_map.get(module_id).unwrap().borrow();
+        if let Some(duplicate) = tsm.stmt_graph.duplicate_exports().into_iter().next() {
+            return Err(anyhow!(TreeShakingError::DuplicateExport {
+                module: module_id.id.clone(),
+                name: duplicate.name,
+                first: describe(duplicate.first),
+                second: describe(duplicate.second),
+            }));
+        }
+    }
+    Ok(())
+}
+
 // is a greater than b
 fn greater_equal_than(a: usize, b: i64) -> bool {
     if b < 0 {
@@ -617,3 +808,160 @@ fn greater_equal_than(a: usize, b: i64) -> bool {
         (a as i64) >= b
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::TreeShakingWarning;
+    use crate::module::ModuleId;
+    use crate::plugins::tree_shaking::module::TreeShakingInfo;
+    use crate::plugins::tree_shaking::FarmTreeShake;
+    use crate::utils::test_helper::{setup_compiler, PluginTestBuilder};
+
+    fn is_lib_kept(base: &str) -> bool {
+        let compiler = setup_compiler(base, false);
+        compiler.compile().unwrap();
+        let module_graph = compiler.context.module_graph.read().unwrap();
+        module_graph.has_module(&ModuleId::from(compiler.context.root.join("src/lib.js")))
+    }
+
+    #[test]
+    fn test_tree_shaking_level() {
+        // lib is imported but not used, its top level call `track()` keeps it
+        assert!(is_lib_kept("test/build/tree-shaking-level-safe"));
+        // the call is assumed pure, so the side effect is dropped with lib
+        assert!(!is_lib_kept("test/build/tree-shaking-level-aggressive"));
+    }
+
+    #[test]
+    fn test_duplicate_default_re_exports() {
+        let compiler = setup_compiler("test/build/tree-shaking-duplicate-default", false);
+        let err = compiler.compile().unwrap_err().to_string();
+
+        assert!(err.contains("Duplicate export \"default\""));
+        assert!(err.contains("src/barrel.js"));
+        assert!(err.contains("\"./a\" and \"./b\""));
+    }
+
+    #[test]
+    fn test_ambiguous_exports_warning() {
+        let tree_shake = Arc::new(FarmTreeShake::default());
+        let compiler = PluginTestBuilder::new("tree-shaking-ambiguous-exports")
+            .file(
+                "src/index.js",
+                "import { c } from './barrel';\nconsole.log(c);\n",
+            )
+            .file(
+                "src/barrel.js",
+                "export * from './mid';\nexport * from './cjs';\n",
+            )
+            .file(
+                "src/mid.js",
+                "export * from './cjs';\nexport const c = 3;\n",
+            )
+            .file("src/cjs.js", "exports.b = 2;\n")
+            // the built-in one is replaced by the one to check
+            .config(r#"{ "_treeShaking": false }"#)
+            .plugin(tree_shake.clone())
+            .build();
+        compiler.compile().unwrap();
+        let root = &compiler.context.root;
+        let module = |path: &str| root.join(path).to_string_lossy().to_string();
+
+        // one warning per module, though barrel.js has two of them
+        assert_eq!(
+            tree_shake.warnings(),
+            vec![
+                TreeShakingWarning::AmbiguousExports {
+                    module: module("src/barrel.js"),
+                    names: vec!["c".to_string()],
+                },
+                TreeShakingWarning::AmbiguousExports {
+                    module: module("src/mid.js"),
+                    names: vec![],
+                },
+            ]
+        );
+        assert!(tree_shake.warnings()[0]
+            .to_string()
+            .ends_with("makes its exports ambiguous, known exports: c"));
+    }
+
+    #[test]
+    fn test_tree_shaking_info() {
+        let compiler = setup_compiler("test/build/tree-shaking-info", false);
+        compiler.compile().unwrap();
+        let tree_shaking = compiler.context.stats_info.get_tree_shaking();
+        let root = &compiler.context.root;
+
+        assert_eq!(
+            tree_shaking[&root.join("src/lib.js").to_string_lossy().to_string()],
+            TreeShakingInfo {
+                total_statements: 3,
+                retained_statements: 1,
+                removed_idents: 2,
+            }
+        );
+        assert_eq!(
+            tree_shaking[&root.join("src/index.js").to_string_lossy().to_string()],
+            TreeShakingInfo {
+                total_statements: 2,
+                retained_statements: 2,
+                removed_idents: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_keep_exports() {
+        let compiler = setup_compiler("test/build/tree-shaking-keep-exports", false);
+        compiler.compile().unwrap();
+
+        let output =
+            std::fs::read_to_string(compiler.context.config.output.path.join("index.js")).unwrap();
+        assert!(output.contains("kept-by-glob"));
+        assert!(output.contains("kept-by-annotation"));
+        assert!(!output.contains("not-kept"));
+    }
+
+    #[test]
+    fn test_tree_shaking_annotations() {
+        let build = |name: &str| {
+            let compiler = setup_compiler(&format!("test/build/{}", name), false);
+            compiler.compile().unwrap();
+            std::fs::read_to_string(compiler.context.config.output.path.join("index.js")).unwrap()
+        };
+
+        let output = build("tree-shaking-annotations");
+        assert!(output.contains(r#"/*exports: a (used), b (removed), c (used, from "./c")*/"#));
+
+        let output = build("tree-shaking-no-annotations");
+        assert!(!output.contains("exports:"));
+    }
+
+    #[test]
+    fn test_side_effects_overrides() {
+        let build = |name: &str| {
+            let compiler = setup_compiler(&format!("test/build/{}", name), false);
+            compiler.compile().unwrap();
+            std::fs::read_to_string(compiler.context.config.output.path.join("index.js")).unwrap()
+        };
+
+        // the unused import is removed with the module
+        let output = build("side-effects-declared");
+        assert!(!output.contains("window.broken = true"));
+
+        let output = build("side-effects-overrides");
+        assert!(output.contains("window.broken = true"));
+        assert!(output.contains(r#"__mako_require__("node_modules/broken/index.js")"#));
+
+        // so is the bare import, unless it's overridden
+        let output = build("side-effects-declared-bare");
+        assert!(!output.contains("window.broken = true"));
+
+        let output = build("side-effects-overrides-bare");
+        assert!(output.contains("window.broken = true"));
+        assert!(output.contains(r#"__mako_require__("node_modules/broken/index.js")"#));
+    }
+}
@@ -0,0 +1,560 @@
+use std::collections::{HashMap, HashSet};
+
+use swc_core::ecma::ast::{
+    AssignExpr, Class, ClassDecl, ClassMember, Decl, ExportNamedSpecifier, ExportSpecifier, Expr,
+    Function, Id, Ident, ImportDecl, ImportSpecifier, Lit, MemberExpr, MemberProp,
+    Module as SwcModule, ModuleDecl, ModuleExportName, ModuleItem, ParamOrTsParamProp, Pat,
+    PatOrExpr, PropName, Stmt, ThisExpr, UnaryExpr, UnaryOp, UpdateExpr,
+};
+use swc_core::ecma::visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+use super::object_property::{get_prop_key, is_pure_value};
+use crate::mako_profile_function;
+use crate::module::{ModuleAst, ModuleId, ResolveType};
+use crate::module_graph::ModuleGraph;
+
+// Remove the unused static members of top level classes like
+//
+//   export class Utils { static a() {} static b() {} }
+//
+// when every reference of the class, in the module itself and in the
+// modules importing it, is a static member access like `Utils.a()`, and
+// `this` in the static members is only used to access static members.
+// Classes with a super class, decorators or computed keys are kept as is,
+// so are classes that escape (instantiated, extended, passed around,
+// written, accessed with dynamic keys, re-exported or imported as
+// namespace), since their members may be reached in ways not tracked here.
+pub fn remove_unused_class_members(module_graph: &mut ModuleGraph, module_ids: &[ModuleId]) {
+    mako_profile_function!();
+
+    for module_id in module_ids {
+        let used_members = collect_used_members(module_graph, module_id);
+        if used_members.is_empty() {
+            continue;
+        }
+
+        let module = module_graph.get_module_mut(module_id).unwrap();
+        let ast = module.info.as_mut().unwrap().ast.as_script_ast_mut();
+        ast.visit_mut_with(&mut UnusedMembersRemover {
+            used_members: &used_members,
+        });
+    }
+}
+
+// returns the used static members of the shakable classes, keyed by the class ident
+fn collect_used_members(
+    module_graph: &ModuleGraph,
+    module_id: &ModuleId,
+) -> HashMap<Id, HashSet<String>> {
+    let module = module_graph.get_module(module_id).unwrap();
+    if module.is_entry {
+        return HashMap::new();
+    }
+    let ModuleAst::Script(script) = &module.info.as_ref().unwrap().ast else {
+        return HashMap::new();
+    };
+    let is_esm = script
+        .ast
+        .body
+        .iter()
+        .any(|item| matches!(item, ModuleItem::ModuleDecl(_)));
+    if !is_esm {
+        return HashMap::new();
+    }
+
+    let mut candidates = collect_candidates(&script.ast);
+    if candidates.is_empty() {
+        return HashMap::new();
+    }
+
+    // references in the module itself
+    candidates.retain_mut(|candidate| {
+        let mut usage = MemberUsage::new(candidate.local.clone(), true);
+        script.ast.visit_with(&mut usage);
+        candidate.merge(usage)
+    });
+
+    // references in the modules importing it
+    for (dependent_id, dependency) in module_graph.get_dependents(module_id) {
+        if candidates.is_empty() {
+            break;
+        }
+        let exported = candidates
+            .iter()
+            .flat_map(|c| c.exported.iter())
+            .collect::<HashSet<_>>();
+        if exported.is_empty() {
+            break;
+        }
+        if !matches!(dependency.resolve_type, ResolveType::Import(_)) {
+            // re-exported, required or dynamic imported, can't follow the references
+            return HashMap::new();
+        }
+        let dependent = module_graph.get_module(dependent_id).unwrap();
+        let Some(ModuleAst::Script(dependent_script)) = dependent.info.as_ref().map(|i| &i.ast)
+        else {
+            return HashMap::new();
+        };
+
+        let mut imported = vec![];
+        for item in dependent_script.ast.body.iter() {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                specifiers, src, ..
+            })) = item
+            else {
+                continue;
+            };
+            if src.value != dependency.source {
+                continue;
+            }
+            for specifier in specifiers {
+                match specifier {
+                    ImportSpecifier::Named(named) => {
+                        let name = match &named.imported {
+                            Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                            Some(ModuleExportName::Str(str)) => str.value.to_string(),
+                            None => named.local.sym.to_string(),
+                        };
+                        if exported.contains(&name) {
+                            imported.push((name, named.local.to_id()));
+                        }
+                    }
+                    ImportSpecifier::Namespace(_) => return HashMap::new(),
+                    ImportSpecifier::Default(_) => {}
+                }
+            }
+        }
+
+        for (name, local) in imported {
+            let mut usage = MemberUsage::new(local, false);
+            dependent_script.ast.visit_with(&mut usage);
+            candidates.retain_mut(|candidate| {
+                !candidate.exported.contains(&name) || candidate.merge(usage.clone())
+            });
+        }
+    }
+
+    candidates
+        .into_iter()
+        .map(|candidate| (candidate.local, candidate.used_members))
+        .collect()
+}
+
+struct Candidate {
+    local: Id,
+    exported: HashSet<String>,
+    used_members: HashSet<String>,
+}
+
+impl Candidate {
+    // returns false if the class escapes
+    fn merge(&mut self, usage: MemberUsage) -> bool {
+        if usage.escaped {
+            return false;
+        }
+        self.used_members.extend(usage.members);
+        true
+    }
+}
+
+fn collect_candidates(module: &SwcModule) -> Vec<Candidate> {
+    let mut candidates = vec![];
+    for item in module.body.iter() {
+        let (class_decl, exported) = match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Class(class_decl))) => (class_decl, false),
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+                match &export_decl.decl {
+                    Decl::Class(class_decl) => (class_decl, true),
+                    _ => continue,
+                }
+            }
+            _ => continue,
+        };
+        if !is_shakable_class(&class_decl.class) {
+            continue;
+        }
+        candidates.push(Candidate {
+            local: class_decl.ident.to_id(),
+            exported: if exported {
+                HashSet::from([class_decl.ident.sym.to_string()])
+            } else {
+                HashSet::new()
+            },
+            used_members: HashSet::new(),
+        });
+    }
+
+    // export { Utils, Utils as Helpers }
+    let mut escaped = HashSet::new();
+    for item in module.body.iter() {
+        let ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export)) = item else {
+            continue;
+        };
+        if named_export.src.is_some() {
+            continue;
+        }
+        for specifier in named_export.specifiers.iter() {
+            let ExportSpecifier::Named(named) = specifier else {
+                continue;
+            };
+            let ModuleExportName::Ident(orig) = &named.orig else {
+                continue;
+            };
+            let exported = match &named.exported {
+                Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                Some(ModuleExportName::Str(str)) => str.value.to_string(),
+                None => orig.sym.to_string(),
+            };
+            let orig = orig.to_id();
+            if exported == "default" {
+                escaped.insert(orig);
+            } else if let Some(candidate) = candidates.iter_mut().find(|c| c.local == orig) {
+                candidate.exported.insert(exported);
+            }
+        }
+    }
+    candidates.retain(|candidate| !escaped.contains(&candidate.local));
+
+    candidates
+}
+
+// no inheritance, decorators or computed keys
+fn is_shakable_class(class: &Class) -> bool {
+    if class.super_class.is_some() || !class.decorators.is_empty() {
+        return false;
+    }
+    class.body.iter().all(|member| match member {
+        ClassMember::Method(method) => {
+            method.function.decorators.is_empty()
+                && method
+                    .function
+                    .params
+                    .iter()
+                    .all(|param| param.decorators.is_empty())
+                && !matches!(method.key, PropName::Computed(_))
+        }
+        ClassMember::ClassProp(prop) => {
+            prop.decorators.is_empty() && !matches!(prop.key, PropName::Computed(_))
+        }
+        ClassMember::Constructor(constructor) => constructor.params.iter().all(
+            |param| matches!(param, ParamOrTsParamProp::Param(param) if param.decorators.is_empty()),
+        ),
+        ClassMember::PrivateMethod(_)
+        | ClassMember::PrivateProp(_)
+        | ClassMember::StaticBlock(_)
+        | ClassMember::Empty(_)
+        | ClassMember::TsIndexSignature(_) => true,
+        _ => false,
+    })
+}
+
+// the static member name which can be removed if unused
+fn get_removable_static_member(member: &ClassMember) -> Option<String> {
+    match member {
+        ClassMember::Method(method) if method.is_static => get_prop_key(&method.key),
+        ClassMember::ClassProp(prop)
+            if prop.is_static && prop.value.as_ref().map_or(true, |v| is_pure_value(v)) =>
+        {
+            get_prop_key(&prop.key)
+        }
+        _ => None,
+    }
+}
+
+#[derive(Clone)]
+struct MemberUsage {
+    local: Id,
+    // the module defining the class, its declaration and export
+    // specifiers are not references
+    is_defining_module: bool,
+    // `this` refers to the class in its static members
+    this_is_class: bool,
+    escaped: bool,
+    members: HashSet<String>,
+}
+
+impl MemberUsage {
+    fn new(local: Id, is_defining_module: bool) -> Self {
+        Self {
+            local,
+            is_defining_module,
+            this_is_class: false,
+            escaped: false,
+            members: HashSet::new(),
+        }
+    }
+
+    fn is_local(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Ident(ident) => ident.to_id() == self.local,
+            Expr::This(_) => self.this_is_class,
+            _ => false,
+        }
+    }
+
+    fn is_local_member(&self, expr: &Expr) -> bool {
+        matches!(expr, Expr::Member(member) if self.is_local(&member.obj))
+    }
+
+    fn visit_with_this<N: VisitWith<Self>>(&mut self, node: &N, this_is_class: bool) {
+        let old = self.this_is_class;
+        self.this_is_class = this_is_class;
+        node.visit_with(self);
+        self.this_is_class = old;
+    }
+}
+
+impl Visit for MemberUsage {
+    fn visit_import_decl(&mut self, _import_decl: &ImportDecl) {}
+
+    fn visit_export_named_specifier(&mut self, specifier: &ExportNamedSpecifier) {
+        if !self.is_defining_module {
+            specifier.visit_children_with(self);
+        }
+    }
+
+    fn visit_class_decl(&mut self, class_decl: &ClassDecl) {
+        if !self.is_defining_module || class_decl.ident.to_id() != self.local {
+            class_decl.visit_children_with(self);
+            return;
+        }
+        for member in class_decl.class.body.iter() {
+            match member {
+                ClassMember::Method(method) if method.is_static => {
+                    self.visit_with_this(&method.function.params, true);
+                    self.visit_with_this(&method.function.body, true);
+                }
+                ClassMember::PrivateMethod(method) if method.is_static => {
+                    self.visit_with_this(&method.function.params, true);
+                    self.visit_with_this(&method.function.body, true);
+                }
+                ClassMember::ClassProp(prop) if prop.is_static => {
+                    self.visit_with_this(&prop.value, true);
+                }
+                ClassMember::PrivateProp(prop) if prop.is_static => {
+                    self.visit_with_this(&prop.value, true);
+                }
+                ClassMember::StaticBlock(block) => {
+                    self.visit_with_this(&block.body, true);
+                }
+                _ => self.visit_with_this(member, false),
+            }
+        }
+    }
+
+    // functions and classes have their own `this`
+    fn visit_function(&mut self, function: &Function) {
+        let old = self.this_is_class;
+        self.this_is_class = false;
+        function.visit_children_with(self);
+        self.this_is_class = old;
+    }
+
+    fn visit_class(&mut self, class: &Class) {
+        let old = self.this_is_class;
+        self.this_is_class = false;
+        class.visit_children_with(self);
+        self.this_is_class = old;
+    }
+
+    fn visit_member_expr(&mut self, member: &MemberExpr) {
+        if !self.is_local(&member.obj) {
+            member.visit_children_with(self);
+            return;
+        }
+        let name = match &member.prop {
+            MemberProp::Ident(ident) => Some(ident.sym.to_string()),
+            MemberProp::Computed(computed) => match computed.expr.as_ref() {
+                Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+                Expr::Lit(Lit::Num(num)) => Some(num.value.to_string()),
+                _ => None,
+            },
+            // private members are never removed
+            MemberProp::PrivateName(_) => return,
+        };
+        match name {
+            Some(name) => {
+                self.members.insert(name);
+            }
+            None => self.escaped = true,
+        }
+    }
+
+    // writes
+    fn visit_assign_expr(&mut self, assign: &AssignExpr) {
+        if let PatOrExpr::Expr(left) = &assign.left
+            && self.is_local_member(left)
+        {
+            self.escaped = true;
+        }
+        assign.visit_children_with(self);
+    }
+
+    fn visit_pat(&mut self, pat: &Pat) {
+        if let Pat::Expr(expr) = pat
+            && self.is_local_member(expr)
+        {
+            self.escaped = true;
+        }
+        pat.visit_children_with(self);
+    }
+
+    fn visit_update_expr(&mut self, update: &UpdateExpr) {
+        if self.is_local_member(&update.arg) {
+            self.escaped = true;
+        }
+        update.visit_children_with(self);
+    }
+
+    fn visit_unary_expr(&mut self, unary: &UnaryExpr) {
+        if unary.op == UnaryOp::Delete && self.is_local_member(&unary.arg) {
+            self.escaped = true;
+        }
+        unary.visit_children_with(self);
+    }
+
+    fn visit_this_expr(&mut self, _this: &ThisExpr) {
+        if self.this_is_class {
+            self.escaped = true;
+        }
+    }
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        if ident.to_id() == self.local {
+            self.escaped = true;
+        }
+    }
+}
+
+struct UnusedMembersRemover<'a> {
+    used_members: &'a HashMap<Id, HashSet<String>>,
+}
+
+impl VisitMut for UnusedMembersRemover<'_> {
+    fn visit_mut_module_item(&mut self, item: &mut ModuleItem) {
+        // only top level declarations are candidates
+        let class_decl = match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Class(class_decl))) => class_decl,
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+                match &mut export_decl.decl {
+                    Decl::Class(class_decl) => class_decl,
+                    _ => return,
+                }
+            }
+            _ => return,
+        };
+        let Some(used_members) = self.used_members.get(&class_decl.ident.to_id()) else {
+            return;
+        };
+        class_decl
+            .class
+            .body
+            .retain(|member| match get_removable_static_member(member) {
+                Some(name) => used_members.contains(&name),
+                None => true,
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_core::common::SyntaxContext;
+
+    use super::*;
+    use crate::ast::tests::TestUtils;
+
+    fn used_members(code: &str) -> Option<Vec<String>> {
+        let test_utils = TestUtils::gen_js_ast(code);
+        let ast = test_utils.ast.js();
+        let mut candidates = collect_candidates(&ast.ast);
+        let candidate = candidates.pop()?;
+        let mut usage = MemberUsage::new(candidate.local, true);
+        ast.ast.visit_with(&mut usage);
+        if usage.escaped {
+            return None;
+        }
+        let mut members = usage.members.into_iter().collect::<Vec<_>>();
+        members.sort();
+        Some(members)
+    }
+
+    #[test]
+    fn test_static_member_access() {
+        assert_eq!(
+            used_members(
+                r#"
+export class Utils {
+    static a() { return this.b; }
+    static b = 1;
+    static c() {}
+    foo() { return this.bar; }
+}
+console.log(Utils.a(), Utils["d"]);
+"#
+            ),
+            Some(vec!["a".to_string(), "b".to_string(), "d".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_escaped() {
+        let cases = [
+            "export class Utils { static a() {} } console.log(Utils);",
+            "export class Utils { static a() {} } new Utils();",
+            "export class Utils { static a() {} } class Sub extends Utils {}",
+            "export class Utils { static a() {} } Utils[key]();",
+            "export class Utils { static a() {} } Utils.a = null;",
+            "export class Utils { static a() { return this; } }",
+            "export class Utils { static a() { return this[key]; } }",
+            "class Utils { static a() {} } export default Utils;",
+            "class Utils { static a() {} } export { Utils as default };",
+        ];
+        for case in cases {
+            assert_eq!(used_members(case), None, "{}", case);
+        }
+    }
+
+    #[test]
+    fn test_not_shakable_class() {
+        let cases = [
+            "export class Utils extends Base { static a() {} }",
+            "export class Utils { static [key]() {} }",
+            "@decorator export class Utils { static a() {} }",
+            "export class Utils { @decorator static a() {} }",
+            "export const Utils = class { static a() {} };",
+        ];
+        for case in cases {
+            assert_eq!(used_members(case), None, "{}", case);
+        }
+    }
+
+    #[test]
+    fn test_remove_unused_members() {
+        let mut test_utils = TestUtils::gen_js_ast(
+            r#"export class Utils {
+    static a() {}
+    static b() {}
+    static c = 1;
+    static d = sideEffect();
+    e() {}
+}"#,
+        );
+        let ast = test_utils.ast.js_mut();
+        let local = (
+            "Utils".into(),
+            SyntaxContext::empty().apply_mark(ast.top_level_mark),
+        );
+        let used_members = HashMap::from([(local, HashSet::from(["a".to_string()]))]);
+        ast.ast.visit_mut_with(&mut UnusedMembersRemover {
+            used_members: &used_members,
+        });
+        assert_eq!(
+            test_utils.js_ast_to_code(),
+            r#"export class Utils {
+    static a() {}
+    static d = sideEffect();
+    e() {}
+}"#
+        );
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::HashSet;
+
+use swc_core::ecma::ast::{
+    Class, ClassMember, ClassMethod, Decl, DefaultDecl, Expr, Lit, MemberExpr, MemberProp,
+    ModuleDecl, ModuleItem, ObjectPatProp, PropName, Stmt, SuperProp, SuperPropExpr,
+};
+use swc_core::ecma::visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+use super::object_property::get_prop_key;
+use crate::mako_profile_function;
+use crate::module::{ModuleAst, ModuleId};
+use crate::module_graph::ModuleGraph;
+
+// the methods called by the runtime rather than by name in the code
+const PROTOCOL_METHODS: [&str; 6] = [
+    "toString",
+    "valueOf",
+    "toJSON",
+    "toLocaleString",
+    "then",
+    "handleEvent",
+];
+
+// Remove the instance methods of top level classes like
+//
+//   export class Api { get() {} post() {} }
+//
+// whose names are never read as a property anywhere in the module graph,
+// `api.get()`, `this.get`, `super.get`, `const { get } = api` and the
+// string literal `"get"` all count as reads of `get`. Instances flow
+// anywhere, so unlike the static members, the usage is tracked by name
+// instead of by reference. Classes with a super class, decorators or
+// dynamic `this[key]` reads are kept as is, and so are the methods with
+// computed keys, since they may be called by code outside of the module
+// graph or with names not tracked here.
+pub fn remove_unused_class_methods(module_graph: &mut ModuleGraph, module_ids: &[ModuleId]) {
+    mako_profile_function!();
+
+    let mut used_names = UsedNames::default();
+    for module in module_graph.modules() {
+        if let Some(ModuleAst::Script(script)) = module.info.as_ref().map(|i| &i.ast) {
+            script.ast.visit_with(&mut used_names);
+        }
+    }
+    let used_names = used_names.names;
+
+    for module_id in module_ids {
+        let module = module_graph.get_module_mut(module_id).unwrap();
+        if module.is_entry {
+            continue;
+        }
+        let Some(ModuleAst::Script(script)) = module.info.as_mut().map(|i| &mut i.ast) else {
+            continue;
+        };
+        let is_esm = script
+            .ast
+            .body
+            .iter()
+            .any(|item| matches!(item, ModuleItem::ModuleDecl(_)));
+        if !is_esm {
+            continue;
+        }
+
+        script.ast.visit_mut_with(&mut UnusedMethodsRemover {
+            used_names: &used_names,
+        });
+    }
+}
+
+#[derive(Default)]
+struct UsedNames {
+    names: HashSet<String>,
+}
+
+impl Visit for UsedNames {
+    fn visit_member_expr(&mut self, member: &MemberExpr) {
+        if let MemberProp::Ident(ident) = &member.prop {
+            self.names.insert(ident.sym.to_string());
+        }
+        member.visit_children_with(self);
+    }
+
+    fn visit_super_prop_expr(&mut self, super_prop: &SuperPropExpr) {
+        if let SuperProp::Ident(ident) = &super_prop.prop {
+            self.names.insert(ident.sym.to_string());
+        }
+        super_prop.visit_children_with(self);
+    }
+
+    fn visit_object_pat_prop(&mut self, prop: &ObjectPatProp) {
+        match prop {
+            ObjectPatProp::KeyValue(key_value) => {
+                if let Some(name) = get_prop_key(&key_value.key) {
+                    self.names.insert(name);
+                }
+            }
+            ObjectPatProp::Assign(assign) => {
+                self.names.insert(assign.key.sym.to_string());
+            }
+            ObjectPatProp::Rest(_) => {}
+        }
+        prop.visit_children_with(self);
+    }
+
+    // `obj["get"]`, `Reflect.get(obj, "get")`, `"get" in obj`
+    fn visit_lit(&mut self, lit: &Lit) {
+        if let Lit::Str(str) = lit {
+            self.names.insert(str.value.to_string());
+        }
+    }
+}
+
+// no inheritance, decorators or dynamic reads of `this`
+fn is_shakable_class(class: &Class) -> bool {
+    if class.super_class.is_some() || !class.decorators.is_empty() {
+        return false;
+    }
+    let mut dynamic_this = DynamicThisAccess::default();
+    class.body.visit_with(&mut dynamic_this);
+    !dynamic_this.found
+}
+
+#[derive(Default)]
+struct DynamicThisAccess {
+    found: bool,
+}
+
+impl Visit for DynamicThisAccess {
+    fn visit_member_expr(&mut self, member: &MemberExpr) {
+        if let Expr::This(_) = member.obj.as_ref()
+            && let MemberProp::Computed(computed) = &member.prop
+            && !matches!(computed.expr.as_ref(), Expr::Lit(Lit::Str(_)))
+        {
+            self.found = true;
+        }
+        member.visit_children_with(self);
+    }
+}
+
+// the instance method name which can be removed if unused
+fn get_removable_method(method: &ClassMethod) -> Option<String> {
+    if method.is_static
+        || !method.function.decorators.is_empty()
+        || method
+            .function
+            .params
+            .iter()
+            .any(|param| !param.decorators.is_empty())
+        || matches!(method.key, PropName::Computed(_))
+    {
+        return None;
+    }
+    get_prop_key(&method.key).filter(|name| !PROTOCOL_METHODS.contains(&name.as_str()))
+}
+
+struct UnusedMethodsRemover<'a> {
+    used_names: &'a HashSet<String>,
+}
+
+impl VisitMut for UnusedMethodsRemover<'_> {
+    fn visit_mut_module_item(&mut self, item: &mut ModuleItem) {
+        // only top level declarations are candidates
+        let class = match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Class(class_decl))) => &mut class_decl.class,
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+                match &mut export_decl.decl {
+                    Decl::Class(class_decl) => &mut class_decl.class,
+                    _ => return,
+                }
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export_default)) => {
+                match &mut export_default.decl {
+                    DefaultDecl::Class(class_expr) => &mut class_expr.class,
+                    _ => return,
+                }
+            }
+            _ => return,
+        };
+        if !is_shakable_class(class) {
+            return;
+        }
+        class.body.retain(|member| match member {
+            ClassMember::Method(method) => match get_removable_method(method) {
+                Some(name) => self.used_names.contains(&name),
+                None => true,
+            },
+            _ => true,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_core::ecma::visit::{VisitMutWith, VisitWith};
+
+    use super::{UnusedMethodsRemover, UsedNames};
+    use crate::ast::tests::TestUtils;
+    use crate::compiler::Compiler;
+    use crate::config::{Config, Mode, OptimizationConfig};
+
+    fn used_names(code: &str) -> Vec<String> {
+        let test_utils = TestUtils::gen_js_ast(code);
+        let mut used_names = UsedNames::default();
+        test_utils.ast.js().ast.visit_with(&mut used_names);
+        let mut names = used_names.names.into_iter().collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    fn remove_unused_methods(code: &str, used_names: &[&str]) -> String {
+        let mut test_utils = TestUtils::gen_js_ast(code);
+        let used_names = used_names.iter().map(|n| n.to_string()).collect();
+        test_utils
+            .ast
+            .js_mut()
+            .ast
+            .visit_mut_with(&mut UnusedMethodsRemover {
+                used_names: &used_names,
+            });
+        test_utils.js_ast_to_code()
+    }
+
+    #[test]
+    fn test_used_names() {
+        assert_eq!(
+            used_names(
+                r#"
+api.get();
+this.post;
+const { put, patch: p } = api;
+api["head"];
+api[key];
+"#
+            ),
+            vec!["get", "head", "patch", "post", "put"]
+        );
+    }
+
+    #[test]
+    fn test_remove_unused_methods() {
+        assert_eq!(
+            remove_unused_methods(
+                r#"export class Api {
+    get() {}
+    post() {}
+    static put() {}
+    [key]() {}
+    toString() {}
+}"#,
+                &["get"]
+            ),
+            r#"export class Api {
+    get() {}
+    static put() {}
+    [key]() {}
+    toString() {}
+}"#
+        );
+    }
+
+    #[test]
+    fn test_not_shakable_class() {
+        let cases = [
+            "export class Api extends Base { post() {} }",
+            "export class Api { get() { return this[key]; } post() {} }",
+            "export class Api { @decorator post() {} }",
+            "export const Api = class { post() {} };",
+        ];
+        for case in cases {
+            assert!(
+                remove_unused_methods(case, &[]).contains("post()"),
+                "{}",
+                case
+            );
+        }
+    }
+
+    #[test]
+    fn test_remove_unused_class_methods() {
+        let root = std::env::current_dir()
+            .unwrap()
+            .join("test/build/class-methods");
+        let mut config = Config::new(&root, None, None).unwrap();
+        config.hmr = None;
+        config.minify = false;
+        config.mode = Mode::Production;
+        config.optimization = Some(OptimizationConfig {
+            skip_modules: Some(false),
+            concatenate_modules: Some(false),
+            inline_constants: Some(false),
+            class_methods: Some(true),
+        });
+        let compiler = Compiler::new(config, root.clone(), Default::default(), None).unwrap();
+        compiler.compile().unwrap();
+
+        let output =
+            std::fs::read_to_string(compiler.context.config.output.path.join("index.js")).unwrap();
+        assert!(output.contains("get(path)"));
+        assert!(!output.contains("post(path, body)"));
+    }
+}
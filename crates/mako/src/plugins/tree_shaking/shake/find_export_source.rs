@@ -1,4 +1,4 @@
-use crate::plugins::tree_shaking::module::{is_ident_sym_equal, TreeShakeModule};
+use crate::plugins::tree_shaking::module::{is_ident_equal, is_ident_sym_equal, TreeShakeModule};
 use crate::plugins::tree_shaking::shake::skip_module::{ReExportSource, ReExportType};
 use crate::plugins::tree_shaking::shake::strip_context;
 use crate::plugins::tree_shaking::statement_graph::{ExportSpecifierInfo, ImportSpecifierInfo};
@@ -101,10 +101,11 @@ impl TreeShakeModule {
                             }
                             ExportSpecifierInfo::Default(export_default_ident) => {
                                 if ident == "default" {
-                                    if export_default_ident.is_some() {
-                                        // TODO figure out how webpack handle this
-                                        // re_export_type = Some(ReExportType::Default);
-                                        // local_ident = Some(default_ident.clone());
+                                    // export default foo; it's followed to the import of
+                                    // foo if there is one
+                                    if let Some(default_ident) = export_default_ident {
+                                        re_export_type = Some(ReExportType::Default);
+                                        local_ident = Some(default_ident.clone());
                                         break;
                                     } else {
                                         return Some(ReExportSource {
@@ -130,40 +131,48 @@ impl TreeShakeModule {
         }
 
         if let Some(local) = &local_ident {
-            for stmt in self.stmt_graph.stmts() {
-                if let Some(import_info) = &stmt.import_info {
-                    if let Some(import_specifier) = import_info.find_define_specifier(local) {
-                        match import_specifier {
-                            ImportSpecifierInfo::Namespace(_namespace) => {
-                                return Some(ReExportSource {
-                                    re_export_type: ReExportType::Namespace,
-                                    source: Some(import_info.source.clone()),
-                                });
-                            }
-                            ImportSpecifierInfo::Named {
-                                imported,
-                                local: imported_local,
-                            } => {
-                                if is_ident_sym_equal(local, imported_local) {
-                                    let next_name = if let Some(imported) = imported {
-                                        imported.clone()
-                                    } else {
-                                        local.clone()
-                                    };
-
-                                    return Some(ReExportSource {
-                                        re_export_type: ReExportType::Named(strip_context(
-                                            &next_name,
-                                        )),
-                                        source: Some(import_info.source.clone()),
-                                    });
-                                }
-                            }
-                            ImportSpecifierInfo::Default(name) => {
-                                if local == name {
-                                    return None;
-                                }
-                            }
+            // the local may be defined after the export specifier, e.g. a
+            // hoisted `function fn() {}` following `export { fn }`, so look up
+            // the defining statement instead of relying on the order
+            let define_stmt = self.stmt_graph.stmts().into_iter().find(|stmt| {
+                stmt.defined_idents
+                    .iter()
+                    .any(|defined| is_ident_equal(defined, local))
+            });
+            if let Some(import_info) = define_stmt.and_then(|stmt| stmt.import_info.as_ref())
+                && let Some(import_specifier) = import_info.find_define_specifier(local)
+            {
+                match import_specifier {
+                    ImportSpecifierInfo::Namespace(_namespace) => {
+                        return Some(ReExportSource {
+                            re_export_type: ReExportType::Namespace,
+                            source: Some(import_info.source.clone()),
+                        });
+                    }
+                    ImportSpecifierInfo::Named {
+                        imported,
+                        local: imported_local,
+                    } => {
+                        if is_ident_sym_equal(local, imported_local) {
+                            let next_name = if let Some(imported) = imported {
+                                imported.clone()
+                            } else {
+                                local.clone()
+                            };
+
+                            return Some(ReExportSource {
+                                re_export_type: ReExportType::Named(strip_context(&next_name)),
+                                source: Some(import_info.source.clone()),
+                            });
+                        }
+                    }
+                    // import foo from "x"; export { foo } or export { foo as bar }
+                    ImportSpecifierInfo::Default(name) => {
+                        if local == name {
+                            return Some(ReExportSource {
+                                re_export_type: ReExportType::Default,
+                                source: Some(import_info.source.clone()),
+                            });
                         }
                     }
                 }
@@ -190,8 +199,10 @@ mod tests {
     use crate::ast::file::{Content, File, JsContent};
     use crate::ast::js_ast::JsAst;
     use crate::compiler::Context;
+    use crate::config::TreeShakingLevel;
     use crate::module::{Module, ModuleAst, ModuleInfo};
     use crate::plugins::tree_shaking::shake::skip_module::ReExportSource;
+    use crate::plugins::tree_shaking::statement_graph::ExportSpecifierInfo;
 
     impl ReExportSource {
         pub fn describe(&self) -> String {
@@ -209,7 +220,23 @@ mod tests {
 
         let re_export_source = tsm.find_skipable_export_source(&"a".to_string());
 
-        assert!(re_export_source.is_none());
+        assert_eq!(
+            re_export_source.unwrap().describe(),
+            r#"ReExport from ./a.js by Default"#
+        );
+    }
+
+    #[test]
+    fn test_find_import_default_export_renamed() {
+        let tsm = tsm_with_code(r#" import a from "./a.js"; export { a as b }; "#);
+
+        let re_export_source = tsm.find_skipable_export_source(&"b".to_string());
+
+        assert_eq!(
+            re_export_source.unwrap().describe(),
+            r#"ReExport from ./a.js by Default"#
+        );
+        assert!(tsm.find_skipable_export_source(&"a".to_string()).is_none());
     }
 
     #[test]
@@ -218,19 +245,22 @@ mod tests {
 
         let re_export_source = tsm.find_skipable_export_source(&"default".to_string());
 
-        assert!(re_export_source.is_none());
+        assert_eq!(
+            re_export_source.unwrap().describe(),
+            r#"ReExport from ./a.js by Default"#
+        );
     }
+
     #[test]
     fn test_find_import_named_export_default() {
         let tsm = tsm_with_code(r#" import {a} from "./a.js"; export default a;"#);
 
         let re_export_source = tsm.find_skipable_export_source(&"default".to_string());
 
-        assert!(re_export_source.is_none());
-        // assert_eq!(
-        //     re_export_source.unwrap().describe(),
-        //     r#"ReExport from ./a.js by Named("a")"#
-        // );
+        assert_eq!(
+            re_export_source.unwrap().describe(),
+            r#"ReExport from ./a.js by Named("a")"#
+        );
     }
 
     #[test]
@@ -239,12 +269,10 @@ mod tests {
 
         let re_export_source = tsm.find_skipable_export_source(&"default".to_string());
 
-        assert!(re_export_source.is_none());
-
-        // assert_eq!(
-        //     re_export_source.unwrap().describe(),
-        //     r#"ReExport from ./a.js by Named("z")"#
-        // );
+        assert_eq!(
+            re_export_source.unwrap().describe(),
+            r#"ReExport from ./a.js by Named("z")"#
+        );
     }
 
     #[test]
@@ -268,6 +296,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_find_import_namespace_export_renamed() {
+        let tsm = tsm_with_code(r#" import * as a from "./a.js"; export { a as b };"#);
+
+        let re_export_source = tsm.find_skipable_export_source(&"b".to_string());
+
+        assert_eq!(
+            re_export_source.unwrap().describe(),
+            r#"ReExport from ./a.js by Namespace"#
+        );
+    }
+
     #[test]
     fn test_find_import_named_export_named() {
         let tsm = tsm_with_code(r#" import { a } from "./a.js"; export { a };"#);
@@ -378,18 +418,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_find_export_star_from_with_local_import() {
+        let mut tsm =
+            tsm_with_code(r#" import { b } from "./b.js"; export { b }; export * from "./a.js" "#);
+        set_star_exports(&mut tsm, &["a"]);
+
+        let re_export_source = tsm.find_skipable_export_source(&"a".to_string());
+
+        assert_eq!(
+            re_export_source.unwrap().describe(),
+            r#"ReExport from ./a.js by Named("a")"#
+        );
+
+        let re_export_source = tsm.find_skipable_export_source(&"b".to_string());
+
+        assert_eq!(
+            re_export_source.unwrap().describe(),
+            r#"ReExport from ./b.js by Named("b")"#
+        );
+    }
+
+    #[test]
+    fn test_find_export_star_from_not_exported() {
+        let mut tsm = tsm_with_code(r#" import { b } from "./b.js"; export * from "./a.js" "#);
+        set_star_exports(&mut tsm, &["a"]);
+
+        let re_export_source = tsm.find_skipable_export_source(&"b".to_string());
+
+        assert!(re_export_source.is_none());
+    }
+
     #[test]
     fn test_find_export_default_local_ident() {
         let tsm = tsm_with_code(r#"const a=1; export default a "#);
 
         let re_export_source = tsm.find_skipable_export_source(&"default".to_string());
 
-        assert!(re_export_source.is_none());
-
-        // assert_eq!(
-        //     re_export_source.unwrap().describe(),
-        //     r#"Direct Export Default"#
-        // );
+        assert_eq!(
+            re_export_source.unwrap().describe(),
+            r#"Direct Export Default"#
+        );
     }
 
     #[test]
@@ -404,6 +473,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_find_export_default_anonymous_function() {
+        let tsm = tsm_with_code(r#"export default function(){} "#);
+
+        let re_export_source = tsm.find_skipable_export_source(&"default".to_string());
+
+        assert_eq!(
+            re_export_source.unwrap().describe(),
+            r#"Direct Export Default"#
+        );
+    }
+
     #[test]
     fn test_find_export_default_class() {
         let tsm = tsm_with_code(r#" export default class Test{} "#);
@@ -440,6 +521,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_find_export_named_hoisted_fn() {
+        let tsm = tsm_with_code(r#" export { fn }; function fn(){} "#);
+
+        let re_export_source = tsm.find_skipable_export_source(&"fn".to_string());
+
+        assert_eq!(
+            re_export_source.unwrap().describe(),
+            r#"Direct Export Named("fn")"#
+        );
+    }
+
+    #[test]
+    fn test_find_export_named_before_import() {
+        let tsm = tsm_with_code(r#" export { a }; import { a } from "./a.js"; "#);
+
+        let re_export_source = tsm.find_skipable_export_source(&"a".to_string());
+
+        assert_eq!(
+            re_export_source.unwrap().describe(),
+            r#"ReExport from ./a.js by Named("a")"#
+        );
+    }
+
     #[test]
     fn test_find_export_dec_expr() {
         let tsm = tsm_with_code(r#" export const a = 1 "#);
@@ -452,6 +557,29 @@ mod tests {
         );
     }
 
+    // the idents of `export * from` are filled from the module graph when
+    // shaking, fill them by hand here
+    fn set_star_exports(tsm: &mut TreeShakeModule, idents: &[&str]) {
+        let ids = tsm
+            .stmt_graph
+            .stmts()
+            .into_iter()
+            .filter(|stmt| {
+                stmt.export_info.as_ref().is_some_and(|info| {
+                    matches!(info.specifiers.first(), Some(ExportSpecifierInfo::All(_)))
+                })
+            })
+            .map(|stmt| stmt.id)
+            .collect::<Vec<_>>();
+        for id in ids {
+            if let Some(export_info) = &mut tsm.stmt_graph.stmt_mut(&id).export_info {
+                export_info.specifiers = vec![ExportSpecifierInfo::All(
+                    idents.iter().map(|ident| ident.to_string()).collect(),
+                )];
+            }
+        }
+    }
+
     fn tsm_with_code(code: &str) -> TreeShakeModule {
         let context: Arc<Context> = Default::default();
 
@@ -478,8 +606,14 @@ mod tests {
             side_effects: false,
         };
 
+        let comments = context.meta.script.origin_comments.read().unwrap();
         GLOBALS.set(&context.meta.script.globals, || {
-            TreeShakeModule::new(&mako_module, 0)
+            TreeShakeModule::new(
+                &mako_module,
+                0,
+                TreeShakingLevel::Recommended,
+                comments.get_swc_comments().clone(),
+            )
         })
     }
 }
@@ -0,0 +1,275 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+
+use swc_core::ecma::ast::{
+    Decl, ExportSpecifier, Expr, Id, ImportSpecifier, KeyValueProp, Lit, ModuleDecl,
+    ModuleExportName, ModuleItem, Prop, PropName, VarDeclKind,
+};
+use swc_core::ecma::visit::{VisitMut, VisitMutWith};
+
+use crate::mako_profile_function;
+use crate::module::ModuleId;
+use crate::module_graph::ModuleGraph;
+use crate::plugins::tree_shaking::module::{ModuleSystem, TreeShakeModule};
+use crate::plugins::tree_shaking::shake::skip_module::{find_ident_export_source, ReExportType};
+
+// Inline the imported primitive constants like
+//
+//   // constants.ts
+//   export const API_URL = 'https://example.com';
+//   // config.ts
+//   export { API_URL } from './constants';
+//   // index.ts
+//   import { API_URL } from './config';
+//   fetch(API_URL);
+//
+// into `fetch('https://example.com')` and remove the import, so that the
+// config modules can be shaken if nothing else is used. Only `export const`
+// declarations with literal values are inlined, the modules from the
+// importer to the declaring module must be free of side effects, since they
+// are not executed any more once the import is removed.
+pub(super) fn inline_constant_imports(
+    module_graph: &mut ModuleGraph,
+    tree_shake_modules_ids: &[ModuleId],
+    tree_shake_modules_map: &HashMap<ModuleId, RefCell<TreeShakeModule>>,
+) {
+    mako_profile_function!();
+
+    for module_id in tree_shake_modules_ids {
+        let Some(tsm) = tree_shake_modules_map.get(module_id) else {
+            continue;
+        };
+        if tsm.borrow().module_system != ModuleSystem::ESModule {
+            continue;
+        }
+
+        let constants = collect_constant_imports(module_graph, tree_shake_modules_map, module_id);
+        if constants.is_empty() {
+            continue;
+        }
+
+        let swc_module = module_graph
+            .get_module_mut(module_id)
+            .unwrap()
+            .info
+            .as_mut()
+            .unwrap()
+            .ast
+            .as_script_ast_mut();
+        swc_module.visit_mut_with(&mut ConstantInliner {
+            constants: &constants,
+        });
+        remove_inlined_specifiers(&mut swc_module.body, &constants);
+
+        let mut tsm = tsm.borrow_mut();
+        tsm.update_stmt_graph(swc_module);
+        // the rebuilt statements lose the side effects from the deps
+        tsm.update_side_effect();
+    }
+}
+
+// local ident of the import specifier -> the constant value
+fn collect_constant_imports(
+    module_graph: &ModuleGraph,
+    tree_shake_modules_map: &HashMap<ModuleId, RefCell<TreeShakeModule>>,
+    module_id: &ModuleId,
+) -> HashMap<Id, Lit> {
+    let swc_module = module_graph
+        .get_module(module_id)
+        .unwrap()
+        .info
+        .as_ref()
+        .unwrap()
+        .ast
+        .as_script_ast();
+
+    let re_exported = re_exported_locals(&swc_module.body);
+    let mut constants = HashMap::new();
+    for item in swc_module.body.iter() {
+        let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item else {
+            continue;
+        };
+        if import_decl.type_only {
+            continue;
+        }
+        let source = import_decl.src.value.to_string();
+        let Some(imported_module_id) =
+            module_graph.get_dependency_module_by_source(module_id, &source)
+        else {
+            continue;
+        };
+        for specifier in import_decl.specifiers.iter() {
+            let ImportSpecifier::Named(named) = specifier else {
+                continue;
+            };
+            if named.is_type_only || re_exported.contains(&named.local.to_id()) {
+                continue;
+            }
+            let imported = match &named.imported {
+                Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                Some(ModuleExportName::Str(str)) => str.value.to_string(),
+                None => named.local.sym.to_string(),
+            };
+            // side effects of the modules in between are checked when finding the source
+            let Some(replace) = find_ident_export_source(
+                module_graph,
+                tree_shake_modules_map,
+                imported_module_id,
+                &imported,
+            ) else {
+                continue;
+            };
+            let (ReExportType::Named(name), None) = (
+                &replace.re_export_source.re_export_type,
+                &replace.re_export_source.source,
+            ) else {
+                continue;
+            };
+            if let Some(value) = find_exported_constant(module_graph, &replace.from_module_id, name)
+            {
+                constants.insert(named.local.to_id(), value);
+            }
+        }
+    }
+    constants
+}
+
+// `export const name = <literal>;`
+fn find_exported_constant(
+    module_graph: &ModuleGraph,
+    module_id: &ModuleId,
+    name: &str,
+) -> Option<Lit> {
+    let module = module_graph.get_module(module_id)?;
+    let swc_module = module.info.as_ref()?.ast.as_script()?;
+    swc_module.ast.body.iter().find_map(|item| {
+        let ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) = item else {
+            return None;
+        };
+        let Decl::Var(var_decl) = &export_decl.decl else {
+            return None;
+        };
+        if var_decl.kind != VarDeclKind::Const {
+            return None;
+        }
+        var_decl.decls.iter().find_map(|decl| {
+            let ident = decl.name.as_ident()?;
+            if ident.sym != *name {
+                return None;
+            }
+            match decl.init.as_deref() {
+                Some(Expr::Lit(
+                    lit @ (Lit::Str(_) | Lit::Num(_) | Lit::Bool(_) | Lit::Null(_)),
+                )) => Some(lit.clone()),
+                _ => None,
+            }
+        })
+    })
+}
+
+// the imported bindings exported by `export { x }` can't be inlined
+fn re_exported_locals(body: &[ModuleItem]) -> HashSet<Id> {
+    body.iter()
+        .filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export))
+                if named_export.src.is_none() =>
+            {
+                Some(named_export.specifiers.iter())
+            }
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|specifier| match specifier {
+            ExportSpecifier::Named(named) => match &named.orig {
+                ModuleExportName::Ident(ident) => Some(ident.to_id()),
+                ModuleExportName::Str(_) => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+fn remove_inlined_specifiers(body: &mut Vec<ModuleItem>, constants: &HashMap<Id, Lit>) {
+    body.retain_mut(|item| {
+        let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item else {
+            return true;
+        };
+        if import_decl.specifiers.is_empty() {
+            return true;
+        }
+        import_decl.specifiers.retain(|specifier| match specifier {
+            ImportSpecifier::Named(named) => !constants.contains_key(&named.local.to_id()),
+            _ => true,
+        });
+        !import_decl.specifiers.is_empty()
+    });
+}
+
+struct ConstantInliner<'a> {
+    constants: &'a HashMap<Id, Lit>,
+}
+
+impl VisitMut for ConstantInliner<'_> {
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        if let Expr::Ident(ident) = expr
+            && let Some(value) = self.constants.get(&ident.to_id())
+        {
+            *expr = Expr::Lit(value.clone());
+            return;
+        }
+        expr.visit_mut_children_with(self);
+    }
+
+    // { API_URL } -> { API_URL: "..." }
+    fn visit_mut_prop(&mut self, prop: &mut Prop) {
+        if let Prop::Shorthand(ident) = prop
+            && let Some(value) = self.constants.get(&ident.to_id())
+        {
+            *prop = Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(ident.clone()),
+                value: Box::new(Expr::Lit(value.clone())),
+            });
+            return;
+        }
+        prop.visit_mut_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compiler::Compiler;
+    use crate::config::{Config, Mode, OptimizationConfig};
+    use crate::module::ModuleId;
+
+    #[test]
+    fn test_inline_constant_imports() {
+        let root = std::env::current_dir()
+            .unwrap()
+            .join("test/build/inline-constants");
+        let mut config = Config::new(&root, None, None).unwrap();
+        config.hmr = None;
+        config.minify = false;
+        config.mode = Mode::Production;
+        config.optimization = Some(OptimizationConfig {
+            skip_modules: Some(false),
+            concatenate_modules: Some(false),
+            inline_constants: Some(true),
+            class_methods: Some(false),
+        });
+        let compiler = Compiler::new(config, root.clone(), Default::default(), None).unwrap();
+        compiler.compile().unwrap();
+
+        let module_graph = compiler.context.module_graph.read().unwrap();
+        let module_id = |path: &str| ModuleId::from(root.join(path));
+        // both the re-exporting config and the declaring module are dropped
+        assert!(!module_graph.has_module(&module_id("src/config.ts")));
+        assert!(!module_graph.has_module(&module_id("src/constants.ts")));
+        // objects are not inlined
+        assert!(module_graph.has_module(&module_id("src/options.ts")));
+
+        let output =
+            std::fs::read_to_string(compiler.context.config.output.path.join("index.js")).unwrap();
+        assert!(output.contains(r#"fetch("https://example.com/api""#));
+        assert!(output.contains(r#"API_URL: "https://example.com/api""#));
+    }
+}
@@ -67,7 +67,8 @@ pub fn optimize_module_graph(
                     .any(|d| matches!(d.resolve_type, ResolveType::DynamicImport))
             });
 
-            if dynamic_imported {
+            // the exports of the inner modules are not exposed
+            if dynamic_imported || tsm.keep_exports {
                 can_be_inner = false;
             }
 
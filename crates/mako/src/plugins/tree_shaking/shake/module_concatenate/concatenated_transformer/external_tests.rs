@@ -277,6 +277,8 @@ fn run_test(code: &str, ccn_ctx: &mut ConcatenateContext) -> String {
             optimization: Some(OptimizationConfig {
                 concatenate_modules: Some(true),
                 skip_modules: Some(true),
+                inline_constants: None,
+                class_methods: None,
             }),
             mode: Mode::Production,
             minify: true,
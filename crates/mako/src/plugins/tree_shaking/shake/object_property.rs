@@ -0,0 +1,558 @@
+use std::collections::{HashMap, HashSet};
+
+use swc_core::common::SyntaxContext;
+use swc_core::ecma::ast::{
+    ArrowExpr, AssignExpr, CallExpr, Callee, Class, Decl, ExportNamedSpecifier, ExportSpecifier,
+    Expr, Function, Id, Ident, ImportDecl, ImportSpecifier, Lit, MemberExpr, MemberProp,
+    Module as SwcModule, ModuleDecl, ModuleExportName, ModuleItem, ObjectLit, OptCall, Pat,
+    PatOrExpr, Prop, PropName, PropOrSpread, Stmt, UnaryExpr, UnaryOp, UpdateExpr, VarDeclKind,
+    VarDeclarator,
+};
+use swc_core::ecma::visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+use crate::mako_profile_function;
+use crate::module::{ModuleAst, ModuleId, ResolveType};
+use crate::module_graph::ModuleGraph;
+
+// Remove the unused properties of const object literals like
+//
+//   export const Colors = { red, green };
+//
+// when every reference of the object, in the module itself and in the
+// modules importing it, is a static property read like `Colors.red`.
+// Objects wrapped by `Object.freeze` are supported too. Once the object
+// escapes (passed around, spread, called as method, written, accessed with
+// dynamic keys, re-exported or imported as namespace), it's kept as is.
+pub fn remove_unused_object_properties(module_graph: &mut ModuleGraph, module_ids: &[ModuleId]) {
+    mako_profile_function!();
+
+    for module_id in module_ids {
+        let used_props = collect_used_properties(module_graph, module_id);
+        if used_props.is_empty() {
+            continue;
+        }
+
+        let module = module_graph.get_module_mut(module_id).unwrap();
+        let ast = module.info.as_mut().unwrap().ast.as_script_ast_mut();
+        ast.visit_mut_with(&mut UnusedPropertiesRemover {
+            used_props: &used_props,
+        });
+    }
+}
+
+// returns the used properties of the shakable objects, keyed by the local ident
+fn collect_used_properties(
+    module_graph: &ModuleGraph,
+    module_id: &ModuleId,
+) -> HashMap<Id, HashSet<String>> {
+    let module = module_graph.get_module(module_id).unwrap();
+    if module.is_entry {
+        return HashMap::new();
+    }
+    let ModuleAst::Script(script) = &module.info.as_ref().unwrap().ast else {
+        return HashMap::new();
+    };
+    let is_esm = script
+        .ast
+        .body
+        .iter()
+        .any(|item| matches!(item, ModuleItem::ModuleDecl(_)));
+    if !is_esm {
+        return HashMap::new();
+    }
+
+    let unresolved_ctxt = SyntaxContext::empty().apply_mark(script.unresolved_mark);
+    let mut candidates = collect_candidates(&script.ast, unresolved_ctxt);
+    if candidates.is_empty() {
+        return HashMap::new();
+    }
+
+    // references in the module itself
+    candidates.retain_mut(|candidate| {
+        let mut usage = PropertyUsage::new(candidate.local.clone(), true);
+        script.ast.visit_with(&mut usage);
+        candidate.merge(usage)
+    });
+
+    // references in the modules importing it
+    for (dependent_id, dependency) in module_graph.get_dependents(module_id) {
+        if candidates.is_empty() {
+            break;
+        }
+        let exported = candidates
+            .iter()
+            .flat_map(|c| c.exported.iter())
+            .collect::<HashSet<_>>();
+        if exported.is_empty() {
+            break;
+        }
+        if !matches!(dependency.resolve_type, ResolveType::Import(_)) {
+            // re-exported, required or dynamic imported, can't follow the references
+            return HashMap::new();
+        }
+        let dependent = module_graph.get_module(dependent_id).unwrap();
+        let Some(ModuleAst::Script(dependent_script)) = dependent.info.as_ref().map(|i| &i.ast)
+        else {
+            return HashMap::new();
+        };
+
+        let mut imported = vec![];
+        for item in dependent_script.ast.body.iter() {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                specifiers, src, ..
+            })) = item
+            else {
+                continue;
+            };
+            if src.value != dependency.source {
+                continue;
+            }
+            for specifier in specifiers {
+                match specifier {
+                    ImportSpecifier::Named(named) => {
+                        let name = match &named.imported {
+                            Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                            Some(ModuleExportName::Str(str)) => str.value.to_string(),
+                            None => named.local.sym.to_string(),
+                        };
+                        if exported.contains(&name) {
+                            imported.push((name, named.local.to_id()));
+                        }
+                    }
+                    ImportSpecifier::Namespace(_) => return HashMap::new(),
+                    ImportSpecifier::Default(_) => {}
+                }
+            }
+        }
+
+        for (name, local) in imported {
+            let mut usage = PropertyUsage::new(local, false);
+            dependent_script.ast.visit_with(&mut usage);
+            candidates.retain_mut(|candidate| {
+                !candidate.exported.contains(&name) || candidate.merge(usage.clone())
+            });
+        }
+    }
+
+    candidates
+        .into_iter()
+        .map(|candidate| (candidate.local, candidate.used_props))
+        .collect()
+}
+
+struct Candidate {
+    local: Id,
+    exported: HashSet<String>,
+    used_props: HashSet<String>,
+}
+
+impl Candidate {
+    // returns false if the object escapes
+    fn merge(&mut self, usage: PropertyUsage) -> bool {
+        if usage.escaped {
+            return false;
+        }
+        self.used_props.extend(usage.props);
+        true
+    }
+}
+
+fn collect_candidates(module: &SwcModule, unresolved_ctxt: SyntaxContext) -> Vec<Candidate> {
+    let mut candidates = vec![];
+    let mut add_var_decl = |decls: &[VarDeclarator], exported: bool| {
+        for decl in decls {
+            if let Pat::Ident(binding) = &decl.name
+                && let Some(init) = &decl.init
+                && get_shakable_object(init, unresolved_ctxt).is_some()
+            {
+                candidates.push(Candidate {
+                    local: binding.id.to_id(),
+                    exported: if exported {
+                        HashSet::from([binding.id.sym.to_string()])
+                    } else {
+                        HashSet::new()
+                    },
+                    used_props: HashSet::new(),
+                });
+            }
+        }
+    };
+
+    for item in module.body.iter() {
+        match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl)))
+                if var_decl.kind == VarDeclKind::Const =>
+            {
+                add_var_decl(&var_decl.decls, false);
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+                if let Decl::Var(var_decl) = &export_decl.decl
+                    && var_decl.kind == VarDeclKind::Const
+                {
+                    add_var_decl(&var_decl.decls, true);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // export { Colors, Colors as Palette }
+    let mut escaped = HashSet::new();
+    for item in module.body.iter() {
+        let ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export)) = item else {
+            continue;
+        };
+        if named_export.src.is_some() {
+            continue;
+        }
+        for specifier in named_export.specifiers.iter() {
+            let ExportSpecifier::Named(named) = specifier else {
+                continue;
+            };
+            let ModuleExportName::Ident(orig) = &named.orig else {
+                continue;
+            };
+            let exported = match &named.exported {
+                Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                Some(ModuleExportName::Str(str)) => str.value.to_string(),
+                None => orig.sym.to_string(),
+            };
+            let orig = orig.to_id();
+            if exported == "default" {
+                escaped.insert(orig);
+            } else if let Some(candidate) = candidates.iter_mut().find(|c| c.local == orig) {
+                candidate.exported.insert(exported);
+            }
+        }
+    }
+    candidates.retain(|candidate| !escaped.contains(&candidate.local));
+
+    candidates
+}
+
+// `{ ... }` or `Object.freeze({ ... })` with only static keys
+fn get_shakable_object(expr: &Expr, unresolved_ctxt: SyntaxContext) -> Option<&ObjectLit> {
+    let object = match expr {
+        Expr::Object(object) => object,
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(box Expr::Member(MemberExpr { obj, prop, .. })),
+            args,
+            ..
+        }) if args.len() == 1 && args[0].spread.is_none() => {
+            let is_object = matches!(
+                obj.as_ref(),
+                Expr::Ident(ident) if ident.sym == *"Object" && ident.span.ctxt == unresolved_ctxt
+            );
+            let is_object_freeze =
+                is_object && matches!(prop, MemberProp::Ident(ident) if ident.sym == *"freeze");
+            match args[0].expr.as_ref() {
+                Expr::Object(object) if is_object_freeze => object,
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    let is_static = object.props.iter().all(|prop| match prop {
+        PropOrSpread::Prop(box Prop::Shorthand(_)) => true,
+        PropOrSpread::Prop(box Prop::KeyValue(key_value)) => get_prop_key(&key_value.key).is_some(),
+        // spreads, methods and accessors
+        _ => false,
+    });
+    if is_static {
+        Some(object)
+    } else {
+        None
+    }
+}
+
+pub(super) fn get_prop_key(key: &PropName) -> Option<String> {
+    match key {
+        PropName::Ident(ident) => Some(ident.sym.to_string()),
+        PropName::Str(str) => Some(str.value.to_string()),
+        PropName::Num(num) => Some(num.value.to_string()),
+        _ => None,
+    }
+}
+
+// values which can be removed without side effects
+pub(super) fn is_pure_value(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(_) | Expr::Ident(_) | Expr::Arrow(_) | Expr::Fn(_) => true,
+        Expr::Tpl(tpl) => tpl.exprs.is_empty(),
+        Expr::Paren(paren) => is_pure_value(&paren.expr),
+        _ => false,
+    }
+}
+
+#[derive(Clone)]
+struct PropertyUsage {
+    local: Id,
+    // the module defining the object, its declaration and export
+    // specifiers are not references
+    is_defining_module: bool,
+    escaped: bool,
+    props: HashSet<String>,
+}
+
+impl PropertyUsage {
+    fn new(local: Id, is_defining_module: bool) -> Self {
+        Self {
+            local,
+            is_defining_module,
+            escaped: false,
+            props: HashSet::new(),
+        }
+    }
+
+    fn is_local(&self, expr: &Expr) -> bool {
+        matches!(expr, Expr::Ident(ident) if ident.to_id() == self.local)
+    }
+
+    fn is_local_member(&self, expr: &Expr) -> bool {
+        matches!(expr, Expr::Member(member) if self.is_local(&member.obj))
+    }
+}
+
+impl Visit for PropertyUsage {
+    fn visit_import_decl(&mut self, _import_decl: &ImportDecl) {}
+
+    fn visit_export_named_specifier(&mut self, specifier: &ExportNamedSpecifier) {
+        if !self.is_defining_module {
+            specifier.visit_children_with(self);
+        }
+    }
+
+    fn visit_var_declarator(&mut self, decl: &VarDeclarator) {
+        match &decl.name {
+            Pat::Ident(binding) if self.is_defining_module && binding.id.to_id() == self.local => {
+                decl.init.visit_with(self);
+            }
+            _ => decl.visit_children_with(self),
+        }
+    }
+
+    fn visit_member_expr(&mut self, member: &MemberExpr) {
+        if !self.is_local(&member.obj) {
+            member.visit_children_with(self);
+            return;
+        }
+        let prop = match &member.prop {
+            MemberProp::Ident(ident) => Some(ident.sym.to_string()),
+            MemberProp::Computed(computed) => match computed.expr.as_ref() {
+                Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+                Expr::Lit(Lit::Num(num)) => Some(num.value.to_string()),
+                _ => None,
+            },
+            MemberProp::PrivateName(_) => None,
+        };
+        match prop {
+            Some(prop) => {
+                self.props.insert(prop);
+            }
+            None => self.escaped = true,
+        }
+    }
+
+    // writes
+    fn visit_assign_expr(&mut self, assign: &AssignExpr) {
+        if let PatOrExpr::Expr(left) = &assign.left
+            && self.is_local_member(left)
+        {
+            self.escaped = true;
+        }
+        assign.visit_children_with(self);
+    }
+
+    fn visit_pat(&mut self, pat: &Pat) {
+        if let Pat::Expr(expr) = pat
+            && self.is_local_member(expr)
+        {
+            self.escaped = true;
+        }
+        pat.visit_children_with(self);
+    }
+
+    fn visit_update_expr(&mut self, update: &UpdateExpr) {
+        if self.is_local_member(&update.arg) {
+            self.escaped = true;
+        }
+        update.visit_children_with(self);
+    }
+
+    fn visit_unary_expr(&mut self, unary: &UnaryExpr) {
+        if unary.op == UnaryOp::Delete && self.is_local_member(&unary.arg) {
+            self.escaped = true;
+        }
+        unary.visit_children_with(self);
+    }
+
+    // method calls bind `this` to the object
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Callee::Expr(callee) = &call.callee
+            && self.is_local_member(callee)
+        {
+            self.escaped = true;
+        }
+        call.visit_children_with(self);
+    }
+
+    fn visit_opt_call(&mut self, call: &OptCall) {
+        if self.is_local_member(&call.callee) {
+            self.escaped = true;
+        }
+        call.visit_children_with(self);
+    }
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        if ident.to_id() == self.local {
+            self.escaped = true;
+        }
+    }
+}
+
+struct UnusedPropertiesRemover<'a> {
+    used_props: &'a HashMap<Id, HashSet<String>>,
+}
+
+impl VisitMut for UnusedPropertiesRemover<'_> {
+    fn visit_mut_var_declarator(&mut self, decl: &mut VarDeclarator) {
+        let Pat::Ident(binding) = &decl.name else {
+            return;
+        };
+        let Some(used_props) = self.used_props.get(&binding.id.to_id()) else {
+            return;
+        };
+        let Some(init) = decl.init.as_mut() else {
+            return;
+        };
+        let object = match init.as_mut() {
+            Expr::Object(object) => object,
+            Expr::Call(CallExpr { args, .. }) if args.len() == 1 => match args[0].expr.as_mut() {
+                Expr::Object(object) => object,
+                _ => return,
+            },
+            _ => return,
+        };
+        object.props.retain(|prop| match prop {
+            PropOrSpread::Prop(box Prop::Shorthand(ident)) => {
+                used_props.contains(&ident.sym.to_string())
+            }
+            PropOrSpread::Prop(box Prop::KeyValue(key_value)) => {
+                get_prop_key(&key_value.key).map_or(true, |key| used_props.contains(&key))
+                    || !is_pure_value(&key_value.value)
+            }
+            _ => true,
+        });
+    }
+
+    // only top level declarations are candidates
+    fn visit_mut_function(&mut self, _function: &mut Function) {}
+
+    fn visit_mut_arrow_expr(&mut self, _arrow: &mut ArrowExpr) {}
+
+    fn visit_mut_class(&mut self, _class: &mut Class) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::tests::TestUtils;
+
+    fn used_props(code: &str) -> Option<Vec<String>> {
+        let test_utils = TestUtils::gen_js_ast(code);
+        let ast = test_utils.ast.js();
+        let unresolved_ctxt = SyntaxContext::empty().apply_mark(ast.unresolved_mark);
+        let mut candidates = collect_candidates(&ast.ast, unresolved_ctxt);
+        let candidate = candidates.pop()?;
+        let mut usage = PropertyUsage::new(candidate.local, true);
+        ast.ast.visit_with(&mut usage);
+        if usage.escaped {
+            return None;
+        }
+        let mut props = usage.props.into_iter().collect::<Vec<_>>();
+        props.sort();
+        Some(props)
+    }
+
+    #[test]
+    fn test_static_property_access() {
+        assert_eq!(
+            used_props(
+                r#"
+const red = 1, green = 2;
+export const Colors = { red, green };
+console.log(Colors.red, Colors["red"]);
+"#
+            ),
+            Some(vec!["red".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_object_freeze() {
+        assert_eq!(
+            used_props(
+                r#"
+const Colors = Object.freeze({ red: 1, green: 2 });
+console.log(Colors.green);
+export { Colors };
+"#
+            ),
+            Some(vec!["green".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_escaped() {
+        let cases = [
+            "export const Colors = { red: 1 }; console.log(Colors);",
+            "export const Colors = { red: 1 }; console.log(Colors[key]);",
+            "export const Colors = { red: 1 }; Colors.red = 2;",
+            "export const Colors = { red: 1 }; Colors.red();",
+            "export const Colors = { red: 1 }; delete Colors.red;",
+            "export const Colors = { red: 1 }; const o = { ...Colors };",
+            "const Colors = { red: 1 }; export default Colors;",
+            "const Colors = { red: 1 }; export { Colors as default };",
+        ];
+        for case in cases {
+            assert_eq!(used_props(case), None, "{}", case);
+        }
+    }
+
+    #[test]
+    fn test_not_shakable_object() {
+        let cases = [
+            "export const Colors = { ...other, red: 1 };",
+            "export const Colors = { [key]: 1 };",
+            "export const Colors = { get red() { return 1 } };",
+            "export let Colors = { red: 1 };",
+            "const Object = {}; export const Colors = Object.freeze({ red: 1 });",
+        ];
+        for case in cases {
+            assert_eq!(used_props(case), None, "{}", case);
+        }
+    }
+
+    #[test]
+    fn test_remove_unused_properties() {
+        let mut test_utils = TestUtils::gen_js_ast(
+            r#"export const Colors = Object.freeze({ red: 1, green: 2, blue: sideEffect() });"#,
+        );
+        let ast = test_utils.ast.js_mut();
+        let local = (
+            "Colors".into(),
+            SyntaxContext::empty().apply_mark(ast.top_level_mark),
+        );
+        let used_props = HashMap::from([(local, HashSet::from(["red".to_string()]))]);
+        ast.ast.visit_mut_with(&mut UnusedPropertiesRemover {
+            used_props: &used_props,
+        });
+        assert_eq!(
+            test_utils.js_ast_to_code(),
+            r#"export const Colors = Object.freeze({
+    red: 1,
+    blue: sideEffect()
+});"#
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use anyhow::Result;
@@ -139,6 +139,8 @@ impl ReExportSource {
 #[derive(Debug)]
 pub enum ReExportType {
     // export * as x from "x"
+    // import * as x from "y"; export { x }
+    // the whole module is used, so it's not followed any further
     Namespace,
     // import x from "y"
     // export x from "y"
@@ -165,6 +167,7 @@ pub(super) fn skip_module_optimize(
     module_graph: &mut ModuleGraph,
     tree_shake_modules_ids: &Vec<ModuleId>,
     tree_shake_modules_map: &HashMap<ModuleId, RefCell<TreeShakeModule>>,
+    imports_only: bool,
     _context: &Arc<Context>,
 ) -> Result<()> {
     mako_profile_function!();
@@ -426,8 +429,10 @@ pub(super) fn skip_module_optimize(
                     }
                 }
 
+                // only consumers' imports are rewritten with optimizePackageImports
                 if let Some(export_info) = &stmt.export_info
                     && let Some(source) = &export_info.source
+                    && !imports_only
                 {
                     if let Some(tsm_ref) = get_imported_tree_shake_module(
                         current_module_id,
@@ -532,12 +537,35 @@ fn get_imported_tree_shake_module<'a>(
     }
 }
 
-fn find_ident_export_source(
+pub(super) fn find_ident_export_source(
     module_graph: &ModuleGraph,
     tsm_map: &HashMap<ModuleId, RefCell<TreeShakeModule>>,
     proxy_module_id: &ModuleId,
     used_ident: &String,
 ) -> Option<ReExportReplace> {
+    find_ident_export_source_visited(
+        module_graph,
+        tsm_map,
+        proxy_module_id,
+        used_ident,
+        &mut HashSet::new(),
+    )
+}
+
+// modules re-exporting each other (`export { a } from "./b"` in a.js and
+// `export { a } from "./a"` in b.js) make a cycle, stop following it when the
+// same ident of the same module is met again
+fn find_ident_export_source_visited(
+    module_graph: &ModuleGraph,
+    tsm_map: &HashMap<ModuleId, RefCell<TreeShakeModule>>,
+    proxy_module_id: &ModuleId,
+    used_ident: &String,
+    visited: &mut HashSet<(ModuleId, String)>,
+) -> Option<ReExportReplace> {
+    if !visited.insert((proxy_module_id.clone(), used_ident.clone())) {
+        return None;
+    }
+
     if let Some(tsm) = tsm_map.get(proxy_module_id) {
         let proxy_tsm = tsm.borrow();
 
@@ -563,11 +591,12 @@ fn find_ident_export_source(
                     if !next_tsm.has_side_effect() {
                         let ref_ident = re_export_source.to_outer_ref();
 
-                        if let Some(next_replace) = find_ident_export_source(
+                        if let Some(next_replace) = find_ident_export_source_visited(
                             module_graph,
                             tsm_map,
                             &next_tsm.module_id,
                             &ref_ident,
+                            visited,
                         ) {
                             return Some(ReExportReplace {
                                 re_export_ident: used_ident.clone(),
@@ -603,3 +632,97 @@ fn find_ident_export_source(
 
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use swc_core::common::GLOBALS;
+
+    use super::find_ident_export_source;
+    use crate::ast::file::{Content, File, JsContent};
+    use crate::ast::js_ast::JsAst;
+    use crate::compiler::Context;
+    use crate::config::TreeShakingLevel;
+    use crate::module::{
+        Dependency, Module, ModuleAst, ModuleId, ModuleInfo, NamedExportType, ResolveType,
+    };
+    use crate::module_graph::ModuleGraph;
+    use crate::plugins::tree_shaking::module::TreeShakeModule;
+
+    fn module_with_code(path: &str, code: &str, context: &Arc<Context>) -> Module {
+        let file = File::with_content(
+            path.to_string(),
+            Content::Js(JsContent {
+                content: code.to_string(),
+                ..Default::default()
+            }),
+            context.clone(),
+        );
+        let ast = JsAst::new(&file, context.clone()).unwrap();
+        Module {
+            id: path.into(),
+            is_entry: false,
+            info: Some(ModuleInfo {
+                ast: ModuleAst::Script(ast),
+                file,
+                ..Default::default()
+            }),
+            side_effects: false,
+        }
+    }
+
+    fn re_export_dependency(source: &str) -> Dependency {
+        Dependency {
+            source: source.to_string(),
+            resolve_as: None,
+            resolve_type: ResolveType::ExportNamed(NamedExportType::Named),
+            order: 0,
+            span: None,
+        }
+    }
+
+    #[test]
+    fn test_circular_re_export() {
+        let context: Arc<Context> = Default::default();
+        let a = module_with_code("a.js", r#"export { a } from "./b";"#, &context);
+        let b = module_with_code("b.js", r#"export { a } from "./a";"#, &context);
+        let a_id = a.id.clone();
+        let b_id = b.id.clone();
+
+        let comments = context.meta.script.origin_comments.read().unwrap();
+        let tsm_map = GLOBALS.set(&context.meta.script.globals, || {
+            [&a, &b]
+                .into_iter()
+                .enumerate()
+                .map(|(order, module)| {
+                    let tsm = TreeShakeModule::new(
+                        module,
+                        order,
+                        TreeShakingLevel::Recommended,
+                        comments.get_swc_comments().clone(),
+                    );
+                    (module.id.clone(), RefCell::new(tsm))
+                })
+                .collect::<HashMap<ModuleId, RefCell<TreeShakeModule>>>()
+        });
+
+        let mut module_graph = ModuleGraph::new();
+        module_graph.add_module(a);
+        module_graph.add_module(b);
+        module_graph.add_dependency(&a_id, &b_id, re_export_dependency("./b"));
+        module_graph.add_dependency(&b_id, &a_id, re_export_dependency("./a"));
+
+        let replace =
+            find_ident_export_source(&module_graph, &tsm_map, &a_id, &"a".to_string()).unwrap();
+
+        // the chain stops where the cycle closes
+        assert_eq!(replace.from_module_id, a_id);
+        assert_eq!(
+            replace.re_export_source.describe(),
+            "ReExport from ./a by Named(\"a\")"
+        );
+    }
+}
@@ -11,6 +11,8 @@ pub(crate) mod used_idents_collector;
 use analyze_imports_and_exports::analyze_imports_and_exports;
 use swc_core::common::{Span, SyntaxContext};
 
+use crate::ast::comments::MakoComments;
+use crate::config::TreeShakingLevel;
 use crate::plugins::tree_shaking::module::{is_ident_equal, UsedIdent};
 use crate::plugins::tree_shaking::shake::strip_context;
 use crate::plugins::tree_shaking::statement_graph::analyze_imports_and_exports::StatementInfo;
@@ -98,8 +100,9 @@ impl From<&ExportInfo> for ExportSource {
 impl ExportSpecifierInfo {
     pub fn to_idents(&self) -> Vec<String> {
         match self {
-            ExportSpecifierInfo::All(_what) => {
-                vec![]
+            // the names are filled in once the exports of the source are known
+            ExportSpecifierInfo::All(idents) => {
+                idents.iter().map(|ident| strip_context(ident)).collect()
             }
             ExportSpecifierInfo::Named { local, exported } => {
                 if let Some(exp) = exported {
@@ -233,6 +236,15 @@ impl ExportInfo {
     }
 }
 
+/// A name exported more than once by a module, the sources are `None` for the
+/// local exports
+#[derive(Debug, PartialEq, Eq)]
+pub struct DuplicateExport {
+    pub name: String,
+    pub first: Option<String>,
+    pub second: Option<String>,
+}
+
 #[derive(Debug)]
 pub struct Statement {
     pub id: StatementId,
@@ -249,7 +261,13 @@ pub struct Statement {
 }
 
 impl Statement {
-    pub fn new(id: StatementId, stmt: &ModuleItem, unresolved_ctxt: SyntaxContext) -> Self {
+    pub fn new(
+        id: StatementId,
+        stmt: &ModuleItem,
+        unresolved_ctxt: SyntaxContext,
+        level: TreeShakingLevel,
+        comments: &MakoComments,
+    ) -> Self {
         let StatementInfo {
             import_info,
             export_info,
@@ -259,7 +277,7 @@ impl Statement {
             is_self_executed,
             span,
             has_side_effects,
-        } = analyze_imports_and_exports(&id, stmt, None, unresolved_ctxt);
+        } = analyze_imports_and_exports(&id, stmt, None, unresolved_ctxt, level, comments);
 
         Self {
             id,
@@ -285,12 +303,17 @@ pub struct StatementGraph {
 }
 
 impl StatementGraph {
-    pub fn new(module: &SwcModule, unresolved_ctxt: SyntaxContext) -> Self {
+    pub fn new(
+        module: &SwcModule,
+        unresolved_ctxt: SyntaxContext,
+        level: TreeShakingLevel,
+        comments: &MakoComments,
+    ) -> Self {
         let mut g = petgraph::graph::Graph::new();
         let mut id_index_map = HashMap::new();
 
         for (index, stmt) in module.body.iter().enumerate() {
-            let statement = Statement::new(index, stmt, unresolved_ctxt);
+            let statement = Statement::new(index, stmt, unresolved_ctxt, level, comments);
 
             let node = g.add_node(statement);
             id_index_map.insert(index, node);
@@ -299,20 +322,28 @@ impl StatementGraph {
         let mut graph = Self { g, id_index_map };
         let mut edges_to_add = Vec::new();
 
+        // the statements that define the ident, in the statements order
+        let mut def_stmts_map: HashMap<&String, Vec<StatementId>> = HashMap::new();
+        for def_stmt in graph.stmts() {
+            for di in &def_stmt.defined_idents {
+                def_stmts_map.entry(di).or_default().push(def_stmt.id);
+            }
+        }
+
         for stmt in graph.stmts() {
             // find the statement that defines the ident
-            for def_stmt in graph.stmts() {
-                let mut deps_idents = HashSet::new();
+            let mut deps: BTreeMap<StatementId, HashSet<String>> = BTreeMap::new();
 
-                for di in &def_stmt.defined_idents {
-                    if stmt.used_idents.contains(di) {
-                        deps_idents.insert(di.clone());
+            for ui in &stmt.used_idents {
+                if let Some(def_stmt_ids) = def_stmts_map.get(ui) {
+                    for def_stmt_id in def_stmt_ids {
+                        deps.entry(*def_stmt_id).or_default().insert(ui.clone());
                     }
                 }
+            }
 
-                if !deps_idents.is_empty() {
-                    edges_to_add.push((stmt.id, def_stmt.id, deps_idents));
-                }
+            for (def_stmt_id, deps_idents) in deps {
+                edges_to_add.push((stmt.id, def_stmt_id, deps_idents));
             }
         }
 
@@ -385,6 +416,37 @@ impl StatementGraph {
             .collect()
     }
 
+    // `export * from` is left out, the names it conflicts with are ambiguous
+    // and not exported instead
+    pub fn duplicate_exports(&self) -> Vec<DuplicateExport> {
+        let mut exported: HashMap<String, Option<String>> = HashMap::new();
+        let mut duplicates = vec![];
+
+        for stmt in self.stmts() {
+            let Some(export_info) = &stmt.export_info else {
+                continue;
+            };
+            for name in export_info
+                .specifiers
+                .iter()
+                .filter(|specifier| !matches!(specifier, ExportSpecifierInfo::All(_)))
+                .flat_map(|specifier| specifier.to_idents())
+            {
+                if let Some(first) = exported.get(&name) {
+                    duplicates.push(DuplicateExport {
+                        name,
+                        first: first.clone(),
+                        second: export_info.source.clone(),
+                    });
+                } else {
+                    exported.insert(name, export_info.source.clone());
+                }
+            }
+        }
+
+        duplicates
+    }
+
     pub fn analyze_used_statements_and_idents(
         &self,
         used_exports: BTreeMap<StatementId, HashSet<UsedIdent>>,
@@ -498,3 +560,128 @@ impl StatementGraph {
         used_statements
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::sync::Arc;
+
+    use swc_core::common::{SyntaxContext, GLOBALS};
+
+    use super::{ExportInfo, ExportSpecifierInfo, StatementGraph};
+    use crate::ast::file::{Content, File, JsContent};
+    use crate::ast::js_ast::JsAst;
+    use crate::compiler::Context;
+    use crate::config::TreeShakingLevel;
+
+    // the edges built by comparing every pair of statements
+    fn pairwise_edges(graph: &StatementGraph) -> Vec<(usize, usize, HashSet<String>)> {
+        let mut edges = vec![];
+        for stmt in graph.stmts() {
+            for def_stmt in graph.stmts() {
+                let idents = def_stmt
+                    .defined_idents
+                    .intersection(&stmt.used_idents)
+                    .cloned()
+                    .collect::<HashSet<_>>();
+                if !idents.is_empty() {
+                    edges.push((stmt.id, def_stmt.id, idents));
+                }
+            }
+        }
+        edges
+    }
+
+    #[test]
+    fn test_edges() {
+        let mut code = String::new();
+        for i in 0..50 {
+            code.push_str(&format!("import {{ i{i} }} from './i{i}';\n"));
+            code.push_str(&format!("var v{i} = i{i} + (v{} || 0);\n", i / 2));
+            code.push_str(&format!(
+                "function f{i}() {{ return v{i} + f{}(); }}\n",
+                i / 3
+            ));
+            code.push_str(&format!("export {{ f{i}, v{i} as w{i} }};\n"));
+        }
+        code.push_str("var v0 = 1;\nconsole.log(v0, f49);\n");
+
+        statement_graph(&code, |graph| {
+            let edges = graph
+                .edges()
+                .into_iter()
+                .map(|(from, to, edge)| (from.id, to.id, edge.idents.clone()))
+                .collect::<Vec<_>>();
+
+            assert!(edges.len() > 200);
+            assert_eq!(edges, pairwise_edges(graph));
+        });
+    }
+
+    fn statement_graph(code: &str, f: impl FnOnce(&mut StatementGraph)) {
+        let context: Arc<Context> = Default::default();
+        let file = File::with_content(
+            "test.js".to_string(),
+            Content::Js(JsContent {
+                content: code.to_string(),
+                ..Default::default()
+            }),
+            context.clone(),
+        );
+        let ast = JsAst::new(&file, context.clone()).unwrap();
+
+        GLOBALS.set(&context.meta.script.globals, || {
+            let unresolved_ctxt = SyntaxContext::empty().apply_mark(ast.unresolved_mark);
+            let comments = context.meta.script.origin_comments.read().unwrap();
+            let mut graph = StatementGraph::new(
+                &ast.ast,
+                unresolved_ctxt,
+                TreeShakingLevel::Recommended,
+                comments.get_swc_comments(),
+            );
+            f(&mut graph);
+        });
+    }
+
+    #[test]
+    fn test_export_all_idents() {
+        // export * from "./a", with the exports of ./a filled in
+        let export_info = ExportInfo {
+            source: Some("./a".to_string()),
+            specifiers: vec![ExportSpecifierInfo::All(vec![
+                "foo".to_string(),
+                "bar".to_string(),
+            ])],
+            stmt_id: 0,
+        };
+
+        assert_eq!(
+            export_info
+                .specifiers
+                .iter()
+                .flat_map(|specifier| specifier.to_idents())
+                .collect::<Vec<_>>(),
+            vec!["foo".to_string(), "bar".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_export_all_not_duplicate() {
+        statement_graph(
+            "export * from './a';\nexport { foo } from './b';\n",
+            |graph| {
+                graph.stmt_mut(&0).export_info = Some(ExportInfo {
+                    source: Some("./a".to_string()),
+                    specifiers: vec![ExportSpecifierInfo::All(vec![
+                        "foo".to_string(),
+                        "bar".to_string(),
+                    ])],
+                    stmt_id: 0,
+                });
+
+                // the explicit export wins over the one of `export *`
+                assert!(graph.duplicate_exports().is_empty());
+            },
+        );
+    }
+}
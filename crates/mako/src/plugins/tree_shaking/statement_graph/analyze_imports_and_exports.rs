@@ -3,13 +3,15 @@ use std::ops::Deref;
 
 use swc_core::common::{Span, SyntaxContext, DUMMY_SP};
 use swc_core::ecma::ast as swc_ecma_ast;
-use swc_core::ecma::ast::{Expr, ModuleExportName, ModuleItem, VarDecl};
+use swc_core::ecma::ast::{Callee, Expr, ExprOrSpread, ModuleExportName, ModuleItem, VarDecl};
 use swc_core::ecma::utils::{ExprCtx, ExprExt};
 use swc_core::ecma::visit::VisitWith;
 
 use super::defined_idents_collector::DefinedIdentsCollector;
 use super::used_idents_collector::{self, UsedIdentsCollector};
 use super::{ExportInfo, ExportSpecifierInfo, ImportInfo, ImportSpecifierInfo, StatementId};
+use crate::ast::comments::MakoComments;
+use crate::config::TreeShakingLevel;
 
 #[derive(Debug)]
 pub struct StatementInfo {
@@ -28,6 +30,8 @@ pub fn analyze_imports_and_exports(
     stmt: &ModuleItem,
     used_defined_idents: Option<HashSet<String>>,
     unresolve_ctxt: SyntaxContext,
+    level: TreeShakingLevel,
+    comments: &MakoComments,
 ) -> StatementInfo {
     let mut defined_idents = HashSet::new();
     let mut used_idents = HashSet::new();
@@ -415,7 +419,7 @@ pub fn analyze_imports_and_exports(
                                 .insert(defined_ident.clone(), local_used_idents.clone());
                         }
 
-                        if !is_pure_var_decl(var_decl, unresolve_ctxt) {
+                        if !is_pure_var_decl(var_decl, unresolve_ctxt, level, comments) {
                             is_self_executed = true;
                         }
                     }
@@ -427,7 +431,7 @@ pub fn analyze_imports_and_exports(
             swc_ecma_ast::Stmt::Expr(expr) => {
                 span = expr.span;
 
-                if !is_pure_expression(&expr.expr, unresolve_ctxt) {
+                if !is_pure_expression(&expr.expr, unresolve_ctxt, level, comments) {
                     is_self_executed = true;
                 }
                 analyze_and_insert_used_idents(expr, None);
@@ -447,19 +451,75 @@ pub fn analyze_imports_and_exports(
     }
 }
 
-fn is_pure_var_decl(var: &VarDecl, unresolved_ctxt: SyntaxContext) -> bool {
+fn is_pure_var_decl(
+    var: &VarDecl,
+    unresolved_ctxt: SyntaxContext,
+    level: TreeShakingLevel,
+    comments: &MakoComments,
+) -> bool {
     var.decls.iter().all(|decl| {
         if let Some(ref init) = decl.init {
-            is_pure_expression(init, unresolved_ctxt)
+            is_pure_expression(init, unresolved_ctxt, level, comments)
         } else {
             true
         }
     })
 }
 
-fn is_pure_expression(expr: &Expr, unresolved_ctxt: SyntaxContext) -> bool {
+fn is_pure_expression(
+    expr: &Expr,
+    unresolved_ctxt: SyntaxContext,
+    level: TreeShakingLevel,
+    comments: &MakoComments,
+) -> bool {
+    // the calls are assumed pure in the aggressive level, or with a
+    // `/*#__PURE__*/` in front of them, but not the arguments, e.g. `foo(a = 1)`
+    let is_pure_call = |span| level == TreeShakingLevel::Aggressive || comments.has_pure(span);
+    match expr {
+        Expr::Call(call) if is_pure_call(call.span) => {
+            return match &call.callee {
+                Callee::Expr(callee) => is_pure_callee(callee, unresolved_ctxt),
+                _ => false,
+            } && are_pure_args(&call.args, unresolved_ctxt, level, comments);
+        }
+        Expr::New(new) if is_pure_call(new.span) => {
+            return is_pure_callee(&new.callee, unresolved_ctxt)
+                && new.args.as_ref().map_or(true, |args| {
+                    are_pure_args(args, unresolved_ctxt, level, comments)
+                });
+        }
+        Expr::Paren(paren) => {
+            return is_pure_expression(&paren.expr, unresolved_ctxt, level, comments);
+        }
+        Expr::Seq(seq) => {
+            return seq
+                .exprs
+                .iter()
+                .all(|expr| is_pure_expression(expr, unresolved_ctxt, level, comments));
+        }
+        _ => {}
+    }
     !expr.may_have_side_effects(&ExprCtx {
         unresolved_ctxt,
         is_unresolved_ref_safe: false,
     })
 }
+
+// referencing the callee is pure, even if it's a global
+fn is_pure_callee(callee: &Expr, unresolved_ctxt: SyntaxContext) -> bool {
+    !callee.may_have_side_effects(&ExprCtx {
+        unresolved_ctxt,
+        is_unresolved_ref_safe: true,
+    })
+}
+
+fn are_pure_args(
+    args: &[ExprOrSpread],
+    unresolved_ctxt: SyntaxContext,
+    level: TreeShakingLevel,
+    comments: &MakoComments,
+) -> bool {
+    args.iter().all(|arg| {
+        arg.spread.is_none() && is_pure_expression(&arg.expr, unresolved_ctxt, level, comments)
+    })
+}
@@ -0,0 +1,182 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::Result;
+use pathdiff::diff_paths;
+use swc_core::ecma::ast::{Id, Ident, ImportDecl, ImportSpecifier};
+use swc_core::ecma::visit::{Visit, VisitWith};
+use tracing::warn;
+
+use crate::compiler::Context;
+use crate::plugin::Plugin;
+
+pub struct UnusedAssetsPlugin {}
+
+impl Plugin for UnusedAssetsPlugin {
+    fn name(&self) -> &str {
+        "unused_assets"
+    }
+
+    fn generate_begin(&self, context: &Arc<Context>) -> Result<()> {
+        if context.args.watch {
+            return Ok(());
+        }
+        let warnings = UnusedAssetsPlugin::collect_unused_assets(context);
+        if !warnings.is_empty() {
+            warn!(
+                "emitted assets are imported but never used:\n{}",
+                warnings.join("\n")
+            );
+        }
+        Ok(())
+    }
+}
+
+impl UnusedAssetsPlugin {
+    // `<asset> is imported by <module> but never used` of the emitted assets
+    fn collect_unused_assets(context: &Arc<Context>) -> Vec<String> {
+        let assets_info = context.assets_info.lock().unwrap();
+        if assets_info.is_empty() {
+            return vec![];
+        }
+        let relative = |path: &str| {
+            let absolute_path = PathBuf::from(path);
+            diff_paths(&absolute_path, &context.root)
+                .unwrap_or(absolute_path)
+                .to_string_lossy()
+                .to_string()
+        };
+
+        let module_graph = context.module_graph.read().unwrap();
+        let mut warnings = vec![];
+        for module in module_graph.modules() {
+            if module.id.id.contains("node_modules") {
+                continue;
+            }
+            let ast = match module.info.as_ref().and_then(|info| info.ast.as_script()) {
+                Some(ast) => ast,
+                None => continue,
+            };
+            let mut collector = AssetImportCollector::default();
+            ast.ast.visit_with(&mut collector);
+            let unused_sources = collector.unused_sources();
+            if unused_sources.is_empty() {
+                continue;
+            }
+            for (dep_module_id, dep) in module_graph.get_dependencies(&module.id) {
+                if unused_sources.contains(&dep.source)
+                    && assets_info.contains_key(&dep_module_id.id)
+                {
+                    warnings.push(format!(
+                        "{} is imported by {} but never used",
+                        relative(&dep_module_id.id),
+                        relative(&module.id.id)
+                    ));
+                }
+            }
+        }
+
+        warnings.sort();
+        warnings.dedup();
+        warnings
+    }
+}
+
+#[derive(Default)]
+struct AssetImportCollector {
+    // import source -> local bindings
+    imports: Vec<(String, Vec<Id>)>,
+    used: HashSet<Id>,
+}
+
+impl AssetImportCollector {
+    // sources of the imports whose bindings are all unused, including the
+    // ones without bindings, e.g. `import './a.png'`
+    fn unused_sources(&self) -> Vec<String> {
+        self.imports
+            .iter()
+            .filter(|(_, locals)| locals.iter().all(|local| !self.used.contains(local)))
+            .map(|(source, _)| source.clone())
+            .collect()
+    }
+}
+
+impl Visit for AssetImportCollector {
+    fn visit_import_decl(&mut self, import_decl: &ImportDecl) {
+        if import_decl.type_only {
+            return;
+        }
+        let locals = import_decl
+            .specifiers
+            .iter()
+            .map(|specifier| match specifier {
+                ImportSpecifier::Named(named) => named.local.to_id(),
+                ImportSpecifier::Default(default) => default.local.to_id(),
+                ImportSpecifier::Namespace(namespace) => namespace.local.to_id(),
+            })
+            .collect();
+        self.imports
+            .push((import_decl.src.value.to_string(), locals));
+        // the local bindings of specifiers are not usages
+    }
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.used.insert(ident.to_id());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_core::ecma::visit::VisitWith;
+
+    use super::{AssetImportCollector, UnusedAssetsPlugin};
+    use crate::ast::tests::TestUtils;
+    use crate::utils::test_helper::setup_compiler;
+
+    #[test]
+    fn test_unused_assets() {
+        let compiler = setup_compiler("test/build/unused-assets", false);
+        compiler.compile().unwrap();
+        assert_eq!(
+            UnusedAssetsPlugin::collect_unused_assets(&compiler.context),
+            vec!["src/unused.png is imported by src/index.js but never used"]
+        );
+    }
+
+    #[test]
+    fn test_unused_sources() {
+        assert_eq!(
+            run(r#"
+import used from './used.png';
+import unused from './unused.png';
+import './side-effect.png';
+import { a } from './named.svg';
+console.log(used, window.unused);
+export { a };
+            "#),
+            vec!["./unused.png", "./side-effect.png"]
+        );
+    }
+
+    #[test]
+    fn test_shadowed_binding_is_unused() {
+        assert_eq!(
+            run(r#"
+import logo from './logo.png';
+function foo(logo) {
+    return logo;
+}
+            "#),
+            vec!["./logo.png"]
+        );
+    }
+
+    fn run(js_code: &str) -> Vec<String> {
+        let test_utils = TestUtils::gen_js_ast(js_code);
+        let ast = test_utils.ast.js();
+        let mut collector = AssetImportCollector::default();
+        ast.ast.visit_with(&mut collector);
+        collector.unused_sources()
+    }
+}
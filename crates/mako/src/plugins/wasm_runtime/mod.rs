@@ -3,6 +3,7 @@ use std::sync::Arc;
 use anyhow;
 
 use crate::compiler::Context;
+use crate::config::WasmMode;
 use crate::plugin::Plugin;
 
 pub struct WasmRuntimePlugin {}
@@ -13,16 +14,90 @@ impl Plugin for WasmRuntimePlugin {
     }
 
     fn runtime_plugins(&self, context: &Arc<Context>) -> anyhow::Result<Vec<String>> {
-        if context
+        let has_async_wasm = context
             .assets_info
             .lock()
             .unwrap()
             .values()
-            .any(|info| info.ends_with(".wasm"))
-        {
+            .any(|info| info.ends_with(".wasm"));
+        // the sync ones are inlined instead of emitted
+        let has_sync_wasm = context.config.wasm.mode == WasmMode::Sync
+            && context
+                .module_graph
+                .read()
+                .unwrap()
+                .modules()
+                .iter()
+                .any(|module| module.id.id.ends_with(".wasm"));
+        if has_async_wasm || has_sync_wasm {
             Ok(vec![include_str!("wasm_runtime.js").to_string()])
         } else {
             Ok(vec![])
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::compiler::Compiler;
+    use crate::utils::test_helper::PluginTestBuilder;
+
+    // (module (func (export "add") (param i32 i32) (result i32)
+    //   local.get 0 local.get 1 i32.add))
+    const ADD_WASM: &str = "\x00asm\x01\x00\x00\x00\x01\x07\x01\x60\x02\x7f\x7f\x01\x7f\x03\x02\x01\x00\x07\x07\x01\x03add\x00\x00\x0a\x09\x01\x07\x00\x20\x00\x20\x01\x6a\x0b";
+
+    fn build(name: &str, config: &str) -> Compiler {
+        let compiler = PluginTestBuilder::new(name)
+            .file(
+                "src/index.js",
+                "import { add } from './add.wasm';\nconsole.log(add(1, 2));\n",
+            )
+            .file("src/add.wasm", ADD_WASM)
+            .config(config)
+            .build();
+        compiler.compile().unwrap();
+        compiler
+    }
+
+    fn output(compiler: &Compiler) -> String {
+        std::fs::read_to_string(compiler.context.config.output.path.join("index.js")).unwrap()
+    }
+
+    #[test]
+    fn test_async_wasm() {
+        let compiler = build("wasm-async", "{}");
+        let output = output(&compiler);
+        // the importer awaits the instantiation
+        assert!(output.contains("__mako_require__._async("));
+        assert!(output.contains("require._interopreRequireWasm(exports, \"add."));
+        assert!(!output.contains("_interopreRequireWasmSync(exports"));
+    }
+
+    #[test]
+    fn test_sync_wasm() {
+        let compiler = build("wasm-sync", r#"{ "wasm": { "mode": "sync" } }"#);
+        let output = output(&compiler);
+        // the importer requires the exports directly
+        assert!(!output.contains("__mako_require__._async("));
+        assert!(output.contains(
+            "require._interopreRequireWasmSync(exports, \"data:application/wasm;base64,"
+        ));
+        assert!(output.contains("requireModule._interopreRequireWasmSync = "));
+        assert!(!compiler
+            .context
+            .assets_info
+            .lock()
+            .unwrap()
+            .values()
+            .any(|asset| asset.ends_with(".wasm")));
+    }
+
+    #[test]
+    fn test_sync_wasm_over_limit() {
+        let compiler = build(
+            "wasm-sync-over-limit",
+            r#"{ "wasm": { "mode": "sync", "syncLimit": 8 } }"#,
+        );
+        assert!(output(&compiler).contains("__mako_require__._async("));
+    }
+}
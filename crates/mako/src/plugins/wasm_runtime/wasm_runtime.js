@@ -10,3 +10,17 @@ requireModule._interopreRequireWasm = (exports, wasmModulePath, importsObj) => {
     .then((bytes) => WebAssembly.instantiate(bytes, importsObj))
     .then((res) => Object.assign(exports, res.instance.exports));
 };
+
+// `wasm.mode: "sync"`, the bytes are inlined as a data url
+requireModule._interopreRequireWasmSync = (exports, wasmDataUrl, importsObj) => {
+  const base64 = wasmDataUrl.slice(wasmDataUrl.indexOf(',') + 1);
+  const bytes =
+    typeof Buffer === 'function'
+      ? Buffer.from(base64, 'base64')
+      : Uint8Array.from(atob(base64), (c) => c.charCodeAt(0));
+  const instance = new WebAssembly.Instance(
+    new WebAssembly.Module(bytes),
+    importsObj,
+  );
+  return Object.assign(exports, instance.exports);
+};
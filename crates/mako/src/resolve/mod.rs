@@ -1,12 +1,16 @@
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::vec;
 
 use anyhow::{anyhow, Result};
 use cached::proc_macro::cached;
 use convert_case::{Case, Casing};
-use oxc_resolver::{Alias, AliasValue, ResolveError as OxcResolveError, ResolveOptions, Resolver};
+use oxc_resolver::{
+    Alias, AliasValue, FileSystem, ResolveError as OxcResolveError, ResolveOptions, Resolver,
+    ResolverGeneric,
+};
+use rayon::prelude::*;
 use regex::{Captures, Regex};
 use thiserror::Error;
 use tracing::debug;
@@ -15,7 +19,7 @@ mod resource;
 pub(crate) use resource::{ExternalResource, ResolvedResource, ResolverResource};
 
 use crate::ast::file::parse_path;
-use crate::compiler::Context;
+use crate::compiler::{Compiler, Context};
 use crate::config::{
     Config, ExternalAdvancedSubpathConverter, ExternalAdvancedSubpathTarget, ExternalConfig,
     Platform,
@@ -70,9 +74,35 @@ pub fn resolve(
 
     let source = dep.resolve_as.as_ref().unwrap_or(&dep.source);
 
+    // deduped packages are resolved from the root, so that the nested copies
+    // of them are never used
+    if is_deduped(source, &context.config.resolve.dedupe) {
+        let root_path = context.root.join("package.json");
+        return do_resolve(
+            &root_path.to_string_lossy(),
+            source,
+            resolver,
+            Some(&context.config.externals),
+        );
+    }
+
     do_resolve(path, source, resolver, Some(&context.config.externals))
 }
 
+fn is_deduped(source: &str, dedupe: &[String]) -> bool {
+    if dedupe.is_empty() || source.starts_with('.') || source.starts_with('/') {
+        return false;
+    }
+    // the package name of `@scope/name/sub` is `@scope/name`
+    let segments = if source.starts_with('@') { 2 } else { 1 };
+    let package_name = source
+        .splitn(segments + 1, '/')
+        .take(segments)
+        .collect::<Vec<_>>()
+        .join("/");
+    dedupe.contains(&package_name)
+}
+
 #[cached(key = "String", convert = r#"{ re.to_string() }"#)]
 fn create_external_regex(re: &str) -> Regex {
     Regex::new(re).unwrap()
@@ -306,77 +336,90 @@ pub fn get_module_extensions() -> Vec<String> {
     ]
 }
 
+// package.json "imports" field for "#" prefixed self imports, it's looked up in
+// the nearest package.json of the importer and resolved with the condition names
+fn get_imports_fields() -> Vec<Vec<String>> {
+    vec![vec!["imports".to_string()]]
+}
+
+// the user conditions replace the defaults, and `...` in them is expanded to
+// the defaults, e.g. `["worker", "..."]`
+fn get_condition_names(config: &Config, defaults: &[&str]) -> Vec<String> {
+    let defaults = defaults.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+    let conditions = if config.resolve.condition_names.is_empty() {
+        defaults
+    } else {
+        config
+            .resolve
+            .condition_names
+            .iter()
+            .flat_map(|c| {
+                if c == "..." {
+                    defaults.clone()
+                } else {
+                    vec![c.clone()]
+                }
+            })
+            .collect()
+    };
+    Rsc::generate_resolve_conditions(config, conditions)
+}
+
 fn get_resolver(config: &Config, resolver_type: ResolverType) -> Resolver {
+    Resolver::new(get_resolve_options(config, resolver_type))
+}
+
+fn get_resolve_options(config: &Config, resolver_type: ResolverType) -> ResolveOptions {
     let alias = parse_alias(config.resolve.alias.clone());
     let is_browser = config.platform == Platform::Browser;
     let extensions = get_module_extensions();
-    let options = match (resolver_type, is_browser) {
+    match (resolver_type, is_browser) {
         (ResolverType::Cjs, true) => ResolveOptions {
             alias,
             extensions,
-            condition_names: Rsc::generate_resolve_conditions(
+            condition_names: get_condition_names(
                 config,
-                vec![
-                    "require".to_string(),
-                    "module".to_string(),
-                    "webpack".to_string(),
-                    "browser".to_string(),
-                ],
+                &["require", "module", "webpack", "browser"],
             ),
             main_fields: vec![
                 "browser".to_string(),
                 "module".to_string(),
                 "main".to_string(),
             ],
+            imports_fields: get_imports_fields(),
             alias_fields: vec![vec!["browser".to_string()]],
             ..Default::default()
         },
         (ResolverType::Esm, true) => ResolveOptions {
             alias,
             extensions,
-            condition_names: Rsc::generate_resolve_conditions(
+            condition_names: get_condition_names(
                 config,
-                vec![
-                    "import".to_string(),
-                    "module".to_string(),
-                    "webpack".to_string(),
-                    "browser".to_string(),
-                ],
+                &["import", "module", "webpack", "browser"],
             ),
             main_fields: vec![
                 "browser".to_string(),
                 "module".to_string(),
                 "main".to_string(),
             ],
+            imports_fields: get_imports_fields(),
             alias_fields: vec![vec!["browser".to_string()]],
             ..Default::default()
         },
         (ResolverType::Esm, false) => ResolveOptions {
             alias,
             extensions,
-            condition_names: Rsc::generate_resolve_conditions(
-                config,
-                vec![
-                    "import".to_string(),
-                    "module".to_string(),
-                    "webpack".to_string(),
-                ],
-            ),
+            condition_names: get_condition_names(config, &["import", "module", "webpack"]),
             main_fields: vec!["module".to_string(), "main".to_string()],
+            imports_fields: get_imports_fields(),
             ..Default::default()
         },
         (ResolverType::Cjs, false) => ResolveOptions {
             alias,
             extensions,
-            condition_names: Rsc::generate_resolve_conditions(
-                config,
-                vec![
-                    "require".to_string(),
-                    "module".to_string(),
-                    "webpack".to_string(),
-                ],
-            ),
+            condition_names: get_condition_names(config, &["require", "module", "webpack"]),
             main_fields: vec!["module".to_string(), "main".to_string()],
+            imports_fields: get_imports_fields(),
             ..Default::default()
         },
         // css must be browser
@@ -394,9 +437,7 @@ fn get_resolver(config: &Config, resolver_type: ResolverType) -> Resolver {
             resolve_to_context: true,
             ..Default::default()
         },
-    };
-
-    Resolver::new(options)
+    }
 }
 
 fn parse_alias(alias: Vec<(String, String)>) -> Alias {
@@ -408,6 +449,31 @@ fn parse_alias(alias: Vec<(String, String)>) -> Alias {
     result
 }
 
+impl Compiler {
+    /// Resolves the specifiers imported by the importer ahead of a build, the
+    /// resolutions and the files visited on the way are cached by the
+    /// resolvers, so resolving them again in the build is fast. It's safe to
+    /// be called concurrently and more than once.
+    pub fn warm_resolve(&self, specifiers: &[String], importer: &Path) {
+        let dir = importer.parent().unwrap_or(importer);
+        let resolvers = &self.context.resolvers;
+        for resolver_type in [ResolverType::Esm, ResolverType::Cjs] {
+            warm_resolver(resolvers.get(&resolver_type).unwrap(), dir, specifiers);
+        }
+    }
+}
+
+// the failures are ignored, they are reported when the build resolves them
+fn warm_resolver<Fs: FileSystem + Default>(
+    resolver: &ResolverGeneric<Fs>,
+    dir: &Path,
+    specifiers: &[String],
+) {
+    specifiers.par_iter().for_each(|specifier| {
+        let _ = resolver.resolve(dir, specifier);
+    });
+}
+
 pub fn clear_resolver_cache(resolvers: &Resolvers) {
     resolvers
         .iter()
@@ -417,12 +483,75 @@ pub fn clear_resolver_cache(resolvers: &Resolvers) {
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
+    use std::io;
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use oxc_resolver::{FileMetadata, FileSystem, FileSystemOs, ResolverGeneric};
 
     use crate::config::{
         Config, ExternalAdvanced, ExternalAdvancedSubpath, ExternalAdvancedSubpathConverter,
         ExternalAdvancedSubpathRule, ExternalAdvancedSubpathTarget, ExternalConfig,
     };
     use crate::resolve::ResolverType;
+    use crate::utils::test_helper::setup_compiler;
+
+    /// Counts the accesses to the file system
+    #[derive(Default, Clone)]
+    struct CountingFs {
+        fs: Arc<FileSystemOs>,
+        count: Arc<AtomicUsize>,
+    }
+
+    impl CountingFs {
+        fn take_count(&self) -> usize {
+            self.count.swap(0, Ordering::SeqCst)
+        }
+    }
+
+    impl FileSystem for CountingFs {
+        fn read_to_string(&self, path: &Path) -> io::Result<String> {
+            self.count.fetch_add(1, Ordering::SeqCst);
+            self.fs.read_to_string(path)
+        }
+
+        fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+            self.count.fetch_add(1, Ordering::SeqCst);
+            self.fs.metadata(path)
+        }
+
+        fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+            self.count.fetch_add(1, Ordering::SeqCst);
+            self.fs.symlink_metadata(path)
+        }
+
+        fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+            self.count.fetch_add(1, Ordering::SeqCst);
+            self.fs.canonicalize(path)
+        }
+    }
+
+    #[test]
+    fn test_warm_resolve() {
+        let fixture = std::env::current_dir().unwrap().join("test/resolve/normal");
+        let config: Config = Default::default();
+        let fs = CountingFs::default();
+        let resolver = ResolverGeneric::new_with_file_system(
+            fs.clone(),
+            super::get_resolve_options(&config, ResolverType::Esm),
+        );
+        let specifiers = vec!["./source".to_string(), "foo".to_string()];
+
+        super::warm_resolver(&resolver, &fixture, &specifiers);
+        assert!(fs.take_count() > 0);
+
+        // warming again or resolving the warmed specifiers hits the cache
+        super::warm_resolver(&resolver, &fixture, &specifiers);
+        let resolution = resolver.resolve(&fixture, "foo").unwrap();
+        assert_eq!(resolution.path(), fixture.join("node_modules/foo/index.js"));
+        assert_eq!(fs.take_count(), 0);
+    }
 
     #[test]
     fn test_resolve() {
@@ -459,6 +588,68 @@ mod tests {
         assert_eq!(x, "node_modules/foo/esm-browser.js".to_string());
     }
 
+    #[test]
+    fn test_resolve_imports_field() {
+        let x = resolve("test/resolve/imports", None, None, "src/index.ts", "#utils");
+        assert_eq!(x, "src/utils.ts".to_string());
+        let x = resolve(
+            "test/resolve/imports",
+            None,
+            None,
+            "src/index.ts",
+            "#internal/foo",
+        );
+        assert_eq!(x, "src/internal/foo.ts".to_string());
+        // scoped to the nearest package.json
+        let x = resolve(
+            "test/resolve/imports",
+            None,
+            None,
+            "packages/inner/src/index.ts",
+            "#utils",
+        );
+        assert_eq!(x, "packages/inner/src/utils.ts".to_string());
+    }
+
+    #[test]
+    fn test_resolve_imports_field_conditions() {
+        let x = resolve("test/resolve/imports", None, None, "src/index.ts", "#env");
+        assert_eq!(x, "src/env.browser.ts".to_string());
+        let x = resolve("test/resolve/imports", None, None, "src/index.ts", "#dep");
+        assert_eq!(x, "src/dep.cjs".to_string());
+        let x = base_resolve(
+            "test/resolve/imports",
+            None,
+            None,
+            "src/index.ts",
+            "#dep",
+            ResolverType::Esm,
+        )
+        .0;
+        assert_eq!(x, "src/dep.mjs".to_string());
+    }
+
+    #[test]
+    fn test_resolve_condition_names() {
+        let x = resolve(
+            "test/resolve/condition_names",
+            None,
+            None,
+            "index.ts",
+            "foo",
+        );
+        assert_eq!(x, "node_modules/foo/index.js".to_string());
+        let x = condition_names_resolve(vec!["worker", "..."], "foo");
+        assert_eq!(x, "node_modules/foo/worker.js".to_string());
+        let x = condition_names_resolve(vec!["deno"], "foo");
+        assert_eq!(x, "node_modules/foo/deno.js".to_string());
+        let x = condition_names_resolve(vec!["worker", "..."], "foo/bar");
+        assert_eq!(x, "node_modules/foo/bar.js".to_string());
+        // the defaults are replaced without `...`
+        let x = condition_names_resolve(vec!["worker"], "foo/bar");
+        assert_eq!(x, "node_modules/foo/bar.mjs".to_string());
+    }
+
     #[test]
     fn test_resolve_alias() {
         let alias = vec![("bar".to_string(), "foo".to_string())];
@@ -738,6 +929,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_is_deduped() {
+        let dedupe = vec!["react".to_string(), "@scope/pkg".to_string()];
+        assert!(super::is_deduped("react", &dedupe));
+        assert!(super::is_deduped("react/jsx-runtime", &dedupe));
+        assert!(super::is_deduped("@scope/pkg/sub", &dedupe));
+        assert!(!super::is_deduped("react-dom", &dedupe));
+        assert!(!super::is_deduped("@scope/other", &dedupe));
+        assert!(!super::is_deduped("./react", &dedupe));
+    }
+
+    #[test]
+    fn test_resolve_dedupe() {
+        let compiler = setup_compiler("test/build/resolve-dedupe", false);
+        compiler.compile().unwrap();
+        let module_graph = compiler.context.module_graph.read().unwrap();
+        let react_modules = module_graph
+            .modules()
+            .into_iter()
+            .map(|module| module.id.id.clone())
+            .filter(|id| id.contains("/react/"))
+            .collect::<Vec<_>>();
+        assert_eq!(react_modules.len(), 1);
+        assert!(react_modules[0].ends_with("resolve-dedupe/node_modules/react/index.js"));
+    }
+
     fn resolve(
         base: &str,
         alias: Option<Vec<(String, String)>>,
@@ -768,6 +985,25 @@ mod tests {
         base_resolve(base, alias, externals, path, source, ResolverType::Cjs)
     }
 
+    fn condition_names_resolve(condition_names: Vec<&str>, source: &str) -> String {
+        let fixture = std::env::current_dir()
+            .unwrap()
+            .join("test/resolve/condition_names");
+        let mut config: Config = Default::default();
+        config.resolve.condition_names = condition_names.iter().map(|c| c.to_string()).collect();
+        let resolver = super::get_resolver(&config, ResolverType::Cjs);
+        let resource = super::do_resolve(
+            &fixture.join("index.ts").to_string_lossy(),
+            source,
+            &resolver,
+            None,
+        )
+        .unwrap();
+        resource
+            .get_resolved_path()
+            .replace(format!("{}/", fixture.to_str().unwrap()).as_str(), "")
+    }
+
     fn base_resolve(
         base: &str,
         alias: Option<Vec<(String, String)>>,
@@ -54,6 +54,8 @@ self.$RefreshSig$ = () => (type) => type;
 
   let latestHash = '';
   let updating = false;
+  // the ids of the css modules of the latest update, if it's a css only one
+  let cssModules = [];
 
   function runHotUpdate() {
     if (hadRuntimeError) {
@@ -62,7 +64,10 @@ self.$RefreshSig$ = () => (type) => type;
 
     if (latestHash !== require.currentHash()) {
       updating = true;
-      return Promise.all([module.hot.check(), module.hot.updateChunksUrlMap()])
+      return Promise.all([
+        module.hot.check(cssModules),
+        module.hot.updateChunksUrlMap(),
+      ])
         .then(() => {
           updating = false;
           return runHotUpdate();
@@ -76,8 +81,53 @@ self.$RefreshSig$ = () => (type) => type;
     }
   }
 
+  const overlayId = '__mako_build_error_overlay__';
+
+  function showBuildErrors(errors) {
+    let overlay = document.getElementById(overlayId);
+    if (!overlay) {
+      overlay = document.createElement('div');
+      overlay.id = overlayId;
+      overlay.style.cssText =
+        'position:fixed;inset:0;z-index:2147483647;overflow:auto;padding:24px;background:rgba(0,0,0,0.85);color:#ff5555;font:13px/1.5 Menlo,Consolas,monospace;';
+      document.body.appendChild(overlay);
+    }
+    overlay.innerHTML = '';
+    const title = document.createElement('div');
+    title.textContent = '[Mako] Failed to compile';
+    title.style.cssText = 'font-size:18px;margin-bottom:16px;';
+    overlay.appendChild(title);
+    errors.forEach((error) => {
+      const pre = document.createElement('pre');
+      pre.textContent = error;
+      pre.style.cssText = 'white-space:pre-wrap;color:#e8e8e8;';
+      overlay.appendChild(pre);
+    });
+  }
+
+  function clearBuildErrors() {
+    const overlay = document.getElementById(overlayId);
+    if (overlay) {
+      overlay.remove();
+    }
+  }
+
   socket.addEventListener('message', (rawMessage) => {
     const msg = JSON.parse(rawMessage.data);
+    // the rebuild is failed, shown until the next successful rebuild
+    if (msg.errors) {
+      msg.errors.forEach((error) => console.error(error));
+      showBuildErrors(msg.errors);
+      return;
+    }
+    clearBuildErrors();
+    // the config is changed and the bundle is rebuilt from scratch
+    if (msg.reload) {
+      location.reload();
+      return;
+    }
+    // only the stylesheets are changed, they are swapped without a reload
+    cssModules = msg.css || [];
     latestHash = msg.hash;
 
     if (!updating) {
@@ -0,0 +1,15 @@
+// reload the page after each rebuild when hmr is disabled
+(function () {
+  function getHost() {
+    if (process.env.SOCKET_SERVER) {
+      return new URL(process.env.SOCKET_SERVER);
+    }
+    return location;
+  }
+
+  const h = getHost();
+  const source = new EventSource(`${h.protocol}//${h.host}/__/reload`);
+  source.addEventListener('message', () => {
+    location.reload();
+  });
+})();
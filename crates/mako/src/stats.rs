@@ -1,5 +1,5 @@
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::Path;
 use std::sync::{Arc, Mutex};
@@ -13,7 +13,9 @@ use swc_core::common::source_map::Pos;
 
 use crate::compiler::{Compiler, Context};
 use crate::features::rsc::{RscClientInfo, RscCssModules};
-use crate::generate::chunk::ChunkType;
+use crate::generate::chunk::{ChunkId, ChunkType};
+use crate::plugins::import::ImportRewriteInfo;
+use crate::plugins::tree_shaking::TreeShakingInfo;
 
 impl Compiler {
     pub fn create_stats_info(&self) -> StatsJsonMap {
@@ -58,8 +60,10 @@ impl Compiler {
             });
 
         // 获取 assets
-        stats_map.assets = stats_info
-            .get_assets()
+        // assets are added in parallel, sort them to keep stats stable
+        let mut assets = stats_info.get_assets();
+        assets.sort_by(|a, b| a.hashname.cmp(&b.hashname));
+        stats_map.assets = assets
             .iter()
             .map(|asset| StatsJsonAssetsItem {
                 assets_type: StatsJsonType::Asset(asset.assets_type.clone()),
@@ -179,13 +183,50 @@ impl Compiler {
                 }
             })
             .collect();
+        // the chunks requested along with a chunk and their total size
+        let chunk_sizes = stats_info.get_assets().iter().fold(
+            HashMap::<String, u64>::new(),
+            |mut sizes, asset| {
+                *sizes.entry(asset.chunk_id.clone()).or_default() += asset.size;
+                sizes
+            },
+        );
+        let request_step = |chunk_id: &ChunkId| {
+            let mut chunks = vec![chunk_id.id.clone()];
+            let mut parallel = chunk_graph
+                .entry_dependencies_chunk(chunk_id)
+                .into_iter()
+                .chain(chunk_graph.sync_dependencies_chunk(chunk_id))
+                .map(|id| id.id)
+                .collect::<Vec<_>>();
+            parallel.sort();
+            chunks.extend(parallel);
+            let size = chunks
+                .iter()
+                .map(|id| chunk_sizes.get(id).copied().unwrap_or(0))
+                .sum::<u64>();
+            StatsJsonRequestStep { chunks, size }
+        };
         stats_map.entrypoints = chunks
             .iter()
             .filter_map(|chunk| match &chunk.chunk_type {
                 ChunkType::Entry(_, name, _) => {
-                    let mut chunks = chunk_graph
-                        .entry_dependencies_chunk(&chunk.id)
+                    let steps = chunk_graph
+                        .critical_request_chain(&chunk.id, |id| request_step(id).size)
+                        .iter()
+                        .map(&request_step)
+                        .collect::<Vec<_>>();
+                    let critical_request_chain = StatsJsonCriticalRequestChain {
+                        depth: steps.len(),
+                        size: steps.iter().map(|step| step.size).sum(),
+                        steps,
+                    };
+
+                    let mut chunks = chunk
+                        .chunk_type
+                        .runtime_chunk_id(context.config.runtime_chunk)
                         .into_iter()
+                        .chain(chunk_graph.entry_dependencies_chunk(&chunk.id))
                         .map(|id| id.id)
                         .collect::<Vec<_>>();
 
@@ -196,17 +237,29 @@ impl Compiler {
                         StatsJsonEntryItem {
                             name: name.clone(),
                             chunks,
+                            critical_request_chain,
                         },
                     ))
                 }
                 _ => None,
             })
-            .collect::<HashMap<_, _>>();
+            .collect::<BTreeMap<_, _>>();
         stats_map.chunk_modules = chunk_modules;
 
-        stats_map.modules = stats_info.get_modules();
+        stats_map.modules = stats_info.get_modules().into_iter().collect();
         stats_map.rsc_client_components = stats_info.get_rsc_client_components();
+        stats_map
+            .rsc_client_components
+            .sort_by(|a, b| a.path.cmp(&b.path));
         stats_map.rsc_css_modules = stats_info.get_rsc_css_modules();
+        stats_map
+            .rsc_css_modules
+            .sort_by(|a, b| a.path.cmp(&b.path));
+        stats_map.import_rewrites = stats_info.get_import_rewrites();
+        stats_map
+            .import_rewrites
+            .sort_by(|a, b| (&a.path, &a.rewritten).cmp(&(&b.path, &b.rewritten)));
+        stats_map.tree_shaking = stats_info.get_tree_shaking().into_iter().collect();
 
         stats_map
     }
@@ -346,6 +399,8 @@ pub struct StatsInfo {
     pub rsc_client_components: Mutex<Vec<RscClientInfo>>,
     pub rsc_css_modules: Mutex<Vec<RscCssModules>>,
     pub modules: Mutex<HashMap<String, ModuleInfo>>,
+    pub import_rewrites: Mutex<Vec<ImportRewriteInfo>>,
+    pub tree_shaking: Mutex<HashMap<String, TreeShakingInfo>>,
 }
 
 impl StatsInfo {
@@ -355,6 +410,8 @@ impl StatsInfo {
             rsc_client_components: Mutex::new(vec![]),
             rsc_css_modules: Mutex::new(vec![]),
             modules: Mutex::new(HashMap::new()),
+            import_rewrites: Mutex::new(vec![]),
+            tree_shaking: Mutex::new(HashMap::new()),
         }
     }
 
@@ -433,6 +490,22 @@ impl StatsInfo {
     pub fn add_rsc_css_module(&self, rsc_css_module: RscCssModules) {
         self.rsc_css_modules.lock().unwrap().push(rsc_css_module)
     }
+
+    pub fn get_import_rewrites(&self) -> Vec<ImportRewriteInfo> {
+        self.import_rewrites.lock().unwrap().clone()
+    }
+
+    pub fn add_import_rewrite(&self, import_rewrite: ImportRewriteInfo) {
+        self.import_rewrites.lock().unwrap().push(import_rewrite)
+    }
+
+    pub fn get_tree_shaking(&self) -> HashMap<String, TreeShakingInfo> {
+        self.tree_shaking.lock().unwrap().clone()
+    }
+
+    pub fn add_tree_shaking(&self, module_id: String, info: TreeShakingInfo) {
+        self.tree_shaking.lock().unwrap().insert(module_id, info);
+    }
 }
 
 impl Default for StatsInfo {
@@ -496,9 +569,26 @@ pub struct StatsJsonChunkItem {
     pub origins: Vec<StatsJsonChunkOriginItem>,
 }
 #[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsJsonEntryItem {
     pub name: String,
     pub chunks: Vec<String>,
+    pub critical_request_chain: StatsJsonCriticalRequestChain,
+}
+/// The longest chain of the chunks loaded one after another for an entry,
+/// see [crate::generate::chunk_graph::ChunkGraph::critical_request_chain]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct StatsJsonCriticalRequestChain {
+    pub depth: usize,
+    /// Total size of the files of the chain in bytes
+    pub size: u64,
+    pub steps: Vec<StatsJsonRequestStep>,
+}
+/// A chunk and the sync chunks requested along with it
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct StatsJsonRequestStep {
+    pub chunks: Vec<String>,
+    pub size: u64,
 }
 #[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -509,12 +599,16 @@ pub struct StatsJsonMap {
     output_path: String,
     assets: Vec<StatsJsonAssetsItem>,
     chunk_modules: Vec<StatsJsonChunkModuleItem>,
-    modules: HashMap<String, ModuleInfo>,
+    modules: BTreeMap<String, ModuleInfo>,
     chunks: Vec<StatsJsonChunkItem>,
-    entrypoints: HashMap<String, StatsJsonEntryItem>,
+    entrypoints: BTreeMap<String, StatsJsonEntryItem>,
     rsc_client_components: Vec<RscClientInfo>,
     #[serde(rename = "rscCSSModules")]
     rsc_css_modules: Vec<RscCssModules>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    import_rewrites: Vec<ImportRewriteInfo>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    tree_shaking: BTreeMap<String, TreeShakingInfo>,
     pub start_time: i64,
     pub end_time: i64,
 }
@@ -527,12 +621,14 @@ impl StatsJsonMap {
             root_path: String::new(),
             output_path: String::new(),
             assets: vec![],
-            modules: HashMap::new(),
+            modules: BTreeMap::new(),
             chunk_modules: vec![],
             chunks: vec![],
-            entrypoints: HashMap::new(),
+            entrypoints: BTreeMap::new(),
             rsc_client_components: vec![],
             rsc_css_modules: vec![],
+            import_rewrites: vec![],
+            tree_shaking: BTreeMap::new(),
             start_time: 0,
             end_time: 0,
         }
@@ -577,3 +673,39 @@ fn file_size(path: &str) -> Result<u64> {
     let metadata = fs::metadata(path)?;
     Ok(metadata.len())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::module::ModuleId;
+    use crate::utils::test_helper::setup_compiler;
+
+    #[test]
+    fn test_critical_request_chain() {
+        let compiler = setup_compiler("test/build/critical-request-chain", false);
+        compiler.compile().unwrap();
+
+        let stats = compiler.create_stats_info();
+        let chain = &stats.entrypoints["index"].critical_request_chain;
+        let chunk_id = |path: &str| {
+            ModuleId::from(compiler.context.root.join(path)).generate(&compiler.context)
+        };
+        assert_eq!(chain.depth, 3);
+        assert_eq!(
+            chain
+                .steps
+                .iter()
+                .map(|step| step.chunks[0].clone())
+                .collect::<Vec<_>>(),
+            vec![
+                chunk_id("src/index.js"),
+                chunk_id("src/a.js"),
+                chunk_id("src/b.js")
+            ]
+        );
+        assert!(chain.steps.iter().all(|step| step.size > 0));
+        assert_eq!(
+            chain.size,
+            chain.steps.iter().map(|step| step.size).sum::<u64>()
+        );
+    }
+}
@@ -1,11 +1,176 @@
-use tracing_subscriber::{fmt, EnvFilter};
+use std::fmt::Debug;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, EnvFilter, Layer};
+
+/// Target of the events of the build log, see [crate::plugins::build_log]
+pub const BUILD_LOG_TARGET: &str = "mako::build_log";
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    #[default]
+    Text,
+    // newline-delimited json events of the build in the log file
+    Json,
+}
 
 pub fn init_logger() {
-    fmt()
-        .with_env_filter(
-            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("mako=info")),
-        )
-        .with_span_events(fmt::format::FmtSpan::NONE)
-        .without_time()
-        .init();
+    init_logger_with_format(LogFormat::Text, None).unwrap();
+}
+
+/// `log_file` is where the json events are written to, it's required by
+/// [LogFormat::Json] so that they are not mixed with the other outputs
+pub fn init_logger_with_format(format: LogFormat, log_file: Option<&Path>) -> Result<()> {
+    let env_filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("mako=info"));
+    match format {
+        LogFormat::Text => {
+            fmt()
+                .with_env_filter(env_filter)
+                .with_span_events(fmt::format::FmtSpan::NONE)
+                .without_time()
+                .init();
+        }
+        LogFormat::Json => {
+            let log_file =
+                log_file.ok_or_else(|| anyhow!("--log-format=json requires --log-file"))?;
+            let log_file = File::create(log_file)
+                .map_err(|e| anyhow!("Create log file {:?} failed: {}", log_file, e))?;
+            // the build log goes to the log file only, the human logs stay on stdout
+            let env_filter = env_filter.add_directive(
+                format!("{}=off", BUILD_LOG_TARGET)
+                    .parse()
+                    .expect("valid directive"),
+            );
+            tracing_subscriber::registry()
+                .with(
+                    fmt::layer()
+                        .with_span_events(fmt::format::FmtSpan::NONE)
+                        .without_time()
+                        .with_filter(env_filter),
+                )
+                .with(json_lines_layer(Mutex::new(log_file)))
+                .init();
+        }
+    }
+    Ok(())
+}
+
+/// A layer which writes the events of the build log as json lines, e.g.
+/// `{"type":"phase","timestamp":1700000000000,"payload":{...}}`, the warnings
+/// and errors of mako are written as the `warning` and `error` events, and
+/// the totals of them are added to the payload of the `done` event.
+pub fn json_lines_layer<S, W>(make_writer: W) -> impl Layer<S>
+where
+    S: Subscriber,
+    W: for<'a> MakeWriter<'a> + Send + Sync + 'static,
+{
+    JsonLinesLayer {
+        make_writer,
+        warnings: AtomicUsize::new(0),
+        errors: AtomicUsize::new(0),
+    }
+    .with_filter(
+        Targets::new()
+            .with_target("mako", Level::WARN)
+            .with_target(BUILD_LOG_TARGET, Level::INFO),
+    )
+}
+
+struct JsonLinesLayer<W> {
+    make_writer: W,
+    warnings: AtomicUsize,
+    errors: AtomicUsize,
+}
+
+impl<S, W> Layer<S> for JsonLinesLayer<W>
+where
+    S: Subscriber,
+    W: for<'a> MakeWriter<'a> + 'static,
+{
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = JsonLinesVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        let (kind, mut payload) = if metadata.target() == BUILD_LOG_TARGET {
+            (visitor.kind, visitor.payload)
+        } else {
+            let kind = if *metadata.level() == Level::ERROR {
+                "error"
+            } else {
+                "warning"
+            };
+            let payload = json!({
+                "message": visitor.message,
+                "target": metadata.target(),
+            });
+            (kind.to_string(), payload)
+        };
+        match kind.as_str() {
+            "warning" => {
+                self.warnings.fetch_add(1, Ordering::Relaxed);
+            }
+            "error" => {
+                self.errors.fetch_add(1, Ordering::Relaxed);
+            }
+            "done" => {
+                if let Some(payload) = payload.as_object_mut() {
+                    payload.insert(
+                        "warnings".to_string(),
+                        self.warnings.load(Ordering::Relaxed).into(),
+                    );
+                    payload.insert(
+                        "errors".to_string(),
+                        self.errors.load(Ordering::Relaxed).into(),
+                    );
+                }
+            }
+            _ => {}
+        }
+        let line = json!({
+            "type": kind,
+            "timestamp": chrono::Local::now().timestamp_millis(),
+            "payload": payload,
+        });
+        // one write per line, so that the lines of the threads don't interleave
+        let _ = self
+            .make_writer
+            .make_writer()
+            .write_all(format!("{}\n", line).as_bytes());
+    }
+}
+
+#[derive(Default)]
+struct JsonLinesVisitor {
+    kind: String,
+    payload: Value,
+    message: String,
+}
+
+impl Visit for JsonLinesVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "kind" => self.kind = value.to_string(),
+            "payload" => self.payload = serde_json::from_str(value).unwrap_or(Value::Null),
+            _ => self.record_debug(field, &value),
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        }
+    }
 }
@@ -1,10 +1,21 @@
 use std::fs;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
+use anyhow::Result;
+use swc_core::ecma::ast::Module as SwcModule;
+use swc_core::ecma::codegen::text_writer::JsWriter;
+use swc_core::ecma::codegen::{Config as JsCodegenConfig, Emitter};
 use tracing_subscriber::{fmt, EnvFilter};
 
-use crate::compiler::{self, Compiler};
+use crate::ast::file::Content;
+use crate::compiler::{self, Args, Compiler, Context};
 use crate::config::{Config, Mode};
+use crate::generate::chunk_graph::ChunkGraph;
 use crate::module::{Module, ModuleId};
+use crate::module_graph::ModuleGraph;
+use crate::plugin::{Plugin, PluginGenerateEndParams, PluginLoadParam, PluginTransformJsParam};
+use crate::stats::StatsJsonMap;
 
 #[macro_export]
 macro_rules! assert_display_snapshot {
@@ -69,6 +80,254 @@ pub fn setup_compiler(base: &str, cleanup: bool) -> Compiler {
     compiler::Compiler::new(config, root, Default::default(), None).unwrap()
 }
 
+/// Builds a compiler over the files written to a temp dir of its own, with
+/// the given plugins registered before the builtin ones, e.g.
+///
+/// ```ignore
+/// let compiler = PluginTestBuilder::new("my-plugin")
+///     .file("src/index.js", "console.log(1);")
+///     .plugin(Arc::new(MyPlugin {}))
+///     .build();
+/// compiler.compile().unwrap();
+/// ```
+///
+/// It's for the tests of the plugins and of the watch mode, which change the
+/// files, the plain builds use the fixtures of `test/build` with
+/// [setup_compiler].
+#[allow(dead_code)]
+pub struct PluginTestBuilder {
+    name: String,
+    files: Vec<(String, String)>,
+    config: Option<String>,
+    plugins: Vec<Arc<dyn Plugin>>,
+    watch: bool,
+    hmr: bool,
+}
+
+#[allow(dead_code)]
+impl PluginTestBuilder {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            files: vec![],
+            config: None,
+            plugins: vec![],
+            watch: false,
+            hmr: false,
+        }
+    }
+
+    /// Path is relative to the root, `src/index.*` is the default entry
+    pub fn file(mut self, path: &str, content: &str) -> Self {
+        self.files.push((path.to_string(), content.to_string()));
+        self
+    }
+
+    /// Content of `mako.config.json`
+    pub fn config(mut self, config: &str) -> Self {
+        self.config = Some(config.to_string());
+        self
+    }
+
+    pub fn plugin(mut self, plugin: Arc<dyn Plugin>) -> Self {
+        self.plugins.push(plugin);
+        self
+    }
+
+    /// Build in watch mode, so that `update` and `emit_dev_chunks` can be used
+    pub fn watch(mut self) -> Self {
+        self.watch = true;
+        self
+    }
+
+    /// Keep `hmr` of the config, so that `generate_hot_update_chunks` emits
+    /// the hot update files, it's used together with [Self::watch]
+    pub fn hmr(mut self) -> Self {
+        self.hmr = true;
+        self
+    }
+
+    pub fn build(self) -> Compiler {
+        setup_logger();
+        // unique per build, so that the tests running in parallel, even the
+        // ones of different test processes, don't share the files
+        static BUILDS: AtomicUsize = AtomicUsize::new(0);
+        let root = std::env::temp_dir().join("mako-plugin-test").join(format!(
+            "{}-{}-{}",
+            self.name,
+            std::process::id(),
+            BUILDS.fetch_add(1, Ordering::Relaxed)
+        ));
+        if root.exists() {
+            fs::remove_dir_all(&root).unwrap();
+        }
+        for (path, content) in self.files.iter() {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        fs::create_dir_all(&root).unwrap();
+        if let Some(config) = &self.config {
+            fs::write(root.join("mako.config.json"), config).unwrap();
+        }
+
+        // `src/index.js` is not one of the default entries of mako
+        let default_config = root
+            .join("src/index.js")
+            .exists()
+            .then_some(r#"{ "entry": { "index": "src/index.js" } }"#);
+        let mut config = Config::new(&root, default_config, None).unwrap();
+        if !self.hmr {
+            config.hmr = None;
+        }
+        config.minify = false;
+        config.mode = Mode::Production;
+        config.optimization = None;
+
+        compiler::Compiler::new(config, root, Args { watch: self.watch }, Some(self.plugins))
+            .unwrap()
+    }
+}
+
+/// A hook invocation recorded by [HookRecorder]
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HookCall {
+    pub hook: String,
+    /// Relative path of the module for `load` and `transform_js`
+    pub path: Option<String>,
+    /// Code of the received ast for `transform_js`
+    pub code: Option<String>,
+}
+
+/// Records the invocations of the build hooks, the modules are loaded and
+/// transformed in parallel, so only the order of the hooks of different
+/// stages is stable.
+#[allow(dead_code)]
+#[derive(Default)]
+pub struct HookRecorder {
+    calls: Mutex<Vec<HookCall>>,
+}
+
+#[allow(dead_code)]
+impl HookRecorder {
+    pub fn calls(&self) -> Vec<HookCall> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    pub fn calls_of(&self, hook: &str) -> Vec<HookCall> {
+        self.calls()
+            .into_iter()
+            .filter(|call| call.hook == hook)
+            .collect()
+    }
+
+    /// Index of the first invocation of the hook
+    pub fn position(&self, hook: &str) -> Option<usize> {
+        self.calls().iter().position(|call| call.hook == hook)
+    }
+
+    fn record(&self, hook: &str, path: Option<String>, code: Option<String>) {
+        self.calls.lock().unwrap().push(HookCall {
+            hook: hook.to_string(),
+            path,
+            code,
+        });
+    }
+
+    fn relative(path: &str, context: &Arc<Context>) -> String {
+        pathdiff::diff_paths(path, &context.root)
+            .map(|path| path.to_string_lossy().to_string())
+            .unwrap_or(path.to_string())
+    }
+
+    fn emit(ast: &SwcModule, context: &Arc<Context>) -> String {
+        let cm = context.meta.script.cm.clone();
+        let mut buf = vec![];
+        {
+            let mut emitter = Emitter {
+                cfg: JsCodegenConfig::default(),
+                cm: cm.clone(),
+                comments: None,
+                wr: Box::new(JsWriter::new(cm, "\n", &mut buf, None)),
+            };
+            emitter.emit_module(ast).unwrap();
+        }
+        String::from_utf8(buf).unwrap()
+    }
+}
+
+impl Plugin for HookRecorder {
+    fn name(&self) -> &str {
+        "hook_recorder"
+    }
+
+    fn load(&self, param: &PluginLoadParam, context: &Arc<Context>) -> Result<Option<Content>> {
+        let path = Self::relative(param.file.path.to_str().unwrap(), context);
+        self.record("load", Some(path), None);
+        Ok(None)
+    }
+
+    fn transform_js(
+        &self,
+        param: &PluginTransformJsParam,
+        ast: &mut SwcModule,
+        context: &Arc<Context>,
+    ) -> Result<()> {
+        let path = Self::relative(param.path, context);
+        self.record("transform_js", Some(path), Some(Self::emit(ast, context)));
+        Ok(())
+    }
+
+    fn build_start(&self, _context: &Arc<Context>) -> Result<()> {
+        self.record("build_start", None, None);
+        Ok(())
+    }
+
+    fn generate_begin(&self, _context: &Arc<Context>) -> Result<()> {
+        self.record("generate_begin", None, None);
+        Ok(())
+    }
+
+    fn generate_end(
+        &self,
+        _params: &PluginGenerateEndParams,
+        _context: &Arc<Context>,
+    ) -> Result<()> {
+        self.record("generate_end", None, None);
+        Ok(())
+    }
+
+    fn optimize_module_graph(
+        &self,
+        _module_graph: &mut ModuleGraph,
+        _context: &Arc<Context>,
+    ) -> Result<()> {
+        self.record("optimize_module_graph", None, None);
+        Ok(())
+    }
+
+    fn before_optimize_chunk(&self, _context: &Arc<Context>) -> Result<()> {
+        self.record("before_optimize_chunk", None, None);
+        Ok(())
+    }
+
+    fn optimize_chunk(
+        &self,
+        _chunk_graph: &mut ChunkGraph,
+        _module_graph: &mut ModuleGraph,
+        _context: &Arc<Context>,
+    ) -> Result<()> {
+        self.record("optimize_chunk", None, None);
+        Ok(())
+    }
+
+    fn build_success(&self, _stats: &StatsJsonMap, _context: &Arc<Context>) -> Result<()> {
+        self.record("build_success", None, None);
+        Ok(())
+    }
+}
+
 pub fn setup_logger() {
     let _result = fmt()
         .with_env_filter(EnvFilter::from_default_env())
@@ -12,7 +12,7 @@ use swc_core::ecma::visit::{VisitMut, VisitMutWith};
 
 use crate::ast::utils::is_commonjs_require;
 use crate::compiler::Context;
-use crate::module::{Dependency, ModuleId};
+use crate::module::{Dependency, ModuleId, ResolveType};
 
 const ASYNC_IMPORTED_MODULE: &str = "_async__mako_imported_module_";
 
@@ -205,26 +205,57 @@ pub fn mark_async(
 ) -> HashMap<ModuleId, Vec<Dependency>> {
     let mut async_deps_by_module_id = HashMap::new();
     let mut module_graph = context.module_graph.write().unwrap();
-    // TODO: 考虑成环的场景
-    module_ids.iter().for_each(|module_id| {
-        let deps = module_graph.get_dependencies_info(module_id);
-        let async_deps: Vec<Dependency> = deps
-            .into_iter()
-            .filter(|(_, dep, is_async)| dep.resolve_type.is_sync_esm() && *is_async)
-            .map(|(_, dep, _)| dep.clone())
-            .collect();
-        let module = module_graph.get_module_mut(module_id).unwrap();
-        if let Some(info) = module.info.as_mut() {
-            // a module with async deps need to be polluted into async module
-            if !info.is_async && !async_deps.is_empty() {
-                info.is_async = true;
+    // module_ids are sorted from the leaf nodes, one pass is enough without
+    // cycles, a module in a cycle may be visited before its async deps are
+    // marked, so repeat until nothing changes
+    loop {
+        let mut changed = false;
+        module_ids.iter().for_each(|module_id| {
+            let deps = module_graph.get_dependencies_info(module_id);
+            let async_deps: Vec<Dependency> = deps
+                .into_iter()
+                .filter(|(_, dep, is_async)| dep.resolve_type.is_sync_esm() && *is_async)
+                .map(|(_, dep, _)| dep.clone())
+                .collect();
+            let module = module_graph.get_module_mut(module_id).unwrap();
+            if let Some(info) = module.info.as_mut() {
+                // a module with async deps need to be polluted into async module
+                if !info.is_async && !async_deps.is_empty() {
+                    info.is_async = true;
+                    changed = true;
+                }
+                async_deps_by_module_id.insert(module_id.clone(), async_deps);
             }
-            async_deps_by_module_id.insert(module_id.clone(), async_deps);
+        });
+        if !changed {
+            break;
         }
-    });
+    }
     async_deps_by_module_id
 }
 
+// `require()` of an async module gets a Promise instead of the exports,
+// returns the (importer, async module) pairs, call it after mark_async
+pub fn find_required_async_modules(
+    module_ids: &[ModuleId],
+    context: &Arc<Context>,
+) -> Vec<(ModuleId, ModuleId)> {
+    let module_graph = context.module_graph.read().unwrap();
+    module_ids
+        .iter()
+        .flat_map(|module_id| {
+            module_graph
+                .get_dependencies_info(module_id)
+                .into_iter()
+                .filter(|(_, dep, is_async)| {
+                    matches!(dep.resolve_type, ResolveType::Require) && *is_async
+                })
+                .map(|(dep_module_id, _, _)| (module_id.clone(), dep_module_id.clone()))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use swc_core::common::GLOBALS;
@@ -236,11 +267,68 @@ mod tests {
     use swc_core::ecma::visit::{VisitMutWith, VisitWith};
     use swc_node_comments::SwcComments;
 
-    use super::AsyncModule;
+    use super::{find_required_async_modules, AsyncModule};
     use crate::ast::tests::TestUtils;
     use crate::generate::chunk::{Chunk, ChunkType};
+    use crate::module::ModuleId;
+    use crate::utils::test_helper::{get_module, setup_compiler};
     use crate::visitors::dep_analyzer::DepAnalyzer;
 
+    #[test]
+    fn test_mark_async_with_cycle() {
+        let compiler = setup_compiler("test/build/top-level-await", false);
+        compiler.compile().unwrap();
+        let is_async = |path: &str| {
+            get_module(&compiler, path)
+                .info
+                .is_some_and(|info| info.is_async)
+        };
+        // a and b import each other, b imports the top level await module
+        assert!(is_async("src/value.ts"));
+        assert!(is_async("src/b.ts"));
+        assert!(is_async("src/a.ts"));
+        assert!(is_async("src/index.ts"));
+        // require() doesn't make the importer async
+        assert!(!is_async("src/c.ts"));
+
+        let module_id = |path: &str| ModuleId::from(compiler.context.root.join(path));
+        let module_ids = vec![module_id("src/index.ts"), module_id("src/c.ts")];
+        assert_eq!(
+            find_required_async_modules(&module_ids, &compiler.context),
+            vec![(module_id("src/c.ts"), module_id("src/value.ts"))]
+        );
+    }
+
+    #[test]
+    fn test_mark_async_through_chain() {
+        let compiler = setup_compiler("test/build/async-chain", false);
+        compiler.compile().unwrap();
+        let is_async = |path: &str| {
+            get_module(&compiler, path)
+                .info
+                .is_some_and(|info| info.is_async)
+        };
+        let code = |path: &str| {
+            let module = get_module(&compiler, path);
+            let ast = module.info.unwrap().ast;
+            ast.as_script()
+                .unwrap()
+                .generate(compiler.context.clone())
+                .unwrap()
+                .code
+        };
+        // index -> a -> b -> c, c has top level await
+        for path in ["src/c.ts", "src/b.ts", "src/a.ts", "src/index.ts"] {
+            assert!(is_async(path), "{} should be async", path);
+            assert!(code(path).contains("__mako_require__._async(module"));
+        }
+        assert!(!is_async("src/d.ts"));
+        assert!(!code("src/d.ts").contains("__mako_require__._async(module"));
+        // the importers wait for their async deps
+        assert!(code("src/a.ts").contains("handleAsyncDeps"));
+        assert!(code("src/b.ts").contains("handleAsyncDeps"));
+    }
+
     #[test]
     fn test_default_import_async_module() {
         let code = run(r#"
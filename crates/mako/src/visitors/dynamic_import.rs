@@ -12,6 +12,7 @@ use swc_core::ecma::visit::{VisitMut, VisitMutWith};
 use crate::ast::utils::{is_dynamic_import, promise_all, require_ensure};
 use crate::compiler::Context;
 use crate::generate::chunk::ChunkId;
+use crate::module::ModuleId;
 use crate::visitors::dep_replacer::DependenciesToReplace;
 
 pub struct DynamicImport<'a> {
@@ -84,7 +85,19 @@ impl<'a> VisitMut for DynamicImport<'a> {
                     // note: the source is replaced!
                     let resolved_source = source.value.clone().to_string();
                     let chunk_ids = {
-                        let chunk_id: ChunkId = resolved_source.clone().into();
+                        // the chunk id differs from the module id with `chunkIds`
+                        let chunk_id: ChunkId = self
+                            .dep_to_replace
+                            .resolved
+                            .values()
+                            .find(|(id, _)| *id == resolved_source)
+                            .map_or_else(
+                                || resolved_source.clone(),
+                                |(_, raw_id)| {
+                                    ModuleId::from(raw_id.as_str()).generate_chunk_id(&self.context)
+                                },
+                            )
+                            .into();
                         let chunk_graph = &self.context.chunk_graph.read().unwrap();
                         let chunk = chunk_graph.chunk(&chunk_id);
                         let chunk_ids = match chunk {
@@ -7,7 +7,8 @@ use serde_json::Value;
 use swc_core::common::{Mark, DUMMY_SP};
 use swc_core::ecma::ast::{
     ArrayLit, Bool, ComputedPropName, Expr, ExprOrSpread, Ident, KeyValueProp, Lit, MemberExpr,
-    MemberProp, ModuleItem, Null, Number, ObjectLit, Prop, PropOrSpread, Stmt, Str,
+    MemberProp, MetaPropExpr, MetaPropKind, ModuleItem, Null, Number, ObjectLit, Prop,
+    PropOrSpread, Stmt, Str,
 };
 use swc_core::ecma::utils::{quote_ident, ExprExt};
 use swc_core::ecma::visit::{VisitMut, VisitMutWith};
@@ -19,11 +20,11 @@ use crate::config::ConfigError;
 #[derive(Debug)]
 pub struct EnvReplacer {
     unresolved_mark: Mark,
-    define: HashMap<String, Expr>,
+    define: Arc<HashMap<String, Expr>>,
 }
 
 impl EnvReplacer {
-    pub fn new(define: HashMap<String, Expr>, unresolved_mark: Mark) -> Self {
+    pub fn new(define: Arc<HashMap<String, Expr>>, unresolved_mark: Mark) -> Self {
         Self {
             unresolved_mark,
             define,
@@ -78,9 +79,19 @@ impl VisitMut for EnvReplacer {
                     current_member_obj = obj.as_ref();
                 }
 
-                if let Expr::Ident(Ident { sym, .. }) = current_member_obj {
-                    member_visit_path.push('.');
-                    member_visit_path.push_str(sym.as_ref());
+                match current_member_obj {
+                    Expr::Ident(Ident { sym, .. }) => {
+                        member_visit_path.push('.');
+                        member_visit_path.push_str(sym.as_ref());
+                    }
+                    // import.meta.env.X
+                    Expr::MetaProp(MetaPropExpr {
+                        kind: MetaPropKind::ImportMeta,
+                        ..
+                    }) => {
+                        member_visit_path.push_str(".meta.import");
+                    }
+                    _ => {}
                 }
                 let member_visit_path = member_visit_path
                     .split('.')
@@ -126,6 +137,22 @@ impl VisitMut for EnvReplacer {
     }
 }
 
+// the define values are the same for every module, so they are parsed once
+// for the compilation, with the default of `process.env.NODE_ENV`
+pub fn get_define_exprs(context: &Arc<Context>) -> Result<Arc<HashMap<String, Expr>>> {
+    let mut define_exprs = context.define_exprs.lock().unwrap();
+    if let Some(exprs) = define_exprs.as_ref() {
+        return Ok(exprs.clone());
+    }
+    let mut define = context.config.define.clone();
+    define
+        .entry("process.env.NODE_ENV".to_string())
+        .or_insert_with(|| format!("\"{}\"", context.config.mode).into());
+    let exprs = Arc::new(build_env_map(define, context)?);
+    *define_exprs = Some(exprs.clone());
+    Ok(exprs)
+}
+
 pub fn build_env_map(
     env_map: HashMap<String, Value>,
     context: &Arc<Context>,
@@ -147,15 +174,13 @@ fn get_env_expr(v: Value, context: &Arc<Context>) -> Result<Expr> {
                 v.clone()
             };
 
-            // the string content is treat as expression, so it has to be parsed
-            let ast =
-                JsAst::build("_mako_internal/_define_.js", &safe_value, context.clone()).unwrap();
-            let module = ast.ast.body.first().unwrap();
+            // the string content is treat as expression, so it has to be parsed,
+            // e.g. `"\"foo\""` is a string and `"Date.now()"` is a call
+            let ast = JsAst::build("_mako_internal/_define_.js", &safe_value, context.clone())
+                .map_err(|_| anyhow!(ConfigError::InvalidateDefineConfig(v.clone())))?;
 
-            match module {
-                ModuleItem::Stmt(Stmt::Expr(stmt_expr)) => {
-                    return Ok(stmt_expr.expr.as_expr().clone());
-                }
+            match ast.ast.body.as_slice() {
+                [ModuleItem::Stmt(Stmt::Expr(stmt_expr))] => Ok(stmt_expr.expr.as_expr().clone()),
                 _ => Err(anyhow!(ConfigError::InvalidateDefineConfig(v))),
             }
         }
@@ -248,6 +273,45 @@ mod tests {
         .unwrap();
     }
 
+    #[should_panic = "define value '1 +' is not an Expression"]
+    #[test]
+    fn test_invalid_expr_define_value() {
+        let context: Arc<Context> = Arc::new(Default::default());
+        build_env_map(
+            hashmap! {
+                "wrong".to_string() => json!("1 +")
+            },
+            &context,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_ident_expr() {
+        assert_eq!(
+            run(
+                r#"log(A)"#,
+                hashmap! {
+                    "A".to_string() => json!("globalThis.__VERSION__")
+                }
+            ),
+            r#"log(globalThis.__VERSION__);"#
+        );
+    }
+
+    #[test]
+    fn test_arithmetic_expr() {
+        assert_eq!(
+            run(
+                r#"log(A)"#,
+                hashmap! {
+                    "A".to_string() => json!("60 * 60 * 1000")
+                }
+            ),
+            r#"log(60 * 60 * 1000);"#
+        );
+    }
+
     #[test]
     fn test_boolean() {
         assert_eq!(
@@ -348,6 +412,19 @@ log([
         );
     }
 
+    #[test]
+    fn test_import_meta_env() {
+        assert_eq!(
+            run(
+                r#"log(import.meta.env.A)"#,
+                hashmap! {
+                    "import.meta.env.A".to_string() => json!("\"a\"")
+                }
+            ),
+            r#"log("a");"#
+        );
+    }
+
     #[test]
     fn test_computed() {
         assert_eq!(
@@ -379,7 +456,7 @@ log([
         let envs = build_env_map(envs, &test_utils.context).unwrap();
         let ast = test_utils.ast.js_mut();
         GLOBALS.set(&test_utils.context.meta.script.globals, || {
-            let mut visitor = EnvReplacer::new(envs, ast.unresolved_mark);
+            let mut visitor = EnvReplacer::new(Arc::new(envs), ast.unresolved_mark);
             ast.ast.visit_mut_with(&mut visitor);
         });
         test_utils.js_ast_to_code()
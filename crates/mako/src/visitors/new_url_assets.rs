@@ -37,6 +37,9 @@ impl NewUrlAssets {
             &self.context,
         )?;
         let resolved_path = resolved.get_resolved_path();
+        // the asset is emitted by the transform
+        self.context
+            .skip_persistent_cache(&self.path.to_string_lossy());
         Load::handle_asset(
             &File::new(resolved_path.clone(), self.context.clone()),
             false,
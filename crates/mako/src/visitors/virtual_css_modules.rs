@@ -1,4 +1,6 @@
-use swc_core::ecma::ast::{ImportDecl, Str};
+use swc_core::ecma::ast::{
+    Expr, ImportDecl, KeyValueProp, Lit, ObjectLit, Prop, PropName, PropOrSpread, Str,
+};
 use swc_core::ecma::visit::{VisitMut, VisitMutWith};
 
 pub struct VirtualCSSModules {
@@ -15,6 +17,12 @@ pub fn is_css_path(path: &str) -> bool {
 
 impl VisitMut for VirtualCSSModules {
     fn visit_mut_import_decl(&mut self, import_decl: &mut ImportDecl) {
+        // import sheet from './foo.css' with { type: 'css' }
+        if import_decl.with.as_deref().is_some_and(is_css_type) {
+            self.replace_source_with(&mut import_decl.src, "sheet");
+            import_decl.with = None;
+            return;
+        }
         let is_css_modules = is_css_modules_path(&import_decl.src.value)
             || (self.auto_css_modules
                 && is_css_path(&import_decl.src.value)
@@ -26,9 +34,30 @@ impl VisitMut for VirtualCSSModules {
     }
 }
 
+fn is_css_type(with: &ObjectLit) -> bool {
+    with.props.iter().any(|prop| match prop {
+        PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
+            key,
+            value: box Expr::Lit(Lit::Str(value)),
+        })) => {
+            let key = match key {
+                PropName::Ident(ident) => ident.sym.as_ref(),
+                PropName::Str(str) => str.value.as_ref(),
+                _ => return false,
+            };
+            key == "type" && value.value.as_ref() == "css"
+        }
+        _ => false,
+    })
+}
+
 impl VirtualCSSModules {
     fn replace_source(&mut self, source: &mut Str) {
-        let to_replace = format!("{}?asmodule", &source.value.to_string());
+        self.replace_source_with(source, "asmodule");
+    }
+
+    fn replace_source_with(&mut self, source: &mut Str, param: &str) {
+        let to_replace = format!("{}?{}", &source.value.to_string(), param);
         let span = source.span;
         *source = Str::from(to_replace);
         source.span = span;
@@ -67,6 +96,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_css_import_attributes() {
+        assert_eq!(
+            run(
+                r#"import sheet from "./foo.css" with { type: "css" };"#,
+                true
+            ),
+            r#"import sheet from "./foo.css?sheet";"#
+        );
+        assert!(!run(
+            r#"import data from "./foo.json" with { type: "json" };"#,
+            false
+        )
+        .contains("?sheet"));
+    }
+
     fn run(js_code: &str, auto_css_modules: bool) -> String {
         let mut test_utils = TestUtils::gen_js_ast(js_code);
         let ast = test_utils.ast.js_mut();
@@ -0,0 +1,5 @@
+import icon from './assets/icon.png';
+import photo from './assets/remote/photo.png';
+import dot from './assets/dot.png';
+
+console.log(icon, photo, dot);
@@ -0,0 +1,4 @@
+import { a } from './a';
+import { d } from './d';
+
+console.log(a, d);
@@ -0,0 +1,15 @@
+export class Api {
+  base: string;
+
+  constructor(base: string) {
+    this.base = base;
+  }
+
+  get(path: string) {
+    return fetch(this.base + path);
+  }
+
+  post(path: string, body: string) {
+    return fetch(this.base + path, { method: 'POST', body });
+  }
+}
@@ -0,0 +1,4 @@
+import { Api } from './api';
+
+const api = new Api('https://example.com');
+api.get('/users');
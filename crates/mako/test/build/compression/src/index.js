@@ -0,0 +1,2 @@
+import { messages } from './messages';
+console.log(messages.join('\n'));
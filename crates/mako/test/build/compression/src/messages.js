@@ -0,0 +1,2002 @@
+export const messages = [
+  'message 0',
+  'message 1',
+  'message 2',
+  'message 3',
+  'message 4',
+  'message 5',
+  'message 6',
+  'message 7',
+  'message 8',
+  'message 9',
+  'message 10',
+  'message 11',
+  'message 12',
+  'message 13',
+  'message 14',
+  'message 15',
+  'message 16',
+  'message 17',
+  'message 18',
+  'message 19',
+  'message 20',
+  'message 21',
+  'message 22',
+  'message 23',
+  'message 24',
+  'message 25',
+  'message 26',
+  'message 27',
+  'message 28',
+  'message 29',
+  'message 30',
+  'message 31',
+  'message 32',
+  'message 33',
+  'message 34',
+  'message 35',
+  'message 36',
+  'message 37',
+  'message 38',
+  'message 39',
+  'message 40',
+  'message 41',
+  'message 42',
+  'message 43',
+  'message 44',
+  'message 45',
+  'message 46',
+  'message 47',
+  'message 48',
+  'message 49',
+  'message 50',
+  'message 51',
+  'message 52',
+  'message 53',
+  'message 54',
+  'message 55',
+  'message 56',
+  'message 57',
+  'message 58',
+  'message 59',
+  'message 60',
+  'message 61',
+  'message 62',
+  'message 63',
+  'message 64',
+  'message 65',
+  'message 66',
+  'message 67',
+  'message 68',
+  'message 69',
+  'message 70',
+  'message 71',
+  'message 72',
+  'message 73',
+  'message 74',
+  'message 75',
+  'message 76',
+  'message 77',
+  'message 78',
+  'message 79',
+  'message 80',
+  'message 81',
+  'message 82',
+  'message 83',
+  'message 84',
+  'message 85',
+  'message 86',
+  'message 87',
+  'message 88',
+  'message 89',
+  'message 90',
+  'message 91',
+  'message 92',
+  'message 93',
+  'message 94',
+  'message 95',
+  'message 96',
+  'message 97',
+  'message 98',
+  'message 99',
+  'message 100',
+  'message 101',
+  'message 102',
+  'message 103',
+  'message 104',
+  'message 105',
+  'message 106',
+  'message 107',
+  'message 108',
+  'message 109',
+  'message 110',
+  'message 111',
+  'message 112',
+  'message 113',
+  'message 114',
+  'message 115',
+  'message 116',
+  'message 117',
+  'message 118',
+  'message 119',
+  'message 120',
+  'message 121',
+  'message 122',
+  'message 123',
+  'message 124',
+  'message 125',
+  'message 126',
+  'message 127',
+  'message 128',
+  'message 129',
+  'message 130',
+  'message 131',
+  'message 132',
+  'message 133',
+  'message 134',
+  'message 135',
+  'message 136',
+  'message 137',
+  'message 138',
+  'message 139',
+  'message 140',
+  'message 141',
+  'message 142',
+  'message 143',
+  'message 144',
+  'message 145',
+  'message 146',
+  'message 147',
+  'message 148',
+  'message 149',
+  'message 150',
+  'message 151',
+  'message 152',
+  'message 153',
+  'message 154',
+  'message 155',
+  'message 156',
+  'message 157',
+  'message 158',
+  'message 159',
+  'message 160',
+  'message 161',
+  'message 162',
+  'message 163',
+  'message 164',
+  'message 165',
+  'message 166',
+  'message 167',
+  'message 168',
+  'message 169',
+  'message 170',
+  'message 171',
+  'message 172',
+  'message 173',
+  'message 174',
+  'message 175',
+  'message 176',
+  'message 177',
+  'message 178',
+  'message 179',
+  'message 180',
+  'message 181',
+  'message 182',
+  'message 183',
+  'message 184',
+  'message 185',
+  'message 186',
+  'message 187',
+  'message 188',
+  'message 189',
+  'message 190',
+  'message 191',
+  'message 192',
+  'message 193',
+  'message 194',
+  'message 195',
+  'message 196',
+  'message 197',
+  'message 198',
+  'message 199',
+  'message 200',
+  'message 201',
+  'message 202',
+  'message 203',
+  'message 204',
+  'message 205',
+  'message 206',
+  'message 207',
+  'message 208',
+  'message 209',
+  'message 210',
+  'message 211',
+  'message 212',
+  'message 213',
+  'message 214',
+  'message 215',
+  'message 216',
+  'message 217',
+  'message 218',
+  'message 219',
+  'message 220',
+  'message 221',
+  'message 222',
+  'message 223',
+  'message 224',
+  'message 225',
+  'message 226',
+  'message 227',
+  'message 228',
+  'message 229',
+  'message 230',
+  'message 231',
+  'message 232',
+  'message 233',
+  'message 234',
+  'message 235',
+  'message 236',
+  'message 237',
+  'message 238',
+  'message 239',
+  'message 240',
+  'message 241',
+  'message 242',
+  'message 243',
+  'message 244',
+  'message 245',
+  'message 246',
+  'message 247',
+  'message 248',
+  'message 249',
+  'message 250',
+  'message 251',
+  'message 252',
+  'message 253',
+  'message 254',
+  'message 255',
+  'message 256',
+  'message 257',
+  'message 258',
+  'message 259',
+  'message 260',
+  'message 261',
+  'message 262',
+  'message 263',
+  'message 264',
+  'message 265',
+  'message 266',
+  'message 267',
+  'message 268',
+  'message 269',
+  'message 270',
+  'message 271',
+  'message 272',
+  'message 273',
+  'message 274',
+  'message 275',
+  'message 276',
+  'message 277',
+  'message 278',
+  'message 279',
+  'message 280',
+  'message 281',
+  'message 282',
+  'message 283',
+  'message 284',
+  'message 285',
+  'message 286',
+  'message 287',
+  'message 288',
+  'message 289',
+  'message 290',
+  'message 291',
+  'message 292',
+  'message 293',
+  'message 294',
+  'message 295',
+  'message 296',
+  'message 297',
+  'message 298',
+  'message 299',
+  'message 300',
+  'message 301',
+  'message 302',
+  'message 303',
+  'message 304',
+  'message 305',
+  'message 306',
+  'message 307',
+  'message 308',
+  'message 309',
+  'message 310',
+  'message 311',
+  'message 312',
+  'message 313',
+  'message 314',
+  'message 315',
+  'message 316',
+  'message 317',
+  'message 318',
+  'message 319',
+  'message 320',
+  'message 321',
+  'message 322',
+  'message 323',
+  'message 324',
+  'message 325',
+  'message 326',
+  'message 327',
+  'message 328',
+  'message 329',
+  'message 330',
+  'message 331',
+  'message 332',
+  'message 333',
+  'message 334',
+  'message 335',
+  'message 336',
+  'message 337',
+  'message 338',
+  'message 339',
+  'message 340',
+  'message 341',
+  'message 342',
+  'message 343',
+  'message 344',
+  'message 345',
+  'message 346',
+  'message 347',
+  'message 348',
+  'message 349',
+  'message 350',
+  'message 351',
+  'message 352',
+  'message 353',
+  'message 354',
+  'message 355',
+  'message 356',
+  'message 357',
+  'message 358',
+  'message 359',
+  'message 360',
+  'message 361',
+  'message 362',
+  'message 363',
+  'message 364',
+  'message 365',
+  'message 366',
+  'message 367',
+  'message 368',
+  'message 369',
+  'message 370',
+  'message 371',
+  'message 372',
+  'message 373',
+  'message 374',
+  'message 375',
+  'message 376',
+  'message 377',
+  'message 378',
+  'message 379',
+  'message 380',
+  'message 381',
+  'message 382',
+  'message 383',
+  'message 384',
+  'message 385',
+  'message 386',
+  'message 387',
+  'message 388',
+  'message 389',
+  'message 390',
+  'message 391',
+  'message 392',
+  'message 393',
+  'message 394',
+  'message 395',
+  'message 396',
+  'message 397',
+  'message 398',
+  'message 399',
+  'message 400',
+  'message 401',
+  'message 402',
+  'message 403',
+  'message 404',
+  'message 405',
+  'message 406',
+  'message 407',
+  'message 408',
+  'message 409',
+  'message 410',
+  'message 411',
+  'message 412',
+  'message 413',
+  'message 414',
+  'message 415',
+  'message 416',
+  'message 417',
+  'message 418',
+  'message 419',
+  'message 420',
+  'message 421',
+  'message 422',
+  'message 423',
+  'message 424',
+  'message 425',
+  'message 426',
+  'message 427',
+  'message 428',
+  'message 429',
+  'message 430',
+  'message 431',
+  'message 432',
+  'message 433',
+  'message 434',
+  'message 435',
+  'message 436',
+  'message 437',
+  'message 438',
+  'message 439',
+  'message 440',
+  'message 441',
+  'message 442',
+  'message 443',
+  'message 444',
+  'message 445',
+  'message 446',
+  'message 447',
+  'message 448',
+  'message 449',
+  'message 450',
+  'message 451',
+  'message 452',
+  'message 453',
+  'message 454',
+  'message 455',
+  'message 456',
+  'message 457',
+  'message 458',
+  'message 459',
+  'message 460',
+  'message 461',
+  'message 462',
+  'message 463',
+  'message 464',
+  'message 465',
+  'message 466',
+  'message 467',
+  'message 468',
+  'message 469',
+  'message 470',
+  'message 471',
+  'message 472',
+  'message 473',
+  'message 474',
+  'message 475',
+  'message 476',
+  'message 477',
+  'message 478',
+  'message 479',
+  'message 480',
+  'message 481',
+  'message 482',
+  'message 483',
+  'message 484',
+  'message 485',
+  'message 486',
+  'message 487',
+  'message 488',
+  'message 489',
+  'message 490',
+  'message 491',
+  'message 492',
+  'message 493',
+  'message 494',
+  'message 495',
+  'message 496',
+  'message 497',
+  'message 498',
+  'message 499',
+  'message 500',
+  'message 501',
+  'message 502',
+  'message 503',
+  'message 504',
+  'message 505',
+  'message 506',
+  'message 507',
+  'message 508',
+  'message 509',
+  'message 510',
+  'message 511',
+  'message 512',
+  'message 513',
+  'message 514',
+  'message 515',
+  'message 516',
+  'message 517',
+  'message 518',
+  'message 519',
+  'message 520',
+  'message 521',
+  'message 522',
+  'message 523',
+  'message 524',
+  'message 525',
+  'message 526',
+  'message 527',
+  'message 528',
+  'message 529',
+  'message 530',
+  'message 531',
+  'message 532',
+  'message 533',
+  'message 534',
+  'message 535',
+  'message 536',
+  'message 537',
+  'message 538',
+  'message 539',
+  'message 540',
+  'message 541',
+  'message 542',
+  'message 543',
+  'message 544',
+  'message 545',
+  'message 546',
+  'message 547',
+  'message 548',
+  'message 549',
+  'message 550',
+  'message 551',
+  'message 552',
+  'message 553',
+  'message 554',
+  'message 555',
+  'message 556',
+  'message 557',
+  'message 558',
+  'message 559',
+  'message 560',
+  'message 561',
+  'message 562',
+  'message 563',
+  'message 564',
+  'message 565',
+  'message 566',
+  'message 567',
+  'message 568',
+  'message 569',
+  'message 570',
+  'message 571',
+  'message 572',
+  'message 573',
+  'message 574',
+  'message 575',
+  'message 576',
+  'message 577',
+  'message 578',
+  'message 579',
+  'message 580',
+  'message 581',
+  'message 582',
+  'message 583',
+  'message 584',
+  'message 585',
+  'message 586',
+  'message 587',
+  'message 588',
+  'message 589',
+  'message 590',
+  'message 591',
+  'message 592',
+  'message 593',
+  'message 594',
+  'message 595',
+  'message 596',
+  'message 597',
+  'message 598',
+  'message 599',
+  'message 600',
+  'message 601',
+  'message 602',
+  'message 603',
+  'message 604',
+  'message 605',
+  'message 606',
+  'message 607',
+  'message 608',
+  'message 609',
+  'message 610',
+  'message 611',
+  'message 612',
+  'message 613',
+  'message 614',
+  'message 615',
+  'message 616',
+  'message 617',
+  'message 618',
+  'message 619',
+  'message 620',
+  'message 621',
+  'message 622',
+  'message 623',
+  'message 624',
+  'message 625',
+  'message 626',
+  'message 627',
+  'message 628',
+  'message 629',
+  'message 630',
+  'message 631',
+  'message 632',
+  'message 633',
+  'message 634',
+  'message 635',
+  'message 636',
+  'message 637',
+  'message 638',
+  'message 639',
+  'message 640',
+  'message 641',
+  'message 642',
+  'message 643',
+  'message 644',
+  'message 645',
+  'message 646',
+  'message 647',
+  'message 648',
+  'message 649',
+  'message 650',
+  'message 651',
+  'message 652',
+  'message 653',
+  'message 654',
+  'message 655',
+  'message 656',
+  'message 657',
+  'message 658',
+  'message 659',
+  'message 660',
+  'message 661',
+  'message 662',
+  'message 663',
+  'message 664',
+  'message 665',
+  'message 666',
+  'message 667',
+  'message 668',
+  'message 669',
+  'message 670',
+  'message 671',
+  'message 672',
+  'message 673',
+  'message 674',
+  'message 675',
+  'message 676',
+  'message 677',
+  'message 678',
+  'message 679',
+  'message 680',
+  'message 681',
+  'message 682',
+  'message 683',
+  'message 684',
+  'message 685',
+  'message 686',
+  'message 687',
+  'message 688',
+  'message 689',
+  'message 690',
+  'message 691',
+  'message 692',
+  'message 693',
+  'message 694',
+  'message 695',
+  'message 696',
+  'message 697',
+  'message 698',
+  'message 699',
+  'message 700',
+  'message 701',
+  'message 702',
+  'message 703',
+  'message 704',
+  'message 705',
+  'message 706',
+  'message 707',
+  'message 708',
+  'message 709',
+  'message 710',
+  'message 711',
+  'message 712',
+  'message 713',
+  'message 714',
+  'message 715',
+  'message 716',
+  'message 717',
+  'message 718',
+  'message 719',
+  'message 720',
+  'message 721',
+  'message 722',
+  'message 723',
+  'message 724',
+  'message 725',
+  'message 726',
+  'message 727',
+  'message 728',
+  'message 729',
+  'message 730',
+  'message 731',
+  'message 732',
+  'message 733',
+  'message 734',
+  'message 735',
+  'message 736',
+  'message 737',
+  'message 738',
+  'message 739',
+  'message 740',
+  'message 741',
+  'message 742',
+  'message 743',
+  'message 744',
+  'message 745',
+  'message 746',
+  'message 747',
+  'message 748',
+  'message 749',
+  'message 750',
+  'message 751',
+  'message 752',
+  'message 753',
+  'message 754',
+  'message 755',
+  'message 756',
+  'message 757',
+  'message 758',
+  'message 759',
+  'message 760',
+  'message 761',
+  'message 762',
+  'message 763',
+  'message 764',
+  'message 765',
+  'message 766',
+  'message 767',
+  'message 768',
+  'message 769',
+  'message 770',
+  'message 771',
+  'message 772',
+  'message 773',
+  'message 774',
+  'message 775',
+  'message 776',
+  'message 777',
+  'message 778',
+  'message 779',
+  'message 780',
+  'message 781',
+  'message 782',
+  'message 783',
+  'message 784',
+  'message 785',
+  'message 786',
+  'message 787',
+  'message 788',
+  'message 789',
+  'message 790',
+  'message 791',
+  'message 792',
+  'message 793',
+  'message 794',
+  'message 795',
+  'message 796',
+  'message 797',
+  'message 798',
+  'message 799',
+  'message 800',
+  'message 801',
+  'message 802',
+  'message 803',
+  'message 804',
+  'message 805',
+  'message 806',
+  'message 807',
+  'message 808',
+  'message 809',
+  'message 810',
+  'message 811',
+  'message 812',
+  'message 813',
+  'message 814',
+  'message 815',
+  'message 816',
+  'message 817',
+  'message 818',
+  'message 819',
+  'message 820',
+  'message 821',
+  'message 822',
+  'message 823',
+  'message 824',
+  'message 825',
+  'message 826',
+  'message 827',
+  'message 828',
+  'message 829',
+  'message 830',
+  'message 831',
+  'message 832',
+  'message 833',
+  'message 834',
+  'message 835',
+  'message 836',
+  'message 837',
+  'message 838',
+  'message 839',
+  'message 840',
+  'message 841',
+  'message 842',
+  'message 843',
+  'message 844',
+  'message 845',
+  'message 846',
+  'message 847',
+  'message 848',
+  'message 849',
+  'message 850',
+  'message 851',
+  'message 852',
+  'message 853',
+  'message 854',
+  'message 855',
+  'message 856',
+  'message 857',
+  'message 858',
+  'message 859',
+  'message 860',
+  'message 861',
+  'message 862',
+  'message 863',
+  'message 864',
+  'message 865',
+  'message 866',
+  'message 867',
+  'message 868',
+  'message 869',
+  'message 870',
+  'message 871',
+  'message 872',
+  'message 873',
+  'message 874',
+  'message 875',
+  'message 876',
+  'message 877',
+  'message 878',
+  'message 879',
+  'message 880',
+  'message 881',
+  'message 882',
+  'message 883',
+  'message 884',
+  'message 885',
+  'message 886',
+  'message 887',
+  'message 888',
+  'message 889',
+  'message 890',
+  'message 891',
+  'message 892',
+  'message 893',
+  'message 894',
+  'message 895',
+  'message 896',
+  'message 897',
+  'message 898',
+  'message 899',
+  'message 900',
+  'message 901',
+  'message 902',
+  'message 903',
+  'message 904',
+  'message 905',
+  'message 906',
+  'message 907',
+  'message 908',
+  'message 909',
+  'message 910',
+  'message 911',
+  'message 912',
+  'message 913',
+  'message 914',
+  'message 915',
+  'message 916',
+  'message 917',
+  'message 918',
+  'message 919',
+  'message 920',
+  'message 921',
+  'message 922',
+  'message 923',
+  'message 924',
+  'message 925',
+  'message 926',
+  'message 927',
+  'message 928',
+  'message 929',
+  'message 930',
+  'message 931',
+  'message 932',
+  'message 933',
+  'message 934',
+  'message 935',
+  'message 936',
+  'message 937',
+  'message 938',
+  'message 939',
+  'message 940',
+  'message 941',
+  'message 942',
+  'message 943',
+  'message 944',
+  'message 945',
+  'message 946',
+  'message 947',
+  'message 948',
+  'message 949',
+  'message 950',
+  'message 951',
+  'message 952',
+  'message 953',
+  'message 954',
+  'message 955',
+  'message 956',
+  'message 957',
+  'message 958',
+  'message 959',
+  'message 960',
+  'message 961',
+  'message 962',
+  'message 963',
+  'message 964',
+  'message 965',
+  'message 966',
+  'message 967',
+  'message 968',
+  'message 969',
+  'message 970',
+  'message 971',
+  'message 972',
+  'message 973',
+  'message 974',
+  'message 975',
+  'message 976',
+  'message 977',
+  'message 978',
+  'message 979',
+  'message 980',
+  'message 981',
+  'message 982',
+  'message 983',
+  'message 984',
+  'message 985',
+  'message 986',
+  'message 987',
+  'message 988',
+  'message 989',
+  'message 990',
+  'message 991',
+  'message 992',
+  'message 993',
+  'message 994',
+  'message 995',
+  'message 996',
+  'message 997',
+  'message 998',
+  'message 999',
+  'message 1000',
+  'message 1001',
+  'message 1002',
+  'message 1003',
+  'message 1004',
+  'message 1005',
+  'message 1006',
+  'message 1007',
+  'message 1008',
+  'message 1009',
+  'message 1010',
+  'message 1011',
+  'message 1012',
+  'message 1013',
+  'message 1014',
+  'message 1015',
+  'message 1016',
+  'message 1017',
+  'message 1018',
+  'message 1019',
+  'message 1020',
+  'message 1021',
+  'message 1022',
+  'message 1023',
+  'message 1024',
+  'message 1025',
+  'message 1026',
+  'message 1027',
+  'message 1028',
+  'message 1029',
+  'message 1030',
+  'message 1031',
+  'message 1032',
+  'message 1033',
+  'message 1034',
+  'message 1035',
+  'message 1036',
+  'message 1037',
+  'message 1038',
+  'message 1039',
+  'message 1040',
+  'message 1041',
+  'message 1042',
+  'message 1043',
+  'message 1044',
+  'message 1045',
+  'message 1046',
+  'message 1047',
+  'message 1048',
+  'message 1049',
+  'message 1050',
+  'message 1051',
+  'message 1052',
+  'message 1053',
+  'message 1054',
+  'message 1055',
+  'message 1056',
+  'message 1057',
+  'message 1058',
+  'message 1059',
+  'message 1060',
+  'message 1061',
+  'message 1062',
+  'message 1063',
+  'message 1064',
+  'message 1065',
+  'message 1066',
+  'message 1067',
+  'message 1068',
+  'message 1069',
+  'message 1070',
+  'message 1071',
+  'message 1072',
+  'message 1073',
+  'message 1074',
+  'message 1075',
+  'message 1076',
+  'message 1077',
+  'message 1078',
+  'message 1079',
+  'message 1080',
+  'message 1081',
+  'message 1082',
+  'message 1083',
+  'message 1084',
+  'message 1085',
+  'message 1086',
+  'message 1087',
+  'message 1088',
+  'message 1089',
+  'message 1090',
+  'message 1091',
+  'message 1092',
+  'message 1093',
+  'message 1094',
+  'message 1095',
+  'message 1096',
+  'message 1097',
+  'message 1098',
+  'message 1099',
+  'message 1100',
+  'message 1101',
+  'message 1102',
+  'message 1103',
+  'message 1104',
+  'message 1105',
+  'message 1106',
+  'message 1107',
+  'message 1108',
+  'message 1109',
+  'message 1110',
+  'message 1111',
+  'message 1112',
+  'message 1113',
+  'message 1114',
+  'message 1115',
+  'message 1116',
+  'message 1117',
+  'message 1118',
+  'message 1119',
+  'message 1120',
+  'message 1121',
+  'message 1122',
+  'message 1123',
+  'message 1124',
+  'message 1125',
+  'message 1126',
+  'message 1127',
+  'message 1128',
+  'message 1129',
+  'message 1130',
+  'message 1131',
+  'message 1132',
+  'message 1133',
+  'message 1134',
+  'message 1135',
+  'message 1136',
+  'message 1137',
+  'message 1138',
+  'message 1139',
+  'message 1140',
+  'message 1141',
+  'message 1142',
+  'message 1143',
+  'message 1144',
+  'message 1145',
+  'message 1146',
+  'message 1147',
+  'message 1148',
+  'message 1149',
+  'message 1150',
+  'message 1151',
+  'message 1152',
+  'message 1153',
+  'message 1154',
+  'message 1155',
+  'message 1156',
+  'message 1157',
+  'message 1158',
+  'message 1159',
+  'message 1160',
+  'message 1161',
+  'message 1162',
+  'message 1163',
+  'message 1164',
+  'message 1165',
+  'message 1166',
+  'message 1167',
+  'message 1168',
+  'message 1169',
+  'message 1170',
+  'message 1171',
+  'message 1172',
+  'message 1173',
+  'message 1174',
+  'message 1175',
+  'message 1176',
+  'message 1177',
+  'message 1178',
+  'message 1179',
+  'message 1180',
+  'message 1181',
+  'message 1182',
+  'message 1183',
+  'message 1184',
+  'message 1185',
+  'message 1186',
+  'message 1187',
+  'message 1188',
+  'message 1189',
+  'message 1190',
+  'message 1191',
+  'message 1192',
+  'message 1193',
+  'message 1194',
+  'message 1195',
+  'message 1196',
+  'message 1197',
+  'message 1198',
+  'message 1199',
+  'message 1200',
+  'message 1201',
+  'message 1202',
+  'message 1203',
+  'message 1204',
+  'message 1205',
+  'message 1206',
+  'message 1207',
+  'message 1208',
+  'message 1209',
+  'message 1210',
+  'message 1211',
+  'message 1212',
+  'message 1213',
+  'message 1214',
+  'message 1215',
+  'message 1216',
+  'message 1217',
+  'message 1218',
+  'message 1219',
+  'message 1220',
+  'message 1221',
+  'message 1222',
+  'message 1223',
+  'message 1224',
+  'message 1225',
+  'message 1226',
+  'message 1227',
+  'message 1228',
+  'message 1229',
+  'message 1230',
+  'message 1231',
+  'message 1232',
+  'message 1233',
+  'message 1234',
+  'message 1235',
+  'message 1236',
+  'message 1237',
+  'message 1238',
+  'message 1239',
+  'message 1240',
+  'message 1241',
+  'message 1242',
+  'message 1243',
+  'message 1244',
+  'message 1245',
+  'message 1246',
+  'message 1247',
+  'message 1248',
+  'message 1249',
+  'message 1250',
+  'message 1251',
+  'message 1252',
+  'message 1253',
+  'message 1254',
+  'message 1255',
+  'message 1256',
+  'message 1257',
+  'message 1258',
+  'message 1259',
+  'message 1260',
+  'message 1261',
+  'message 1262',
+  'message 1263',
+  'message 1264',
+  'message 1265',
+  'message 1266',
+  'message 1267',
+  'message 1268',
+  'message 1269',
+  'message 1270',
+  'message 1271',
+  'message 1272',
+  'message 1273',
+  'message 1274',
+  'message 1275',
+  'message 1276',
+  'message 1277',
+  'message 1278',
+  'message 1279',
+  'message 1280',
+  'message 1281',
+  'message 1282',
+  'message 1283',
+  'message 1284',
+  'message 1285',
+  'message 1286',
+  'message 1287',
+  'message 1288',
+  'message 1289',
+  'message 1290',
+  'message 1291',
+  'message 1292',
+  'message 1293',
+  'message 1294',
+  'message 1295',
+  'message 1296',
+  'message 1297',
+  'message 1298',
+  'message 1299',
+  'message 1300',
+  'message 1301',
+  'message 1302',
+  'message 1303',
+  'message 1304',
+  'message 1305',
+  'message 1306',
+  'message 1307',
+  'message 1308',
+  'message 1309',
+  'message 1310',
+  'message 1311',
+  'message 1312',
+  'message 1313',
+  'message 1314',
+  'message 1315',
+  'message 1316',
+  'message 1317',
+  'message 1318',
+  'message 1319',
+  'message 1320',
+  'message 1321',
+  'message 1322',
+  'message 1323',
+  'message 1324',
+  'message 1325',
+  'message 1326',
+  'message 1327',
+  'message 1328',
+  'message 1329',
+  'message 1330',
+  'message 1331',
+  'message 1332',
+  'message 1333',
+  'message 1334',
+  'message 1335',
+  'message 1336',
+  'message 1337',
+  'message 1338',
+  'message 1339',
+  'message 1340',
+  'message 1341',
+  'message 1342',
+  'message 1343',
+  'message 1344',
+  'message 1345',
+  'message 1346',
+  'message 1347',
+  'message 1348',
+  'message 1349',
+  'message 1350',
+  'message 1351',
+  'message 1352',
+  'message 1353',
+  'message 1354',
+  'message 1355',
+  'message 1356',
+  'message 1357',
+  'message 1358',
+  'message 1359',
+  'message 1360',
+  'message 1361',
+  'message 1362',
+  'message 1363',
+  'message 1364',
+  'message 1365',
+  'message 1366',
+  'message 1367',
+  'message 1368',
+  'message 1369',
+  'message 1370',
+  'message 1371',
+  'message 1372',
+  'message 1373',
+  'message 1374',
+  'message 1375',
+  'message 1376',
+  'message 1377',
+  'message 1378',
+  'message 1379',
+  'message 1380',
+  'message 1381',
+  'message 1382',
+  'message 1383',
+  'message 1384',
+  'message 1385',
+  'message 1386',
+  'message 1387',
+  'message 1388',
+  'message 1389',
+  'message 1390',
+  'message 1391',
+  'message 1392',
+  'message 1393',
+  'message 1394',
+  'message 1395',
+  'message 1396',
+  'message 1397',
+  'message 1398',
+  'message 1399',
+  'message 1400',
+  'message 1401',
+  'message 1402',
+  'message 1403',
+  'message 1404',
+  'message 1405',
+  'message 1406',
+  'message 1407',
+  'message 1408',
+  'message 1409',
+  'message 1410',
+  'message 1411',
+  'message 1412',
+  'message 1413',
+  'message 1414',
+  'message 1415',
+  'message 1416',
+  'message 1417',
+  'message 1418',
+  'message 1419',
+  'message 1420',
+  'message 1421',
+  'message 1422',
+  'message 1423',
+  'message 1424',
+  'message 1425',
+  'message 1426',
+  'message 1427',
+  'message 1428',
+  'message 1429',
+  'message 1430',
+  'message 1431',
+  'message 1432',
+  'message 1433',
+  'message 1434',
+  'message 1435',
+  'message 1436',
+  'message 1437',
+  'message 1438',
+  'message 1439',
+  'message 1440',
+  'message 1441',
+  'message 1442',
+  'message 1443',
+  'message 1444',
+  'message 1445',
+  'message 1446',
+  'message 1447',
+  'message 1448',
+  'message 1449',
+  'message 1450',
+  'message 1451',
+  'message 1452',
+  'message 1453',
+  'message 1454',
+  'message 1455',
+  'message 1456',
+  'message 1457',
+  'message 1458',
+  'message 1459',
+  'message 1460',
+  'message 1461',
+  'message 1462',
+  'message 1463',
+  'message 1464',
+  'message 1465',
+  'message 1466',
+  'message 1467',
+  'message 1468',
+  'message 1469',
+  'message 1470',
+  'message 1471',
+  'message 1472',
+  'message 1473',
+  'message 1474',
+  'message 1475',
+  'message 1476',
+  'message 1477',
+  'message 1478',
+  'message 1479',
+  'message 1480',
+  'message 1481',
+  'message 1482',
+  'message 1483',
+  'message 1484',
+  'message 1485',
+  'message 1486',
+  'message 1487',
+  'message 1488',
+  'message 1489',
+  'message 1490',
+  'message 1491',
+  'message 1492',
+  'message 1493',
+  'message 1494',
+  'message 1495',
+  'message 1496',
+  'message 1497',
+  'message 1498',
+  'message 1499',
+  'message 1500',
+  'message 1501',
+  'message 1502',
+  'message 1503',
+  'message 1504',
+  'message 1505',
+  'message 1506',
+  'message 1507',
+  'message 1508',
+  'message 1509',
+  'message 1510',
+  'message 1511',
+  'message 1512',
+  'message 1513',
+  'message 1514',
+  'message 1515',
+  'message 1516',
+  'message 1517',
+  'message 1518',
+  'message 1519',
+  'message 1520',
+  'message 1521',
+  'message 1522',
+  'message 1523',
+  'message 1524',
+  'message 1525',
+  'message 1526',
+  'message 1527',
+  'message 1528',
+  'message 1529',
+  'message 1530',
+  'message 1531',
+  'message 1532',
+  'message 1533',
+  'message 1534',
+  'message 1535',
+  'message 1536',
+  'message 1537',
+  'message 1538',
+  'message 1539',
+  'message 1540',
+  'message 1541',
+  'message 1542',
+  'message 1543',
+  'message 1544',
+  'message 1545',
+  'message 1546',
+  'message 1547',
+  'message 1548',
+  'message 1549',
+  'message 1550',
+  'message 1551',
+  'message 1552',
+  'message 1553',
+  'message 1554',
+  'message 1555',
+  'message 1556',
+  'message 1557',
+  'message 1558',
+  'message 1559',
+  'message 1560',
+  'message 1561',
+  'message 1562',
+  'message 1563',
+  'message 1564',
+  'message 1565',
+  'message 1566',
+  'message 1567',
+  'message 1568',
+  'message 1569',
+  'message 1570',
+  'message 1571',
+  'message 1572',
+  'message 1573',
+  'message 1574',
+  'message 1575',
+  'message 1576',
+  'message 1577',
+  'message 1578',
+  'message 1579',
+  'message 1580',
+  'message 1581',
+  'message 1582',
+  'message 1583',
+  'message 1584',
+  'message 1585',
+  'message 1586',
+  'message 1587',
+  'message 1588',
+  'message 1589',
+  'message 1590',
+  'message 1591',
+  'message 1592',
+  'message 1593',
+  'message 1594',
+  'message 1595',
+  'message 1596',
+  'message 1597',
+  'message 1598',
+  'message 1599',
+  'message 1600',
+  'message 1601',
+  'message 1602',
+  'message 1603',
+  'message 1604',
+  'message 1605',
+  'message 1606',
+  'message 1607',
+  'message 1608',
+  'message 1609',
+  'message 1610',
+  'message 1611',
+  'message 1612',
+  'message 1613',
+  'message 1614',
+  'message 1615',
+  'message 1616',
+  'message 1617',
+  'message 1618',
+  'message 1619',
+  'message 1620',
+  'message 1621',
+  'message 1622',
+  'message 1623',
+  'message 1624',
+  'message 1625',
+  'message 1626',
+  'message 1627',
+  'message 1628',
+  'message 1629',
+  'message 1630',
+  'message 1631',
+  'message 1632',
+  'message 1633',
+  'message 1634',
+  'message 1635',
+  'message 1636',
+  'message 1637',
+  'message 1638',
+  'message 1639',
+  'message 1640',
+  'message 1641',
+  'message 1642',
+  'message 1643',
+  'message 1644',
+  'message 1645',
+  'message 1646',
+  'message 1647',
+  'message 1648',
+  'message 1649',
+  'message 1650',
+  'message 1651',
+  'message 1652',
+  'message 1653',
+  'message 1654',
+  'message 1655',
+  'message 1656',
+  'message 1657',
+  'message 1658',
+  'message 1659',
+  'message 1660',
+  'message 1661',
+  'message 1662',
+  'message 1663',
+  'message 1664',
+  'message 1665',
+  'message 1666',
+  'message 1667',
+  'message 1668',
+  'message 1669',
+  'message 1670',
+  'message 1671',
+  'message 1672',
+  'message 1673',
+  'message 1674',
+  'message 1675',
+  'message 1676',
+  'message 1677',
+  'message 1678',
+  'message 1679',
+  'message 1680',
+  'message 1681',
+  'message 1682',
+  'message 1683',
+  'message 1684',
+  'message 1685',
+  'message 1686',
+  'message 1687',
+  'message 1688',
+  'message 1689',
+  'message 1690',
+  'message 1691',
+  'message 1692',
+  'message 1693',
+  'message 1694',
+  'message 1695',
+  'message 1696',
+  'message 1697',
+  'message 1698',
+  'message 1699',
+  'message 1700',
+  'message 1701',
+  'message 1702',
+  'message 1703',
+  'message 1704',
+  'message 1705',
+  'message 1706',
+  'message 1707',
+  'message 1708',
+  'message 1709',
+  'message 1710',
+  'message 1711',
+  'message 1712',
+  'message 1713',
+  'message 1714',
+  'message 1715',
+  'message 1716',
+  'message 1717',
+  'message 1718',
+  'message 1719',
+  'message 1720',
+  'message 1721',
+  'message 1722',
+  'message 1723',
+  'message 1724',
+  'message 1725',
+  'message 1726',
+  'message 1727',
+  'message 1728',
+  'message 1729',
+  'message 1730',
+  'message 1731',
+  'message 1732',
+  'message 1733',
+  'message 1734',
+  'message 1735',
+  'message 1736',
+  'message 1737',
+  'message 1738',
+  'message 1739',
+  'message 1740',
+  'message 1741',
+  'message 1742',
+  'message 1743',
+  'message 1744',
+  'message 1745',
+  'message 1746',
+  'message 1747',
+  'message 1748',
+  'message 1749',
+  'message 1750',
+  'message 1751',
+  'message 1752',
+  'message 1753',
+  'message 1754',
+  'message 1755',
+  'message 1756',
+  'message 1757',
+  'message 1758',
+  'message 1759',
+  'message 1760',
+  'message 1761',
+  'message 1762',
+  'message 1763',
+  'message 1764',
+  'message 1765',
+  'message 1766',
+  'message 1767',
+  'message 1768',
+  'message 1769',
+  'message 1770',
+  'message 1771',
+  'message 1772',
+  'message 1773',
+  'message 1774',
+  'message 1775',
+  'message 1776',
+  'message 1777',
+  'message 1778',
+  'message 1779',
+  'message 1780',
+  'message 1781',
+  'message 1782',
+  'message 1783',
+  'message 1784',
+  'message 1785',
+  'message 1786',
+  'message 1787',
+  'message 1788',
+  'message 1789',
+  'message 1790',
+  'message 1791',
+  'message 1792',
+  'message 1793',
+  'message 1794',
+  'message 1795',
+  'message 1796',
+  'message 1797',
+  'message 1798',
+  'message 1799',
+  'message 1800',
+  'message 1801',
+  'message 1802',
+  'message 1803',
+  'message 1804',
+  'message 1805',
+  'message 1806',
+  'message 1807',
+  'message 1808',
+  'message 1809',
+  'message 1810',
+  'message 1811',
+  'message 1812',
+  'message 1813',
+  'message 1814',
+  'message 1815',
+  'message 1816',
+  'message 1817',
+  'message 1818',
+  'message 1819',
+  'message 1820',
+  'message 1821',
+  'message 1822',
+  'message 1823',
+  'message 1824',
+  'message 1825',
+  'message 1826',
+  'message 1827',
+  'message 1828',
+  'message 1829',
+  'message 1830',
+  'message 1831',
+  'message 1832',
+  'message 1833',
+  'message 1834',
+  'message 1835',
+  'message 1836',
+  'message 1837',
+  'message 1838',
+  'message 1839',
+  'message 1840',
+  'message 1841',
+  'message 1842',
+  'message 1843',
+  'message 1844',
+  'message 1845',
+  'message 1846',
+  'message 1847',
+  'message 1848',
+  'message 1849',
+  'message 1850',
+  'message 1851',
+  'message 1852',
+  'message 1853',
+  'message 1854',
+  'message 1855',
+  'message 1856',
+  'message 1857',
+  'message 1858',
+  'message 1859',
+  'message 1860',
+  'message 1861',
+  'message 1862',
+  'message 1863',
+  'message 1864',
+  'message 1865',
+  'message 1866',
+  'message 1867',
+  'message 1868',
+  'message 1869',
+  'message 1870',
+  'message 1871',
+  'message 1872',
+  'message 1873',
+  'message 1874',
+  'message 1875',
+  'message 1876',
+  'message 1877',
+  'message 1878',
+  'message 1879',
+  'message 1880',
+  'message 1881',
+  'message 1882',
+  'message 1883',
+  'message 1884',
+  'message 1885',
+  'message 1886',
+  'message 1887',
+  'message 1888',
+  'message 1889',
+  'message 1890',
+  'message 1891',
+  'message 1892',
+  'message 1893',
+  'message 1894',
+  'message 1895',
+  'message 1896',
+  'message 1897',
+  'message 1898',
+  'message 1899',
+  'message 1900',
+  'message 1901',
+  'message 1902',
+  'message 1903',
+  'message 1904',
+  'message 1905',
+  'message 1906',
+  'message 1907',
+  'message 1908',
+  'message 1909',
+  'message 1910',
+  'message 1911',
+  'message 1912',
+  'message 1913',
+  'message 1914',
+  'message 1915',
+  'message 1916',
+  'message 1917',
+  'message 1918',
+  'message 1919',
+  'message 1920',
+  'message 1921',
+  'message 1922',
+  'message 1923',
+  'message 1924',
+  'message 1925',
+  'message 1926',
+  'message 1927',
+  'message 1928',
+  'message 1929',
+  'message 1930',
+  'message 1931',
+  'message 1932',
+  'message 1933',
+  'message 1934',
+  'message 1935',
+  'message 1936',
+  'message 1937',
+  'message 1938',
+  'message 1939',
+  'message 1940',
+  'message 1941',
+  'message 1942',
+  'message 1943',
+  'message 1944',
+  'message 1945',
+  'message 1946',
+  'message 1947',
+  'message 1948',
+  'message 1949',
+  'message 1950',
+  'message 1951',
+  'message 1952',
+  'message 1953',
+  'message 1954',
+  'message 1955',
+  'message 1956',
+  'message 1957',
+  'message 1958',
+  'message 1959',
+  'message 1960',
+  'message 1961',
+  'message 1962',
+  'message 1963',
+  'message 1964',
+  'message 1965',
+  'message 1966',
+  'message 1967',
+  'message 1968',
+  'message 1969',
+  'message 1970',
+  'message 1971',
+  'message 1972',
+  'message 1973',
+  'message 1974',
+  'message 1975',
+  'message 1976',
+  'message 1977',
+  'message 1978',
+  'message 1979',
+  'message 1980',
+  'message 1981',
+  'message 1982',
+  'message 1983',
+  'message 1984',
+  'message 1985',
+  'message 1986',
+  'message 1987',
+  'message 1988',
+  'message 1989',
+  'message 1990',
+  'message 1991',
+  'message 1992',
+  'message 1993',
+  'message 1994',
+  'message 1995',
+  'message 1996',
+  'message 1997',
+  'message 1998',
+  'message 1999',
+];
@@ -0,0 +1,3 @@
+import('./a');
+import('./c');
+console.log('index');
@@ -0,0 +1,9 @@
+const lang = navigator.language;
+const someVar = './' + lang;
+
+// expanded to a context module
+const messages = require('./locales/' + lang);
+const a = require('./a');
+const b = require(someVar);
+
+console.log(messages, a, b);
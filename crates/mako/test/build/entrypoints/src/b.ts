@@ -0,0 +1,4 @@
+import { shared } from './shared';
+import './style.css';
+
+console.log(shared);
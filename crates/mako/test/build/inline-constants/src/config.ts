@@ -0,0 +1 @@
+export { API_URL } from './constants';
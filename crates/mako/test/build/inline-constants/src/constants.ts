@@ -0,0 +1 @@
+export const API_URL = "https://example.com/api";
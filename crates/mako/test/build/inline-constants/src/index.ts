@@ -0,0 +1,5 @@
+import { API_URL } from './config';
+import { options } from './options';
+
+fetch(API_URL);
+console.log({ API_URL }, options);
@@ -0,0 +1 @@
+export const options = { retry: 3 };
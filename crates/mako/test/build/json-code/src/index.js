@@ -0,0 +1 @@
+import a from "./a.jsonc"; console.log(a);
@@ -0,0 +1,5 @@
+/**
+ * @license a v2.0.0
+ */
+const a = 1;
+export { a };
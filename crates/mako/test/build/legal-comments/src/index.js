@@ -0,0 +1,5 @@
+import { a } from './a';
+/*! index v1.0.0 | MIT */
+console.log(a);
+// a normal comment
+console.log(2);
@@ -0,0 +1,2 @@
+const api = { _value: 1 };
+import('./lazy').then((m) => m.read(api));
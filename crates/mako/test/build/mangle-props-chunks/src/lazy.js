@@ -0,0 +1 @@
+export const read = (api) => api._value;
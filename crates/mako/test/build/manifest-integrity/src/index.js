@@ -0,0 +1,2 @@
+import './index.css';
+console.log(1);
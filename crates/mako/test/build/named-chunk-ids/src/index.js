@@ -0,0 +1 @@
+import("./pages/user.js");
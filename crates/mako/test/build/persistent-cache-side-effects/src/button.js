@@ -0,0 +1,2 @@
+'use client';
+export default 'Button';
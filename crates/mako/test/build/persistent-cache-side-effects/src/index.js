@@ -0,0 +1,4 @@
+import { DatePicker } from 'foo';
+import Button from './button';
+import { bar } from './bar';
+console.log(DatePicker, Button, bar);
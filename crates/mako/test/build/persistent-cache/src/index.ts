@@ -0,0 +1,4 @@
+import { add } from './add';
+
+const result: number = add(1, 2);
+console.log(`result: ${result}`);
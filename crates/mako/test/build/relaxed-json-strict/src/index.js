@@ -0,0 +1 @@
+import a from "./a.jsonc"; import b from "./b.json5"; import c from "./c.json"; console.log(a, b, c);
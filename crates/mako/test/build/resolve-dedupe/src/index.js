@@ -0,0 +1,4 @@
+import React from 'react';
+import foo from 'foo';
+
+console.log(React.version, foo);
@@ -0,0 +1,5 @@
+'use client';
+
+import { label } from './label';
+
+export default () => label;
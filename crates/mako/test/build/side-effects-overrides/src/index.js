@@ -0,0 +1,2 @@
+import { foo } from 'broken';
+console.log(1);
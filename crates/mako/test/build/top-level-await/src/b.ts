@@ -0,0 +1,5 @@
+import { ab } from './a';
+import { value } from './value';
+
+export const b = value;
+export const ba = () => ab();
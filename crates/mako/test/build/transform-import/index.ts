@@ -0,0 +1,3 @@
+import { DatePicker } from 'foo';
+
+console.log(DatePicker);
@@ -0,0 +1,2 @@
+import { a, c } from './lib';
+console.log(a, c);
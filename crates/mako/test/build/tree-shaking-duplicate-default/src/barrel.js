@@ -0,0 +1,2 @@
+export { default } from './a';
+export { default } from './b';
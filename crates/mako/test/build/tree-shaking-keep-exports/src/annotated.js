@@ -0,0 +1,2 @@
+/*@__KEEP_EXPORTS__*/
+export const d = 'kept-by-annotation';
@@ -0,0 +1,2 @@
+export const a = 'used';
+export const b = 'kept-by-glob';
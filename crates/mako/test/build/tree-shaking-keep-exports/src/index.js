@@ -0,0 +1,4 @@
+import { a } from './globals/lib';
+import './annotated';
+import { c } from './other';
+console.log(a, c);
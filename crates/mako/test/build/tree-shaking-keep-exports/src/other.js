@@ -0,0 +1,2 @@
+export const c = 'used';
+export const e = 'not-kept';
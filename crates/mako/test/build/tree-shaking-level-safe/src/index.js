@@ -0,0 +1,3 @@
+import { helper } from './lib';
+
+console.log('index');
@@ -0,0 +1,7 @@
+function track(name) {
+  window.tracked = name;
+}
+
+track('lib');
+
+export const helper = () => 'helper';
@@ -0,0 +1,4 @@
+import used from './used.png';
+import unused from './unused.png';
+
+console.log(used);
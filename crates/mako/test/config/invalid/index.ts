@@ -1,2 +1 @@
 // keep
-
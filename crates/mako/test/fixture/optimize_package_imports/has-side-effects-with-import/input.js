@@ -1 +0,0 @@
-import { a, b } from './package';
@@ -1,3 +0,0 @@
-export const a = 'a';
-
-console.log(a);
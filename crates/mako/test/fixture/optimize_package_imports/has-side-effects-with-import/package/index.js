@@ -1,7 +0,0 @@
-// Barrel file with side-effects
-'use client';
-
-import { a } from './a';
-import { b } from './b';
-
-export { a, b };
@@ -1 +0,0 @@
-export const b = 'b';
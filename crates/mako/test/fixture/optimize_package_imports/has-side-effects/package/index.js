@@ -1,5 +0,0 @@
-// Barrel file with side-effects
-'use client';
-
-export { a } from './a';
-export { b } from './b';
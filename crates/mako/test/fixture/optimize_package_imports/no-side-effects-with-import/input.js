@@ -1 +0,0 @@
-import { aaa, b } from './package';
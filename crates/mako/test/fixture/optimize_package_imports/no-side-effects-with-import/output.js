@@ -1,2 +0,0 @@
-import { a as aaa } from "$DIR/test/fixture/optimize_package_imports/no-side-effects-with-import/package/a";
-import { b } from "$DIR/test/fixture/optimize_package_imports/no-side-effects-with-import/package/b";
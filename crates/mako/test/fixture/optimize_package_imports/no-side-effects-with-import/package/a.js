@@ -1 +0,0 @@
-export const a = 'a';
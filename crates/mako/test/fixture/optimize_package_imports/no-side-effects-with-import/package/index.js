@@ -1,7 +0,0 @@
-// Barrel file with no side-effects
-'use client';
-
-import { a as aa } from './a';
-import { b } from './b';
-
-export { aa as aaa, b };
@@ -1 +0,0 @@
-import { a, b, cc } from './package';
@@ -1,3 +0,0 @@
-import { a } from "$DIR/test/fixture/optimize_package_imports/no-side-effects/package/a";
-import b from "$DIR/test/fixture/optimize_package_imports/no-side-effects/package/b";
-import * as cc from "$DIR/test/fixture/optimize_package_imports/no-side-effects/package/c";
@@ -1,3 +0,0 @@
-const b = 'b';
-
-export default b;
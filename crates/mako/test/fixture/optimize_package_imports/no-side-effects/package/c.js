@@ -1 +0,0 @@
-export const c = 'c';
@@ -1,6 +0,0 @@
-// Barrel file with no side-effects
-'use client';
-
-export { a } from './a';
-export { default as b } from './b';
-export * as cc from './c';
@@ -0,0 +1,3 @@
+import foo from 'foo';
+
+console.log(foo);
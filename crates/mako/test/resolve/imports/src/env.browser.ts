@@ -0,0 +1 @@
+export default 'env.browser.ts';
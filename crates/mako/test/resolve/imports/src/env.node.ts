@@ -0,0 +1 @@
+export default 'env.node.ts';
@@ -0,0 +1,10 @@
+const assert = require("assert");
+const { parseBuildResult } = require("../../../scripts/test-utils");
+const { files } = parseBuildResult(__dirname);
+
+assert("i18n.js" in files, "should have i18n.js");
+assert(files["i18n.js"].includes("hello_en"), "i18n.js should include en");
+assert(files["i18n.js"].includes("hello_zh"), "i18n.js should include zh");
+assert(!files["i18n.js"].includes("format_util"), "i18n.js should not include util");
+assert(files["index.js"].includes("format_util"), "index.js should include util");
+assert(!files["index.js"].includes("hello_en"), "index.js should not include en");
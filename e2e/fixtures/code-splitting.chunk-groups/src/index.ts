@@ -0,0 +1,5 @@
+import en from './i18n/en';
+import zh from './i18n/zh';
+import { format } from './util';
+
+console.log(format(en.hello), format(zh.hello));
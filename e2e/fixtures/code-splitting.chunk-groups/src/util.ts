@@ -0,0 +1,3 @@
+export function format(text: string) {
+  return `format_util(${text})`;
+}
@@ -0,0 +1,18 @@
+const assert = require("assert");
+const { parseBuildResult } = require("../../../scripts/test-utils");
+const { files } = parseBuildResult(__dirname);
+
+const html = files["index.html"];
+assert.equal(
+  html.split("mako/modulepreload-polyfill").length - 1,
+  1,
+  "should inject modulepreload polyfill once"
+);
+assert(
+  html.indexOf("mako/modulepreload-polyfill") < html.indexOf(`<script type="module" src="/index.js">`),
+  "polyfill should be injected before other scripts"
+);
+assert(
+  html.includes(`<script nomodule src="/index.legacy.js"></script>`),
+  "html should contain the legacy script"
+);
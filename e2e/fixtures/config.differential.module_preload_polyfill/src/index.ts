@@ -0,0 +1,2 @@
+const foo = async () => 'foo';
+foo().then(console.log);
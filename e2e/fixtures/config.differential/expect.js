@@ -0,0 +1,35 @@
+const assert = require("assert");
+const { parseBuildResult } = require("../../../scripts/test-utils");
+const { files } = parseBuildResult(__dirname);
+
+assert("index.js" in files, "modern bundle should be emitted");
+assert("index.legacy.js" in files, "legacy bundle should be emitted");
+assert(
+  files["index.js"].includes("async ()"),
+  "modern bundle should keep modern syntax"
+);
+assert(
+  !files["index.legacy.js"].includes("async ()"),
+  "legacy bundle should be transpiled"
+);
+
+const images = Object.keys(files).filter((file) => file.endsWith(".png"));
+assert.equal(images.length, 1, "image should be shared between the two builds");
+assert(
+  files["index.legacy.js"].includes(images[0]),
+  "legacy bundle should reference the shared image"
+);
+assert(!("index.legacy.css" in files), "css should be shared between the two builds");
+
+const html = files["index.html"];
+assert(
+  html.includes(
+    `<script type="module" src="/index.js"></script><script nomodule src="/index.legacy.js"></script>`
+  ),
+  "html should contain the module/nomodule script pair"
+);
+assert(!html.includes(`<script src="/index.js">`), "original script should be replaced");
+assert(
+  !html.includes("mako/modulepreload-polyfill"),
+  "should not inject modulepreload polyfill by default"
+);
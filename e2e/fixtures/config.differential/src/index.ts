@@ -0,0 +1,5 @@
+import './index.css';
+import logo from './logo.png';
+
+const foo = async () => ({ logo });
+foo().then(console.log);
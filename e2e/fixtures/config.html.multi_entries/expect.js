@@ -0,0 +1,21 @@
+const assert = require('assert');
+const { parseBuildResult } = require('../../../scripts/test-utils');
+const { files } = parseBuildResult(__dirname);
+
+const home = files['home.html'];
+assert(home.includes('<title>Home Page</title>'), 'should substitute title');
+assert(
+  home.includes(
+    '<link rel="stylesheet" href="/home.css" /><script src="/home.js"></script></body>',
+  ),
+  'should inject home tags before </body>',
+);
+assert(!home.includes('about.js'), 'should not inject other entries');
+
+const about = files['about.html'];
+assert(about.includes('<title>About Page</title>'), 'should substitute title');
+assert(
+  about.includes('<script src="/about.js"></script>\n</head>'),
+  'should inject about tags at the marker',
+);
+assert(!about.includes('home.js'), 'should not inject other entries');
@@ -0,0 +1,2 @@
+import './home.css';
+console.log('home');
@@ -0,0 +1,31 @@
+const assert = require("assert");
+const { parseBuildResult } = require("../../../scripts/test-utils");
+const { files } = parseBuildResult(__dirname);
+
+const content = files["index.js"];
+assert.match(content, /^\s*System\.register\(/, "should be wrapped with System.register");
+
+// a minimal SystemJS loader
+const registrations = [];
+global.System = {
+  register(deps, declare) {
+    registrations.push({ deps, declare });
+  },
+};
+require("./dist/index.js");
+assert.strictEqual(registrations.length, 1, "should register once");
+
+const { deps, declare } = registrations[0];
+assert.deepStrictEqual(deps, ["react"], "externals should be the deps");
+const exports = {};
+const { setters, execute } = declare(function (name, value) {
+  if (typeof name === "object") Object.assign(exports, name);
+  else exports[name] = value;
+}, {});
+setters[0]({ __useDefault: true, default: { version: "18.2.0" } });
+execute();
+
+assert.strictEqual(exports.version, "18.2.0", "should import the external from deps");
+assert.strictEqual(exports.sum, 3, "should export the named bindings");
+assert.strictEqual(exports.default, "app", "should export the default binding");
+delete global.System;
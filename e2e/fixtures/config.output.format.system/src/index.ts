@@ -0,0 +1,6 @@
+import React from 'react';
+import { add } from './add';
+
+export const version = React.version;
+export const sum = add(1, 2);
+export default 'app';
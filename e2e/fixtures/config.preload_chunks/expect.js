@@ -0,0 +1,15 @@
+const assert = require("assert");
+const { parseBuildResult } = require("../../../scripts/test-utils");
+const { files } = parseBuildResult(__dirname);
+
+const homeFile = Object.keys(files).find(
+  (file) => file.includes("home") && file.endsWith(".js")
+);
+assert(homeFile, "async chunk of src/home.ts should be emitted");
+assert(/\.\w+\.js$/.test(homeFile), "async chunk should be hashed");
+
+const html = files["index.html"];
+assert(
+  html.includes(`<link rel="preload" href="/${homeFile}" as="script" /></head>`),
+  "should inject preload link of the designated chunk into html"
+);
@@ -0,0 +1,3 @@
+import('./home').then(({ default: home }) => {
+  console.log(home());
+});
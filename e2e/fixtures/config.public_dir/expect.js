@@ -0,0 +1,15 @@
+const assert = require("assert");
+const fs = require("fs");
+const path = require("path");
+
+const distDir = path.join(__dirname, "dist");
+
+assert.equal(
+  fs.readFileSync(path.join(distDir, "favicon.ico"), "utf-8"),
+  "favicon",
+  "favicon.ico should be copied to output root as is"
+);
+assert(
+  fs.existsSync(path.join(distDir, "images/logo.svg")),
+  "directory structure in publicDir should be preserved"
+);
@@ -0,0 +1,15 @@
+const assert = require("assert");
+const { parseBuildResult } = require("../../../scripts/test-utils");
+const { files } = parseBuildResult(__dirname);
+
+assert(files["index.css"].includes(".b"), "normal css should be extracted");
+assert(!files["index.css"].includes(".a"), "css with type: 'css' should not be extracted");
+
+require("./dist/index");
+
+// node has no constructable stylesheets, the shim is used
+const sheet = globalThis.sheet;
+assert(sheet.cssText.includes("color: red"), "default export should contain the css");
+assert.equal(typeof sheet.replaceSync, "function", "default export should be CSSStyleSheet-like");
+sheet.replaceSync(".a { color: green; }");
+assert(sheet.cssText.includes("green"), "replaceSync should work");
@@ -0,0 +1,4 @@
+import sheet from './a.css' with { type: 'css' };
+import './b.css';
+
+globalThis.sheet = sheet;
@@ -0,0 +1,17 @@
+const assert = require("assert");
+const { parseBuildResult } = require("../../../scripts/test-utils");
+const { files } = parseBuildResult(__dirname);
+
+["en", "zh", "ja"].forEach((lang) => {
+  const name = `src_locales_${lang}_js-async.js`;
+  assert(name in files, `should have separate chunk ${name}`);
+  assert(files[name].includes(`hello_${lang}`), `${name} should include hello_${lang}`);
+  assert(!files["index.js"].includes(`hello_${lang}`), `index.js should not include hello_${lang}`);
+});
+
+require("./dist/index");
+
+(async () => {
+  assert.strictEqual((await loadLocale("zh")).default, "hello_zh");
+  await assert.rejects(loadLocale("fr"), /Cannot find module '.\/fr.js'/);
+})();
@@ -0,0 +1 @@
+globalThis.loadLocale = (lang) => import(`./locales/${lang}.js`);
@@ -0,0 +1 @@
+export default 'hello_ja';
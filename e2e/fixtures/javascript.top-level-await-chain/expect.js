@@ -0,0 +1,18 @@
+const assert = require("assert");
+const { parseBuildResult, moduleReg, injectSimpleJest } = require("../../../scripts/test-utils");
+const { files } = parseBuildResult(__dirname);
+injectSimpleJest();
+
+assert.match(
+  files["index.js"],
+  moduleReg("src/double.ts", `__mako_require__._async.+`, true),
+  "importer of the top level await module should be an async module"
+);
+
+assert.match(
+  files["index.js"],
+  moduleReg("src/index.ts", `__mako_require__._async.+`, true),
+  "entry should be an async module"
+);
+
+require("./dist/index.js");
@@ -0,0 +1,11 @@
+import { double } from './double';
+
+const { triple } = await import('./lazy');
+
+it('should observe the awaited value in the importer', () => {
+  expect(double).toBe(42);
+});
+
+it('should observe the awaited value in the async chunk', () => {
+  expect(triple).toBe(63);
+});
@@ -0,0 +1,3 @@
+import { double } from './double';
+
+export const triple = double + 21;
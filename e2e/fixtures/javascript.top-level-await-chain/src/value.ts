@@ -0,0 +1,3 @@
+export const value = await new Promise<number>((resolve) =>
+  setTimeout(() => resolve(21), 10),
+);
@@ -0,0 +1,17 @@
+const assert = require("assert");
+const { parseBuildResult, injectSimpleJest } = require("../../../scripts/test-utils");
+const { files } = parseBuildResult(__dirname);
+
+const content = files["index.js"];
+injectSimpleJest();
+
+assert(
+  content.includes(`_apply_decs_2203`),
+  "TC39 decorators should be used",
+);
+assert(
+  !content.includes(`_ts_decorate`),
+  "legacy decorators should not be used",
+);
+
+require("./dist/index.js");
@@ -0,0 +1,14 @@
+let decoratedKind;
+
+function track(value, context) {
+  decoratedKind = context.kind;
+  return value;
+}
+
+@track
+class Foo {}
+
+it("should run the TC39 decorator", () => {
+  expect(decoratedKind).toBe("class");
+  expect(typeof Foo).toBe("function");
+});
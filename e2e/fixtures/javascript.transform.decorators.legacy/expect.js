@@ -0,0 +1,17 @@
+const assert = require("assert");
+const { parseBuildResult, injectSimpleJest } = require("../../../scripts/test-utils");
+const { files } = parseBuildResult(__dirname);
+
+const content = files["index.js"];
+injectSimpleJest();
+
+assert(
+  content.includes(`Foo = _ts_decorate._([`),
+  "legacy decorator should be used",
+);
+assert(
+  content.includes(`_ts_metadata._("design:paramtypes", [`),
+  "decorator metadata should be emitted",
+);
+
+require("./dist/index.js");
@@ -0,0 +1,16 @@
+class Service {}
+
+function Injectable() {
+  return function (theClass) {
+    theClass.injectable = true;
+  };
+}
+
+@Injectable()
+class Foo {
+  constructor(private service: Service) {}
+}
+
+it("should run the legacy decorator", () => {
+  expect(Foo.injectable).toBe(true);
+});
@@ -0,0 +1,21 @@
+const assert = require('assert');
+const { parseBuildResult, moduleReg } = require('../../../scripts/test-utils');
+const { files } = parseBuildResult(__dirname);
+
+const content = files['index.js'];
+
+assert.strictEqual(
+  (content.match(/function _class_call_check\(/g) || []).length,
+  1,
+  'should emit _class_call_check helper only once',
+);
+assert.match(
+  content,
+  moduleReg('src/a.ts', '_class_call_check._(this, A)', true),
+  'a.ts should reference the shared helper',
+);
+assert.match(
+  content,
+  moduleReg('src/b.ts', '_class_call_check._(this, B)', true),
+  'b.ts should reference the shared helper',
+);
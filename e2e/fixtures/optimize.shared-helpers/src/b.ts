@@ -0,0 +1,3 @@
+export class B {
+  name = 'b';
+}
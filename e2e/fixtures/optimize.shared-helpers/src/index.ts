@@ -0,0 +1,4 @@
+import { A } from './a';
+import { B } from './b';
+
+console.log(new A(), new B());
@@ -0,0 +1,23 @@
+const assert = require("assert");
+const { parseBuildResult } = require("../../../scripts/test-utils");
+const { files } = parseBuildResult(__dirname);
+
+const content = files["index.js"];
+
+assert(
+  content.includes(`"use client"`),
+  "should preserve the use client directive after minify"
+);
+
+const manifest = JSON.parse(files["client-reference-manifest.json"]);
+assert.deepStrictEqual(
+  manifest["src/button.ts"],
+  { id: "src/button.ts", chunks: ["src/button.ts"] },
+  "should contain the client module in client reference manifest"
+);
+assert(
+  Object.keys(files).some(
+    (name) => name.endsWith("-async.js") && files[name].includes(`"use client"`)
+  ),
+  "should split the client module into its own chunk"
+);
@@ -0,0 +1,5 @@
+'use client';
+
+export default () => {
+  return 'button';
+}
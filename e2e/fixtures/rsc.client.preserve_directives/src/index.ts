@@ -0,0 +1,3 @@
+import Button from './button';
+
+console.log(Button());
@@ -0,0 +1,17 @@
+const assert = require("assert");
+
+const warnings = [];
+const warn = console.warn;
+console.warn = (...args) => warnings.push(args.join(" "));
+try {
+  require("./dist/index");
+} finally {
+  console.warn = warn;
+}
+
+assert.strictEqual(warnings.length, 1, "should warn once");
+assert.match(
+  warnings[0],
+  /circular dependency: .*a\.js -> .*b\.js -> .*a\.js, "a" of ".*a\.js" is accessed before it is initialized/,
+  "should warn on accessing uninitialized export in a cycle",
+);
@@ -0,0 +1,7 @@
+import { b } from './b';
+
+export var a = 'a';
+
+export function getB() {
+  return b;
+}
@@ -0,0 +1,6 @@
+import { a, getB } from './a';
+
+// `a` is not initialized yet, but hoisted `getB` is
+console.log(a, typeof getB);
+
+export var b = 'b';
@@ -0,0 +1,3 @@
+import { a, getB } from './a';
+
+console.log(a, getB());
@@ -0,0 +1,14 @@
+const assert = require("assert");
+const { parseBuildResult } = require("../../../scripts/test-utils");
+const { files } = parseBuildResult(__dirname);
+
+const content = files["index.js"];
+
+assert(content.includes(`used_upper_`), "should keep used static method");
+assert(content.includes(`used_prefix`), "should keep static property used by this");
+assert(!content.includes(`unused_lower_`), "should remove unused static method");
+assert(!content.includes(`unused_suffix`), "should remove unused static property");
+assert(
+  content.includes(`escaped_register`) && content.includes(`escaped_unregister`),
+  "should keep all static members of instantiated class",
+);
@@ -0,0 +1,4 @@
+import { Registry, StringUtils } from './utils';
+
+console.log(StringUtils.upper('a'), StringUtils.wrap('b'));
+console.log(new Registry());
@@ -0,0 +1,22 @@
+export class StringUtils {
+  static upper(s: string) {
+    return `used_upper_${s.toUpperCase()}`;
+  }
+  static lower(s: string) {
+    return `unused_lower_${s.toLowerCase()}`;
+  }
+  static prefix = 'used_prefix';
+  static suffix = 'unused_suffix';
+  static wrap(s: string) {
+    return `${this.prefix}${s}`;
+  }
+}
+
+export class Registry {
+  static register() {
+    return 'escaped_register';
+  }
+  static unregister() {
+    return 'escaped_unregister';
+  }
+}
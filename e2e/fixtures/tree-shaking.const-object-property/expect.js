@@ -0,0 +1,14 @@
+const assert = require("assert");
+const { parseBuildResult } = require("../../../scripts/test-utils");
+const { files } = parseBuildResult(__dirname);
+
+const content = files["index.js"];
+
+assert(content.includes(`color_red`), "should keep used property");
+assert(!content.includes(`color_green`), "should remove unused property");
+assert(content.includes(`size_small`), "should keep used property of frozen object");
+assert(!content.includes(`size_large`), "should remove unused property of frozen object");
+assert(
+  content.includes(`shape_circle`) && content.includes(`shape_square`),
+  "should keep all properties of escaped object",
+);
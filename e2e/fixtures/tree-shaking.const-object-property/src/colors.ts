@@ -0,0 +1,8 @@
+const red = "color_red";
+const green = "color_green";
+
+export const Colors = { red, green };
+
+export const Sizes = Object.freeze({ small: "size_small", large: "size_large" });
+
+export const Shapes = { circle: "shape_circle", square: "shape_square" };
@@ -0,0 +1,5 @@
+import { Colors, Sizes, Shapes } from './colors';
+
+console.log(Colors.red, Sizes["small"]);
+// escaped, all properties are kept
+console.log(Object.keys(Shapes));
@@ -0,0 +1,22 @@
+const assert = require('assert');
+const { parseBuildResult } = require('../../../scripts/test-utils');
+const { files } = parseBuildResult(__dirname);
+
+const content = files['index.js'];
+
+assert(
+  content.includes('node_modules/barrel/lib/foo.js'),
+  'should import foo from the origin module',
+);
+assert(
+  !content.includes('node_modules/barrel/index.js'),
+  'should not import from the barrel',
+);
+assert(
+  !content.includes('node_modules/barrel/lib/bar.js'),
+  'should not include unused re-exports',
+);
+assert(
+  content.includes('node_modules/effects/index.js'),
+  'should keep importing from the barrel with side effects',
+);
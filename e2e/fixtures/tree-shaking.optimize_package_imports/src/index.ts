@@ -0,0 +1,4 @@
+import { foo } from 'barrel';
+import { qux } from 'effects';
+
+console.log(foo, qux);
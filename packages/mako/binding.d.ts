@@ -132,7 +132,13 @@ export interface BuildParams {
     targets?: Record<string, number>;
     platform?: 'node' | 'browser';
     hmr?: false | {};
-    devServer?: false | { host?: string; port?: number };
+    devServer?:
+      | false
+      | {
+          host?: string;
+          port?: number;
+          mimeTypes?: Record<string, string>;
+        };
     px2rem?:
       | false
       | {
@@ -196,6 +202,7 @@ export interface BuildParams {
       | {
           skipModules?: boolean;
         };
+    optimizePackageImports?: boolean;
     react?: {
       runtime?: 'automatic' | 'classic';
       pragma?: string;
@@ -226,5 +233,6 @@ export interface BuildParams {
   };
   plugins: Array<JsHooks>;
   watch: boolean;
+  watchBuild?: boolean;
 }
 export function build(buildParams: BuildParams): Promise<void>;
@@ -27,6 +27,7 @@ import { check } from './checker';
         break;
       }
       let watch = argv.watch || argv.w || false;
+      let watchBuild = argv.watchBuild || false;
       let root = argv.root || process.cwd();
       check(root);
       await require('./').build({
@@ -36,6 +37,7 @@ import { check } from './checker';
         },
         plugins: [],
         watch,
+        watchBuild,
       });
       break;
     case undefined:
@@ -77,9 +79,11 @@ function showBuildHelp() {
   console.log(`  --help,-h`);
   console.log(`  --root`);
   console.log(`  --watch,-w`);
+  console.log(`  --watch-build`);
   console.log(``);
   console.log(`Examples:`);
   console.log(`  mako build`);
   console.log(`  mako build --watch`);
+  console.log(`  mako build --watch-build`);
   console.log(`  mako build --root ./src`);
 }
@@ -19,6 +19,7 @@ type BuildParams = {
   config: Config;
   root: binding.BuildParams['root'];
   watch: binding.BuildParams['watch'];
+  watchBuild?: binding.BuildParams['watchBuild'];
 };
 
 export { BuildParams };
@@ -107,7 +108,7 @@ export async function build(params: BuildParams) {
       }
     },
     generateEnd() {
-      if (!params.watch) {
+      if (!params.watch && !params.watchBuild) {
         less.terminate();
       }
     },
@@ -128,7 +129,7 @@ export async function build(params: BuildParams) {
         }
       },
       generateEnd() {
-        if (!params.watch) {
+        if (!params.watch && !params.watchBuild) {
           sass.terminate();
         }
       },
@@ -198,7 +199,7 @@ export async function build(params: BuildParams) {
   if (params.config.forkTSChecker) {
     let forkTypeChecker = new ForkTSChecker({
       root: params.root,
-      watch: params.watch,
+      watch: params.watch || !!params.watchBuild,
     });
     forkTypeChecker.runTypeCheckInChildProcess();
   }
@@ -11,6 +11,7 @@ build({
   root: cwd,
   config,
   watch: process.argv.includes('--watch'),
+  watchBuild: process.argv.includes('--watch-build'),
 }).catch((e) => {
   console.error(e);
   process.exit(1);
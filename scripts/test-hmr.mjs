@@ -139,6 +139,51 @@ export default Foo;
   await cleanup({ process, browser });
 });
 
+runTest('js: import() boundary, change lazy module', async () => {
+  write(
+    normalizeFiles({
+      '/src/index.tsx': `
+document.getElementById("root")!.innerHTML = \`<div id="lazy"></div><section>\${Math.random()}</section>\`;
+import('./lazy');
+    `,
+      '/src/lazy.ts': `
+document.getElementById("lazy")!.innerHTML = "Lazy";
+export default 1;
+    `,
+    }),
+  );
+  const { process } = await startMakoDevServer();
+  await delay(DELAY_TIME);
+  const { browser, page } = await startBrowser();
+  let lastResult;
+  let thisResult;
+  let isReload;
+  lastResult = normalizeHtml(await getRootHtml(page));
+  assert.equal(
+    lastResult.html,
+    '<div id="lazy">Lazy</div>',
+    'Initial render',
+  );
+  write({
+    '/src/lazy.ts': `
+document.getElementById("lazy")!.innerHTML = "Lazy Modified";
+export default 1;
+    `,
+  });
+  await delay(DELAY_TIME);
+  thisResult = normalizeHtml(await getRootHtml(page));
+  console.log(`new html`, thisResult.html);
+  assert.equal(
+    thisResult.html,
+    '<div id="lazy">Lazy Modified</div>',
+    'Initial render 2',
+  );
+  isReload = lastResult.random !== thisResult.random;
+  assert.equal(isReload, false, 'should not run the importer again');
+  lastResult = thisResult;
+  await cleanup({ process, browser });
+});
+
 runTest('js: hmr with runtime promise reject error', async () => {
   write(
     normalizeFiles({
@@ -1737,6 +1782,156 @@ export default () => {
   });
   await delay(DELAY_TIME);
 });
+runTest('dev: serve files in publicDir', async () => {
+  write(
+    normalizeFiles(
+      {
+        '/public/favicon.ico': 'favicon',
+        '/src/index.tsx': `
+import React from 'react';
+import ReactDOM from "react-dom/client";
+ReactDOM.createRoot(document.getElementById("root")!).render(<div>App</div>);
+    `,
+      },
+      { publicDir: 'public', copy: [] },
+    ),
+  );
+  const { process } = await startMakoDevServer();
+  await delay(DELAY_TIME);
+  const res = await fetch(`http://localhost:${MAKO_DEV_PORT}/favicon.ico`);
+  assert.equal(res.status, 200, 'favicon.ico status');
+  assert.equal(await res.text(), 'favicon', 'favicon.ico content');
+  assert(
+    !fs.existsSync(path.join(tmp, 'dist', 'favicon.ico')),
+    'publicDir should not be copied in dev',
+  );
+  await cleanup({ process });
+});
+
+runTest('dev: respond content-type by mime types', async () => {
+  write(
+    normalizeFiles(
+      {
+        '/public/app.wasm': 'wasm',
+        '/public/app.glb': 'glb',
+        '/src/index.tsx': `
+import React from 'react';
+import ReactDOM from "react-dom/client";
+ReactDOM.createRoot(document.getElementById("root")!).render(<div>App</div>);
+    `,
+      },
+      {
+        publicDir: 'public',
+        copy: [],
+        devServer: { mimeTypes: { glb: 'model/gltf-binary' } },
+      },
+    ),
+  );
+  const { process } = await startMakoDevServer();
+  await delay(DELAY_TIME);
+  let res = await fetch(`http://localhost:${MAKO_DEV_PORT}/app.wasm`);
+  assert.equal(res.status, 200, 'app.wasm status');
+  assert.equal(
+    res.headers.get('content-type'),
+    'application/wasm',
+    'wasm content-type',
+  );
+  res = await fetch(`http://localhost:${MAKO_DEV_PORT}/app.glb`);
+  assert.equal(
+    res.headers.get('content-type'),
+    'model/gltf-binary',
+    'custom content-type',
+  );
+  await cleanup({ process });
+});
+
+runTest('dev: write hot update files to hmr.hotUpdateDir', async () => {
+  const hotUpdateDir = path.join(root, 'tmp', 'hot-update');
+  fs.rmSync(hotUpdateDir, { recursive: true, force: true });
+  write(
+    normalizeFiles(
+      {
+        '/src/App.tsx': `
+export default () => {
+  return <div>App</div>;
+};
+    `,
+        '/src/index.tsx': `
+import React from 'react';
+import ReactDOM from "react-dom/client";
+import App from './App';
+ReactDOM.createRoot(document.getElementById("root")!).render(<App />);
+    `,
+      },
+      { hmr: { hotUpdateDir } },
+    ),
+  );
+  const { process } = await startMakoDevServer();
+  await delay(DELAY_TIME);
+  const { browser, page } = await startBrowser();
+  let lastResult;
+  lastResult = normalizeHtml(await getRootHtml(page));
+  assert.equal(lastResult.html, '<div>App</div>', 'Initial render');
+  write({
+    '/src/App.tsx': `
+export default () => {
+  return <div>App Modified</div>;
+};
+    `,
+  });
+  await delay(DELAY_TIME);
+  lastResult = normalizeHtml(await getRootHtml(page));
+  assert.equal(
+    lastResult.html,
+    '<div>App Modified</div>',
+    'should apply hot update served from hotUpdateDir',
+  );
+  assert(
+    fs.readdirSync(hotUpdateDir).some((file) => file.endsWith('.hot-update.js')),
+    'should write hot update files to hotUpdateDir',
+  );
+  assert(
+    !fs
+      .readdirSync(path.join(tmp, 'dist'))
+      .some((file) => file.includes('hot-update')),
+    'should not write hot update files to dist',
+  );
+  await cleanup({ process, browser });
+  fs.rmSync(hotUpdateDir, { recursive: true, force: true });
+});
+
+runTest('watch-build: rewrite output files on change', async () => {
+  write(
+    normalizeFiles({
+      '/src/index.tsx': `
+console.log('before change');
+    `,
+    }),
+  );
+  const { process } = await startMakoWatchBuild();
+  const output = path.join(tmp, 'dist', 'index.js');
+  assert(
+    fs.readFileSync(output, 'utf-8').includes('before change'),
+    'initial build',
+  );
+  write({
+    '/src/index.tsx': `
+console.log('after change');
+    `,
+  });
+  await delay(DELAY_TIME);
+  const content = fs.readFileSync(output, 'utf-8');
+  assert(content.includes('after change'), 'output should be rewritten');
+  assert(!content.includes('before change'), 'output should be rewritten');
+  assert(
+    !fs
+      .readdirSync(path.join(tmp, 'dist'))
+      .some((file) => file.includes('hot-update')),
+    'should not emit hot update files',
+  );
+  await cleanup({ process });
+});
+
 function normalizeFiles(files, makoConfig = {}) {
   return {
     '/public/index.html': `
@@ -1806,6 +2001,22 @@ async function startMakoDevServer() {
   return { process: p };
 }
 
+async function startMakoWatchBuild() {
+  const p = $`${path.join(
+    root,
+    'scripts',
+    'mako.js',
+  )} ${tmp} --watch-build`.nothrow();
+  const output = path.join(tmp, 'dist', 'index.js');
+  let waited = 0;
+  while (!fs.existsSync(output) && waited < 10000) {
+    await delay(100);
+    waited += 100;
+  }
+  assert(fs.existsSync(output), 'watch build should write the output');
+  return { process: p };
+}
+
 async function startBrowser() {
   const browser = await chromium.launch();
   const context = await browser.newContext(devices['iPhone 11']);
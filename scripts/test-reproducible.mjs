@@ -0,0 +1,53 @@
+import assert from 'assert';
+import test from 'node:test';
+import 'zx/globals';
+
+// build the same fixture twice in separate processes,
+// and the outputs should be byte-identical
+const root = process.cwd();
+const fixtures = path.join(root, 'e2e/fixtures');
+const dirs = argv.only
+  ? [argv.only]
+  : [
+      'code-splitting.complex',
+      'code-splitting.granular',
+      'config.manifest',
+      'css.css-modules',
+    ];
+
+function readOutputs(dir) {
+  const files = {};
+  function walk(current) {
+    for (const file of fs.readdirSync(current)) {
+      const p = path.join(current, file);
+      if (fs.statSync(p).isDirectory()) {
+        walk(p);
+      } else {
+        files[path.relative(dir, p)] = fs.readFileSync(p);
+      }
+    }
+  }
+  walk(dir);
+  return files;
+}
+
+for (const dir of dirs) {
+  await test(dir, async () => {
+    const cwd = path.join(fixtures, dir);
+    const dist = path.join(cwd, 'dist');
+    const outputs = [];
+    for (let i = 0; i < 2; i++) {
+      await $`${path.join(root, 'scripts', 'mako.js')} ${cwd}`;
+      outputs.push(readOutputs(dist));
+    }
+    const [first, second] = outputs;
+    assert.deepEqual(
+      Object.keys(second).sort(),
+      Object.keys(first).sort(),
+      'output files should be the same',
+    );
+    for (const file of Object.keys(first)) {
+      assert(first[file].equals(second[file]), `${file} should be identical`);
+    }
+  });
+}